@@ -1,6 +1,8 @@
 #![allow(missing_docs)]
 //! Defines fluent builder for styles.
 
+use std::cell::Cell;
+
 use bevy::{
     asset::AssetPath,
     color::{LinearRgba, Srgba},
@@ -15,6 +17,7 @@ pub struct StyleBuilder<'a, 'w> {
     pub target: &'a mut EntityWorldMut<'w>,
     pub(crate) node: ui::Node,
     pub(crate) node_changed: bool,
+    pub(crate) uses_layout_direction: Cell<bool>,
 }
 
 impl<'a, 'w> StyleBuilder<'a, 'w> {
@@ -24,6 +27,7 @@ impl<'a, 'w> StyleBuilder<'a, 'w> {
             target,
             node,
             node_changed: false,
+            uses_layout_direction: Cell::new(false),
         }
     }
 
@@ -35,6 +39,23 @@ impl<'a, 'w> StyleBuilder<'a, 'w> {
         })
     }
 
+    /// Returns the current [`LayoutDirection`](crate::LayoutDirection), which is left-to-right
+    /// unless the resource has been inserted.
+    pub fn layout_direction(&self) -> crate::LayoutDirection {
+        self.uses_layout_direction.set(true);
+        self.target
+            .world()
+            .get_resource::<crate::LayoutDirection>()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns true if any of the styles applied so far depend on the
+    /// [`LayoutDirection`](crate::LayoutDirection), and so need to be re-applied when it changes.
+    pub fn uses_layout_direction(&self) -> bool {
+        self.uses_layout_direction.get()
+    }
+
     /// Consumes the [`StyleBuilder`] and applies the style to the target entity.
     pub fn finish(self) {
         if self.node_changed {
@@ -93,7 +114,8 @@ impl ColorParam for &str {
     }
 }
 
-/// Trait that represents a CSS "length"
+/// Trait that represents a CSS "length". Every [`StyleBuilder`] method which takes a length
+/// accepts any of: a [`ui::Val`], or a bare `f32` or `i32`, which is interpreted as pixels.
 pub trait LengthParam {
     fn to_val(self) -> ui::Val;
 }
@@ -0,0 +1,82 @@
+use super::builder::{ColorParam, LengthParam, StyleBuilder};
+use bevy::ui;
+
+#[allow(missing_docs)]
+pub trait StyleBuilderBoxShadow {
+    /// Set the drop shadow of the node. Passing a color of `None` removes the shadow.
+    fn box_shadow(
+        &mut self,
+        offset_x: impl LengthParam,
+        offset_y: impl LengthParam,
+        blur: impl LengthParam,
+        spread: impl LengthParam,
+        color: impl ColorParam,
+    ) -> &mut Self;
+}
+
+impl<'a, 'w> StyleBuilderBoxShadow for StyleBuilder<'a, 'w> {
+    fn box_shadow(
+        &mut self,
+        offset_x: impl LengthParam,
+        offset_y: impl LengthParam,
+        blur: impl LengthParam,
+        spread: impl LengthParam,
+        color: impl ColorParam,
+    ) -> &mut Self {
+        match color.to_val() {
+            Some(color) => {
+                self.target.insert(ui::BoxShadow {
+                    color,
+                    x_offset: offset_x.to_val(),
+                    y_offset: offset_y.to_val(),
+                    spread_radius: spread.to_val(),
+                    blur_radius: blur.to_val(),
+                });
+            }
+            None => {
+                self.target.remove::<ui::BoxShadow>();
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::{color::Color, prelude::World};
+
+    #[test]
+    fn test_box_shadow() {
+        let mut world = World::default();
+        let id = world.spawn_empty().id();
+
+        let mut target = world.entity_mut(id);
+        StyleBuilder::new(&mut target, ui::Node::default()).box_shadow(2, 4, 8, 0, Color::BLACK);
+        let shadow = world.get::<ui::BoxShadow>(id).unwrap();
+        assert_eq!(shadow.x_offset, ui::Val::Px(2.));
+        assert_eq!(shadow.y_offset, ui::Val::Px(4.));
+        assert_eq!(shadow.blur_radius, ui::Val::Px(8.));
+        assert_eq!(shadow.spread_radius, ui::Val::Px(0.));
+        assert_eq!(shadow.color, Color::BLACK);
+
+        // Setting the shadow again updates the existing component.
+        let mut target = world.entity_mut(id);
+        StyleBuilder::new(&mut target, ui::Node::default()).box_shadow(0, 1, 3, 1, Color::WHITE);
+        let shadow = world.get::<ui::BoxShadow>(id).unwrap();
+        assert_eq!(shadow.y_offset, ui::Val::Px(1.));
+        assert_eq!(shadow.spread_radius, ui::Val::Px(1.));
+        assert_eq!(shadow.color, Color::WHITE);
+
+        // A color of `None` removes the shadow.
+        let mut target = world.entity_mut(id);
+        StyleBuilder::new(&mut target, ui::Node::default()).box_shadow(
+            0,
+            0,
+            0,
+            0,
+            Option::<Color>::None,
+        );
+        assert!(world.get::<ui::BoxShadow>(id).is_none());
+    }
+}
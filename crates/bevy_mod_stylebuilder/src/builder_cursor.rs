@@ -0,0 +1,124 @@
+use bevy::{
+    picking::{focus::HoverMap, pointer::PointerId},
+    prelude::*,
+    window::SystemCursorIcon,
+    winit::cursor::{CursorIcon, CustomCursor},
+};
+
+use super::builder::{MaybeHandleOrPath, StyleBuilder};
+
+/// A component which can be added to an entity to specify the cursor that should be used when
+/// the mouse is over the entity. Relies on the picking hover map to determine which entity is
+/// being hovered; if the hovered entity has no cursor, its ancestors are searched.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub enum Cursor {
+    /// Don't show a cursor. Often used when we want to display a 3d cursor instead.
+    Hidden,
+
+    /// Show one of the standard system cursors.
+    Icon(SystemCursorIcon),
+
+    /// Show a custom cursor image, with the given hotspot in pixels.
+    Image(Handle<Image>, Vec2),
+}
+
+#[allow(missing_docs)]
+pub trait StyleBuilderCursor {
+    fn cursor(&mut self, icon: SystemCursorIcon) -> &mut Self;
+    fn cursor_builtin(&mut self, icon: SystemCursorIcon) -> &mut Self;
+    fn cursor_image<'p>(
+        &mut self,
+        path: impl Into<MaybeHandleOrPath<'p, Image>>,
+        hotspot: Vec2,
+    ) -> &mut Self;
+    fn cursor_hidden(&mut self) -> &mut Self;
+}
+
+impl<'a, 'w> StyleBuilderCursor for StyleBuilder<'a, 'w> {
+    fn cursor(&mut self, icon: SystemCursorIcon) -> &mut Self {
+        self.cursor_builtin(icon)
+    }
+
+    fn cursor_builtin(&mut self, icon: SystemCursorIcon) -> &mut Self {
+        set_cursor(self.target, Cursor::Icon(icon));
+        self
+    }
+
+    fn cursor_image<'p>(
+        &mut self,
+        path: impl Into<MaybeHandleOrPath<'p, Image>>,
+        hotspot: Vec2,
+    ) -> &mut Self {
+        match path.into() {
+            MaybeHandleOrPath::Handle(h) => set_cursor(self.target, Cursor::Image(h, hotspot)),
+            MaybeHandleOrPath::Path(p) => {
+                let image = self.load_asset::<Image>(p);
+                set_cursor(self.target, Cursor::Image(image, hotspot));
+            }
+            MaybeHandleOrPath::None => {
+                self.target.remove::<Cursor>();
+            }
+        };
+        self
+    }
+
+    fn cursor_hidden(&mut self) -> &mut Self {
+        set_cursor(self.target, Cursor::Hidden);
+        self
+    }
+}
+
+fn set_cursor(target: &mut EntityWorldMut, cursor: Cursor) {
+    match target.get_mut::<Cursor>() {
+        Some(mut current) => {
+            if *current != cursor {
+                *current = cursor;
+            }
+        }
+        None => {
+            target.insert(cursor);
+        }
+    }
+}
+
+/// System which sets the window cursor based on the [`Cursor`] of the hovered entity.
+pub(crate) fn update_cursor(
+    mut commands: Commands,
+    hover_map: Option<Res<HoverMap>>,
+    parent_query: Query<&Parent>,
+    cursor_query: Query<&Cursor>,
+    mut windows: Query<(Entity, &mut Window, Option<&CursorIcon>)>,
+) {
+    let cursor = hover_map.and_then(|hover_map| match hover_map.get(&PointerId::Mouse) {
+        Some(hover_set) => hover_set.keys().find_map(|entity| {
+            cursor_query.get(*entity).ok().or_else(|| {
+                parent_query
+                    .iter_ancestors(*entity)
+                    .find_map(|e| cursor_query.get(e).ok())
+            })
+        }),
+        None => None,
+    });
+
+    let (visible, icon) = match cursor {
+        Some(Cursor::Hidden) => (false, CursorIcon::from(SystemCursorIcon::Default)),
+        Some(Cursor::Icon(icon)) => (true, CursorIcon::from(*icon)),
+        Some(Cursor::Image(image, hotspot)) => (
+            true,
+            CursorIcon::from(CustomCursor::Image {
+                handle: image.clone(),
+                hotspot: (hotspot.x as u16, hotspot.y as u16),
+            }),
+        ),
+        None => (true, CursorIcon::from(SystemCursorIcon::Default)),
+    };
+
+    for (entity, mut window, current) in windows.iter_mut() {
+        if window.cursor_options.visible != visible {
+            window.cursor_options.visible = visible;
+        }
+        if current != Some(&icon) {
+            commands.entity(entity).insert(icon.clone());
+        }
+    }
+}
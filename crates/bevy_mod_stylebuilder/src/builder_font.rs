@@ -7,6 +7,9 @@ use bevy::prelude::*;
 
 pub trait StyleBuilderFont {
     fn color(&mut self, color: impl ColorParam) -> &mut Self;
+    /// Set the text color, which is inherited by descendant text nodes that use
+    /// [`UseInheritedTextStyles`](crate::UseInheritedTextStyles). Same as `color`.
+    fn font_color(&mut self, color: impl ColorParam) -> &mut Self;
     fn font<'p>(&mut self, path: impl Into<MaybeHandleOrPath<'p, Font>>) -> &mut Self;
     fn font_size(&mut self, val: impl OptFloatParam) -> &mut Self;
 }
@@ -25,6 +28,10 @@ impl<'a, 'w> StyleBuilderFont for StyleBuilder<'a, 'w> {
         self
     }
 
+    fn font_color(&mut self, color: impl ColorParam) -> &mut Self {
+        self.color(color)
+    }
+
     fn font<'p>(&mut self, path: impl Into<MaybeHandleOrPath<'p, Font>>) -> &mut Self {
         let font = match path.into() {
             MaybeHandleOrPath::Handle(h) => Some(h),
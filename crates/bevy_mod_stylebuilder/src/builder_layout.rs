@@ -1,11 +1,27 @@
 use super::builder::{LengthParam, OptFloatParam, StyleBuilder, UiRectParam};
-use bevy::ui;
+use bevy::{prelude::Resource, ui};
+
+/// Resource which determines the inline (reading) direction used by the logical style
+/// properties such as [`StyleBuilderLayout::padding_inline_start`], and by
+/// [`StyleBuilderLayout::flex_row`]. If the resource is not present, left-to-right is assumed.
+///
+/// Changing the resource causes any styles which were applied through `bevy_quill` to be
+/// re-applied.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutDirection {
+    /// Left-to-right: the inline start is on the left.
+    #[default]
+    Ltr,
+    /// Right-to-left: the inline start is on the right.
+    Rtl,
+}
 
 #[allow(missing_docs)]
 pub trait StyleBuilderLayout {
     fn display(&mut self, disp: ui::Display) -> &mut Self;
 
-    /// Set the display to flex and the flex direction to row.
+    /// Set the display to flex and the flex direction to row. The row is reversed when the
+    /// [`LayoutDirection`] is right-to-left.
     fn flex_row(&mut self) -> &mut Self;
 
     /// Set the display to flex and the flex direction to column.
@@ -31,11 +47,33 @@ pub trait StyleBuilderLayout {
     fn margin_right(&mut self, length: impl LengthParam) -> &mut Self;
     fn margin_top(&mut self, length: impl LengthParam) -> &mut Self;
     fn margin_bottom(&mut self, length: impl LengthParam) -> &mut Self;
+
+    /// Set the margin on the side where reading starts: left, or right if the
+    /// [`LayoutDirection`] is right-to-left.
+    fn margin_inline_start(&mut self, length: impl LengthParam) -> &mut Self;
+
+    /// Set the margin on the side where reading ends: right, or left if the
+    /// [`LayoutDirection`] is right-to-left.
+    fn margin_inline_end(&mut self, length: impl LengthParam) -> &mut Self;
     fn padding(&mut self, rect: impl UiRectParam) -> &mut Self;
     fn padding_left(&mut self, length: impl LengthParam) -> &mut Self;
     fn padding_right(&mut self, length: impl LengthParam) -> &mut Self;
     fn padding_top(&mut self, length: impl LengthParam) -> &mut Self;
     fn padding_bottom(&mut self, length: impl LengthParam) -> &mut Self;
+
+    /// Set the padding on the side where reading starts: left, or right if the
+    /// [`LayoutDirection`] is right-to-left.
+    fn padding_inline_start(&mut self, length: impl LengthParam) -> &mut Self;
+
+    /// Set the padding on the side where reading ends: right, or left if the
+    /// [`LayoutDirection`] is right-to-left.
+    fn padding_inline_end(&mut self, length: impl LengthParam) -> &mut Self;
+
+    /// Set the padding at the start of the block direction (the top).
+    fn padding_block_start(&mut self, length: impl LengthParam) -> &mut Self;
+
+    /// Set the padding at the end of the block direction (the bottom).
+    fn padding_block_end(&mut self, length: impl LengthParam) -> &mut Self;
     fn border(&mut self, rect: impl UiRectParam) -> &mut Self;
     fn border_left(&mut self, length: impl LengthParam) -> &mut Self;
     fn border_right(&mut self, length: impl LengthParam) -> &mut Self;
@@ -80,7 +118,10 @@ impl<'a, 'w> StyleBuilderLayout for StyleBuilder<'a, 'w> {
 
     fn flex_row(&mut self) -> &mut Self {
         self.node.display = ui::Display::Flex;
-        self.node.flex_direction = ui::FlexDirection::Row;
+        self.node.flex_direction = match self.layout_direction() {
+            LayoutDirection::Ltr => ui::FlexDirection::Row,
+            LayoutDirection::Rtl => ui::FlexDirection::RowReverse,
+        };
         self.node_changed = true;
         self
     }
@@ -213,6 +254,20 @@ impl<'a, 'w> StyleBuilderLayout for StyleBuilder<'a, 'w> {
         self
     }
 
+    fn margin_inline_start(&mut self, length: impl LengthParam) -> &mut Self {
+        match self.layout_direction() {
+            LayoutDirection::Ltr => self.margin_left(length),
+            LayoutDirection::Rtl => self.margin_right(length),
+        }
+    }
+
+    fn margin_inline_end(&mut self, length: impl LengthParam) -> &mut Self {
+        match self.layout_direction() {
+            LayoutDirection::Ltr => self.margin_right(length),
+            LayoutDirection::Rtl => self.margin_left(length),
+        }
+    }
+
     fn padding(&mut self, rect: impl UiRectParam) -> &mut Self {
         self.node.padding = rect.to_uirect();
         self.node_changed = true;
@@ -243,6 +298,28 @@ impl<'a, 'w> StyleBuilderLayout for StyleBuilder<'a, 'w> {
         self
     }
 
+    fn padding_inline_start(&mut self, length: impl LengthParam) -> &mut Self {
+        match self.layout_direction() {
+            LayoutDirection::Ltr => self.padding_left(length),
+            LayoutDirection::Rtl => self.padding_right(length),
+        }
+    }
+
+    fn padding_inline_end(&mut self, length: impl LengthParam) -> &mut Self {
+        match self.layout_direction() {
+            LayoutDirection::Ltr => self.padding_right(length),
+            LayoutDirection::Rtl => self.padding_left(length),
+        }
+    }
+
+    fn padding_block_start(&mut self, length: impl LengthParam) -> &mut Self {
+        self.padding_top(length)
+    }
+
+    fn padding_block_end(&mut self, length: impl LengthParam) -> &mut Self {
+        self.padding_bottom(length)
+    }
+
     fn border(&mut self, rect: impl UiRectParam) -> &mut Self {
         self.node.border = rect.to_uirect();
         self.node_changed = true;
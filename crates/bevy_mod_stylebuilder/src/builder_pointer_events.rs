@@ -5,6 +5,21 @@ use super::builder::StyleBuilder;
 #[allow(missing_docs)]
 pub trait StyleBuilderPointerEvents {
     fn pointer_events(&mut self, enabled: bool) -> &mut Self;
+
+    /// Enable picking for this element: it can be hovered, and blocks picking of the elements
+    /// underneath it. This is the default.
+    fn pointer_events_all(&mut self) -> &mut Self;
+
+    /// Disable picking for this element entirely: it is neither hovered nor blocks the elements
+    /// underneath it. Its children can still be picked, since picking is not inherited.
+    fn pointer_events_off(&mut self) -> &mut Self;
+
+    /// Make this element a pass-through container: it can still be hovered, but does not block
+    /// picking of the elements underneath it. Unlike [`pointer_events_off`], this lets the
+    /// element track hover state while events also reach whatever lies behind it.
+    ///
+    /// [`pointer_events_off`]: StyleBuilderPointerEvents::pointer_events_off
+    fn pointer_events_container(&mut self) -> &mut Self;
 }
 
 impl<'a, 'w> StyleBuilderPointerEvents for StyleBuilder<'a, 'w> {
@@ -15,4 +30,20 @@ impl<'a, 'w> StyleBuilderPointerEvents for StyleBuilder<'a, 'w> {
         };
         self
     }
+
+    fn pointer_events_all(&mut self) -> &mut Self {
+        self.pointer_events(true)
+    }
+
+    fn pointer_events_off(&mut self) -> &mut Self {
+        self.pointer_events(false)
+    }
+
+    fn pointer_events_container(&mut self) -> &mut Self {
+        self.target.insert(PickingBehavior {
+            should_block_lower: false,
+            is_hoverable: true,
+        });
+        self
+    }
 }
@@ -0,0 +1,56 @@
+use super::builder::StyleBuilder;
+use bevy::{
+    math::{Quat, Vec2},
+    transform::components::Transform,
+};
+
+/// Methods which set the 2D transform of a UI node. Each method only changes its own part of
+/// the [`Transform`], so they can be combined with each other, and with animations which
+/// update a different part of the transform.
+pub trait StyleBuilderTransform {
+    /// Set the rotation of the node around the z axis, in radians.
+    fn rotate_z(&mut self, angle_radians: f32) -> &mut Self;
+
+    /// Set the scale of the node.
+    fn scale_2d(&mut self, scale: Vec2) -> &mut Self;
+
+    /// Set the translation of the node.
+    fn translate_2d(&mut self, offset: Vec2) -> &mut Self;
+}
+
+impl<'a, 'w> StyleBuilder<'a, 'w> {
+    /// Update the target's transform, inserting a default one if there is none.
+    fn update_transform(&mut self, update: impl FnOnce(&mut Transform)) {
+        match self.target.get_mut::<Transform>() {
+            Some(mut transform) => update(&mut transform),
+            None => {
+                let mut transform = Transform::default();
+                update(&mut transform);
+                self.target.insert(transform);
+            }
+        }
+    }
+}
+
+impl<'a, 'w> StyleBuilderTransform for StyleBuilder<'a, 'w> {
+    fn rotate_z(&mut self, angle_radians: f32) -> &mut Self {
+        self.update_transform(|transform| {
+            transform.rotation = Quat::from_rotation_z(angle_radians);
+        });
+        self
+    }
+
+    fn scale_2d(&mut self, scale: Vec2) -> &mut Self {
+        self.update_transform(|transform| {
+            transform.scale = scale.extend(transform.scale.z);
+        });
+        self
+    }
+
+    fn translate_2d(&mut self, offset: Vec2) -> &mut Self {
+        self.update_transform(|transform| {
+            transform.translation = offset.extend(transform.translation.z);
+        });
+        self
+    }
+}
@@ -3,10 +3,14 @@ mod builder;
 mod builder_background;
 mod builder_border_color;
 mod builder_border_radius;
+mod builder_box_shadow;
+#[cfg(feature = "cursor")]
+mod builder_cursor;
 mod builder_font;
 mod builder_layout;
 mod builder_outline;
 mod builder_pointer_events;
+mod builder_transform;
 mod builder_visibility;
 mod builder_z_index;
 mod text_styles;
@@ -23,10 +27,14 @@ pub use builder::*;
 pub use builder_background::StyleBuilderBackground;
 pub use builder_border_color::StyleBuilderBorderColor;
 pub use builder_border_radius::StyleBuilderBorderRadius;
+pub use builder_box_shadow::StyleBuilderBoxShadow;
+#[cfg(feature = "cursor")]
+pub use builder_cursor::{Cursor, StyleBuilderCursor};
 pub use builder_font::StyleBuilderFont;
-pub use builder_layout::StyleBuilderLayout;
+pub use builder_layout::{LayoutDirection, StyleBuilderLayout};
 pub use builder_outline::StyleBuilderOutline;
 pub use builder_pointer_events::StyleBuilderPointerEvents;
+pub use builder_transform::StyleBuilderTransform;
 pub use builder_visibility::StyleBuilderVisibility;
 pub use builder_z_index::StyleBuilderZIndex;
 use text_styles::update_text_styles;
@@ -147,5 +155,7 @@ pub struct StyleBuilderPlugin;
 impl Plugin for StyleBuilderPlugin {
     fn build(&self, app: &mut bevy::app::App) {
         app.add_systems(Update, update_text_styles.in_set(StyleBuilderSystemSet));
+        #[cfg(feature = "cursor")]
+        app.add_systems(Update, builder_cursor::update_cursor);
     }
 }
@@ -92,3 +92,57 @@ fn compute_inherited_style(
     let color = TextColor(styles.color.unwrap_or(Color::WHITE));
     (font, color)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::{color::palettes::css, ecs::system::RunSystemOnce};
+
+    #[test]
+    fn test_inherited_text_color() {
+        let mut world = World::default();
+        let root = world
+            .spawn(InheritableFontStyles {
+                color: Some(css::RED.into()),
+                font_size: Some(20.),
+                ..default()
+            })
+            .id();
+        let middle = world
+            .spawn(InheritableFontStyles {
+                font_size: Some(16.),
+                ..default()
+            })
+            .set_parent(root)
+            .id();
+        let inherits = world
+            .spawn((Text::new("inherits"), UseInheritedTextStyles))
+            .set_parent(middle)
+            .id();
+        let overrides = world
+            .spawn((
+                Text::new("overrides"),
+                UseInheritedTextStyles,
+                InheritableFontStyles {
+                    color: Some(css::BLUE.into()),
+                    ..default()
+                },
+            ))
+            .set_parent(middle)
+            .id();
+
+        world.run_system_once(update_text_styles).unwrap();
+
+        // The color comes from the nearest ancestor which sets it.
+        assert_eq!(
+            world.get::<TextColor>(inherits).unwrap().0,
+            Color::from(css::RED)
+        );
+        assert_eq!(world.get::<TextFont>(inherits).unwrap().font_size, 16.);
+        // A node which sets its own color does not inherit one.
+        assert_eq!(
+            world.get::<TextColor>(overrides).unwrap().0,
+            Color::from(css::BLUE)
+        );
+    }
+}
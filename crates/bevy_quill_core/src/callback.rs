@@ -7,10 +7,22 @@ use bevy::{
 
 use crate::Cx;
 
-/// Contains a reference to a callback. `P` is the type of the props.
-#[derive(PartialEq, Debug)]
-pub struct Callback<P: SystemInput = ()> {
-    pub(crate) id: SystemId<P, ()>,
+/// Contains a reference to a callback. `P` is the type of the props, and `R` is the type of
+/// the value returned by the callback.
+pub struct Callback<P: SystemInput = (), R = ()> {
+    pub(crate) id: SystemId<P, R>,
+}
+
+impl<P: SystemInput, R> PartialEq for Callback<P, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<P: SystemInput, R> std::fmt::Debug for Callback<P, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Callback").field("id", &self.id).finish()
+    }
 }
 
 pub trait AnyCallback: 'static {
@@ -20,28 +32,28 @@ pub trait AnyCallback: 'static {
 
 impl dyn AnyCallback + Send + Sync {
     /// Get the original typed callback.
-    pub fn downcast<P: SystemInput + 'static>(&self) -> Callback<P> {
-        if TypeId::of::<P>() == self.type_id() {
+    pub fn downcast<P: SystemInput + 'static, R: 'static>(&self) -> Callback<P, R> {
+        if TypeId::of::<(P, R)>() == self.type_id() {
             // Safe because we just checked the type.
-            unsafe { *(self as *const dyn AnyCallback as *const Callback<P>) }
+            unsafe { *(self as *const dyn AnyCallback as *const Callback<P, R>) }
         } else {
             panic!("downcast failed")
         }
     }
 }
 
-impl<P: SystemInput + 'static> AnyCallback for Callback<P> {
+impl<P: SystemInput + 'static, R: 'static> AnyCallback for Callback<P, R> {
     fn remove(&self, world: &mut World) {
         // println!("Removing callback");
         world.unregister_system(self.id).unwrap();
     }
     fn type_id(&self) -> TypeId {
-        TypeId::of::<P>()
+        TypeId::of::<(P, R)>()
     }
 }
 
-impl<P: SystemInput> Copy for Callback<P> {}
-impl<P: SystemInput> Clone for Callback<P> {
+impl<P: SystemInput, R> Copy for Callback<P, R> {}
+impl<P: SystemInput, R> Clone for Callback<P, R> {
     fn clone(&self) -> Self {
         *self
     }
@@ -76,7 +88,7 @@ impl<'p, 'w> RunCallback for Cx<'p, 'w> {
         P: SystemInput + 'static,
         P::Inner<'static>: Send,
     {
-        self.world_mut().run_callback(callback, props);
+        self.with_world_mut(|world| world.run_callback(callback, props));
     }
 }
 
@@ -97,3 +109,29 @@ impl Command for UnregisterCallbackCmd {
         self.0.remove(world)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::TrackingScope;
+
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Received(Option<bool>);
+
+    #[test]
+    fn test_callback_with_input() {
+        let mut world = World::default();
+        world.init_resource::<Received>();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let owner = world.spawn_empty().id();
+        let mut cx = Cx::new(&mut world, owner, &mut scope);
+
+        // Callback inputs are passed as `In<T>`, as with any other Bevy system.
+        let callback = cx.create_callback(|value: In<bool>, world: &mut World| {
+            world.resource_mut::<Received>().0 = Some(*value);
+        });
+        cx.run_callback(callback, true);
+        assert_eq!(world.resource::<Received>().0, Some(true));
+    }
+}
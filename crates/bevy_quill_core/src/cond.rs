@@ -1,7 +1,7 @@
 use bevy::ecs::world::{DeferredWorld, World};
 use bevy::prelude::*;
 
-use crate::{Cx, View};
+use crate::{tracking_scope::TrackingScope, Cx, View};
 
 // Cond
 
@@ -22,6 +22,14 @@ impl<Pos: View, Neg: View> Cond<Pos, Neg> {
     pub fn new(test: bool, pos: Pos, neg: Neg) -> Self {
         Self { test, pos, neg }
     }
+
+    /// Construct a `Cond` View which keeps both branches alive. Instead of razing the inactive
+    /// branch, its display nodes are hidden with [`Display::None`] and its reactions are
+    /// suspended, so that any local state (mutables, scroll position, etc.) is retained when
+    /// the condition flips back. Each branch is built the first time it becomes active.
+    pub fn new_persistent(test: bool, pos: Pos, neg: Neg) -> CondPersistent<Pos, Neg> {
+        CondPersistent { test, pos, neg }
+    }
 }
 
 impl<Pos: View, Neg: View> View for Cond<Pos, Neg> {
@@ -62,7 +70,7 @@ impl<Pos: View, Neg: View> View for Cond<Pos, Neg> {
 
                 _ => {
                     // Despawn old state and construct new state
-                    self.raze(&mut DeferredWorld::from(cx.world_mut()), state);
+                    cx.with_world_mut(|world| self.raze(&mut DeferredWorld::from(world), state));
                     *state = Self::State::True(self.pos.build(cx));
                     true
                 }
@@ -76,7 +84,7 @@ impl<Pos: View, Neg: View> View for Cond<Pos, Neg> {
 
                 _ => {
                     // Despawn old state and construct new state
-                    self.raze(&mut DeferredWorld::from(cx.world_mut()), state);
+                    cx.with_world_mut(|world| self.raze(&mut DeferredWorld::from(world), state));
                     *state = Self::State::False(self.neg.build(cx));
                     true
                 }
@@ -104,3 +112,274 @@ impl<Pos: View, Neg: View> View for Cond<Pos, Neg> {
         }
     }
 }
+
+// CondPersistent
+
+/// State for a single branch of a [`CondPersistent`].
+pub struct PersistentBranch<S> {
+    state: S,
+    /// Tracking scopes which were spawned by this branch.
+    scopes: Vec<Entity>,
+    /// Display nodes which were hidden, along with their original display mode.
+    hidden: Vec<(Entity, Display)>,
+}
+
+pub struct CondPersistentState<Pos, Neg> {
+    active: bool,
+    pos: Option<PersistentBranch<Pos>>,
+    neg: Option<PersistentBranch<Neg>>,
+}
+
+/// A conditional view, like [`Cond`], which hides the inactive branch rather than razing it.
+/// Constructed via [`Cond::new_persistent`].
+pub struct CondPersistent<Pos: View, Neg: View> {
+    test: bool,
+    pos: Pos,
+    neg: Neg,
+}
+
+impl<Pos: View, Neg: View> View for CondPersistent<Pos, Neg> {
+    type State = CondPersistentState<Pos::State, Neg::State>;
+
+    fn nodes(&self, world: &World, state: &Self::State, out: &mut Vec<Entity>) {
+        #[cfg(feature = "verbose")]
+        info!("nodes()");
+
+        // Inactive nodes stay attached to the parent, they are just not displayed.
+        if let Some(pos) = &state.pos {
+            self.pos.nodes(world, &pos.state, out);
+        }
+        if let Some(neg) = &state.neg {
+            self.neg.nodes(world, &neg.state, out);
+        }
+    }
+
+    fn build(&self, cx: &mut Cx) -> Self::State {
+        #[cfg(feature = "verbose")]
+        info!("build()");
+
+        if self.test {
+            CondPersistentState {
+                active: true,
+                pos: Some(build_branch(&self.pos, cx)),
+                neg: None,
+            }
+        } else {
+            CondPersistentState {
+                active: false,
+                pos: None,
+                neg: Some(build_branch(&self.neg, cx)),
+            }
+        }
+    }
+
+    fn rebuild(&self, cx: &mut Cx, state: &mut Self::State) -> bool {
+        #[cfg(feature = "verbose")]
+        info!("rebuild()");
+
+        if self.test == state.active {
+            return if self.test {
+                rebuild_branch(&self.pos, cx, state.pos.as_mut().unwrap())
+            } else {
+                rebuild_branch(&self.neg, cx, state.neg.as_mut().unwrap())
+            };
+        }
+
+        state.active = self.test;
+        if self.test {
+            if let Some(neg) = state.neg.as_mut() {
+                cx.with_world_mut(|world| hide_branch(&self.neg, world, neg));
+            }
+            match state.pos.as_mut() {
+                Some(pos) => {
+                    cx.with_world_mut(|world| show_branch(world, pos));
+                    rebuild_branch(&self.pos, cx, pos);
+                }
+                None => state.pos = Some(build_branch(&self.pos, cx)),
+            }
+        } else {
+            if let Some(pos) = state.pos.as_mut() {
+                cx.with_world_mut(|world| hide_branch(&self.pos, world, pos));
+            }
+            match state.neg.as_mut() {
+                Some(neg) => {
+                    cx.with_world_mut(|world| show_branch(world, neg));
+                    rebuild_branch(&self.neg, cx, neg);
+                }
+                None => state.neg = Some(build_branch(&self.neg, cx)),
+            }
+        }
+        true
+    }
+
+    fn attach_children(&self, world: &mut World, state: &mut Self::State) -> bool {
+        #[cfg(feature = "verbose")]
+        info!("attach_children()",);
+
+        let mut changed = false;
+        if let Some(pos) = state.pos.as_mut() {
+            changed |= self.pos.attach_children(world, &mut pos.state);
+        }
+        if let Some(neg) = state.neg.as_mut() {
+            changed |= self.neg.attach_children(world, &mut neg.state);
+        }
+        changed
+    }
+
+    fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
+        #[cfg(feature = "verbose")]
+        info!("raze()");
+
+        if let Some(pos) = state.pos.as_mut() {
+            self.pos.raze(world, &mut pos.state);
+        }
+        if let Some(neg) = state.neg.as_mut() {
+            self.neg.raze(world, &mut neg.state);
+        }
+    }
+}
+
+/// Return the child tracking scopes of the current owner.
+fn owned_scopes(cx: &Cx) -> Vec<Entity> {
+    let world = cx.world();
+    world
+        .get::<Children>(cx.owner())
+        .map(|children| {
+            children
+                .iter()
+                .copied()
+                .filter(|child| world.get::<TrackingScope>(*child).is_some())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Record any tracking scopes which were added to the owner since `before` was taken.
+fn add_new_scopes(cx: &Cx, before: &[Entity], scopes: &mut Vec<Entity>) {
+    let world = cx.world();
+    scopes.retain(|scope| world.get_entity(*scope).is_ok());
+    for scope in owned_scopes(cx) {
+        if !before.contains(&scope) && !scopes.contains(&scope) {
+            scopes.push(scope);
+        }
+    }
+}
+
+fn build_branch<V: View>(view: &V, cx: &mut Cx) -> PersistentBranch<V::State> {
+    let before = owned_scopes(cx);
+    let state = view.build(cx);
+    let mut scopes = Vec::new();
+    add_new_scopes(cx, &before, &mut scopes);
+    PersistentBranch {
+        state,
+        scopes,
+        hidden: Vec::new(),
+    }
+}
+
+fn rebuild_branch<V: View>(view: &V, cx: &mut Cx, branch: &mut PersistentBranch<V::State>) -> bool {
+    let before = owned_scopes(cx);
+    let changed = view.rebuild(cx, &mut branch.state);
+    add_new_scopes(cx, &before, &mut branch.scopes);
+    changed
+}
+
+fn hide_branch<V: View>(view: &V, world: &mut World, branch: &mut PersistentBranch<V::State>) {
+    let mut nodes: Vec<Entity> = Vec::new();
+    view.nodes(world, &branch.state, &mut nodes);
+    branch.hidden.clear();
+    for node in nodes {
+        if let Some(mut style) = world.get_mut::<Node>(node) {
+            branch.hidden.push((node, style.display));
+            style.display = Display::None;
+        }
+    }
+    suspend_scopes(world, &branch.scopes, true);
+}
+
+fn show_branch<S>(world: &mut World, branch: &mut PersistentBranch<S>) {
+    for (node, display) in branch.hidden.drain(..) {
+        if let Some(mut style) = world.get_mut::<Node>(node) {
+            style.display = display;
+        }
+    }
+    suspend_scopes(world, &branch.scopes, false);
+}
+
+/// Set the suspended flag on the given tracking scopes and all of their descendant scopes.
+fn suspend_scopes(world: &mut World, scopes: &[Entity], suspended: bool) {
+    let mut stack: Vec<Entity> = scopes.to_vec();
+    while let Some(entity) = stack.pop() {
+        let Ok(mut entt) = world.get_entity_mut(entity) else {
+            continue;
+        };
+        if let Some(mut scope) = entt.get_mut::<TrackingScope>() {
+            scope.suspended = suspended;
+        }
+        if let Some(children) = entt.get::<Children>() {
+            stack.extend(children.iter().copied());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nodes<V: View>(world: &World, view: &V, state: &V::State) -> Vec<Entity> {
+        let mut out = Vec::new();
+        View::nodes(view, world, state, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_cond_persistent() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+
+        let view = Cond::new_persistent(true, "yes", "no");
+        let mut state = View::build(&view, &mut Cx::new(&mut world, owner, &mut scope));
+        let yes_nodes = nodes(&world, &view, &state);
+        assert_eq!(yes_nodes.len(), 1);
+        assert!(state.neg.is_none());
+
+        // Flipping the condition hides the old branch instead of despawning it.
+        let view = Cond::new_persistent(false, "yes", "no");
+        assert!(View::rebuild(
+            &view,
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state
+        ));
+        world.flush();
+        let all_nodes = nodes(&world, &view, &state);
+        assert_eq!(all_nodes.len(), 2);
+        assert_eq!(all_nodes[0], yes_nodes[0]);
+        assert_eq!(
+            world.get::<Node>(yes_nodes[0]).unwrap().display,
+            Display::None
+        );
+        assert_eq!(
+            world.get::<Node>(all_nodes[1]).unwrap().display,
+            Display::Flex
+        );
+
+        // Flipping back restores the original entity and shows it again.
+        let view = Cond::new_persistent(true, "yes", "no");
+        assert!(View::rebuild(
+            &view,
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state
+        ));
+        world.flush();
+        assert_eq!(nodes(&world, &view, &state), all_nodes);
+        assert_eq!(
+            world.get::<Node>(yes_nodes[0]).unwrap().display,
+            Display::Flex
+        );
+        assert_eq!(
+            world.get::<Node>(all_nodes[1]).unwrap().display,
+            Display::None
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::Component;
+
+/// Component which holds a value provided to descendant views by [`Cx::provide_context`].
+/// The component lives on the entity of the view which provided it, so it is removed when
+/// that view is razed.
+///
+/// [`Cx::provide_context`]: crate::Cx::provide_context
+#[derive(Component)]
+pub(crate) struct Context<T: Send + Sync + 'static>(pub(crate) T);
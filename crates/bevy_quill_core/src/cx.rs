@@ -1,15 +1,29 @@
 use std::{cell::RefCell, marker::PhantomData, sync::Arc};
 
 use bevy::{
+    asset::{Asset, AssetServer, Assets, Handle, LoadState},
+    core::Name,
     ecs::{
-        bundle::Bundle, event::Event, observer::Observer, system::IntoObserverSystem,
+        bundle::Bundle,
+        event::Event,
+        observer::Observer,
+        query::{QueryFilter, ROQueryItem, ReadOnlyQueryData},
+        system::IntoObserverSystem,
         world::DeferredWorld,
     },
     hierarchy::{BuildChildren, Parent},
-    prelude::{Component, Entity, IntoSystem, Resource, SystemInput, World},
+    prelude::{Component, Entity, IntoSystem, Mut, Resource, SystemInput, World},
+    state::state::{FreelyMutableState, NextState, State, States},
+    time::Time,
 };
 
-use crate::{mutable::Mutable, tracking_scope::HookState, Callback, MutableCell, WriteMutable};
+use crate::{
+    context::Context,
+    mutable::{Mutable, NamedMutableRegistry, ResourceSelector},
+    tracking_scope::HookState,
+    view_template::ViewTemplateType,
+    Callback, MutableCell, WriteMutable,
+};
 use crate::{tracking_scope::TrackingScope, ReadMutable};
 
 #[derive(Clone)]
@@ -32,6 +46,70 @@ impl Default for EffectOptions {
     }
 }
 
+/// The load state of an asset, as returned by [`Cx::use_asset`].
+pub enum AssetLoadState<'a, A: Asset> {
+    /// The asset has not finished loading yet.
+    Loading,
+    /// The asset is loaded.
+    Loaded(&'a A),
+    /// The asset could not be loaded.
+    Failed,
+}
+
+impl<'a, A: Asset> AssetLoadState<'a, A> {
+    /// Return the asset if it is loaded.
+    pub fn loaded(&self) -> Option<&'a A> {
+        match self {
+            AssetLoadState::Loaded(asset) => Some(asset),
+            _ => None,
+        }
+    }
+}
+
+/// Component which holds the state of a timer created by [`Cx::create_interval`] or
+/// [`Cx::create_timeout`].
+#[derive(Component)]
+pub(crate) struct ViewTimer {
+    /// Time between firings, in seconds.
+    duration: f32,
+    /// Time elapsed since the timer was started or last fired, in seconds.
+    elapsed: f32,
+    /// Whether the timer fires repeatedly, or only once.
+    repeat: bool,
+    /// Set once a non-repeating timer has fired.
+    finished: bool,
+    callback: Arc<dyn Fn(&mut World) + Send + Sync>,
+}
+
+/// System which advances the timers created by [`Cx::create_interval`] and
+/// [`Cx::create_timeout`], and runs the callbacks of the ones which have elapsed. A timer fires
+/// at most once per frame.
+pub(crate) fn run_view_timers(world: &mut World) {
+    let Some(delta) = world.get_resource::<Time>().map(|time| time.delta_secs()) else {
+        return;
+    };
+    let mut q_timers = world.query::<&mut ViewTimer>();
+    let mut callbacks: Vec<Arc<dyn Fn(&mut World) + Send + Sync>> = Vec::new();
+    for mut timer in q_timers.iter_mut(world) {
+        if timer.finished {
+            continue;
+        }
+        timer.elapsed += delta;
+        if timer.elapsed >= timer.duration {
+            callbacks.push(timer.callback.clone());
+            if timer.repeat && timer.duration > 0. {
+                // Drop any backlog, rather than firing repeatedly to catch up.
+                timer.elapsed %= timer.duration;
+            } else {
+                timer.finished = true;
+            }
+        }
+    }
+    for callback in callbacks {
+        callback(world);
+    }
+}
+
 /// A context parameter that is passed to views and callbacks. It contains the reactive
 /// tracking scope, which is used to manage reactive dependencies, as well as a reference to
 /// the Bevy world.
@@ -62,18 +140,72 @@ impl<'p, 'w> Cx<'p, 'w> {
     }
 
     /// Access to mutable world from reactive context.
+    #[deprecated(note = "use with_world_mut")]
     pub fn world_mut(&mut self) -> &mut World {
         self.world
     }
 
+    /// Run a closure with mutable access to the world, and return its result. In debug builds,
+    /// this panics if the closure despawned the entity which owns the tracking scope, which
+    /// would otherwise cause a confusing failure later on. In release builds the check is
+    /// skipped.
+    pub fn with_world_mut<R>(&mut self, f: impl FnOnce(&mut World) -> R) -> R {
+        let result = f(self.world);
+        self.check_owner_alive();
+        result
+    }
+
+    /// Panic if the owner entity has been despawned (debug builds only).
+    fn check_owner_alive(&self) {
+        #[cfg(debug_assertions)]
+        if self.world.get_entity(self.owner).is_err() {
+            panic!(
+                "View owner entity {} was despawned during build. Views must not despawn \
+                their own entity; use the view hierarchy to remove them instead.",
+                self.owner
+            );
+        }
+    }
+
     /// Returns the id of the entity that owns the tracking scope.
     pub fn owner(&self) -> Entity {
         self.owner
     }
 
+    /// Run `f` with a context which has a different owner entity, but shares this context's
+    /// tracking scope. Views built with it see `owner` as their parent.
+    pub(crate) fn with_owner<R>(&mut self, owner: Entity, f: impl FnOnce(&mut Cx) -> R) -> R {
+        let mut tracking = self.tracking.borrow_mut();
+        f(&mut Cx::new(self.world, owner, &mut tracking))
+    }
+
+    /// Returns the [`Name`] of the entity that owns the tracking scope, if it has one. View
+    /// templates are named after their type by default.
+    pub fn owner_name(&self) -> Option<&str> {
+        self.world.get::<Name>(self.owner).map(|name| name.as_str())
+    }
+
+    /// Set the [`Name`] of the entity that owns the tracking scope. This name is used to
+    /// identify the view in diagnostic messages, such as when reactions fail to converge.
+    pub fn set_debug_name(&mut self, name: &str) {
+        let owner = self.owner;
+        match self.world.get_mut::<Name>(owner) {
+            Some(mut current) => {
+                if current.as_str() != name {
+                    current.set(name.to_string());
+                }
+            }
+            None => {
+                self.world
+                    .entity_mut(owner)
+                    .insert(Name::new(name.to_string()));
+            }
+        }
+    }
+
     // Spawn an empty [`Entity`]. The caller is responsible for despawning the entity.
     // pub fn create_entity_untracked(&mut self) -> Entity {
-    //     self.world_mut().spawn_empty().id()
+    //     self.world.spawn_empty().id()
     // }
 
     /// Spawn an empty [`Entity`]. The entity will be despawned when the tracking scope is dropped.
@@ -85,7 +217,7 @@ impl<'p, 'w> Cx<'p, 'w> {
                 panic!("Expected create_entity() hook, found something else");
             }
             None => {
-                let entity = self.world_mut().spawn_empty().id();
+                let entity = self.world.spawn_empty().id();
                 self.tracking
                     .borrow_mut()
                     .push_hook(HookState::Entity(entity));
@@ -113,11 +245,11 @@ impl<'p, 'w> Cx<'p, 'w> {
             None => {
                 let owner = self.owner();
                 let cell = self
-                    .world_mut()
+                    .world
                     .spawn(MutableCell::<T>(init))
                     .set_parent(owner)
                     .id();
-                let component = self.world_mut().register_component::<MutableCell<T>>();
+                let component = self.world.register_component::<MutableCell<T>>();
                 self.tracking
                     .borrow_mut()
                     .push_hook(HookState::Mutable(cell, component));
@@ -130,6 +262,60 @@ impl<'p, 'w> Cx<'p, 'w> {
         }
     }
 
+    /// Create a new [`Mutable`] which is identified by a name. Unlike [`Cx::create_mutable`],
+    /// the value is not lost when the owning view is razed and then rebuilt in the same
+    /// update (for example, inside a [`Cond`](crate::Cond) that flips): the existing
+    /// mutable with the same key and value type is reused, and `init` is ignored. The mutable
+    /// is despawned once no view references it at the end of an update.
+    ///
+    /// The key is scoped to the type of the template which calls this: instances of the same
+    /// template which use the same key and value type share the same mutable, but other
+    /// templates which happen to use the same key do not.
+    pub fn create_mutable_keyed<T>(&mut self, key: &str, init: T) -> Mutable<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        let hook = self.tracking.borrow_mut().next_hook();
+        match hook {
+            Some(HookState::NamedMutable(cell, component, _)) => Mutable {
+                cell,
+                component,
+                marker: PhantomData,
+            },
+
+            Some(_) => {
+                panic!("Expected create_mutable_keyed() hook, found something else");
+            }
+            None => {
+                let world = &mut *self.world;
+                let owner_type = world.get::<ViewTemplateType>(self.owner).map(|t| t.0);
+                let key = (owner_type, std::any::TypeId::of::<T>(), key.to_string());
+                let component = world.register_component::<MutableCell<T>>();
+                world.init_resource::<NamedMutableRegistry>();
+                let existing = world.resource_mut::<NamedMutableRegistry>().acquire(&key);
+                let existing = existing.filter(|cell| world.get_entity(*cell).is_ok());
+                let cell = match existing {
+                    Some(cell) => cell,
+                    None => {
+                        let cell = world.spawn(MutableCell::<T>(init)).id();
+                        world
+                            .resource_mut::<NamedMutableRegistry>()
+                            .insert(key.clone(), cell);
+                        cell
+                    }
+                };
+                self.tracking
+                    .borrow_mut()
+                    .push_hook(HookState::NamedMutable(cell, component, key));
+                Mutable {
+                    cell,
+                    component,
+                    marker: PhantomData,
+                }
+            }
+        }
+    }
+
     /// Create an effect which runs each time the reactive context is executed, *and* the given
     /// dependencies change.
     ///
@@ -168,7 +354,7 @@ impl<'p, 'w> Cx<'p, 'w> {
             Some(HookState::Effect(prev_deps)) => match prev_deps.downcast_ref::<D>() {
                 Some(prev_deps) => {
                     if *prev_deps != deps {
-                        effect_fn(self.world, deps.clone());
+                        self.with_world_mut(|world| effect_fn(world, deps.clone()));
                         self.tracking
                             .borrow_mut()
                             .replace_hook(HookState::Effect(Arc::new(deps)));
@@ -183,7 +369,7 @@ impl<'p, 'w> Cx<'p, 'w> {
             }
             None => {
                 if options.run_immediately {
-                    effect_fn(self.world, deps.clone());
+                    self.with_world_mut(|world| effect_fn(world, deps.clone()));
                 }
                 self.tracking
                     .borrow_mut()
@@ -223,13 +409,9 @@ impl<'p, 'w> Cx<'p, 'w> {
                         prev_memo.result.clone()
                     }
                 }
-                None => {
-                    panic!("Memo dependencies type mismatch");
-                }
+                None => self.memo_type_mismatch::<R, D>(),
             },
-            Some(_) => {
-                panic!("Expected create_memo() hook, found something else");
-            }
+            Some(other) => self.hook_order_mismatch("create_memo", &other),
             None => {
                 let result = factory_fn(self.world, deps.clone());
                 self.tracking
@@ -243,6 +425,94 @@ impl<'p, 'w> Cx<'p, 'w> {
         }
     }
 
+    /// Return a memoized value which is only recomputed when `deps` changes. This is the same
+    /// as [`Cx::create_memo`], for factory functions which don't need access to the world;
+    /// hook order errors are reported as `create_memo()`.
+    ///
+    /// Arguments:
+    /// - `factory_fn`: The function which computes the memoized value.
+    /// - `deps`: The dependencies which cause the value to be recomputed.
+    pub fn use_memo<
+        R: Clone + Send + Sync + 'static,
+        D: PartialEq + Clone + Send + Sync + 'static,
+    >(
+        &mut self,
+        factory_fn: impl Fn() -> R + Send + Sync,
+        deps: D,
+    ) -> R {
+        self.create_memo(|_, _| factory_fn(), deps)
+    }
+
+    /// Create a timer which runs `callback` every `interval_secs` seconds, measured using the
+    /// [`Time`] resource. The timer is stopped when the view is razed. If the interval changes
+    /// from the previous time the template was run, the timer is restarted; the callback is
+    /// replaced each time, so it can capture values from the presenter.
+    pub fn create_interval(
+        &mut self,
+        interval_secs: f32,
+        callback: impl Fn(&mut World) + Send + Sync + 'static,
+    ) {
+        self.create_timer(interval_secs, true, callback);
+    }
+
+    /// Create a timer which runs `callback` once, after `secs` seconds. This behaves like
+    /// [`Cx::create_interval`], except that the callback is only run once; changing the
+    /// delay restarts the timer, so that the callback runs again.
+    pub fn create_timeout(
+        &mut self,
+        secs: f32,
+        callback: impl Fn(&mut World) + Send + Sync + 'static,
+    ) {
+        self.create_timer(secs, false, callback);
+    }
+
+    fn create_timer(
+        &mut self,
+        duration: f32,
+        repeat: bool,
+        callback: impl Fn(&mut World) + Send + Sync + 'static,
+    ) {
+        let hook = self.tracking.borrow_mut().next_hook();
+        match hook {
+            Some(HookState::Timer(entity)) => {
+                if let Some(mut timer) = self.world.get_mut::<ViewTimer>(entity) {
+                    if timer.duration != duration || timer.repeat != repeat {
+                        timer.duration = duration;
+                        timer.repeat = repeat;
+                        timer.elapsed = 0.;
+                        timer.finished = false;
+                    }
+                    timer.callback = Arc::new(callback);
+                }
+            }
+            Some(other) => self.hook_order_mismatch(
+                if repeat {
+                    "create_interval"
+                } else {
+                    "create_timeout"
+                },
+                &other,
+            ),
+            None => {
+                let owner = self.owner();
+                let entity = self
+                    .world
+                    .spawn(ViewTimer {
+                        duration,
+                        elapsed: 0.,
+                        repeat,
+                        finished: false,
+                        callback: Arc::new(callback),
+                    })
+                    .set_parent(owner)
+                    .id();
+                self.tracking
+                    .borrow_mut()
+                    .push_hook(HookState::Timer(entity));
+            }
+        }
+    }
+
     pub fn create_observer<
         E: Event,
         B: Bundle,
@@ -266,9 +536,9 @@ impl<'p, 'w> Cx<'p, 'w> {
                 {
                     prev_observer
                 } else {
-                    self.world_mut().despawn(prev_observer);
+                    self.world.despawn(prev_observer);
                     let observer = self
-                        .world_mut()
+                        .world
                         .spawn(Observer::new(system).with_entity(target))
                         .id();
                     self.tracking.borrow_mut().replace_hook(HookState::Observer(
@@ -284,7 +554,7 @@ impl<'p, 'w> Cx<'p, 'w> {
             }
             None => {
                 let observer = self
-                    .world_mut()
+                    .world
                     .spawn(Observer::new(system).with_entity(target))
                     .id();
                 self.tracking.borrow_mut().push_hook(HookState::Observer(
@@ -331,13 +601,9 @@ impl<'p, 'w> Cx<'p, 'w> {
                         prev_memo.result.clone()
                     }
                 }
-                None => {
-                    panic!("Memo dependencies type mismatch");
-                }
+                None => self.memo_type_mismatch::<R, D>(),
             },
-            Some(_) => {
-                panic!("Expected create_memo() hook, found something else");
-            }
+            Some(other) => self.hook_order_mismatch("create_memo", &other),
             None => {
                 let result = factory_fn(self, deps.clone());
                 self.tracking
@@ -356,22 +622,30 @@ impl<'p, 'w> Cx<'p, 'w> {
     ///
     /// Note: This function takes no deps argument, the callback is only registered once the first
     /// time it is called. Subsequent calls will return the original callback.
+    ///
+    /// As with any Bevy system, a callback which accepts an argument must take it as its first
+    /// parameter, wrapped in [`In`]: `|value: In<bool>, world: &mut World| { ... }`. The result
+    /// is then a `Callback<In<bool>>`. A closure taking a bare `bool` is not a valid system.
+    ///
+    /// A callback can also return a value, in which case it is a `Callback<P, R>`, where `R`
+    /// is the return type.
     pub fn create_callback<
         P: Send + Sync + SystemInput + 'static,
+        R: 'static,
         M,
-        S: IntoSystem<P, (), M> + 'static,
+        S: IntoSystem<P, R, M> + 'static,
     >(
         &mut self,
         callback: S,
-    ) -> Callback<P> {
+    ) -> Callback<P, R> {
         let hook = self.tracking.borrow_mut().next_hook();
         match hook {
-            Some(HookState::Callback(cb)) => cb.as_ref().downcast::<P>(),
+            Some(HookState::Callback(cb)) => cb.as_ref().downcast::<P, R>(),
             Some(_) => {
                 panic!("Expected create_callback() hook, found something else");
             }
             None => {
-                let id = self.world_mut().register_system(callback);
+                let id = self.world.register_system(callback);
                 let result = Callback { id };
                 self.tracking
                     .borrow_mut()
@@ -407,11 +681,11 @@ impl<'p, 'w> Cx<'p, 'w> {
             None => {
                 let owner = self.owner();
                 let cell = self
-                    .world_mut()
+                    .world
                     .spawn(MutableCell::<T>(init))
                     .set_parent(owner)
                     .id();
-                let component = self.world_mut().register_component::<MutableCell<T>>();
+                let component = self.world.register_component::<MutableCell<T>>();
                 self.tracking
                     .borrow_mut()
                     .push_hook(HookState::Mutable(cell, component));
@@ -428,7 +702,7 @@ impl<'p, 'w> Cx<'p, 'w> {
     /// be accessed by this context any any child contexts via [`use_inherited_component`].
     pub fn insert(&mut self, component: impl Component) {
         let owner = self.owner;
-        self.world_mut().entity_mut(owner).insert(component);
+        self.world.entity_mut(owner).insert(component);
     }
 
     /// Return a reference to the resource of the given type. Calling this function
@@ -444,6 +718,74 @@ impl<'p, 'w> Cx<'p, 'w> {
         self.world.resource::<T>()
     }
 
+    /// Return a value derived from the resource `R` by `selector`. Unlike [`Cx::use_resource`],
+    /// the presenter does not depend on the resource itself, only on the selected value: it is
+    /// re-run when the selected value changes, not every time the resource is mutated.
+    ///
+    /// The selected value is held in a [`Mutable`], which is updated once per frame, before
+    /// the views react. The selector should therefore be cheap to run.
+    pub fn use_resource_selector<
+        R: Resource,
+        T: PartialEq + Clone + Send + Sync + 'static,
+        F: Fn(&R) -> T + Send + Sync + 'static,
+    >(
+        &mut self,
+        selector: F,
+    ) -> T {
+        let mutable = self.create_mutable(selector(self.world.resource::<R>()));
+        // Replace the selector each time, since it may capture values from the presenter.
+        self.world
+            .entity_mut(mutable.cell)
+            .insert(ResourceSelector::new(mutable.cell, selector));
+        mutable.get_clone(self)
+    }
+
+    /// Return the load state of the asset referred to by `handle`. The presenter is re-run when
+    /// the asset finishes loading or fails to load, so views can display a placeholder until
+    /// the asset is ready. Changes to the contents of an asset that is already loaded do not
+    /// cause the presenter to re-run.
+    ///
+    /// This requires the [`AssetServer`] resource, which is added by the `AssetPlugin`.
+    pub fn use_asset<A: Asset>(&mut self, handle: &Handle<A>) -> AssetLoadState<'_, A> {
+        let id = handle.id();
+        let failed = self.use_resource_selector(move |server: &AssetServer| {
+            matches!(server.get_load_state(id), Some(LoadState::Failed(_)))
+        });
+        // Subscribe to the asset being added or removed, but not to other changes to the
+        // assets of this type.
+        self.use_resource_selector(move |assets: &Assets<A>| assets.contains(id));
+        match self.world.resource::<Assets<A>>().get(id) {
+            Some(asset) => AssetLoadState::Loaded(asset),
+            None if failed => AssetLoadState::Failed,
+            None => AssetLoadState::Loading,
+        }
+    }
+
+    /// Return a mutable reference to the resource of the given type, marking it as written by
+    /// the current presenter invocation. Changes to a written resource do not cause the
+    /// presenter to re-run, even if it also reads the resource, so the presenter does not
+    /// react to its own writes.
+    pub fn write_resource<T: Resource>(&mut self) -> Mut<'_, T> {
+        self.tracking
+            .borrow_mut()
+            .track_resource_write::<T>(self.world);
+        self.world.resource_mut::<T>()
+    }
+
+    /// Return the current value of the Bevy state `S`. Calling this function adds the
+    /// [`State<S>`] resource as a dependency of the current presenter invocation, so the
+    /// presenter will re-run whenever the state changes.
+    pub fn use_state_machine<S: States>(&self) -> &S {
+        self.use_resource::<State<S>>().get()
+    }
+
+    /// Return a mutable reference to the [`NextState<S>`] resource, which can be used to
+    /// schedule a state transition. This does not add any dependencies to the current
+    /// presenter invocation.
+    pub fn use_next_state_machine<S: FreelyMutableState>(&mut self) -> Mut<'_, NextState<S>> {
+        self.world.resource_mut::<NextState<S>>()
+    }
+
     /// Return a reference to the Component `C` on the given entity.
     pub fn use_component<C: Component>(&self, entity: Entity) -> Option<&C> {
         match self.world.get_entity(entity) {
@@ -465,6 +807,50 @@ impl<'p, 'w> Cx<'p, 'w> {
         }
     }
 
+    /// Return true if the entity has the Component `C`. The presence of the component is added
+    /// to the current tracking scope, so the presenter is re-run when the component is added
+    /// or removed, but not when its value changes.
+    pub fn has_component<C: Component>(&mut self, entity: Entity) -> bool {
+        let cid = self.world.register_component::<C>();
+        let exists = self
+            .world
+            .get_entity(entity)
+            .is_ok_and(|e| e.contains_id(cid));
+        self.tracking
+            .borrow_mut()
+            .track_component_presence(entity, cid, exists);
+        exists
+    }
+
+    /// Return the results of the query `Q`, filtered by `F`, for all matching entities. Calling
+    /// this function adds the set of matching entities as a dependency of the current presenter
+    /// invocation, along with the components read by the query on each of those entities. The
+    /// presenter is re-run when an entity starts or stops matching the query, or when one of
+    /// the components it read is changed or removed.
+    ///
+    /// The query is re-run once per frame to detect changes in the set of matching entities,
+    /// so this should be used with filters that match a modest number of entities.
+    pub fn use_query<Q: ReadOnlyQueryData + 'static, F: QueryFilter + 'static>(
+        &mut self,
+    ) -> Vec<ROQueryItem<'_, Q>> {
+        let mut entity_query = self.world.query_filtered::<Entity, F>();
+        let mut query = self.world.query_filtered::<Q, F>();
+        let world: &World = self.world;
+        let entities: Vec<Entity> = entity_query.iter(world).collect();
+        let access = query.component_access().access();
+        let mut tracking = self.tracking.borrow_mut();
+        for entity in entities.iter() {
+            for cid in world.entity(*entity).archetype().components() {
+                if access.has_component_read(cid) {
+                    tracking.track_component_id(*entity, cid, true);
+                }
+            }
+        }
+        tracking.track_query(std::any::type_name::<(Q, F)>(), entities, entity_query);
+        drop(tracking);
+        query.iter(world).collect()
+    }
+
     /// Return a reference to the Component `C` on the given entity. This version does not
     /// add the component to the tracking scope, and is intended for components that update
     /// frequently.
@@ -493,11 +879,99 @@ impl<'p, 'w> Cx<'p, 'w> {
         }
     }
 
+    /// Panic because the hook at the current position was created by a different method on a
+    /// previous run, which means that hooks were called conditionally or in a different order.
+    fn hook_order_mismatch(&self, expected: &str, found: &HookState) -> ! {
+        panic!(
+            "Expected {}() hook at position {}, found {}() hook. Hooks must be called in the \
+            same order every time the template is run; they cannot be called conditionally.",
+            expected,
+            self.tracking.borrow().hook_index(),
+            found.name()
+        );
+    }
+
+    fn memo_type_mismatch<R, D>(&self) -> ! {
+        panic!(
+            "Memo at position {} was previously created with a different result or dependency \
+            type; expected result {} and dependencies {}.",
+            self.tracking.borrow().hook_index(),
+            std::any::type_name::<R>(),
+            std::any::type_name::<D>()
+        );
+    }
+
+    /// Provide a value to this view and its descendants, which can be retrieved with
+    /// [`Cx::use_context`]. Unlike a resource, different subtrees can be given different values
+    /// of the same type. The value is stored on the owner entity, and is removed when this view
+    /// is razed.
+    ///
+    /// Each call replaces the previous value. Descendants which read the context only react
+    /// if the new value is different from the previous one.
+    pub fn provide_context<T: Clone + PartialEq + Send + Sync + 'static>(&mut self, value: T) {
+        let owner = self.owner;
+        let mut entt = self.world.entity_mut(owner);
+        match entt.get_mut::<Context<T>>() {
+            // Avoid marking the context as changed if the value is the same.
+            Some(mut context) => {
+                if context.0 != value {
+                    context.0 = value;
+                }
+            }
+            None => {
+                entt.insert(Context(value));
+            }
+        }
+    }
+
+    /// Return the nearest value of type `T` which was provided by this view or one of its
+    /// ancestors using [`Cx::provide_context`]. The context is added to the current tracking
+    /// scope.
+    pub fn use_context<T: Clone + Send + Sync + 'static>(&mut self) -> Option<&T> {
+        // Register the component type, so that a context which hasn't been provided yet can
+        // still be tracked.
+        self.world.register_component::<Context<T>>();
+        self.use_inherited_component::<Context<T>>()
+            .map(|context| &context.0)
+    }
+
     /// Add a cleanup function which is run once before the next reaction, or when the owner
     /// entity for this context is despawned.
     pub fn on_cleanup(&mut self, cleanup: impl FnOnce(&mut DeferredWorld) + Send + Sync + 'static) {
         self.tracking.borrow_mut().add_cleanup(cleanup);
     }
+
+    /// Add a function which is run once, at the end of the first build of the view. Unlike an
+    /// effect, it is not run again when the view is rebuilt; the function passed on later
+    /// rebuilds is ignored. The function is passed the entity which owns the view.
+    pub fn on_mount(&mut self, mount: impl FnOnce(&mut World, Entity) + Send + Sync + 'static) {
+        let hook = self.tracking.borrow_mut().next_hook();
+        match hook {
+            Some(HookState::Mount) => {}
+            Some(other) => self.hook_order_mismatch("on_mount", &other),
+            None => {
+                let mut tracking = self.tracking.borrow_mut();
+                tracking.push_hook(HookState::Mount);
+                tracking.add_mount(mount);
+            }
+        }
+    }
+
+    /// Add a function which is run once, when the view is razed. Unlike [`Cx::on_cleanup`],
+    /// it is not run between rebuilds; the function passed on later rebuilds is ignored. The
+    /// function is passed the entity which owns the view, which has not yet been despawned.
+    pub fn on_unmount(&mut self, unmount: impl FnOnce(&mut World, Entity) + Send + Sync + 'static) {
+        let hook = self.tracking.borrow_mut().next_hook();
+        match hook {
+            Some(HookState::Unmount) => {}
+            Some(other) => self.hook_order_mismatch("on_unmount", &other),
+            None => {
+                let mut tracking = self.tracking.borrow_mut();
+                tracking.push_hook(HookState::Unmount);
+                tracking.add_unmount(unmount);
+            }
+        }
+    }
 }
 
 impl<'p, 'w> ReadMutable for Cx<'p, 'w> {
@@ -557,6 +1031,13 @@ impl<'p, 'w> WriteMutable for Cx<'p, 'w> {
         self.world.write_mutable_clone(mutable, value);
     }
 
+    fn write_mutable_force<T>(&mut self, mutable: Entity, value: T)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.world.write_mutable_force(mutable, value);
+    }
+
     fn update_mutable<T, F: FnOnce(bevy::prelude::Mut<T>)>(&mut self, mutable: Entity, updater: F)
     where
         T: Send + Sync + 'static,
@@ -564,3 +1045,338 @@ impl<'p, 'w> WriteMutable for Cx<'p, 'w> {
         self.world.update_mutable(mutable, updater);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct LifecycleCount {
+        mounts: usize,
+        unmounts: usize,
+    }
+
+    fn add_lifecycle_hooks(cx: &mut Cx) {
+        cx.on_mount(|world, _| world.resource_mut::<LifecycleCount>().mounts += 1);
+        cx.on_unmount(|world, _| world.resource_mut::<LifecycleCount>().unmounts += 1);
+    }
+
+    #[test]
+    fn test_lifecycle_hooks_run_once() {
+        let mut world = World::default();
+        world.init_resource::<LifecycleCount>();
+        let owner = world.spawn_empty().id();
+
+        // First build registers the hooks.
+        let mut scope = TrackingScope::new(world.change_tick());
+        add_lifecycle_hooks(&mut Cx::new(&mut world, owner, &mut scope));
+        world.entity_mut(owner).insert(scope);
+        TrackingScope::run_mounts(&mut world, owner);
+        assert_eq!(world.resource::<LifecycleCount>().mounts, 1);
+
+        // Rebuilding does not register them again.
+        let mut next_scope = TrackingScope::new(world.change_tick());
+        let mut scope = world.entity_mut(owner).take::<TrackingScope>().unwrap();
+        next_scope.take_hooks(&mut scope);
+        add_lifecycle_hooks(&mut Cx::new(&mut world, owner, &mut next_scope));
+        scope.take_deps(&mut next_scope);
+        world.entity_mut(owner).insert(scope);
+        TrackingScope::run_mounts(&mut world, owner);
+        assert_eq!(world.resource::<LifecycleCount>().mounts, 1);
+        assert_eq!(world.resource::<LifecycleCount>().unmounts, 0);
+
+        TrackingScope::run_unmounts(&mut world, owner);
+        TrackingScope::run_unmounts(&mut world, owner);
+        assert_eq!(world.resource::<LifecycleCount>().unmounts, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected on_unmount() hook at position 0, found on_mount()")]
+    fn test_lifecycle_hook_order_mismatch() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        Cx::new(&mut world, owner, &mut scope).on_mount(|_, _| {});
+        let mut next_scope = TrackingScope::new(world.change_tick());
+        next_scope.take_hooks(&mut scope);
+        Cx::new(&mut world, owner, &mut next_scope).on_unmount(|_, _| {});
+    }
+
+    #[derive(Resource)]
+    struct Factor(i32);
+
+    #[test]
+    fn test_memo_recomputed_when_deps_change() {
+        let mut world = World::default();
+        world.insert_resource(Factor(2));
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let run = |world: &mut World, scope: &mut TrackingScope, deps: i32| {
+            let mut next_scope = TrackingScope::new(world.change_tick());
+            next_scope.take_hooks(scope);
+            let result = Cx::new(world, owner, &mut next_scope)
+                .create_memo(|world, n| n * world.resource::<Factor>().0, deps);
+            scope.take_deps(&mut next_scope);
+            result
+        };
+
+        assert_eq!(run(&mut world, &mut scope, 1), 2);
+
+        // The factory is not re-run while the deps are unchanged.
+        world.insert_resource(Factor(3));
+        assert_eq!(run(&mut world, &mut scope, 1), 2);
+        assert_eq!(run(&mut world, &mut scope, 2), 6);
+    }
+
+    #[test]
+    fn test_create_mutable_keyed_scoped_to_template() {
+        let mut world = World::default();
+        let template_a = ViewTemplateType(std::any::TypeId::of::<u8>());
+        let template_b = ViewTemplateType(std::any::TypeId::of::<u16>());
+        let owner_a1 = world.spawn(ViewTemplateType(template_a.0)).id();
+        let owner_a2 = world.spawn(template_a).id();
+        let owner_b = world.spawn(template_b).id();
+        let mut keyed = |owner: Entity| {
+            let mut scope = TrackingScope::new(world.change_tick());
+            Cx::new(&mut world, owner, &mut scope).create_mutable_keyed("expanded", false)
+        };
+
+        // Instances of the same template share the mutable; other templates don't.
+        let a1 = keyed(owner_a1);
+        let a2 = keyed(owner_a2);
+        let b = keyed(owner_b);
+        assert_eq!(a1.id(), a2.id());
+        assert_ne!(a1.id(), b.id());
+    }
+
+    #[test]
+    fn test_use_memo() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let mut run = |deps: i32| {
+            let mut next_scope = TrackingScope::new(world.change_tick());
+            next_scope.take_hooks(&mut scope);
+            let result = Cx::new(&mut world, owner, &mut next_scope).use_memo(
+                || {
+                    calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    deps * 10
+                },
+                deps,
+            );
+            scope.take_deps(&mut next_scope);
+            result
+        };
+
+        assert_eq!(run(1), 10);
+        assert_eq!(run(1), 10);
+        assert_eq!(run(2), 20);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected create_memo() hook at position 0, found create_entity()")]
+    fn test_memo_hook_order_mismatch() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        Cx::new(&mut world, owner, &mut scope).create_entity();
+        let mut next_scope = TrackingScope::new(world.change_tick());
+        next_scope.take_hooks(&mut scope);
+        Cx::new(&mut world, owner, &mut next_scope).create_memo(|_, _| 0, ());
+    }
+
+    #[test]
+    fn test_use_context_nearest_ancestor() {
+        let mut world = World::default();
+        let root = world.spawn_empty().id();
+        let middle = world.spawn_empty().set_parent(root).id();
+        let leaf = world.spawn_empty().set_parent(middle).id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        Cx::new(&mut world, root, &mut scope).provide_context(1);
+        Cx::new(&mut world, middle, &mut scope).provide_context("middle");
+
+        let mut cx = Cx::new(&mut world, leaf, &mut scope);
+        assert_eq!(cx.use_context::<i32>(), Some(&1));
+        assert_eq!(cx.use_context::<&str>(), Some(&"middle"));
+        assert_eq!(cx.use_context::<bool>(), None);
+
+        // Contexts provided by a descendant are not visible to the ancestors.
+        let mut cx = Cx::new(&mut world, root, &mut scope);
+        assert_eq!(cx.use_context::<&str>(), None);
+    }
+
+    #[test]
+    fn test_provide_same_context() {
+        let mut world = World::default();
+        let root = world.spawn_empty().id();
+        let leaf = world.spawn_empty().set_parent(root).id();
+        let mut root_scope = TrackingScope::new(world.change_tick());
+        Cx::new(&mut world, root, &mut root_scope).provide_context(1);
+
+        world.increment_change_tick();
+        let mut scope = TrackingScope::new(world.change_tick());
+        assert_eq!(
+            Cx::new(&mut world, leaf, &mut scope).use_context::<i32>(),
+            Some(&1)
+        );
+
+        // Providing the same value again doesn't cause consumers to react.
+        world.increment_change_tick();
+        Cx::new(&mut world, root, &mut root_scope).provide_context(1);
+        let tick = world.change_tick();
+        assert!(!scope.dependencies_changed(&world, tick));
+
+        // Providing a different value does.
+        world.increment_change_tick();
+        Cx::new(&mut world, root, &mut root_scope).provide_context(2);
+        let tick = world.change_tick();
+        assert!(scope.dependencies_changed(&world, tick));
+    }
+
+    #[derive(Resource)]
+    struct PanelWidth(f32);
+
+    #[test]
+    fn test_use_resource_selector() {
+        let mut world = World::default();
+        world.insert_resource(PanelWidth(300.));
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let wide = Cx::new(&mut world, owner, &mut scope)
+            .use_resource_selector(|width: &PanelWidth| width.0 > 400.);
+        assert!(!wide);
+
+        // Mutating the resource without changing the selected value is not a change.
+        world.increment_change_tick();
+        world.resource_mut::<PanelWidth>().0 = 350.;
+        crate::mutable::update_resource_selectors(&mut world);
+        let tick = world.change_tick();
+        assert!(!scope.dependencies_changed(&world, tick));
+
+        world.increment_change_tick();
+        world.resource_mut::<PanelWidth>().0 = 500.;
+        crate::mutable::update_resource_selectors(&mut world);
+        let tick = world.change_tick();
+        assert!(scope.dependencies_changed(&world, tick));
+
+        // Re-running the presenter reads the updated value.
+        let mut next_scope = TrackingScope::new(world.change_tick());
+        next_scope.take_hooks(&mut scope);
+        let wide = Cx::new(&mut world, owner, &mut next_scope)
+            .use_resource_selector(|width: &PanelWidth| width.0 > 400.);
+        assert!(wide);
+    }
+
+    #[derive(Component)]
+    struct Marker(u32);
+
+    #[test]
+    fn test_has_component() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let target = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        assert!(!Cx::new(&mut world, owner, &mut scope).has_component::<Marker>(target));
+        let tick = world.change_tick();
+        assert!(!scope.dependencies_changed(&world, tick));
+
+        // Adding the component is a change.
+        world.entity_mut(target).insert(Marker(0));
+        let tick = world.change_tick();
+        assert!(scope.dependencies_changed(&world, tick));
+
+        let tick = world.change_tick();
+        let mut scope = TrackingScope::new(tick);
+        assert!(Cx::new(&mut world, owner, &mut scope).has_component::<Marker>(target));
+
+        // Mutating the component is not a change, since only its presence was tracked.
+        world.increment_change_tick();
+        world.get_mut::<Marker>(target).unwrap().0 = 1;
+        let tick = world.change_tick();
+        assert!(!scope.dependencies_changed(&world, tick));
+
+        // Removing it is.
+        world.entity_mut(target).remove::<Marker>();
+        let tick = world.change_tick();
+        assert!(scope.dependencies_changed(&world, tick));
+    }
+
+    #[test]
+    fn test_use_query() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let first = world.spawn(Marker(1)).id();
+        world.spawn(Marker(2));
+        let tick = world.change_tick();
+        let mut scope = TrackingScope::new(tick);
+        let mut values: Vec<u32> = Cx::new(&mut world, owner, &mut scope)
+            .use_query::<&Marker, ()>()
+            .iter()
+            .map(|m| m.0)
+            .collect();
+        values.sort();
+        assert_eq!(values, vec![1, 2]);
+        let tick = world.change_tick();
+        assert!(!scope.dependencies_changed(&world, tick));
+
+        // Mutating a component read by the query is a change.
+        world.increment_change_tick();
+        world.get_mut::<Marker>(first).unwrap().0 = 3;
+        let tick = world.change_tick();
+        assert!(scope.dependencies_changed(&world, tick));
+
+        let mut scope = TrackingScope::new(world.change_tick());
+        Cx::new(&mut world, owner, &mut scope).use_query::<&Marker, ()>();
+        let tick = world.change_tick();
+        assert!(!scope.dependencies_changed(&world, tick));
+
+        // So is a new entity matching the query.
+        world.spawn(Marker(4));
+        let tick = world.change_tick();
+        assert!(scope.dependencies_changed(&world, tick));
+    }
+
+    #[derive(Resource, Default)]
+    struct TimerCount {
+        interval: usize,
+        timeout: usize,
+    }
+
+    #[test]
+    fn test_create_interval_and_timeout() {
+        let mut world = World::default();
+        world.init_resource::<TimerCount>();
+        world.init_resource::<Time>();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let mut cx = Cx::new(&mut world, owner, &mut scope);
+        cx.create_interval(1.0, |world| {
+            world.resource_mut::<TimerCount>().interval += 1
+        });
+        cx.create_timeout(1.5, |world| world.resource_mut::<TimerCount>().timeout += 1);
+
+        let step = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(0.6));
+            crate::cx::run_view_timers(world);
+        };
+
+        step(&mut world);
+        assert_eq!(world.resource::<TimerCount>().interval, 0);
+        step(&mut world);
+        assert_eq!(world.resource::<TimerCount>().interval, 1);
+        assert_eq!(world.resource::<TimerCount>().timeout, 0);
+        step(&mut world);
+        step(&mut world);
+        assert_eq!(world.resource::<TimerCount>().interval, 2);
+        assert_eq!(world.resource::<TimerCount>().timeout, 1);
+
+        // The timeout only fires once.
+        step(&mut world);
+        step(&mut world);
+        assert_eq!(world.resource::<TimerCount>().timeout, 1);
+    }
+}
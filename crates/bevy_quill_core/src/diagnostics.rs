@@ -0,0 +1,45 @@
+use bevy::{
+    app::{App, Plugin, Update},
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    prelude::{Entity, IntoSystemConfigs, Res, Resource},
+};
+
+use crate::QuillUpdateSystemSet;
+
+/// Statistics about the reaction control system, updated each frame when the resource is
+/// present. [`QuillDiagnosticsPlugin`] inserts this resource.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ReactivityDiagnostics {
+    /// Number of reaction passes run during the most recent frame.
+    pub iterations: usize,
+
+    /// Largest number of reaction passes observed in a single frame.
+    pub max_iterations: usize,
+
+    /// The scopes which were still changing when reactions last failed to converge. This is
+    /// recorded just before the reaction control system panics.
+    pub divergent: Vec<Entity>,
+}
+
+/// Plugin which reports the number of reaction passes per frame as a Bevy diagnostic, and
+/// inserts the [`ReactivityDiagnostics`] resource.
+#[derive(Default)]
+pub struct QuillDiagnosticsPlugin;
+
+impl QuillDiagnosticsPlugin {
+    /// Number of reaction passes run each frame.
+    pub const REACTION_ITERATIONS: DiagnosticPath =
+        DiagnosticPath::const_new("quill/reaction_iterations");
+
+    fn diagnostic_system(mut diagnostics: Diagnostics, stats: Res<ReactivityDiagnostics>) {
+        diagnostics.add_measurement(&Self::REACTION_ITERATIONS, || stats.iterations as f64);
+    }
+}
+
+impl Plugin for QuillDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReactivityDiagnostics>()
+            .register_diagnostic(Diagnostic::new(Self::REACTION_ITERATIONS))
+            .add_systems(Update, Self::diagnostic_system.after(QuillUpdateSystemSet));
+    }
+}
@@ -26,25 +26,25 @@ impl View for Dynamic {
     type State = (Arc<dyn AnyView>, BoxedState);
 
     fn nodes(&self, world: &World, state: &Self::State, out: &mut Vec<Entity>) {
-        state.0.nodes(world, &state.1, out);
+        View::nodes(&state.0, world, &state.1, out);
     }
 
     fn build(&self, cx: &mut crate::Cx) -> Self::State {
         let view = self.children.0.clone();
-        let state = view.build(cx);
+        let state = View::build(&view, cx);
         (view, state)
     }
 
     fn rebuild(&self, cx: &mut crate::Cx, state: &mut Self::State) -> bool {
-        if state.0.view_type_id() == self.children.0.view_type_id() {
+        if View::view_type_id(&state.0) == View::view_type_id(&self.children.0) {
             state.0 = self.children.0.clone();
-            state.0.rebuild(cx, &mut state.1)
+            View::rebuild(&state.0, cx, &mut state.1)
         } else {
-            state
-                .0
-                .raze(&mut DeferredWorld::from(cx.world_mut()), &mut state.1);
+            cx.with_world_mut(|world| {
+                View::raze(&state.0, &mut DeferredWorld::from(world), &mut state.1)
+            });
             let view = self.children.0.clone();
-            let new_state = view.build(cx);
+            let new_state = View::build(&view, cx);
             state.0 = view;
             state.1 = new_state;
             true
@@ -52,11 +52,11 @@ impl View for Dynamic {
     }
 
     fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
-        state.0.raze(world, &mut state.1)
+        View::raze(&state.0, world, &mut state.1)
     }
 
     fn attach_children(&self, world: &mut World, state: &mut Self::State) -> bool {
-        state.0.attach_children(world, &mut state.1)
+        View::attach_children(&state.0, world, &mut state.1)
     }
 }
 
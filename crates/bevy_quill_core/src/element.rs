@@ -5,10 +5,15 @@ use bevy_mod_stylebuilder::{StyleBuilder, StyleTuple};
 
 use crate::{
     cx::Cx,
+    dynamic::Dynamic,
     effects::{self, AppendEffect, CallbackEffect, EffectTuple, EntityEffect},
-    insert::{ConditionalInsertComponentEffect, InsertBundleEffect, StaticInsertBundleEffect},
+    insert::{
+        CachedInsertBundleEffect, ConditionalInsertComponentEffect, InsertBundleEffect,
+        StaticInsertBundleEffect,
+    },
     style::{ApplyDynamicStylesEffect, ApplyStaticStylesEffect},
-    view::View,
+    view::{despawn_if_exists, View},
+    view_child::IntoViewChild,
 };
 
 /// A view which generates an entity bundle.
@@ -71,6 +76,22 @@ impl<B: Bundle + Default, C: View, E: EffectTuple> Element<B, C, E> {
         }
     }
 
+    /// Set a single child for this element, which is only displayed when `condition` is true.
+    /// The child is built as an `Option<View>` rather than a [`Cond`], so no entities are
+    /// created for it while the condition is false. The option is wrapped in a [`Dynamic`],
+    /// which razes the child when the condition changes from true to false.
+    ///
+    /// [`Cond`]: crate::Cond
+    pub fn optional_child(
+        self,
+        condition: bool,
+        child: impl IntoViewChild,
+    ) -> Element<B, Dynamic, E> {
+        self.children(Dynamic::new(
+            condition.then(|| child.into_view_child()).into_view_child(),
+        ))
+    }
+
     /// Add an effect to this element.
     pub fn add_effect<E1: EntityEffect>(
         self,
@@ -169,6 +190,30 @@ impl<B: Bundle + Default, C: View, E: EffectTuple> Element<B, C, E> {
         })
     }
 
+    /// Insert a bundle into the target entity. The bundle will be recomputed whenever the
+    /// dependencies change, but only re-inserted if the new value differs from the previous one.
+    ///
+    /// Arguments:
+    /// - bundle_gen: A function which computes the bundle based on the dependencies.
+    /// - deps: The dependencies which trigger a recompute of the bundle.
+    pub fn insert_cached<
+        B2: Bundle + PartialEq + Clone,
+        S: Fn(D) -> B2 + Send + Sync,
+        D: PartialEq + Clone + Send + Sync,
+    >(
+        self,
+        bundle_gen: S,
+        deps: D,
+    ) -> Element<B, C, <E as AppendEffect<CachedInsertBundleEffect<B2, S, D>>>::Result>
+    where
+        E: AppendEffect<CachedInsertBundleEffect<B2, S, D>>,
+    {
+        self.add_effect(CachedInsertBundleEffect {
+            factory: bundle_gen,
+            deps,
+        })
+    }
+
     /// Insert a component into the target entity if the condition is true. If the condition
     /// later becomes false, the component will be removed.
     ///
@@ -197,28 +242,29 @@ impl<B: Bundle + Default, C: View, E: EffectTuple + 'static> View for Element<B,
     fn build(&self, cx: &mut Cx) -> Self::State {
         let owner = cx.owner();
         if self.debug_name.is_empty() {
-            cx.world_mut()
-                .entity_mut(owner)
-                .insert(Name::new("Element"));
+            cx.with_world_mut(|world| {
+                world.entity_mut(owner).insert(Name::new("Element"));
+            });
         } else {
-            cx.world_mut()
-                .entity_mut(owner)
-                .insert(Name::new(format!("Element::{}", self.debug_name)));
+            cx.with_world_mut(|world| {
+                world
+                    .entity_mut(owner)
+                    .insert(Name::new(format!("Element::{}", self.debug_name)));
+            });
         }
 
         // Build display entity if it doesn't already exist.
-        let display = match self.display {
+        let display = cx.with_world_mut(|world| match self.display {
             Some(display) => {
-                cx.world_mut()
+                world
                     .entity_mut(display)
                     .insert((B::default(), Name::new(self.debug_name.clone())));
                 display
             }
-            None => cx
-                .world_mut()
+            None => world
                 .spawn((B::default(), Name::new(self.debug_name.clone())))
                 .id(),
-        };
+        });
 
         // Run attached effects.
         let eff_state = effects::EffectTuple::apply(&self.effects, cx, display);
@@ -227,16 +273,16 @@ impl<B: Bundle + Default, C: View, E: EffectTuple + 'static> View for Element<B,
         let children = self.children.build(cx);
         let mut nodes: Vec<Entity> = Vec::new();
         self.children.nodes(cx.world(), &children, &mut nodes);
-        cx.world_mut()
-            .entity_mut(display)
-            .replace_children(&nodes.to_vec());
+        cx.with_world_mut(|world| {
+            world.entity_mut(display).replace_children(&nodes);
+        });
         (display, children, eff_state)
     }
 
     fn rebuild(&self, cx: &mut crate::cx::Cx, state: &mut Self::State) -> bool {
         effects::EffectTuple::reapply(&self.effects, cx, state.0, &mut state.2);
         if self.children.rebuild(cx, &mut state.1) {
-            self.attach_children(cx.world_mut(), state);
+            cx.with_world_mut(|world| self.attach_children(world, state));
         }
         // Note that we always return false, since the Element entity doesn't change.
         false
@@ -247,13 +293,18 @@ impl<B: Bundle + Default, C: View, E: EffectTuple + 'static> View for Element<B,
         info!("Razing element: {}", state.0);
 
         // Delete the display node.
-        world.commands().entity(state.0).remove_parent();
         if self.display.is_none() {
             // Only despawn the display entity if we created it. If we got it from the outside,
             // then it's the responsibility of the caller to clean it up.
-            world.commands().entity(state.0).despawn();
+            despawn_if_exists(world, state.0);
         } else {
-            world.commands().entity(state.0).remove::<B>();
+            let entity = state.0;
+            world.commands().queue(move |world: &mut World| {
+                if let Ok(mut entt) = world.get_entity_mut(entity) {
+                    entt.remove_parent();
+                    entt.remove::<B>();
+                }
+            });
         }
         self.children.raze(world, &mut state.1);
     }
@@ -267,3 +318,41 @@ impl<B: Bundle + Default, C: View, E: EffectTuple + 'static> View for Element<B,
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracking_scope::TrackingScope;
+
+    fn texts(world: &mut World) -> usize {
+        world.flush();
+        world.query::<&Text>().iter(world).count()
+    }
+
+    #[test]
+    fn test_optional_child() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let element = |show: bool| Element::<Node>::new().optional_child(show, "child");
+
+        let mut state = View::build(&element(false), &mut Cx::new(&mut world, owner, &mut scope));
+        assert_eq!(texts(&mut world), 0);
+
+        // The child is built when the condition becomes true, and razed when it becomes false.
+        View::rebuild(
+            &element(true),
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state,
+        );
+        assert_eq!(texts(&mut world), 1);
+        assert_eq!(world.get::<Children>(state.0).unwrap().len(), 1);
+
+        View::rebuild(
+            &element(false),
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state,
+        );
+        assert_eq!(texts(&mut world), 0);
+    }
+}
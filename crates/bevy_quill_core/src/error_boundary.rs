@@ -0,0 +1,356 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use std::collections::HashSet;
+
+use bevy::core::Name;
+use bevy::ecs::component::Tick;
+use bevy::ecs::world::{DeferredWorld, World};
+use bevy::hierarchy::{BuildChildren, DespawnRecursiveExt, Parent};
+use bevy::log::error;
+use bevy::prelude::{Entity, In};
+
+use crate::{context::Context, Callback, Cx, RunCallback, View};
+
+/// The message of a panic caught by an [`ErrorBoundary`]. This is provided as a context value
+/// to the fallback while it is displayed, so that the fallback can show it using
+/// [`Cx::use_context`]. The context is only visible within the fallback, not to the
+/// boundary's siblings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewError(pub String);
+
+/// A view which catches panics that occur while building or rebuilding its children, and
+/// displays a fallback view instead of crashing the app.
+///
+/// Only panics which happen synchronously within the boundary are caught: this includes the
+/// first `create()` of any [`ViewTemplate`](crate::ViewTemplate) among the children, but not
+/// later reactions of those templates, which are run independently of the boundary. Entities
+/// which were spawned by the children before the panic are despawned.
+///
+/// While in the error state, the children are retried each time the boundary itself is
+/// rebuilt.
+pub struct ErrorBoundary<C: View = (), FB: View = ()> {
+    children: C,
+    fallback: FB,
+    on_error: Option<Callback<In<String>>>,
+}
+
+impl ErrorBoundary {
+    /// Construct a new `ErrorBoundary` with no children and an empty fallback.
+    pub fn new() -> Self {
+        Self {
+            children: (),
+            fallback: (),
+            on_error: None,
+        }
+    }
+}
+
+impl Default for ErrorBoundary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: View, FB: View> ErrorBoundary<C, FB> {
+    /// Set the child views, whose panics will be caught.
+    pub fn children<C2: View>(self, children: C2) -> ErrorBoundary<C2, FB> {
+        ErrorBoundary {
+            children,
+            fallback: self.fallback,
+            on_error: self.on_error,
+        }
+    }
+
+    /// Set the view which is displayed after a panic has been caught.
+    pub fn fallback<FB2: View>(self, fallback: FB2) -> ErrorBoundary<C, FB2> {
+        ErrorBoundary {
+            children: self.children,
+            fallback,
+            on_error: self.on_error,
+        }
+    }
+
+    /// Set the callback called when a panic is caught. The callback receives the panic
+    /// message as `In<String>`.
+    pub fn on_error(mut self, on_error: Callback<In<String>>) -> Self {
+        self.on_error = Some(on_error);
+        self
+    }
+
+    /// Build the children, catching any panic. Since the partially-built state of the children
+    /// is lost when they panic, the entities they spawned are found by their change ticks and
+    /// despawned.
+    fn try_build_children(&self, cx: &mut Cx) -> Result<C::State, String> {
+        let last_run = cx.with_world_mut(|world| world.increment_change_tick());
+        catch_unwind(AssertUnwindSafe(|| self.children.build(cx))).map_err(|payload| {
+            let owner = cx.owner();
+            cx.with_world_mut(|world| despawn_spawned_since(world, last_run, owner));
+            panic_message(payload)
+        })
+    }
+
+    /// Record a caught panic: log it and notify the callback.
+    fn report(&self, cx: &mut Cx, message: &str) {
+        error!("Panic caught by ErrorBoundary: {}", message);
+        if let Some(on_error) = self.on_error {
+            cx.with_world_mut(|world| world.commands().run_callback(on_error, message.to_string()));
+        }
+    }
+
+    /// Build the fallback, under a new entity which provides the [`ViewError`] context.
+    fn build_fallback(&self, cx: &mut Cx, message: &str) -> (Entity, FB::State) {
+        let owner = cx.owner();
+        let scope = cx.with_world_mut(|world| {
+            world
+                .spawn((
+                    Name::new("ErrorBoundary"),
+                    Context(ViewError(message.to_string())),
+                ))
+                .set_parent(owner)
+                .id()
+        });
+        (scope, cx.with_owner(scope, |cx| self.fallback.build(cx)))
+    }
+
+    /// Raze the fallback and despawn the entity which provides its context.
+    fn raze_fallback(&self, world: &mut DeferredWorld, fallback: &mut (Entity, FB::State)) {
+        self.fallback.raze(world, &mut fallback.1);
+        world.commands().entity(fallback.0).despawn_recursive();
+    }
+}
+
+impl<C: View, FB: View> View for ErrorBoundary<C, FB> {
+    /// The state of the children if they built successfully, or the panic message, and the
+    /// entity which provides the error context along with the state of the fallback.
+    type State = (Result<C::State, String>, Option<(Entity, FB::State)>);
+
+    fn nodes(&self, world: &World, state: &Self::State, out: &mut Vec<Entity>) {
+        match state {
+            (Ok(ref child_state), _) => self.children.nodes(world, child_state, out),
+            (Err(_), Some((_, ref fb_state))) => self.fallback.nodes(world, fb_state, out),
+            (Err(_), None) => {}
+        }
+    }
+
+    fn build(&self, cx: &mut Cx) -> Self::State {
+        match self.try_build_children(cx) {
+            Ok(child_state) => (Ok(child_state), None),
+            Err(message) => {
+                self.report(cx, &message);
+                let fallback = self.build_fallback(cx, &message);
+                (Err(message), Some(fallback))
+            }
+        }
+    }
+
+    fn rebuild(&self, cx: &mut Cx, state: &mut Self::State) -> bool {
+        match state.0 {
+            Ok(ref mut child_state) => {
+                match catch_unwind(AssertUnwindSafe(|| self.children.rebuild(cx, child_state))) {
+                    Ok(changed) => changed,
+                    Err(payload) => {
+                        // The child state may be partially updated, so discard it.
+                        let message = panic_message(payload);
+                        cx.with_world_mut(|world| {
+                            self.children
+                                .raze(&mut DeferredWorld::from(world), child_state)
+                        });
+                        self.report(cx, &message);
+                        let fallback = self.build_fallback(cx, &message);
+                        *state = (Err(message), Some(fallback));
+                        true
+                    }
+                }
+            }
+
+            Err(_) => match self.try_build_children(cx) {
+                // The children recovered: remove the fallback.
+                Ok(child_state) => {
+                    if let Some(ref mut fallback) = state.1 {
+                        cx.with_world_mut(|world| {
+                            self.raze_fallback(&mut DeferredWorld::from(world), fallback)
+                        });
+                    }
+                    *state = (Ok(child_state), None);
+                    true
+                }
+
+                // Still failing: update the message and patch the fallback.
+                Err(message) => {
+                    self.report(cx, &message);
+                    let changed = match state.1 {
+                        Some((scope, ref mut fb_state)) => {
+                            cx.with_world_mut(|world| {
+                                world
+                                    .entity_mut(scope)
+                                    .insert(Context(ViewError(message.clone())));
+                            });
+                            cx.with_owner(scope, |cx| self.fallback.rebuild(cx, fb_state))
+                        }
+                        None => {
+                            state.1 = Some(self.build_fallback(cx, &message));
+                            true
+                        }
+                    };
+                    state.0 = Err(message);
+                    changed
+                }
+            },
+        }
+    }
+
+    fn attach_children(&self, world: &mut World, state: &mut Self::State) -> bool {
+        match state {
+            (Ok(ref mut child_state), _) => self.children.attach_children(world, child_state),
+            (Err(_), Some((_, ref mut fb_state))) => self.fallback.attach_children(world, fb_state),
+            (Err(_), None) => false,
+        }
+    }
+
+    fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
+        match state {
+            (Ok(ref mut child_state), _) => self.children.raze(world, child_state),
+            (Err(_), Some(ref mut fallback)) => self.raze_fallback(world, fallback),
+            (Err(_), None) => {}
+        }
+    }
+}
+
+/// Despawn the entities which were spawned after `last_run`, other than `owner`: these are the
+/// entities whose components were all added since then. Only the roots of the hierarchies
+/// which were spawned are despawned, along with their descendants.
+fn despawn_spawned_since(world: &mut World, last_run: Tick, owner: Entity) {
+    let this_run = world.change_tick();
+    let spawned: HashSet<Entity> = world
+        .iter_entities()
+        .filter(|entt| {
+            let mut components = entt.archetype().components().peekable();
+            entt.id() != owner
+                && components.peek().is_some()
+                && components.all(|id| {
+                    entt.get_change_ticks_by_id(id)
+                        .is_some_and(|ticks| ticks.is_added(last_run, this_run))
+                })
+        })
+        .map(|entt| entt.id())
+        .collect();
+    for entity in spawned.iter() {
+        let parent = world.get::<Parent>(*entity).map(|parent| parent.get());
+        if !parent.is_some_and(|parent| spawned.contains(&parent)) {
+            world.entity_mut(*entity).despawn_recursive();
+        }
+    }
+}
+
+/// Extract the message from a panic payload.
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracking_scope::TrackingScope;
+    use bevy::prelude::Text;
+
+    /// A text view which panics when built or rebuilt if `fail` is set.
+    struct Fails(bool);
+
+    impl View for Fails {
+        type State = <&'static str as View>::State;
+
+        fn nodes(&self, world: &World, state: &Self::State, out: &mut Vec<Entity>) {
+            "ok".nodes(world, state, out);
+        }
+
+        fn build(&self, cx: &mut Cx) -> Self::State {
+            assert!(!self.0, "build failed");
+            "ok".build(cx)
+        }
+
+        fn rebuild(&self, cx: &mut Cx, state: &mut Self::State) -> bool {
+            assert!(!self.0, "rebuild failed");
+            "ok".rebuild(cx, state)
+        }
+
+        fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
+            "ok".raze(world, state);
+        }
+    }
+
+    fn boundary(fail: bool) -> ErrorBoundary<Fails, &'static str> {
+        ErrorBoundary::new()
+            .children(Fails(fail))
+            .fallback("fallback")
+    }
+
+    #[test]
+    fn test_error_boundary() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+
+        // A panic during build displays the fallback.
+        let view = boundary(true);
+        let mut state = view.build(&mut Cx::new(&mut world, owner, &mut scope));
+        assert_eq!(
+            state.0.as_ref().err().map(String::as_str),
+            Some("build failed")
+        );
+        // The error is provided to the fallback, but not to the owner and its other children.
+        let fallback_scope = state.1.as_ref().unwrap().0;
+        assert_eq!(
+            world
+                .get::<Context<ViewError>>(fallback_scope)
+                .map(|c| c.0.clone()),
+            Some(ViewError("build failed".to_string()))
+        );
+        assert!(world.get::<Context<ViewError>>(owner).is_none());
+
+        // Once the children succeed, the fallback is removed.
+        let view = boundary(false);
+        assert!(view.rebuild(&mut Cx::new(&mut world, owner, &mut scope), &mut state));
+        world.flush();
+        assert!(state.0.is_ok());
+        assert!(state.1.is_none());
+        assert!(world.get_entity(fallback_scope).is_err());
+
+        // A panic during rebuild switches back to the fallback.
+        let view = boundary(true);
+        assert!(view.rebuild(&mut Cx::new(&mut world, owner, &mut scope), &mut state));
+        assert_eq!(
+            state.0.as_ref().err().map(String::as_str),
+            Some("rebuild failed")
+        );
+        let mut nodes = Vec::new();
+        view.nodes(&world, &state, &mut nodes);
+        assert_eq!(nodes.len(), 1);
+    }
+
+    #[test]
+    fn test_error_boundary_despawns_partial_build() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+
+        // The first child is built before the second one panics.
+        let view = ErrorBoundary::new()
+            .children(("partial", Fails(true)))
+            .fallback("fallback");
+        let state = View::build(&view, &mut Cx::new(&mut world, owner, &mut scope));
+        assert!(state.0.is_err());
+        let mut texts: Vec<String> = world
+            .query::<&Text>()
+            .iter(&world)
+            .map(|text| text.0.clone())
+            .collect();
+        texts.sort();
+        assert_eq!(texts, vec!["fallback"]);
+    }
+}
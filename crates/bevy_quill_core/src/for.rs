@@ -1,4 +1,6 @@
-use crate::{ForIndex, View};
+use std::hash::Hash;
+
+use crate::{ForIndex, ForKeyed, ForSlice, Mutable, View};
 
 use super::ForEach;
 
@@ -6,6 +8,8 @@ use super::ForEach;
 /// * `For::each()`
 /// * `For::each_cmp()`
 /// * `For::index()`
+/// * `For::keyed()`
+/// * `For::slice()`
 pub struct For;
 
 impl For {
@@ -58,4 +62,43 @@ impl For {
     ) -> ForEach<Item, Iter, V, impl Fn(&Item, &Item) -> bool, F, ()> {
         ForEach::new(iter, |a, b| a == b, each)
     }
+
+    /// Construct a for loop which identifies each item by a key. The `key` function returns a
+    /// unique key for each item, and `each` returns the view for the item. During rebuild,
+    /// items are matched with the previous ones by key rather than by position or value, so
+    /// when the list is reordered or an item's value changes, the existing child views (and
+    /// any state they hold) are moved and patched rather than razed and rebuilt. Views whose
+    /// keys have disappeared are razed, and views for new keys are built.
+    pub fn keyed<
+        Item: Send + Sync + Clone + 'static,
+        K: Eq + Hash + Send + Sync + 'static,
+        KF: Fn(&Item) -> K + Send + Sync + 'static,
+        V: View,
+        F: Fn(&Item) -> V + Send + Sync + 'static,
+    >(
+        items: &[Item],
+        key: KF,
+        each: F,
+    ) -> ForKeyed<Item, K, KF, V, F, ()> {
+        ForKeyed::new(items, key, each)
+    }
+
+    /// Construct a for loop over the contents of a [`Mutable`] vector. Only this view reacts
+    /// when the vector changes, and the new items are compared element-by-element with the
+    /// previous ones, so that only the views for items which were inserted or removed are
+    /// built or razed. This makes appending to a long list, such as a log, inexpensive.
+    ///
+    /// The `each` function is compared by pointer, so if it is a closure which is created each
+    /// time the parent runs, re-running the parent also re-runs the list. Items which haven't
+    /// changed are still not rebuilt.
+    pub fn slice<
+        Item: Clone + PartialEq + Send + Sync + 'static,
+        V: View,
+        F: Fn(&Item) -> V + Send + Sync + 'static,
+    >(
+        items: Mutable<Vec<Item>>,
+        each: F,
+    ) -> ForSlice<Item, V> {
+        ForSlice::new(items, each)
+    }
 }
@@ -0,0 +1,384 @@
+use std::{fmt::Display, sync::Arc};
+
+use bevy::{
+    prelude::{Component, Entity, With, World},
+    tasks::{block_on, futures_lite::future, Task},
+};
+
+use crate::{Callback, Cond, Cx, For, IntoViewChild, Mutable, View, ViewChild, ViewTemplate};
+
+/// The loading state of an asynchronously-populated list.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum LoadState<T> {
+    /// The load has not started yet.
+    #[default]
+    Pending,
+    /// The load task is running.
+    Loading,
+    /// The load has completed successfully.
+    Ready(T),
+    /// The load failed with the given message.
+    Error(String),
+}
+
+/// Component which holds a running async task. The task is type-erased so that a single
+/// system can poll tasks of every result type. Dropping the component cancels the task.
+#[derive(Component)]
+pub(crate) struct AsyncTaskPoller(Box<dyn FnMut(&mut World) -> bool + Send + Sync>);
+
+impl AsyncTaskPoller {
+    /// Create a poller for `task`, which calls `on_ready` when the task completes.
+    fn new<T: Send + 'static>(
+        mut task: Task<T>,
+        on_ready: impl Fn(&mut World, T) + Send + Sync + 'static,
+    ) -> Self {
+        Self(Box::new(move |world| {
+            match block_on(future::poll_once(&mut task)) {
+                Some(result) => {
+                    on_ready(world, result);
+                    true
+                }
+                None => false,
+            }
+        }))
+    }
+}
+
+/// System which polls all running [`AsyncTaskPoller`]s, and removes them once their task
+/// has completed.
+pub(crate) fn poll_async_tasks(world: &mut World) {
+    let mut q_pollers = world.query_filtered::<Entity, With<AsyncTaskPoller>>();
+    let entities: Vec<Entity> = q_pollers.iter(world).collect();
+    for entity in entities {
+        let Some(mut poller) = world.entity_mut(entity).take::<AsyncTaskPoller>() else {
+            continue;
+        };
+        if !(poller.0)(world) {
+            if let Ok(mut entt) = world.get_entity_mut(entity) {
+                entt.insert(poller);
+            }
+        }
+    }
+}
+
+/// The output of the task which loads the items of a [`ForAsync`]. This is either the list of
+/// items, or a `Result` whose error is displayed using the error view.
+pub trait LoadResult<Item>: Send + 'static {
+    /// Convert the output of the task into either the list of items or an error message.
+    fn into_result(self) -> Result<Vec<Item>, String>;
+}
+
+impl<Item: Send + 'static> LoadResult<Item> for Vec<Item> {
+    fn into_result(self) -> Result<Vec<Item>, String> {
+        Ok(self)
+    }
+}
+
+impl<Item: Send + 'static, E: Display + Send + 'static> LoadResult<Item> for Result<Vec<Item>, E> {
+    fn into_result(self) -> Result<Vec<Item>, String> {
+        self.map_err(|err| err.to_string())
+    }
+}
+
+/// A list view whose items are loaded asynchronously, for example from a file or an asset.
+/// The load callback returns a [`Task`], typically spawned on the
+/// [`AsyncComputeTaskPool`](bevy::tasks::AsyncComputeTaskPool),
+/// which produces the list of items. It is called when the view is first built, and again
+/// whenever `deps` changes. A placeholder view is displayed while the task is running, and the
+/// items are displayed as with [`For::each`] once it completes.
+///
+/// The task is cancelled if the view is razed, or the items are reloaded, before it completes.
+pub struct ForAsync<
+    Item: Clone + PartialEq + Send + Sync + 'static,
+    V: View,
+    D: PartialEq + Clone + Send + Sync + 'static = (),
+    R: LoadResult<Item> = Vec<Item>,
+> {
+    load: Callback<(), Task<R>>,
+    deps: D,
+    each: Arc<dyn Fn(&Item) -> V + Send + Sync>,
+    placeholder: ViewChild,
+    fallback: ViewChild,
+    error: Option<Arc<dyn Fn(&str) -> ViewChild + Send + Sync>>,
+}
+
+impl<
+        Item: Clone + PartialEq + Send + Sync + 'static,
+        V: View,
+        D: PartialEq + Clone + Send + Sync + 'static,
+        R: LoadResult<Item>,
+    > ForAsync<Item, V, D, R>
+{
+    /// Construct a new [`ForAsync`].
+    ///
+    /// Arguments:
+    /// * `load`: Callback which returns a task that produces the list of items.
+    /// * `deps`: Dependencies of the load; when these change, the items are reloaded.
+    /// * `each`: Function which produces a view for each item.
+    ///
+    /// The view is only updated when the `load` callback or `deps` change. Since the other
+    /// arguments are usually closures which are created anew each time the parent runs, they
+    /// are not compared.
+    pub fn new<F: Fn(&Item) -> V + Send + Sync + 'static>(
+        load: Callback<(), Task<R>>,
+        deps: D,
+        each: F,
+    ) -> Self {
+        Self {
+            load,
+            deps,
+            each: Arc::new(each),
+            placeholder: ViewChild::default(),
+            fallback: ViewChild::default(),
+            error: None,
+        }
+    }
+
+    /// Set the view to display while the items are loading.
+    pub fn placeholder(mut self, placeholder: impl IntoViewChild) -> Self {
+        self.placeholder = placeholder.into_view_child();
+        self
+    }
+
+    /// Set the view to display if the list of items is empty.
+    pub fn fallback(mut self, fallback: impl IntoViewChild) -> Self {
+        self.fallback = fallback.into_view_child();
+        self
+    }
+
+    /// Set the function which produces the view to display if loading fails. It is passed
+    /// the error message.
+    pub fn error<E: IntoViewChild>(
+        mut self,
+        error: impl Fn(&str) -> E + Send + Sync + 'static,
+    ) -> Self {
+        self.error = Some(Arc::new(move |message| error(message).into_view_child()));
+        self
+    }
+}
+
+impl<
+        Item: Clone + PartialEq + Send + Sync + 'static,
+        V: View,
+        D: PartialEq + Clone + Send + Sync + 'static,
+        R: LoadResult<Item>,
+    > Clone for ForAsync<Item, V, D, R>
+{
+    fn clone(&self) -> Self {
+        Self {
+            load: self.load,
+            deps: self.deps.clone(),
+            each: self.each.clone(),
+            placeholder: self.placeholder.clone(),
+            fallback: self.fallback.clone(),
+            error: self.error.clone(),
+        }
+    }
+}
+
+impl<
+        Item: Clone + PartialEq + Send + Sync + 'static,
+        V: View,
+        D: PartialEq + Clone + Send + Sync + 'static,
+        R: LoadResult<Item>,
+    > PartialEq for ForAsync<Item, V, D, R>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.load == other.load && self.deps == other.deps
+    }
+}
+
+impl<
+        Item: Clone + PartialEq + Send + Sync + 'static,
+        V: View,
+        D: PartialEq + Clone + Send + Sync + 'static,
+        R: LoadResult<Item>,
+    > ViewTemplate for ForAsync<Item, V, D, R>
+where
+    V::State: Clone,
+{
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let load_state: Mutable<LoadState<Vec<Item>>> = cx.create_mutable(LoadState::Pending);
+        // Entity which holds the running task. Despawning it cancels the task, as does
+        // replacing it with a new one.
+        let task_holder = cx.create_entity();
+        let load = self.load;
+        cx.create_effect(
+            move |world, _| {
+                let task = world.run_system_with_input(load.id, ()).unwrap();
+                load_state.set_clone(world, LoadState::Loading);
+                world.entity_mut(task_holder).insert(AsyncTaskPoller::new(
+                    task,
+                    move |world, result: R| {
+                        load_state.set_clone(
+                            world,
+                            match result.into_result() {
+                                Ok(items) => LoadState::Ready(items),
+                                Err(message) => LoadState::Error(message),
+                            },
+                        );
+                    },
+                ));
+            },
+            self.deps.clone(),
+        );
+        let (items, error) = match load_state.get_clone(cx) {
+            LoadState::Pending | LoadState::Loading => (None, None),
+            LoadState::Ready(items) => (Some(items), None),
+            LoadState::Error(message) => (None, Some(message)),
+        };
+        let error_view = match (&error, &self.error) {
+            (Some(message), Some(error_fn)) => error_fn(message),
+            _ => ViewChild::default(),
+        };
+        let each = self.each.clone();
+
+        (
+            Cond::new(
+                items.is_none() && error.is_none(),
+                self.placeholder.clone(),
+                (),
+            ),
+            Cond::new(
+                items.is_some(),
+                For::each(items.unwrap_or_default(), move |item| each(item))
+                    .with_fallback(self.fallback.clone()),
+                (),
+            ),
+            Cond::new(error.is_some(), error_view, ()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::{build_views, reaction_control_system};
+    use bevy::{
+        prelude::{Resource, Text},
+        tasks::{AsyncComputeTaskPool, TaskPool},
+    };
+
+    fn texts(world: &mut World) -> Vec<String> {
+        world.flush();
+        let mut texts: Vec<String> = world
+            .query::<&Text>()
+            .iter(world)
+            .map(|text| text.0.clone())
+            .collect();
+        texts.sort();
+        texts
+    }
+
+    fn run_until_loaded(world: &mut World) {
+        for _ in 0..1000 {
+            world.increment_change_tick();
+            poll_async_tasks(world);
+            world.flush();
+            reaction_control_system(world);
+            if !is_loading(world) {
+                reaction_control_system(world);
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("Async load did not complete");
+    }
+
+    #[derive(Resource, Default)]
+    struct Catalog {
+        fail: bool,
+        generation: usize,
+    }
+
+    #[derive(Clone, PartialEq)]
+    struct CatalogView;
+
+    impl ViewTemplate for CatalogView {
+        type View = impl View;
+        fn create(&self, cx: &mut Cx) -> Self::View {
+            let generation = cx.use_resource::<Catalog>().generation;
+            let load = cx.create_callback(|world: &mut World| {
+                let catalog = world.resource::<Catalog>();
+                let (fail, generation) = (catalog.fail, catalog.generation);
+                AsyncComputeTaskPool::get().spawn(async move {
+                    if fail {
+                        Err("not found".to_string())
+                    } else {
+                        Ok(vec![format!("a{}", generation), format!("b{}", generation)])
+                    }
+                })
+            });
+            ForAsync::new(load, generation, |item: &String| item.clone())
+                .placeholder("loading")
+                .error(|message| format!("error: {}", message))
+        }
+    }
+
+    fn is_loading(world: &mut World) -> bool {
+        world
+            .query_filtered::<(), With<AsyncTaskPoller>>()
+            .iter(world)
+            .next()
+            .is_some()
+    }
+
+    #[test]
+    fn test_for_async_load() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        let mut world = World::default();
+        world.init_resource::<Catalog>();
+        world.spawn(CatalogView.to_root());
+        build_views(&mut world);
+        assert_eq!(texts(&mut world), vec!["loading"]);
+
+        run_until_loaded(&mut world);
+        assert_eq!(texts(&mut world), vec!["a0", "b0"]);
+    }
+
+    #[test]
+    fn test_for_async_error() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        let mut world = World::default();
+        world.insert_resource(Catalog {
+            fail: true,
+            generation: 0,
+        });
+        world.spawn(CatalogView.to_root());
+        build_views(&mut world);
+        assert_eq!(texts(&mut world), vec!["loading"]);
+
+        run_until_loaded(&mut world);
+        assert_eq!(texts(&mut world), vec!["error: not found"]);
+    }
+
+    #[test]
+    fn test_for_async_reload_on_deps_change() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        let mut world = World::default();
+        world.init_resource::<Catalog>();
+        world.spawn(CatalogView.to_root());
+        build_views(&mut world);
+        run_until_loaded(&mut world);
+        assert_eq!(texts(&mut world), vec!["a0", "b0"]);
+
+        // Re-running the parent with the same deps doesn't reload the items.
+        world.increment_change_tick();
+        world.resource_mut::<Catalog>().fail = true;
+        reaction_control_system(&mut world);
+        assert!(!is_loading(&mut world));
+        assert_eq!(texts(&mut world), vec!["a0", "b0"]);
+
+        // Changing the deps does.
+        world.increment_change_tick();
+        world.insert_resource(Catalog {
+            fail: false,
+            generation: 1,
+        });
+        reaction_control_system(&mut world);
+        assert!(is_loading(&mut world));
+        run_until_loaded(&mut world);
+        assert_eq!(texts(&mut world), vec!["a1", "b1"]);
+    }
+}
@@ -5,7 +5,7 @@ use bevy::{
     prelude::Entity,
 };
 
-use crate::{lcs::lcs, Cx, View};
+use crate::{lcs::lcs, Cx, ForSlice, Mutable, View};
 
 pub struct ListItem<Value: Clone, V: View> {
     value: Value,
@@ -61,6 +61,21 @@ impl<
     }
 }
 
+impl ForEach<(), Vec<()>, (), fn(&(), &()) -> bool, fn(&()), ()> {
+    /// Construct a for loop over the contents of a [`Mutable`] vector. This is the same as
+    /// [`For::slice`](crate::For::slice).
+    pub fn slice<
+        Item: Clone + PartialEq + Send + Sync + 'static,
+        V: View,
+        F: Fn(&Item) -> V + Send + Sync + 'static,
+    >(
+        items: Mutable<Vec<Item>>,
+        each: F,
+    ) -> ForSlice<Item, V> {
+        ForSlice::new(items, each)
+    }
+}
+
 impl<
         Item: Send + Clone,
         Iter: IntoIterator<Item = Item> + Clone,
@@ -116,7 +131,7 @@ where
         if lcs_length == 0 {
             // Raze old elements
             for i in prev_range {
-                prev_state[i].raze(&mut DeferredWorld::from(cx.world_mut()));
+                cx.with_world_mut(|world| prev_state[i].raze(&mut DeferredWorld::from(world)));
                 changed = true;
             }
             // Build new elements
@@ -153,7 +168,7 @@ where
             } else {
                 // Deletions
                 for i in prev_range.start..prev_start {
-                    prev_state[i].raze(&mut DeferredWorld::from(cx.world_mut()));
+                    cx.with_world_mut(|world| prev_state[i].raze(&mut DeferredWorld::from(world)));
                     changed = true;
                 }
             }
@@ -199,7 +214,7 @@ where
             } else {
                 // Deletions
                 for i in prev_end..prev_range.end {
-                    prev_state[i].raze(&mut DeferredWorld::from(cx.world_mut()));
+                    cx.with_world_mut(|world| prev_state[i].raze(&mut DeferredWorld::from(world)));
                     changed = true;
                 }
             }
@@ -269,7 +284,9 @@ where
             match state.1 {
                 // If there are > 0 items, destroy fallback if present.
                 Some(ref mut fb_ent) if next_len > 0 => {
-                    fallback.raze(&mut DeferredWorld::from(cx.world_mut()), fb_ent);
+                    cx.with_world_mut(|world| {
+                        fallback.raze(&mut DeferredWorld::from(world), fb_ent)
+                    });
                     state.1 = None;
                     changed = true;
                 }
@@ -109,10 +109,9 @@ where
             prev_len -= 1;
             let child_state = &mut state.0[prev_len];
             if let Some(ref view) = child_state.view {
-                view.raze(
-                    &mut DeferredWorld::from(cx.world_mut()),
-                    &mut child_state.state,
-                );
+                cx.with_world_mut(|world| {
+                    view.raze(&mut DeferredWorld::from(world), &mut child_state.state)
+                });
             }
             state.0.pop();
             changed = true;
@@ -123,7 +122,9 @@ where
             match state.1 {
                 // If there are > 0 items, destroy fallback if present.
                 Some(ref mut fb_ent) if next_len > 0 => {
-                    fallback.raze(&mut DeferredWorld::from(cx.world_mut()), fb_ent);
+                    cx.with_world_mut(|world| {
+                        fallback.raze(&mut DeferredWorld::from(world), fb_ent)
+                    });
                     state.1 = None;
                     changed = true;
                 }
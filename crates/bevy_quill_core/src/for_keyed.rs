@@ -0,0 +1,272 @@
+use std::{collections::HashMap, hash::Hash};
+
+use bevy::ecs::world::{DeferredWorld, World};
+use bevy::prelude::Entity;
+
+use crate::{Cx, View};
+
+pub struct KeyedListItem<V: View> {
+    view: V,
+    state: V::State,
+}
+
+impl<V: View> KeyedListItem<V> {
+    fn nodes(&self, world: &World, out: &mut Vec<Entity>) {
+        self.view.nodes(world, &self.state, out);
+    }
+
+    fn raze(&mut self, world: &mut DeferredWorld) {
+        self.view.raze(world, &mut self.state);
+    }
+}
+
+#[doc(hidden)]
+pub struct ForKeyed<
+    Item: Send + Clone,
+    K: Eq + Hash,
+    KF: Fn(&Item) -> K + Send,
+    V: View,
+    F: Fn(&Item) -> V + Send,
+    FB: View,
+> {
+    items: Vec<Item>,
+    key: KF,
+    each: F,
+    fallback: Option<FB>,
+}
+
+impl<
+        Item: Send + Clone,
+        K: Eq + Hash,
+        KF: Fn(&Item) -> K + Send,
+        V: View,
+        F: Fn(&Item) -> V + Send,
+    > ForKeyed<Item, K, KF, V, F, ()>
+{
+    pub fn new(items: &[Item], key: KF, each: F) -> Self {
+        Self {
+            items: Vec::from(items),
+            key,
+            each,
+            fallback: None,
+        }
+    }
+}
+
+impl<
+        Item: Send + Clone,
+        K: Eq + Hash,
+        KF: Fn(&Item) -> K + Send,
+        V: View,
+        F: Fn(&Item) -> V + Send,
+        FB: View,
+    > ForKeyed<Item, K, KF, V, F, FB>
+{
+    pub fn with_fallback<FB2: View>(self, fallback: FB2) -> ForKeyed<Item, K, KF, V, F, FB2> {
+        ForKeyed::<Item, K, KF, V, F, FB2> {
+            items: self.items,
+            key: self.key,
+            each: self.each,
+            fallback: Some(fallback),
+        }
+    }
+}
+
+impl<
+        Item: Send + Sync + Clone + 'static,
+        K: Eq + Hash + Send + Sync + 'static,
+        KF: Fn(&Item) -> K + Send + Sync + 'static,
+        V: View,
+        F: Fn(&Item) -> V + Send + Sync + 'static,
+        FB: View,
+    > View for ForKeyed<Item, K, KF, V, F, FB>
+{
+    type State = (Vec<(K, KeyedListItem<V>)>, Option<FB::State>);
+
+    fn nodes(&self, world: &World, state: &Self::State, out: &mut Vec<Entity>) {
+        state.0.iter().for_each(|(_, item)| item.nodes(world, out));
+        if let Some(ref fallback) = self.fallback {
+            if let Some(ref fbstate) = state.1 {
+                fallback.nodes(world, fbstate, out);
+            }
+        }
+    }
+
+    fn build(&self, cx: &mut Cx) -> Self::State {
+        let mut state = (Vec::new(), None);
+        self.rebuild(cx, &mut state);
+        state
+    }
+
+    fn rebuild(&self, cx: &mut Cx, state: &mut Self::State) -> bool {
+        let next_len = self.items.len();
+        let prev_len = state.0.len();
+        let mut changed = false;
+
+        // Index the previous items by key, remembering their previous position so that we can
+        // tell whether the order changed.
+        let mut prev_items: HashMap<K, (usize, KeyedListItem<V>)> =
+            HashMap::with_capacity(prev_len);
+        for (index, (key, item)) in state.0.drain(..).enumerate() {
+            // Keys should be unique; if not, only the last item with a given key is kept.
+            if let Some((_, mut dup)) = prev_items.insert(key, (index, item)) {
+                cx.with_world_mut(|world| dup.raze(&mut DeferredWorld::from(world)));
+                changed = true;
+            }
+        }
+
+        let mut next_state: Vec<(K, KeyedListItem<V>)> = Vec::with_capacity(next_len);
+        for (index, value) in self.items.iter().enumerate() {
+            let key = (self.key)(value);
+            let view = (self.each)(value);
+            match prev_items.remove(&key) {
+                // Existing item: patch the view in place, preserving its state.
+                Some((prev_index, mut item)) => {
+                    changed |= view.rebuild(cx, &mut item.state);
+                    changed |= prev_index != index;
+                    item.view = view;
+                    next_state.push((key, item));
+                }
+
+                // New item: build it from scratch.
+                None => {
+                    let state = view.build(cx);
+                    next_state.push((key, KeyedListItem { view, state }));
+                    changed = true;
+                }
+            }
+        }
+
+        // Raze items whose keys are no longer present.
+        for (_, (_, mut item)) in prev_items.drain() {
+            cx.with_world_mut(|world| item.raze(&mut DeferredWorld::from(world)));
+            changed = true;
+        }
+
+        state.0 = next_state;
+
+        // Handle fallback
+        if let Some(ref fallback) = self.fallback {
+            match state.1 {
+                // If there are > 0 items, destroy fallback if present.
+                Some(ref mut fb_ent) if next_len > 0 => {
+                    cx.with_world_mut(|world| {
+                        fallback.raze(&mut DeferredWorld::from(world), fb_ent)
+                    });
+                    state.1 = None;
+                    changed = true;
+                }
+
+                // If there are no items, render fallback unless already rendered.
+                None if next_len == 0 => {
+                    state.1 = Some(fallback.build(cx));
+                    changed = true;
+                }
+
+                // Otherwise, no change.
+                _ => {}
+            }
+        }
+
+        changed
+    }
+
+    fn attach_children(&self, world: &mut World, state: &mut Self::State) -> bool {
+        let mut changed = false;
+        for (_, item) in state.0.iter_mut() {
+            changed |= item.view.attach_children(world, &mut item.state);
+        }
+        if let Some(ref mut fbstate) = state.1 {
+            changed |= self
+                .fallback
+                .as_ref()
+                .unwrap()
+                .attach_children(world, fbstate);
+        }
+        changed
+    }
+
+    fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
+        for (_, item) in state.0.iter_mut() {
+            item.raze(world);
+        }
+        if let Some(ref mut fbstate) = state.1 {
+            self.fallback.as_ref().unwrap().raze(world, fbstate);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tracking_scope::TrackingScope, For, ViewTemplate};
+
+    #[derive(Clone, PartialEq)]
+    struct Row(u32);
+
+    impl ViewTemplate for Row {
+        type View = impl View;
+        fn create(&self, cx: &mut Cx) -> Self::View {
+            let count = cx.create_mutable(self.0);
+            count.get(cx).to_string()
+        }
+    }
+
+    type RowsState = (Vec<(u32, KeyedListItem<Row>)>, Option<()>);
+
+    fn rows(items: &[u32]) -> impl View<State = RowsState> {
+        For::keyed(items, |item| *item, |item| Row(*item))
+    }
+
+    fn row_entities(state: &RowsState) -> Vec<Entity> {
+        state.0.iter().map(|(_, item)| item.state.0).collect()
+    }
+
+    fn nodes(world: &World, view: &impl View<State = RowsState>, state: &RowsState) -> Vec<Entity> {
+        let mut out = Vec::new();
+        View::nodes(view, world, state, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_keyed_reorder_keeps_state() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+
+        let view = rows(&[1, 2, 3]);
+        let mut state = View::build(&view, &mut Cx::new(&mut world, owner, &mut scope));
+        let entities = row_entities(&state);
+        let text_nodes = nodes(&world, &view, &state);
+        assert_eq!(entities.len(), 3);
+        assert_eq!(text_nodes.len(), 3);
+
+        // Reordering moves the existing child views rather than rebuilding them.
+        let view = rows(&[3, 1, 2]);
+        assert!(View::rebuild(
+            &view,
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state
+        ));
+        world.flush();
+        assert_eq!(
+            row_entities(&state),
+            vec![entities[2], entities[0], entities[1]]
+        );
+        assert_eq!(
+            nodes(&world, &view, &state),
+            vec![text_nodes[2], text_nodes[0], text_nodes[1]]
+        );
+
+        // Removing a key razes only that item.
+        let view = rows(&[3, 2]);
+        assert!(View::rebuild(
+            &view,
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state
+        ));
+        world.flush();
+        assert_eq!(row_entities(&state), vec![entities[2], entities[1]]);
+        assert!(world.get_entity(entities[0]).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use crate::{Cx, For, Mutable, View, ViewTemplate};
+
+/// A list view whose items are read from a [`Mutable`] containing a `Vec`. Created by
+/// [`For::slice`].
+///
+/// The mutable is read within this view's own tracking scope, so a change to the vector only
+/// re-runs this view rather than the view which contains it. The new items are then compared
+/// element-by-element with the previous ones, as with [`For::each`]: only the items which
+/// were inserted or removed are built or razed, so appending to a long list is cheap.
+pub struct ForSlice<Item: Clone + PartialEq + Send + Sync + 'static, V: View> {
+    items: Mutable<Vec<Item>>,
+    each: Arc<dyn Fn(&Item) -> V + Send + Sync>,
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> ForSlice<Item, V> {
+    pub(crate) fn new<F: Fn(&Item) -> V + Send + Sync + 'static>(
+        items: Mutable<Vec<Item>>,
+        each: F,
+    ) -> Self {
+        Self {
+            items,
+            each: Arc::new(each),
+        }
+    }
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> Clone for ForSlice<Item, V> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items,
+            each: self.each.clone(),
+        }
+    }
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> PartialEq for ForSlice<Item, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && Arc::ptr_eq(&self.each, &other.each)
+    }
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> ViewTemplate for ForSlice<Item, V>
+where
+    V::State: Clone,
+{
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let items = self.items.get_clone(cx);
+        let each = self.each.clone();
+        For::each(items, move |item| each(item))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::view::{build_views, reaction_control_system};
+    use crate::{ForEach, TrackingScope};
+    use bevy::prelude::*;
+
+    static EACH_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Resource, Default)]
+    struct LogState {
+        runs: usize,
+        items: Option<Mutable<Vec<String>>>,
+    }
+
+    #[derive(Resource, Default)]
+    struct Generation(usize);
+
+    #[derive(Clone, PartialEq)]
+    struct LogView;
+
+    impl ViewTemplate for LogView {
+        type View = impl View;
+        fn create(&self, cx: &mut Cx) -> Self::View {
+            cx.use_resource::<Generation>();
+            let items = cx.create_mutable(vec!["a".to_string()]);
+            cx.with_world_mut(|world| {
+                let mut log = world.resource_mut::<LogState>();
+                log.runs += 1;
+                log.items = Some(items);
+            });
+            For::slice(items, |item: &String| {
+                EACH_CALLS.fetch_add(1, Ordering::Relaxed);
+                item.clone()
+            })
+        }
+    }
+
+    fn texts(world: &mut World) -> Vec<String> {
+        world.flush();
+        let mut texts: Vec<String> = world
+            .query::<&Text>()
+            .iter(world)
+            .map(|text| text.0.clone())
+            .collect();
+        texts.sort();
+        texts
+    }
+
+    #[test]
+    fn test_for_slice_reacts_without_parent() {
+        let mut world = World::default();
+        world.init_resource::<LogState>();
+        world.init_resource::<Generation>();
+        world.spawn(LogView.to_root());
+        build_views(&mut world);
+        assert_eq!(texts(&mut world), vec!["a"]);
+        assert_eq!(world.resource::<LogState>().runs, 1);
+
+        // Changing the vector updates the list, but doesn't re-run the parent.
+        let items = world.resource::<LogState>().items.unwrap();
+        world.increment_change_tick();
+        items.update(&mut world, |mut items| items.push("b".to_string()));
+        reaction_control_system(&mut world);
+        assert_eq!(texts(&mut world), vec!["a", "b"]);
+        assert_eq!(world.resource::<LogState>().runs, 1);
+
+        // Re-running the parent re-runs the list, since `each` is a new closure, but the items
+        // which haven't changed are not rebuilt.
+        let each_calls = EACH_CALLS.load(Ordering::Relaxed);
+        world.increment_change_tick();
+        world.resource_mut::<Generation>().0 += 1;
+        reaction_control_system(&mut world);
+        assert_eq!(world.resource::<LogState>().runs, 2);
+        assert_eq!(EACH_CALLS.load(Ordering::Relaxed), each_calls);
+        assert_eq!(texts(&mut world), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_for_each_slice() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let items = Cx::new(&mut world, owner, &mut scope).create_mutable(vec!["a".to_string()]);
+        world.flush();
+
+        // `ForEach::slice` is another name for `For::slice`.
+        let view = ForEach::slice(items, |item: &String| item.clone());
+        assert!(view == view.clone());
+        world.spawn(view.to_root());
+        build_views(&mut world);
+        assert_eq!(texts(&mut world), vec!["a"]);
+    }
+}
@@ -0,0 +1,60 @@
+use bevy::ecs::world::{DeferredWorld, World};
+use bevy::prelude::Entity;
+
+use crate::View;
+
+/// A `Fragment` groups several views together without introducing a parent entity of its own.
+/// The display nodes produced by the children are passed through unchanged, so they become
+/// direct children of whatever entity encloses the fragment. This is useful when a
+/// [`ViewTemplate`](crate::ViewTemplate) needs to return more than one top-level node.
+///
+/// Tuples of views already behave this way: `(a, b, c)` is itself a fragment. `Fragment` exists
+/// to make that intent explicit at the call site, and adds no overhead over the bare tuple.
+pub struct Fragment<A: View> {
+    children: A,
+}
+
+impl<A: View> Fragment<A> {
+    /// Construct a new [`Fragment`] from a view, typically a tuple of views.
+    pub fn new(children: A) -> Self {
+        Self { children }
+    }
+}
+
+impl<A: View> View for Fragment<A> {
+    type State = A::State;
+
+    fn nodes(&self, world: &World, state: &Self::State, out: &mut Vec<Entity>) {
+        self.children.nodes(world, state, out)
+    }
+
+    fn build(&self, cx: &mut crate::Cx) -> Self::State {
+        self.children.build(cx)
+    }
+
+    fn rebuild(&self, cx: &mut crate::Cx, state: &mut Self::State) -> bool {
+        self.children.rebuild(cx, state)
+    }
+
+    fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
+        self.children.raze(world, state)
+    }
+
+    fn attach_children(&self, world: &mut World, state: &mut Self::State) -> bool {
+        self.children.attach_children(world, state)
+    }
+}
+
+impl<A: View + Clone> Clone for Fragment<A> {
+    fn clone(&self) -> Self {
+        Self {
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<A: View + PartialEq> PartialEq for Fragment<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.children.eq(&other.children)
+    }
+}
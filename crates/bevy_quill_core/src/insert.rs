@@ -14,8 +14,11 @@ impl<B: Bundle, F: Fn(D) -> B + Send + Sync, D: PartialEq + Clone + Send + Sync>
 {
     type State = D;
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let mut target = cx.world_mut().entity_mut(target);
-        target.insert((self.factory)(self.deps.clone()));
+        cx.with_world_mut(|world| {
+            world
+                .entity_mut(target)
+                .insert((self.factory)(self.deps.clone()));
+        });
         self.deps.clone()
     }
 
@@ -26,6 +29,48 @@ impl<B: Bundle, F: Fn(D) -> B + Send + Sync, D: PartialEq + Clone + Send + Sync>
     }
 }
 
+/// Inserts a bundle into the target. If the deps change, then the bundle will be recomputed,
+/// but it will only be reinserted if it differs from the previously-inserted value. This avoids
+/// needless change detection for bundles which are expensive to update or which are often
+/// recomputed with the same value.
+pub struct CachedInsertBundleEffect<
+    B: Bundle + PartialEq + Clone,
+    F: Fn(D) -> B,
+    D: PartialEq + Clone,
+> {
+    pub factory: F,
+    pub deps: D,
+}
+
+impl<
+        B: Bundle + PartialEq + Clone,
+        F: Fn(D) -> B + Send + Sync,
+        D: PartialEq + Clone + Send + Sync,
+    > EntityEffect for CachedInsertBundleEffect<B, F, D>
+{
+    type State = (D, B);
+    fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
+        let bundle = (self.factory)(self.deps.clone());
+        cx.with_world_mut(|world| {
+            world.entity_mut(target).insert(bundle.clone());
+        });
+        (self.deps.clone(), bundle)
+    }
+
+    fn reapply(&self, cx: &mut Cx, target: Entity, state: &mut Self::State) {
+        if state.0 != self.deps {
+            state.0 = self.deps.clone();
+            let bundle = (self.factory)(self.deps.clone());
+            if state.1 != bundle {
+                cx.with_world_mut(|world| {
+                    world.entity_mut(target).insert(bundle.clone());
+                });
+                state.1 = bundle;
+            }
+        }
+    }
+}
+
 /// Conditionally inserts a bundle into the target. If the condition is true, then the bundle
 /// will be inserted. If the condition later becomes false, the component will be removed.
 pub struct ConditionalInsertComponentEffect<B: Bundle, F: Fn() -> B> {
@@ -39,8 +84,9 @@ impl<C: Component, F: Fn() -> C + Send + Sync> EntityEffect
     type State = bool;
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
         if self.condition {
-            let mut target = cx.world_mut().entity_mut(target);
-            target.insert((self.factory)());
+            cx.with_world_mut(|world| {
+                world.entity_mut(target).insert((self.factory)());
+            });
         }
         self.condition
     }
@@ -51,8 +97,9 @@ impl<C: Component, F: Fn() -> C + Send + Sync> EntityEffect
             if self.condition {
                 self.apply(cx, target);
             } else {
-                let mut target = cx.world_mut().entity_mut(target);
-                target.remove::<C>();
+                cx.with_world_mut(|world| {
+                    world.entity_mut(target).remove::<C>();
+                });
             }
         }
     }
@@ -66,8 +113,9 @@ pub struct StaticInsertBundleEffect<B: Bundle + Clone> {
 impl<B: Bundle + Clone> EntityEffect for StaticInsertBundleEffect<B> {
     type State = ();
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let mut target = cx.world_mut().entity_mut(target);
-        target.insert(self.bundle.clone());
+        cx.with_world_mut(|world| {
+            world.entity_mut(target).insert(self.bundle.clone());
+        });
     }
 
     fn reapply(&self, _cx: &mut Cx, _target: Entity, _state: &mut Self::State) {}
@@ -1,13 +1,20 @@
 #![feature(impl_trait_in_assoc_type, associated_type_defaults)]
 mod callback;
 mod cond;
+mod context;
 mod cx;
+mod diagnostics;
 mod dynamic;
 pub mod effects;
 mod element;
+mod error_boundary;
 mod r#for;
+mod for_async;
 mod for_each;
 mod for_index;
+mod for_keyed;
+mod for_slice;
+mod fragment;
 pub mod insert;
 mod lcs;
 mod mutable;
@@ -21,69 +28,160 @@ mod view_child;
 mod view_template;
 
 use bevy::{
-    app::{App, Plugin, Update},
-    prelude::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
+    app::{App, AppExit, Last, Plugin, Update},
+    prelude::{on_event, IntoSystemConfigs, IntoSystemSetConfigs, Resource, SystemSet},
 };
 use bevy_mod_stylebuilder::{StyleBuilderPlugin, StyleBuilderSystemSet};
+use cx::run_view_timers;
+use for_async::poll_async_tasks;
 
 pub mod prelude {
     pub use super::QuillPlugin;
     pub use crate::callback::*;
-    pub use crate::cond::Cond;
-    pub use crate::cx::Cx;
+    pub use crate::cond::{Cond, CondPersistent};
     pub use crate::cx::EffectOptions;
+    pub use crate::cx::{AssetLoadState, Cx};
     pub use crate::element::*;
+    pub use crate::error_boundary::{ErrorBoundary, ViewError};
+    pub use crate::for_async::{ForAsync, LoadResult, LoadState};
     pub use crate::for_each::ForEach;
     pub use crate::for_index::ForIndex;
+    pub use crate::for_keyed::ForKeyed;
+    pub use crate::for_slice::ForSlice;
+    pub use crate::fragment::Fragment;
     pub use crate::mutable::*;
     pub use crate::r#for::For;
     pub use crate::switch::Switch;
     pub use crate::tracking_scope::TriggerReaction;
     pub use crate::view::*;
-    pub use crate::view_child::{IntoViewChild, ViewChild};
+    pub use crate::view_child::{IntoViewChild, SharedView, ViewChild};
     pub use crate::view_template::ViewTemplate;
+    pub use bevy_quill_macros::view_template;
 }
 
 pub use callback::*;
-pub use cond::Cond;
-pub use cx::Cx;
+pub use cond::{Cond, CondPersistent};
 pub use cx::EffectOptions;
+pub use cx::{AssetLoadState, Cx};
+pub use diagnostics::{QuillDiagnosticsPlugin, ReactivityDiagnostics};
 pub use dynamic::Dynamic;
 pub use element::*;
+pub use error_boundary::{ErrorBoundary, ViewError};
+pub use for_async::{ForAsync, LoadResult, LoadState};
 pub use for_each::ForEach;
 pub use for_index::ForIndex;
+pub use for_keyed::ForKeyed;
+pub use for_slice::ForSlice;
+pub use fragment::Fragment;
 pub use mutable::*;
 pub use portal::Portal;
 pub use r#for::For;
 pub use switch::Switch;
-use tracking_scope::cleanup_tracking_scopes;
 pub use tracking_scope::TrackingScope;
 pub use tracking_scope::TrackingScopeTracing;
 pub use tracking_scope::TriggerReaction;
 pub use view::*;
 pub use view_child::IntoViewChild;
+pub use view_child::SharedView;
 pub use view_child::ViewChild;
 pub use view_template::ViewTemplate;
 
+pub use bevy_quill_macros::view_template;
+
 /// SystemSet that contains the logic to update the quill within the world.
 /// This will run before StyleBuilderSystemSet.
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct QuillUpdateSystemSet;
 
+/// Plugin which installs the Quill reactive framework.
 pub struct QuillPlugin;
 
+impl QuillPlugin {
+    /// Returns a [`QuillConfig`] with the given maximum divergence, which can be added as a
+    /// plugin in place of `QuillPlugin`:
+    ///
+    /// ```ignore
+    /// app.add_plugins(QuillPlugin::with_max_divergence(64));
+    /// ```
+    pub fn with_max_divergence(max_divergence: usize) -> QuillConfig {
+        QuillConfig { max_divergence }
+    }
+}
+
+/// Resource which contains configuration options for Quill. The plugin inserts the default
+/// configuration unless the app has already inserted one; it can also be replaced at any time.
+///
+/// A `QuillConfig` is also a plugin, which inserts itself and then adds [`QuillPlugin`]:
+///
+/// ```ignore
+/// app.add_plugins(QuillConfig { max_divergence: 64 });
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct QuillConfig {
+    /// Maximum number of reaction passes which fail to reduce the number of changed scopes
+    /// before the reaction control system gives up and panics. Setting this to `usize::MAX`
+    /// disables the check entirely.
+    pub max_divergence: usize,
+}
+
+impl Default for QuillConfig {
+    fn default() -> Self {
+        Self {
+            max_divergence: DEFAULT_MAX_DIVERGENCE,
+        }
+    }
+}
+
+impl Plugin for QuillConfig {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.clone()).add_plugins(QuillPlugin);
+    }
+}
+
+/// Marker resource indicating that the Quill component hooks have been registered.
+#[derive(Resource, Debug, Default)]
+pub struct QuillInitialized;
+
 impl Plugin for QuillPlugin {
     fn build(&self, app: &mut App) {
-        cleanup_tracking_scopes(app.world_mut());
-        cleanup_view_roots(app.world_mut());
+        if !app.world().contains_resource::<QuillInitialized>() {
+            cleanup_view_roots(app.world_mut());
+            app.insert_resource(QuillInitialized);
+        }
 
-        app.add_plugins(StyleBuilderPlugin)
+        app.init_resource::<QuillConfig>()
+            .add_plugins(StyleBuilderPlugin)
             .add_systems(
                 Update,
-                (build_views, reaction_control_system, reattach_children)
+                (
+                    build_views,
+                    poll_async_tasks,
+                    run_view_timers,
+                    run_mutable_watchers,
+                    reaction_control_system,
+                    reattach_children,
+                    cleanup_named_mutables,
+                )
                     .chain()
                     .in_set(QuillUpdateSystemSet),
             )
+            .add_systems(
+                Update,
+                update_resource_selectors.before(QuillUpdateSystemSet),
+            )
+            .add_systems(Last, despawn_view_roots.run_if(on_event::<AppExit>))
             .configure_sets(Update, QuillUpdateSystemSet.before(StyleBuilderSystemSet));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_max_divergence() {
+        let mut app = App::new();
+        app.add_plugins(QuillPlugin::with_max_divergence(64));
+        assert_eq!(app.world().resource::<QuillConfig>().max_divergence, 64);
+    }
+}
@@ -1,15 +1,168 @@
+use std::{any::TypeId, sync::Arc};
+
 use bevy::{
     ecs::{
-        component::ComponentId,
+        component::{ComponentId, Tick},
         world::{Command, DeferredWorld},
     },
     prelude::*,
+    utils::HashMap,
 };
 
+use crate::{Callback, RunCallback};
+
 /// Contains a mutable reactive value.
 #[derive(Component)]
 pub(crate) struct MutableCell<T>(pub(crate) T);
 
+/// Key which identifies a named mutable: the type of the template which owns it (if the owner
+/// is a template), the type of the value, and a user-supplied name.
+pub(crate) type NamedMutableKey = (Option<TypeId>, TypeId, String);
+
+struct NamedMutableEntry {
+    /// The entity that holds the mutable value.
+    cell: Entity,
+    /// The number of live tracking scopes that reference this mutable.
+    refs: usize,
+}
+
+/// Resource which holds the mutables created by [`Cx::create_mutable_keyed`], so that their
+/// values survive when the view which owns them is razed and rebuilt. A named mutable is
+/// despawned at the end of the update in which its last owner is razed without being rebuilt.
+///
+/// [`Cx::create_mutable_keyed`]: crate::Cx::create_mutable_keyed
+#[derive(Resource, Default)]
+pub struct NamedMutableRegistry {
+    entries: HashMap<NamedMutableKey, NamedMutableEntry>,
+}
+
+impl NamedMutableRegistry {
+    /// Look up an existing named mutable, adding a reference to it.
+    pub(crate) fn acquire(&mut self, key: &NamedMutableKey) -> Option<Entity> {
+        self.entries.get_mut(key).map(|entry| {
+            entry.refs += 1;
+            entry.cell
+        })
+    }
+
+    /// Register a newly-created named mutable, with a single reference.
+    pub(crate) fn insert(&mut self, key: NamedMutableKey, cell: Entity) {
+        self.entries
+            .insert(key, NamedMutableEntry { cell, refs: 1 });
+    }
+}
+
+/// Command which releases a reference to a named mutable.
+pub(crate) struct ReleaseNamedMutableCmd(pub(crate) NamedMutableKey);
+
+impl Command for ReleaseNamedMutableCmd {
+    fn apply(self, world: &mut World) {
+        if let Some(mut registry) = world.get_resource_mut::<NamedMutableRegistry>() {
+            if let Some(entry) = registry.entries.get_mut(&self.0) {
+                entry.refs = entry.refs.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// System which despawns named mutables that are no longer referenced by any view.
+pub(crate) fn cleanup_named_mutables(world: &mut World) {
+    let Some(mut registry) = world.get_resource_mut::<NamedMutableRegistry>() else {
+        return;
+    };
+    let mut released: Vec<Entity> = Vec::new();
+    registry.entries.retain(|_, entry| {
+        if entry.refs == 0 {
+            released.push(entry.cell);
+            false
+        } else {
+            true
+        }
+    });
+    for cell in released {
+        if let Ok(ent) = world.get_entity_mut(cell) {
+            ent.despawn();
+        }
+    }
+}
+
+/// Component which holds the callbacks registered with [`Mutable::watch`]. It lives on the
+/// mutable's entity, so the watchers are dropped along with the mutable.
+#[derive(Component)]
+pub(crate) struct MutableWatcher {
+    /// The component id of the mutable cell.
+    component: ComponentId,
+    /// The change tick at which the mutable was last checked for changes.
+    last_run: Tick,
+    /// Functions which read the current value and pass it to a watcher callback, along with
+    /// the entity of the callback's system, so that a callback is only registered once.
+    watchers: Vec<(Entity, Arc<dyn Fn(&mut World) + Send + Sync>)>,
+}
+
+/// Component which keeps a mutable up to date with a value selected from a resource. See
+/// [`Cx::use_resource_selector`].
+///
+/// [`Cx::use_resource_selector`]: crate::Cx::use_resource_selector
+#[derive(Component)]
+pub(crate) struct ResourceSelector(pub(crate) Arc<dyn Fn(&mut World) + Send + Sync>);
+
+impl ResourceSelector {
+    /// Create a selector which writes the result of `selector` into the mutable `cell`
+    /// whenever the result differs from the current value.
+    pub(crate) fn new<R: Resource, T: PartialEq + Send + Sync + 'static>(
+        cell: Entity,
+        selector: impl Fn(&R) -> T + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(move |world: &mut World| {
+            let value = selector(world.resource::<R>());
+            if let Some(mut current) = world.get_mut::<MutableCell<T>>(cell) {
+                // Only mark the mutable as changed if the selected value is different.
+                if current.0 != value {
+                    current.0 = value;
+                }
+            }
+        }))
+    }
+}
+
+/// System which updates the mutables created by [`Cx::use_resource_selector`]. The selectors
+/// are run every update, so they should be cheap.
+///
+/// [`Cx::use_resource_selector`]: crate::Cx::use_resource_selector
+pub(crate) fn update_resource_selectors(world: &mut World) {
+    let mut q_selectors = world.query::<&ResourceSelector>();
+    let selectors: Vec<_> = q_selectors
+        .iter(world)
+        .map(|selector| selector.0.clone())
+        .collect();
+    for selector in selectors {
+        selector(world);
+    }
+}
+
+/// System which runs the watchers of all mutables that have changed since the last update.
+pub(crate) fn run_mutable_watchers(world: &mut World) {
+    let this_run = world.change_tick();
+    let mut q_watchers = world.query_filtered::<Entity, With<MutableWatcher>>();
+    let entities: Vec<Entity> = q_watchers.iter(world).collect();
+    for entity in entities {
+        let mut entt = world.entity_mut(entity);
+        let mut watcher = entt.get_mut::<MutableWatcher>().unwrap();
+        let last_run = watcher.last_run;
+        let component = watcher.component;
+        watcher.last_run = this_run;
+        let changed = entt
+            .get_change_ticks_by_id(component)
+            .is_some_and(|ticks| ticks.is_changed(last_run, this_run));
+        if changed {
+            let watchers = entt.get::<MutableWatcher>().unwrap().watchers.clone();
+            for (_, watcher) in watchers {
+                watcher(world);
+            }
+        }
+    }
+}
+
 /// Contains a reference to a reactive mutable variable.
 #[derive(PartialEq, Debug)]
 pub struct Mutable<T> {
@@ -29,6 +182,22 @@ impl<T> Mutable<T> {
     }
 }
 
+impl<T> Mutable<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Set the value of this [`Mutable`], marking it as changed even if the new value is
+    /// equal to the current one. Unlike [`Mutable::set`], this can be used with types which
+    /// don't implement `PartialEq`.
+    ///
+    /// Arguments:
+    /// * `cx`: The reactive context.
+    /// * `value`: The new value.
+    pub fn set_force<R: WriteMutable>(&self, cx: &mut R, value: T) {
+        cx.write_mutable_force(self.cell, value);
+    }
+}
+
 impl<T> Copy for Mutable<T> {}
 impl<T> Clone for Mutable<T> {
     fn clone(&self) -> Self {
@@ -77,7 +246,9 @@ where
         cx.read_mutable(self)
     }
 
-    /// Set the value of this [`Mutable`] with Copy semantics.
+    /// Set the value of this [`Mutable`] with Copy semantics. Views which depend on the
+    /// mutable are only re-run if the new value is different; use [`Mutable::set_force`] to
+    /// always notify them.
     ///
     /// Arguments:
     /// * `cx`: The reactive context.
@@ -99,7 +270,8 @@ where
         cx.read_mutable_clone(self)
     }
 
-    /// Set the value of this [`Mutable`] with Clone semantics.
+    /// Set the value of this [`Mutable`] with Clone semantics. As with [`Mutable::set`],
+    /// nothing is changed if the new value equals the current one.
     ///
     /// Arguments:
     /// * `cx`: The reactive context.
@@ -107,6 +279,40 @@ where
     pub fn set_clone<R: WriteMutable>(&self, cx: &mut R, value: T) {
         cx.write_mutable_clone(self.cell, value);
     }
+
+    /// Register a callback which is called with the new value whenever this [`Mutable`]
+    /// changes, regardless of whether it was changed by a view or by some other system.
+    /// Watchers are run once per update, and are removed when the mutable is despawned.
+    /// Registering the same callback more than once has no effect, so it is safe to call this
+    /// from a presenter which runs many times.
+    ///
+    /// Arguments:
+    /// * `world`: The Bevy world.
+    /// * `callback`: The callback to run when the value changes.
+    pub fn watch(&self, world: &mut World, callback: Callback<In<T>>) {
+        let cell = self.cell;
+        let watcher: Arc<dyn Fn(&mut World) + Send + Sync> = Arc::new(move |world| {
+            let value = world.get::<MutableCell<T>>(cell).unwrap().0.clone();
+            world.run_callback(callback, value);
+        });
+        let key = callback.id.entity();
+        let this_run = world.change_tick();
+        let mut entt = world.entity_mut(cell);
+        match entt.get_mut::<MutableWatcher>() {
+            Some(mut existing) => {
+                if !existing.watchers.iter().any(|(id, _)| *id == key) {
+                    existing.watchers.push((key, watcher));
+                }
+            }
+            None => {
+                entt.insert(MutableWatcher {
+                    component: self.component,
+                    last_run: this_run,
+                    watchers: vec![(key, watcher)],
+                });
+            }
+        }
+    }
 }
 
 /// Trait for low-level read-access to mutables given an entity id.
@@ -148,6 +354,12 @@ pub trait WriteMutable {
     where
         T: Send + Sync + Clone + PartialEq + 'static;
 
+    /// Write the value of a mutable variable, marking it as changed even if the value being
+    /// set matches the existing value.
+    fn write_mutable_force<T>(&mut self, mutable: Entity, value: T)
+    where
+        T: Send + Sync + 'static;
+
     /// Update a mutable value in place using a callback. The callback is passed a
     /// `Mut<T>` which can be used to modify the value.
     fn update_mutable<T, F: FnOnce(Mut<T>)>(&mut self, mutable: Entity, updater: F)
@@ -172,6 +384,20 @@ impl<T: Send + Sync + 'static + PartialEq> Command for UpdateMutableCell<T> {
     }
 }
 
+/// Command which updates the state of a mutable cell without comparing it to the
+/// existing value.
+pub(crate) struct ForceUpdateMutableCell<T> {
+    pub(crate) mutable: Entity,
+    pub(crate) value: T,
+}
+
+impl<T: Send + Sync + 'static> Command for ForceUpdateMutableCell<T> {
+    fn apply(self, world: &mut World) {
+        let mut mutable_ent = world.entity_mut(self.mutable);
+        mutable_ent.get_mut::<MutableCell<T>>().unwrap().0 = self.value;
+    }
+}
+
 impl ReadMutable for World {
     fn read_mutable<T>(&self, mutable: &Mutable<T>) -> T
     where
@@ -225,6 +451,16 @@ impl WriteMutable for World {
         self.commands().queue(UpdateMutableCell { mutable, value });
     }
 
+    /// Write the value of a mutable variable, marking it as changed even if the value being
+    /// set matches the existing value.
+    fn write_mutable_force<T>(&mut self, mutable: Entity, value: T)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.commands()
+            .queue(ForceUpdateMutableCell { mutable, value });
+    }
+
     /// Update a mutable value in place using a callback. The callback is passed a
     /// `Mut<T>` which can be used to modify the value.
     fn update_mutable<T, F: FnOnce(Mut<T>)>(&mut self, mutable: Entity, updater: F)
@@ -290,6 +526,16 @@ impl<'w> WriteMutable for DeferredWorld<'w> {
         self.commands().queue(UpdateMutableCell { mutable, value });
     }
 
+    /// Write the value of a mutable variable, marking it as changed even if the value being
+    /// set matches the existing value.
+    fn write_mutable_force<T>(&mut self, mutable: Entity, value: T)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.commands()
+            .queue(ForceUpdateMutableCell { mutable, value });
+    }
+
     /// Update a mutable value in place using a callback. The callback is passed a
     /// `Mut<T>` which can be used to modify the value.
     fn update_mutable<T, F: FnOnce(Mut<T>)>(&mut self, mutable: Entity, updater: F)
@@ -369,4 +615,75 @@ mod tests {
         assert_eq!(reader.get_clone(&cx), "Goodbye".to_string());
         assert_eq!(reader2.get(&cx), 0);
     }
+
+    #[test]
+    fn test_mutable_set_unchanged() {
+        let mut world = World::default();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let owner = world.spawn_empty().id();
+        let mut cx = Cx::new(&mut world, owner, &mut scope);
+        let mutable = cx.create_mutable::<i32>(1);
+        assert_eq!(mutable.get(&cx), 1);
+        let tick = world.change_tick();
+        scope.tick = tick;
+
+        // Setting the current value doesn't trigger a rebuild.
+        world.increment_change_tick();
+        mutable.set(&mut world, 1);
+        world.flush();
+        let tick = world.change_tick();
+        assert!(!scope.dependencies_changed(&world, tick));
+
+        // Unless the write is forced.
+        mutable.set_force(&mut world, 1);
+        world.flush();
+        let tick = world.change_tick();
+        assert!(scope.dependencies_changed(&world, tick));
+
+        // Setting a different value does trigger a rebuild.
+        scope.tick = world.change_tick();
+        world.increment_change_tick();
+        mutable.set(&mut world, 2);
+        world.flush();
+        let tick = world.change_tick();
+        assert!(scope.dependencies_changed(&world, tick));
+    }
+
+    #[derive(Resource, Default)]
+    struct Watched(Vec<i32>);
+
+    #[test]
+    fn test_mutable_watch() {
+        let mut world = World::default();
+        world.init_resource::<Watched>();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let owner = world.spawn_empty().id();
+        let mut cx = Cx::new(&mut world, owner, &mut scope);
+        let mutable = cx.create_mutable::<i32>(0);
+
+        let id = world.register_system(|value: In<i32>, mut watched: ResMut<Watched>| {
+            watched.0.push(*value);
+        });
+        mutable.watch(&mut world, Callback { id });
+        // Watching with the same callback again, as a presenter would on each run, doesn't
+        // register it twice.
+        mutable.watch(&mut world, Callback { id });
+
+        // Watchers are not run if the value hasn't changed.
+        world.increment_change_tick();
+        run_mutable_watchers(&mut world);
+        assert!(world.resource::<Watched>().0.is_empty());
+
+        // Changed externally, rather than via `set`.
+        world.increment_change_tick();
+        world.update_mutable(mutable.id(), |mut value: Mut<i32>| *value = 5);
+        world.increment_change_tick();
+        run_mutable_watchers(&mut world);
+        assert_eq!(world.resource::<Watched>().0, vec![5]);
+
+        // Only run once per change.
+        world.increment_change_tick();
+        run_mutable_watchers(&mut world);
+        assert_eq!(world.resource::<Watched>().0, vec![5]);
+    }
 }
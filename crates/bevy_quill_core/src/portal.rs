@@ -1,4 +1,5 @@
 use bevy::ecs::world::{DeferredWorld, World};
+use bevy::hierarchy::{BuildChildren, Parent};
 use bevy::prelude::Entity;
 use bevy::ui::TargetCamera;
 
@@ -7,19 +8,39 @@ use crate::View;
 /// A `Portal` represents a UI node that is displayed with no parent node, causing it's location to
 /// be relative to the window rather than any parent node. This only affects the display hierarchy,
 /// the [`View`] hierarchy is unaffected.
+///
+/// Alternatively, the portal can attach its children to a specific entity using
+/// [`Portal::target_entity`]; this is useful in apps with multiple camera viewports, where the
+/// children need to be displayed on a particular camera.
 pub struct Portal<A: View> {
     children: A,
+    target: Option<Entity>,
 }
 
 impl<A: View> Portal<A> {
     /// Construct a new [`Portal`] view.
     pub fn new(children: A) -> Self {
-        Self { children }
+        Self {
+            children,
+            target: None,
+        }
+    }
+
+    /// Set the entity which the portal's children are attached to. If `None`, the children
+    /// are displayed with no parent, on the same camera as the portal.
+    ///
+    /// The target should not be an element whose children are managed by another view, since
+    /// those children are replaced whenever that view is rebuilt.
+    pub fn target_entity(mut self, target: impl Into<Option<Entity>>) -> Self {
+        self.target = target.into();
+        self
     }
 }
 
 impl<A: View> View for Portal<A> {
-    type State = (A::State, Option<Entity>);
+    /// The state of the children, the inherited camera, and the entity the children are
+    /// currently attached to.
+    type State = (A::State, Option<Entity>, Option<Entity>);
 
     fn nodes(&self, _world: &World, _state: &Self::State, _out: &mut Vec<Entity>) {}
 
@@ -27,11 +48,19 @@ impl<A: View> View for Portal<A> {
         let camera = cx
             .use_inherited_component::<TargetCamera>()
             .map(|c| c.entity());
-        (self.children.build(cx), camera)
+        let mut state = (self.children.build(cx), camera, None);
+        // The children are not attached until the parent calls `attach_children`, which doesn't
+        // happen on the first build, so attach them to the target entity now.
+        if self.target.is_some() {
+            cx.with_world_mut(|world| self.attach_to_target(world, &mut state));
+        }
+        state
     }
 
     fn rebuild(&self, cx: &mut crate::Cx, state: &mut Self::State) -> bool {
-        self.children.rebuild(cx, &mut state.0)
+        // If the target changed, report a change so that the children get moved.
+        let changed = self.children.rebuild(cx, &mut state.0);
+        changed || state.2 != self.target
     }
 
     fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
@@ -40,15 +69,43 @@ impl<A: View> View for Portal<A> {
 
     fn attach_children(&self, world: &mut World, state: &mut Self::State) -> bool {
         self.children.attach_children(world, &mut state.0);
-        // Make sure all children are on the correct camera.
-        if let Some(camera) = state.1 {
-            let mut nodes: Vec<Entity> = Vec::new();
-            self.children.nodes(world, &state.0, &mut nodes);
-            for node in nodes.to_vec().iter() {
-                world.entity_mut(*node).insert(TargetCamera(camera));
+        self.attach_to_target(world, state);
+        false
+    }
+}
+
+impl<A: View> Portal<A> {
+    /// Attach the children to the target entity, or detach them from the previous target and
+    /// place them on the inherited camera if there is no target.
+    fn attach_to_target(&self, world: &mut World, state: &mut <Self as View>::State) {
+        let mut nodes: Vec<Entity> = Vec::new();
+        self.children.nodes(world, &state.0, &mut nodes);
+        match self.target {
+            // Attach the children to the target entity, unless already attached.
+            Some(target) if world.get_entity(target).is_ok() => {
+                for node in nodes.iter() {
+                    if world.get::<Parent>(*node).map(|p| p.get()) != Some(target) {
+                        world.entity_mut(target).add_child(*node);
+                    }
+                }
+                state.2 = Some(target);
+            }
+
+            _ => {
+                // Detach the children from the previous target, if any.
+                if state.2.take().is_some() {
+                    for node in nodes.iter() {
+                        world.entity_mut(*node).remove_parent();
+                    }
+                }
+                // Make sure all children are on the correct camera.
+                if let Some(camera) = state.1 {
+                    for node in nodes.iter() {
+                        world.entity_mut(*node).insert(TargetCamera(camera));
+                    }
+                }
             }
         }
-        false
     }
 }
 
@@ -56,12 +113,39 @@ impl<A: View + Clone> Clone for Portal<A> {
     fn clone(&self) -> Self {
         Self {
             children: self.children.clone(),
+            target: self.target,
         }
     }
 }
 
 impl<A: View + PartialEq> PartialEq for Portal<A> {
     fn eq(&self, other: &Self) -> bool {
-        self.children.eq(&other.children)
+        self.children.eq(&other.children) && self.target == other.target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cx, TrackingScope};
+    use bevy::prelude::Text;
+
+    #[test]
+    fn test_portal_target_entity_on_first_build() {
+        let mut world = World::default();
+        world.register_component::<TargetCamera>();
+        let owner = world.spawn_empty().id();
+        let target = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        let portal = Portal::new("child").target_entity(target);
+        let state = View::build(&portal, &mut Cx::new(&mut world, owner, &mut scope));
+
+        // The children are attached to the target without waiting for a rebuild.
+        let mut nodes = Vec::new();
+        View::nodes(&portal.children, &world, &state.0, &mut nodes);
+        assert_eq!(nodes.len(), 1);
+        assert!(world.get::<Text>(nodes[0]).is_some());
+        assert_eq!(world.get::<Parent>(nodes[0]).map(|p| p.get()), Some(target));
+        assert_eq!(state.2, Some(target));
     }
 }
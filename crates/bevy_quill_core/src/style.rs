@@ -1,30 +1,70 @@
 use bevy::{prelude::Entity, ui};
-use bevy_mod_stylebuilder::{StyleBuilder, StyleTuple};
+use bevy_mod_stylebuilder::{LayoutDirection, StyleBuilder, StyleTuple};
 
 use crate::{effects::EntityEffect, Cx};
 
-/// Inserts a static, pre-constructed bundle into the target entity. No reactivity.
-pub struct ApplyStaticStylesEffect<S: StyleTuple> {
-    pub(crate) styles: S,
+/// Returns the current [`LayoutDirection`], adding it as a dependency of the current scope if
+/// the resource exists.
+fn use_layout_direction(cx: &Cx) -> LayoutDirection {
+    if cx.world().contains_resource::<LayoutDirection>() {
+        *cx.use_resource::<LayoutDirection>()
+    } else {
+        LayoutDirection::default()
+    }
 }
 
-impl<S: StyleTuple> EntityEffect for ApplyStaticStylesEffect<S> {
-    type State = ();
-    fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let mut target = cx.world_mut().entity_mut(target);
+/// Applies styles to the target entity. If any of the styles used logical (start/end)
+/// properties, returns the [`LayoutDirection`] they were applied with, and adds it as a
+/// dependency; otherwise the styles don't need to react to it, and this returns `None`.
+fn apply_styles(
+    cx: &mut Cx,
+    target: Entity,
+    styles: impl FnOnce(&mut StyleBuilder),
+) -> Option<LayoutDirection> {
+    let uses_direction = cx.with_world_mut(|world| {
+        let mut target = world.entity_mut(target);
         let mut node = ui::Node::default();
         if let Some(s) = target.get::<ui::Node>() {
             node.clone_from(s);
         }
         let mut sb = StyleBuilder::new(&mut target, node);
-        self.styles.apply(&mut sb);
+        styles(&mut sb);
+        let uses_direction = sb.uses_layout_direction();
         sb.finish();
+        uses_direction
+    });
+    uses_direction.then(|| use_layout_direction(cx))
+}
+
+/// Returns true if styles which were applied with the given [`LayoutDirection`] need to be
+/// re-applied.
+fn direction_changed(cx: &Cx, direction: Option<LayoutDirection>) -> bool {
+    direction.is_some_and(|direction| direction != use_layout_direction(cx))
+}
+
+/// Inserts a static, pre-constructed bundle into the target entity. The styles are only
+/// re-applied if they use logical properties and the [`LayoutDirection`] changes.
+pub struct ApplyStaticStylesEffect<S: StyleTuple> {
+    pub(crate) styles: S,
+}
+
+impl<S: StyleTuple> EntityEffect for ApplyStaticStylesEffect<S> {
+    type State = Option<LayoutDirection>;
+    fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
+        apply_styles(cx, target, |sb| self.styles.apply(sb))
+    }
+
+    fn reapply(&self, cx: &mut Cx, target: Entity, state: &mut Self::State) {
+        if direction_changed(cx, *state) {
+            *state = self.apply(cx, target);
+        }
     }
 }
 
 /// Applies dynamic styles which are computed reactively. The `deps` field is used to determine
 /// whether the styles need to be recomputed; if the deps have not changed since the previous
-/// update cycle, then the styles are not recomputed.
+/// update cycle, then the styles are not recomputed, unless they use logical properties and
+/// the [`LayoutDirection`] has changed.
 pub struct ApplyDynamicStylesEffect<F: Fn(D, &mut StyleBuilder), D: PartialEq + Clone> {
     pub(crate) style_fn: F,
     pub(crate) deps: D,
@@ -33,22 +73,50 @@ pub struct ApplyDynamicStylesEffect<F: Fn(D, &mut StyleBuilder), D: PartialEq +
 impl<F: Fn(D, &mut StyleBuilder) + Send + Sync, D: PartialEq + Clone + Send + Sync> EntityEffect
     for ApplyDynamicStylesEffect<F, D>
 {
-    type State = D;
+    type State = (D, Option<LayoutDirection>);
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let mut target = cx.world_mut().entity_mut(target);
-        let mut node = ui::Node::default();
-        if let Some(s) = target.get::<ui::Node>() {
-            node.clone_from(s);
-        }
-        let mut sb = StyleBuilder::new(&mut target, node);
-        (self.style_fn)(self.deps.clone(), &mut sb);
-        sb.finish();
-        self.deps.clone()
+        let direction = apply_styles(cx, target, |sb| (self.style_fn)(self.deps.clone(), sb));
+        (self.deps.clone(), direction)
     }
 
     fn reapply(&self, cx: &mut Cx, target: Entity, state: &mut Self::State) {
-        if *state != self.deps {
+        if state.0 != self.deps || direction_changed(cx, state.1) {
             *state = self.apply(cx, target);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracking_scope::TrackingScope;
+    use bevy::prelude::World;
+    use bevy_mod_stylebuilder::StyleBuilderLayout;
+
+    /// Applies the styles in a fresh scope, and returns whether a change to the
+    /// [`LayoutDirection`] causes the scope to react.
+    fn reacts_to_direction<S: StyleTuple>(styles: S) -> bool {
+        let mut world = World::default();
+        world.insert_resource(LayoutDirection::Ltr);
+        world.increment_change_tick();
+        let target = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+        ApplyStaticStylesEffect { styles }
+            .apply(&mut Cx::new(&mut world, target, &mut scope), target);
+
+        world.increment_change_tick();
+        *world.resource_mut::<LayoutDirection>() = LayoutDirection::Rtl;
+        let tick = world.change_tick();
+        scope.dependencies_changed(&world, tick)
+    }
+
+    #[test]
+    fn test_layout_direction_dependency() {
+        assert!(!reacts_to_direction(|sb: &mut StyleBuilder| {
+            sb.padding_left(4);
+        }));
+        assert!(reacts_to_direction(|sb: &mut StyleBuilder| {
+            sb.padding_inline_start(4);
+        }));
+    }
+}
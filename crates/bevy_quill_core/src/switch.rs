@@ -110,7 +110,19 @@ impl_case_append!(V0, 0; V1, 1; V2, 2; V3, 3; V4, 4; V5, 5; V6, 6; V7, 7; V8, 8;
 impl_case_append!(V0, 0; V1, 1; V2, 2; V3, 3; V4, 4; V5, 5; V6, 6; V7, 7; V8, 8; V9, 9; V10, 10; V11, 11; V12, 12; V13, 13; V14, 14);
 impl_case_append!(V0, 0; V1, 1; V2, 2; V3, 3; V4, 4; V5, 5; V6, 6; V7, 7; V8, 8; V9, 9; V10, 10; V11, 11; V12, 12; V13, 13; V14, 14; V15, 15);
 
-/// A conditional view which renders one of two children depending on the condition expression.
+/// A conditional view which renders one of several children depending on a value. Each case
+/// pairs a value with a view, and the views for different cases can be of different types. If
+/// no case matches, the fallback view is rendered instead.
+///
+/// Only the view for the matching case is built. When the value changes to select a different
+/// case, the view for the old case is razed and the view for the new case is built.
+///
+/// ```ignore
+/// Switch::new(mode)
+///     .case(Mode::Edit, EditPanel)
+///     .case(Mode::Preview, "Preview")
+///     .fallback("Unknown mode")
+/// ```
 pub struct Switch<Value, Cases: CaseTuple<Value>, Fallback: View> {
     value: Value,
     cases: Cases,
@@ -131,6 +143,8 @@ impl<Value> Switch<Value, (), ()> {
 impl<Value: Send + Sync + PartialEq, Cases: CaseTuple<Value>, Fallback: View>
     Switch<Value, Cases, Fallback>
 {
+    /// Add a case to the switch: `case` is rendered when the switch value equals `value`.
+    /// Cases are tested in the order they were added.
     pub fn case<CV: View>(
         self,
         value: Value,
@@ -146,6 +160,7 @@ impl<Value: Send + Sync + PartialEq, Cases: CaseTuple<Value>, Fallback: View>
         }
     }
 
+    /// Set the view to render when none of the cases match.
     pub fn fallback<F: View>(self, fallback: F) -> Switch<Value, Cases, F> {
         Switch {
             value: self.value,
@@ -190,18 +205,22 @@ impl<
             }
 
             (Some(index), Some(new_index)) => {
-                self.cases
-                    .at(index)
-                    .raze(&mut DeferredWorld::from(cx.world_mut()), &mut state.1);
+                cx.with_world_mut(|world| {
+                    self.cases
+                        .at(index)
+                        .raze(&mut DeferredWorld::from(world), &mut state.1)
+                });
                 state.0 = Some(new_index);
                 state.1 = self.cases.at(new_index).build(cx);
                 true
             }
 
             (Some(index), None) => {
-                self.cases
-                    .at(index)
-                    .raze(&mut DeferredWorld::from(cx.world_mut()), &mut state.1);
+                cx.with_world_mut(|world| {
+                    self.cases
+                        .at(index)
+                        .raze(&mut DeferredWorld::from(world), &mut state.1)
+                });
                 state.0 = None;
                 state.1 = Box::new(self.fallback.build(cx));
                 true
@@ -209,8 +228,9 @@ impl<
 
             (None, Some(new_index)) => {
                 if let Some(st) = state.1.downcast_mut::<Fallback::State>() {
-                    self.fallback
-                        .raze(&mut DeferredWorld::from(cx.world_mut()), st)
+                    cx.with_world_mut(|world| {
+                        self.fallback.raze(&mut DeferredWorld::from(world), st)
+                    });
                 }
                 state.0 = Some(new_index);
                 state.1 = self.cases.at(new_index).build(cx);
@@ -252,3 +272,88 @@ impl<
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracking_scope::TrackingScope;
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    enum Mode {
+        Text,
+        Pair,
+        Empty,
+        Other,
+    }
+
+    fn switch(mode: Mode) -> impl View<State = (Option<usize>, BoxedState)> {
+        Switch::new(mode)
+            .case(Mode::Text, "text")
+            .case(Mode::Pair, ("first", "second".to_string()))
+            .case(Mode::Empty, ())
+            .fallback("fallback")
+    }
+
+    fn nodes(
+        world: &World,
+        view: &impl View<State = (Option<usize>, BoxedState)>,
+        state: &(Option<usize>, BoxedState),
+    ) -> Vec<Entity> {
+        let mut out = Vec::new();
+        View::nodes(view, world, state, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_switch_between_cases() {
+        let mut world = World::default();
+        let owner = world.spawn_empty().id();
+        let mut scope = TrackingScope::new(world.change_tick());
+
+        let view = switch(Mode::Text);
+        let mut state = View::build(&view, &mut Cx::new(&mut world, owner, &mut scope));
+        assert_eq!(state.0, Some(0));
+        let text_nodes = nodes(&world, &view, &state);
+        assert_eq!(text_nodes.len(), 1);
+
+        // Rebuilding with the same case keeps the existing entities.
+        let view = switch(Mode::Text);
+        assert!(!View::rebuild(
+            &view,
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state
+        ));
+        assert_eq!(nodes(&world, &view, &state), text_nodes);
+
+        // Switching cases razes the old view and builds the new one.
+        let view = switch(Mode::Pair);
+        assert!(View::rebuild(
+            &view,
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state
+        ));
+        world.flush();
+        assert_eq!(state.0, Some(1));
+        assert_eq!(nodes(&world, &view, &state).len(), 2);
+        assert!(world.get_entity(text_nodes[0]).is_err());
+
+        let view = switch(Mode::Empty);
+        assert!(View::rebuild(
+            &view,
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state
+        ));
+        world.flush();
+        assert_eq!(state.0, Some(2));
+        assert!(nodes(&world, &view, &state).is_empty());
+
+        let view = switch(Mode::Other);
+        assert!(View::rebuild(
+            &view,
+            &mut Cx::new(&mut world, owner, &mut scope),
+            &mut state
+        ));
+        assert_eq!(state.0, None);
+        assert_eq!(nodes(&world, &view, &state).len(), 1);
+    }
+}
@@ -1,6 +1,5 @@
 use bevy::{
     ecs::world::DeferredWorld,
-    hierarchy::BuildChildren,
     prelude::{Entity, Text, World},
 };
 
@@ -8,7 +7,7 @@ use bevy::{
 use bevy::log::info;
 use bevy_mod_stylebuilder::UseInheritedTextStyles;
 
-use crate::{cx::Cx, View};
+use crate::{cx::Cx, view::despawn_if_exists, View};
 
 impl View for String {
     type State = Entity;
@@ -18,11 +17,11 @@ impl View for String {
     }
 
     fn build(&self, cx: &mut Cx) -> Self::State {
-        build_text_view(cx.world_mut(), self)
+        cx.with_world_mut(|world| build_text_view(world, self))
     }
 
     fn rebuild(&self, cx: &mut crate::cx::Cx, state: &mut Self::State) -> bool {
-        rebuild_text_view(cx.world_mut(), self, state)
+        cx.with_world_mut(|world| rebuild_text_view(world, self, state))
     }
 
     fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
@@ -30,7 +29,7 @@ impl View for String {
         info!("Razing String View: {}", *state);
 
         // Delete the text node.
-        world.commands().entity(*state).remove_parent().despawn();
+        despawn_if_exists(world, *state);
     }
 }
 
@@ -42,11 +41,11 @@ impl<'a: 'static> View for &'a str {
     }
 
     fn build(&self, cx: &mut Cx) -> Self::State {
-        build_text_view(cx.world_mut(), self)
+        cx.with_world_mut(|world| build_text_view(world, self))
     }
 
     fn rebuild(&self, cx: &mut crate::cx::Cx, state: &mut Self::State) -> bool {
-        rebuild_text_view(cx.world_mut(), self, state)
+        cx.with_world_mut(|world| rebuild_text_view(world, self, state))
     }
 
     fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
@@ -54,7 +53,7 @@ impl<'a: 'static> View for &'a str {
         info!("Razing &str View: {}", *state);
 
         // Delete the text node.
-        world.commands().entity(*state).remove_parent().despawn();
+        despawn_if_exists(world, *state);
     }
 }
 
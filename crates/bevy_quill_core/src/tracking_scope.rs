@@ -1,28 +1,69 @@
 use std::{
     any::Any,
-    sync::{atomic::AtomicBool, Arc},
+    sync::{atomic::AtomicBool, Arc, Mutex},
 };
 
 use bevy::{
     ecs::{
         component::{ComponentId, Tick},
+        query::{QueryFilter, QueryState},
         world::{Command, DeferredWorld},
     },
     prelude::*,
     utils::HashSet,
 };
 
-use crate::{AnyCallback, UnregisterCallbackCmd};
+use crate::{
+    mutable::{NamedMutableKey, ReleaseNamedMutableCmd},
+    AnyCallback, UnregisterCallbackCmd,
+};
 
 /// Tracks the sequence of hook calls within a reaction.
 #[derive(Clone)]
 pub(crate) enum HookState {
     Entity(Entity),
     Mutable(Entity, ComponentId),
+    NamedMutable(Entity, ComponentId, NamedMutableKey),
     Callback(Arc<dyn AnyCallback + Send + Sync>),
     Effect(Arc<dyn Any + Send + Sync + 'static>),
     Memo(Arc<dyn Any + Send + Sync + 'static>),
     Observer(Entity, Entity, Arc<dyn Any + Send + Sync + 'static>),
+    Mount,
+    Unmount,
+    Timer(Entity),
+}
+
+impl HookState {
+    /// The name of the `Cx` method which creates this kind of hook, for diagnostic messages.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            HookState::Entity(_) => "create_entity",
+            HookState::Mutable(_, _) => "create_mutable",
+            HookState::NamedMutable(_, _, _) => "create_mutable_keyed",
+            HookState::Callback(_) => "create_callback",
+            HookState::Effect(_) => "create_effect",
+            HookState::Memo(_) => "create_memo",
+            HookState::Observer(_, _, _) => "create_observer",
+            HookState::Mount => "on_mount",
+            HookState::Unmount => "on_unmount",
+            HookState::Timer(_) => "create_interval/create_timeout",
+        }
+    }
+}
+
+/// A function which is run when a view is mounted or unmounted.
+pub(crate) type LifecycleFn = Box<dyn FnOnce(&mut World, Entity) + 'static + Sync + Send>;
+
+/// A dependency on the set of entities matched by a query.
+pub(crate) struct QueryDependency {
+    /// Type name of the query, for diagnostic messages.
+    name: &'static str,
+
+    /// The entities matched by the query when it was tracked, in iteration order.
+    entities: Vec<Entity>,
+
+    /// Returns the entities currently matched by the query.
+    matches: Box<dyn Fn(&World) -> Vec<Entity> + Send + Sync>,
 }
 
 /// A component that tracks the dependencies of a reactive task.
@@ -37,13 +78,37 @@ pub struct TrackingScope {
     /// Set of components that we are currently subscribed to.
     component_deps: HashSet<(Entity, ComponentId, bool)>,
 
+    /// Set of components whose presence, but not value, we are subscribed to. The flag records
+    /// whether the component was present when it was tracked.
+    presence_deps: HashSet<(Entity, ComponentId, bool)>,
+
+    /// Queries whose set of matching entities we are subscribed to.
+    query_deps: Vec<QueryDependency>,
+
     /// Set of resources that we are currently subscribed to.
     resource_deps: HashSet<ComponentId>,
 
+    /// Set of resources written by the view via [`Cx::write_resource`]. Changes to these
+    /// resources do not trigger a reaction, even if they were also read.
+    ///
+    /// [`Cx::write_resource`]: crate::Cx::write_resource
+    resource_writes: HashSet<ComponentId>,
+
     /// Allows a tracking scope to be explictly marked as changed for reasons other than
     /// a component or resource dependency mutation.
     changed: AtomicBool,
 
+    /// True if the view owned by this scope deferred its build (see
+    /// [`ViewBuildResult::Pending`](crate::ViewBuildResult::Pending)). Pending scopes are
+    /// retried once per frame, without being marked as changed.
+    pub(crate) pending: bool,
+
+    /// True if the scope belongs to a hidden branch of a [`CondPersistent`]. Suspended scopes
+    /// do not react; changes to their dependencies are picked up once they are resumed.
+    ///
+    /// [`CondPersistent`]: crate::CondPersistent
+    pub(crate) suspended: bool,
+
     /// Engine tick used for determining if components have changed. This represents the
     /// time of the previous reaction.
     pub(crate) tick: Tick,
@@ -51,6 +116,12 @@ pub struct TrackingScope {
     /// List of cleanup functions to call when the scope is dropped.
     #[allow(clippy::type_complexity)]
     pub(crate) cleanups: Vec<Box<dyn FnOnce(&mut DeferredWorld) + 'static + Sync + Send>>,
+
+    /// List of functions to call once the view has been built for the first time.
+    pub(crate) mounts: Vec<LifecycleFn>,
+
+    /// List of functions to call when the view is razed.
+    pub(crate) unmounts: Vec<LifecycleFn>,
 }
 
 /// A resource which, if inserted, displays the view entities that have reacted this frame.
@@ -70,10 +141,17 @@ impl TrackingScope {
             hook_states: Vec::new(),
             next_hook_index: 0,
             component_deps: HashSet::default(),
+            presence_deps: HashSet::default(),
+            query_deps: Vec::new(),
             resource_deps: HashSet::default(),
+            resource_writes: HashSet::default(),
             changed: AtomicBool::new(false),
+            pending: false,
+            suspended: false,
             tick,
             cleanups: Vec::new(),
+            mounts: Vec::new(),
+            unmounts: Vec::new(),
         }
     }
 
@@ -89,6 +167,11 @@ impl TrackingScope {
         self.next_hook_index += 1;
     }
 
+    /// The index of the most recently returned hook, for diagnostic messages.
+    pub(crate) fn hook_index(&self) -> usize {
+        self.next_hook_index.saturating_sub(1)
+    }
+
     pub(crate) fn next_hook(&mut self) -> Option<HookState> {
         if self.next_hook_index < self.hook_states.len() {
             let hook = self.hook_states[self.next_hook_index].clone();
@@ -107,14 +190,79 @@ impl TrackingScope {
         self.cleanups.push(Box::new(cleanup));
     }
 
+    /// Add a function which will be run once, after the first build of the view.
+    pub(crate) fn add_mount(
+        &mut self,
+        mount: impl FnOnce(&mut World, Entity) + 'static + Sync + Send,
+    ) {
+        self.mounts.push(Box::new(mount));
+    }
+
+    /// Add a function which will be run once, when the view is razed.
+    pub(crate) fn add_unmount(
+        &mut self,
+        unmount: impl FnOnce(&mut World, Entity) + 'static + Sync + Send,
+    ) {
+        self.unmounts.push(Box::new(unmount));
+    }
+
+    /// Run the mount functions of the tracking scope on `entity`, if any.
+    pub(crate) fn run_mounts(world: &mut World, entity: Entity) {
+        let Some(mut scope) = world.get_mut::<TrackingScope>(entity) else {
+            return;
+        };
+        let mounts = std::mem::take(&mut scope.mounts);
+        for mount_fn in mounts {
+            mount_fn(world, entity);
+        }
+    }
+
+    /// Run the unmount functions of the tracking scope on `entity`, if any.
+    pub(crate) fn run_unmounts(world: &mut World, entity: Entity) {
+        let Some(mut scope) = world.get_mut::<TrackingScope>(entity) else {
+            return;
+        };
+        let unmounts = std::mem::take(&mut scope.unmounts);
+        for unmount_fn in unmounts {
+            unmount_fn(world, entity);
+        }
+    }
+
     /// Convenience method for adding a resource dependency.
     pub(crate) fn track_resource<T: Resource>(&mut self, world: &World) {
-        self.resource_deps.insert(
-            world
-                .components()
-                .resource_id::<T>()
-                .unwrap_or_else(|| panic!("Unknown resource type: {}", std::any::type_name::<T>())),
-        );
+        let id = Self::resource_id::<T>(world);
+        self.resource_deps.insert(id);
+        self.warn_read_write::<T>(id);
+    }
+
+    /// Record that the resource is written by this scope, so that the write does not cause
+    /// the scope to react.
+    pub(crate) fn track_resource_write<T: Resource>(&mut self, world: &World) {
+        let id = Self::resource_id::<T>(world);
+        self.resource_writes.insert(id);
+        self.warn_read_write::<T>(id);
+    }
+
+    fn resource_id<T: Resource>(world: &World) -> ComponentId {
+        world
+            .components()
+            .resource_id::<T>()
+            .unwrap_or_else(|| panic!("Unknown resource type: {}", std::any::type_name::<T>()))
+    }
+
+    /// Warn if a resource is both read and written, since changes made to it elsewhere will
+    /// be ignored.
+    fn warn_read_write<T: Resource>(&self, id: ComponentId) {
+        #[cfg(debug_assertions)]
+        if self.resource_deps.contains(&id) && self.resource_writes.contains(&id) {
+            warn!(
+                "Resource {} is both read and written by the same view; \
+                changes to it will not trigger a reaction",
+                std::any::type_name::<T>()
+            );
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = id;
     }
 
     /// Convenience method for adding a component dependency.
@@ -137,6 +285,34 @@ impl TrackingScope {
         self.component_deps.insert((entity, component, exists));
     }
 
+    /// Add a dependency on whether a component is present on an entity. Unlike
+    /// [`TrackingScope::track_component_id`], changes to the component's value are ignored.
+    pub(crate) fn track_component_presence(
+        &mut self,
+        entity: Entity,
+        component: ComponentId,
+        exists: bool,
+    ) {
+        self.presence_deps.insert((entity, component, exists));
+    }
+
+    /// Add a dependency on the set of entities matched by a query. The query is re-run each
+    /// time the dependencies are checked, and the scope reacts if the entities it matches,
+    /// or their order, differ from `entities`.
+    pub(crate) fn track_query<F: QueryFilter + 'static>(
+        &mut self,
+        name: &'static str,
+        entities: Vec<Entity>,
+        query: QueryState<Entity, F>,
+    ) {
+        let query = Mutex::new(query);
+        self.query_deps.push(QueryDependency {
+            name,
+            entities,
+            matches: Box::new(move |world| query.lock().unwrap().iter(world).collect()),
+        });
+    }
+
     /// Mark the scope as changed for reasons other than a component or resource dependency.
     pub(crate) fn set_changed(&self) {
         self.changed
@@ -147,6 +323,8 @@ impl TrackingScope {
     /// the previous reaction.
     pub(crate) fn dependencies_changed(&self, world: &World, tick: Tick) -> bool {
         self.components_changed(world, tick)
+            || self.presence_changed(world)
+            || self.queries_changed(world)
             || self.resources_changed(world, tick)
             || self.changed.load(std::sync::atomic::Ordering::Relaxed)
     }
@@ -162,22 +340,64 @@ impl TrackingScope {
         })
     }
 
-    fn resources_changed(&self, world: &World, tick: Tick) -> bool {
-        self.resource_deps.iter().any(|c| {
+    fn presence_changed(&self, world: &World) -> bool {
+        self.presence_deps.iter().any(|(e, c, exists)| {
             world
-                .get_resource_change_ticks_by_id(*c)
-                .map(|ct| ct.is_changed(self.tick, tick))
-                .unwrap_or(false)
+                .get_entity(*e)
+                .map_or(*exists, |e| e.contains_id(*c) != *exists)
         })
     }
 
+    fn queries_changed(&self, world: &World) -> bool {
+        self.query_deps
+            .iter()
+            .any(|dep| (dep.matches)(world) != dep.entities)
+    }
+
+    fn resources_changed(&self, world: &World, tick: Tick) -> bool {
+        self.resource_deps
+            .difference(&self.resource_writes)
+            .any(|c| {
+                world
+                    .get_resource_change_ticks_by_id(*c)
+                    .map(|ct| ct.is_changed(self.tick, tick))
+                    .unwrap_or(false)
+            })
+    }
+
+    /// Returns a human-readable description of each dependency of this scope, for use in
+    /// diagnostic messages.
+    pub(crate) fn dependency_names(&self, world: &World) -> Vec<String> {
+        let components = world.components();
+        let component_name = |id: ComponentId| {
+            components
+                .get_info(id)
+                .map_or_else(|| format!("{:?}", id), |info| info.name().to_string())
+        };
+        self.component_deps
+            .iter()
+            .chain(self.presence_deps.iter())
+            .map(|(entity, id, _)| format!("{}@{}", component_name(*id), entity))
+            .chain(self.query_deps.iter().map(|dep| dep.name.to_string()))
+            .chain(self.resource_deps.iter().map(|id| component_name(*id)))
+            .collect()
+    }
+
     /// Take the dependencies from another scope. Typically the other scope is a temporary
     /// scope that is used to compute the next set of dependencies.
     pub(crate) fn take_deps(&mut self, other: &mut Self) {
         self.component_deps = std::mem::take(&mut other.component_deps);
+        self.presence_deps = std::mem::take(&mut other.presence_deps);
+        self.query_deps = std::mem::take(&mut other.query_deps);
         self.resource_deps = std::mem::take(&mut other.resource_deps);
+        self.resource_writes = std::mem::take(&mut other.resource_writes);
         self.cleanups = std::mem::take(&mut other.cleanups);
+        // Lifecycle functions are only registered on the first build, so keep any that
+        // haven't been run yet.
+        self.mounts.append(&mut other.mounts);
+        self.unmounts.append(&mut other.unmounts);
         self.hook_states = std::mem::take(&mut other.hook_states);
+        self.pending = std::mem::take(&mut other.pending);
         self.changed.store(
             other.changed.load(std::sync::atomic::Ordering::Relaxed),
             std::sync::atomic::Ordering::Relaxed,
@@ -197,11 +417,15 @@ impl Command for DespawnEntityCmd {
     }
 }
 
+/// Registers a hook which runs cleanups and releases hook resources when a [`TrackingScope`]
+/// is removed. This is idempotent: calling it more than once has no additional effect.
 pub(crate) fn cleanup_tracking_scopes(world: &mut World) {
     world
         .register_component_hooks::<TrackingScope>()
-        .on_remove(|mut world, entity, _component| {
-            let mut scope = world.get_mut::<TrackingScope>(entity).unwrap();
+        .try_on_remove(|mut world, entity, _component| {
+            let Some(mut scope) = world.get_mut::<TrackingScope>(entity) else {
+                return;
+            };
             let mut cleanups = std::mem::take(&mut scope.cleanups);
             let mut hooks = std::mem::take(&mut scope.hook_states);
             for cleanup_fn in cleanups.drain(..) {
@@ -215,13 +439,19 @@ pub(crate) fn cleanup_tracking_scopes(world: &mut World) {
                     HookState::Mutable(mutable_ent, _) => {
                         world.commands().queue(DespawnEntityCmd(mutable_ent));
                     }
-                    HookState::Observer(ent, _, _) => {
+                    HookState::NamedMutable(_, _, key) => {
+                        world.commands().queue(ReleaseNamedMutableCmd(key));
+                    }
+                    HookState::Observer(ent, _, _) | HookState::Timer(ent) => {
                         world.commands().queue(DespawnEntityCmd(ent));
                     }
                     HookState::Callback(callback) => {
                         world.commands().queue(UnregisterCallbackCmd(callback));
                     }
-                    HookState::Effect(_) | HookState::Memo(_) => {
+                    HookState::Effect(_)
+                    | HookState::Memo(_)
+                    | HookState::Mount
+                    | HookState::Unmount => {
                         // Nothing to do
                     }
                 }
@@ -279,4 +509,21 @@ mod tests {
         let tick = world.change_tick();
         assert!(scope.dependencies_changed(&world, tick));
     }
+
+    #[test]
+    fn test_resource_write_not_tracked() {
+        let mut world = World::default();
+        world.insert_resource(TestResource(false));
+        world.increment_change_tick();
+        let tick = world.change_tick();
+        let mut scope = TrackingScope::new(tick);
+        scope.track_resource::<TestResource>(&world);
+        scope.track_resource_write::<TestResource>(&world);
+
+        // Writing the resource does not trigger a reaction.
+        world.increment_change_tick();
+        world.get_resource_mut::<TestResource>().unwrap().0 = true;
+        let tick = world.change_tick();
+        assert!(!scope.dependencies_changed(&world, tick));
+    }
 }
@@ -1,11 +1,15 @@
 use crate::{
     cx::Cx,
-    tracking_scope::{TrackingScope, TrackingScopeTracing},
+    tracking_scope::{cleanup_tracking_scopes, TrackingScope, TrackingScopeTracing},
+    QuillConfig, ReactivityDiagnostics,
 };
 use bevy::{
-    // core::{DebugName, Name},
-    ecs::{system::SystemState, world::DeferredWorld},
-    hierarchy::{Children, HierarchyQueryExt, Parent},
+    core::Name,
+    ecs::{
+        system::SystemState,
+        world::{Command, DeferredWorld},
+    },
+    hierarchy::{BuildChildren, Children, DespawnRecursiveExt, HierarchyQueryExt, Parent},
     log::warn,
     prelude::{Added, Component, Entity, Query, With, World},
     utils::hashbrown::HashSet,
@@ -37,6 +41,17 @@ pub trait View: Sync + Send + 'static {
     /// This may also spawn child entities representing nested components.
     fn build(&self, cx: &mut Cx) -> Self::State;
 
+    /// Like [`View::build`], but allows the view to defer construction until some condition,
+    /// such as an asset being loaded, is met. A view which returns [`ViewBuildResult::Pending`]
+    /// produces no output, and the build is retried on the next frame.
+    ///
+    /// Deferral is only honored where a view owns a tracking scope: view roots and
+    /// [`ViewTemplate`](crate::ViewTemplate)s. Views nested within other views are always
+    /// built synchronously. The default implementation calls `build()`.
+    fn try_build(&self, cx: &mut Cx) -> ViewBuildResult<Self::State> {
+        ViewBuildResult::Ready(self.build(cx))
+    }
+
     /// Update the internal state of this view, re-creating any UiNodes.
     /// Returns true if the output changed, that is, if `nodes()` would return a different value
     /// than it did before the rebuild.
@@ -77,6 +92,15 @@ pub trait View: Sync + Send + 'static {
     }
 }
 
+/// Result of [`View::try_build`].
+pub enum ViewBuildResult<S> {
+    /// The view is not ready to be built yet; try again on the next frame.
+    Pending,
+
+    /// The view was built, producing the given state.
+    Ready(S),
+}
+
 /// Marker on a [`View`] entity to indicate that it's output [`Vec<Entity>`] has changed, and that
 /// the parent needs to re-attach it's children.
 #[derive(Component)]
@@ -93,9 +117,16 @@ impl<V: View> ViewState<V> {
         if let Some(state) = self.state.as_mut() {
             self.view.rebuild(cx, state)
         } else {
-            let state = self.view.build(cx);
-            self.state = Some(state);
-            true
+            match self.view.try_build(cx) {
+                ViewBuildResult::Ready(state) => {
+                    self.state = Some(state);
+                    true
+                }
+                ViewBuildResult::Pending => {
+                    cx.tracking.borrow_mut().pending = true;
+                    false
+                }
+            }
         }
     }
 
@@ -186,11 +217,7 @@ impl<V: View> AnyViewAdapter for ViewAdapter<V> {
 
     fn rebuild(&self, world: &mut World, entity: Entity, scope: &mut TrackingScope) -> bool {
         let mut cx = Cx::new(world, entity, scope);
-        if let Some(view_cell) = cx
-            .world_mut()
-            .entity_mut(entity)
-            .get_mut::<ViewStateCell<V>>()
-        {
+        if let Some(view_cell) = cx.world().entity(entity).get::<ViewStateCell<V>>() {
             let inner = view_cell.0.clone();
             let mut vstate = inner.lock().unwrap();
             vstate.rebuild(&mut cx)
@@ -200,10 +227,23 @@ impl<V: View> AnyViewAdapter for ViewAdapter<V> {
     }
 
     fn raze(&self, world: &mut DeferredWorld, entity: Entity) {
-        if let Some(vsh) = world.entity(entity).get::<ViewStateCell<V>>() {
-            let inner = vsh.0.clone();
-            inner.lock().unwrap().raze(world);
-        }
+        let Some(inner) = world
+            .get_entity(entity)
+            .ok()
+            .and_then(|entt| entt.get::<ViewStateCell<V>>())
+            .map(|vsh| vsh.0.clone())
+        else {
+            return;
+        };
+        inner.lock().unwrap().raze(world);
+        // Remove the view state before the entity is despawned, so that dropping it doesn't
+        // run any cleanup against a half-despawned entity.
+        world.commands().queue(move |world: &mut World| {
+            TrackingScope::run_unmounts(world, entity);
+            if let Ok(mut entt) = world.get_entity_mut(entity) {
+                entt.remove::<ViewStateCell<V>>();
+            }
+        });
     }
 
     fn attach_children(&self, world: &mut World, entity: Entity) -> bool {
@@ -244,6 +284,14 @@ impl ViewThunk {
 #[derive(Component)]
 pub struct ViewRoot;
 
+impl ViewRoot {
+    /// Returns a command which razes the view hierarchy rooted at `entity`, and then
+    /// despawns it.
+    pub fn despawn(entity: Entity) -> DespawnViewRoot {
+        DespawnViewRoot(entity)
+    }
+}
+
 /// View which renders nothing.
 impl View for () {
     type State = ();
@@ -279,6 +327,8 @@ impl<V: View> View for (V,) {
     }
 }
 
+/// A tuple of views renders each of its members in order, concatenating their outputs without
+/// adding an entity of its own. See also [`Fragment`](crate::Fragment).
 #[impl_for_tuples(2, 32)]
 #[tuple_types_custom_trait_bound(View)]
 impl View for Tuple {
@@ -311,7 +361,8 @@ impl View for Tuple {
 }
 
 /// An optional [`View`], renders nothing if the view is `None`. Note that this is not dynamic,
-/// you can't switch back and forth between `Some` and `None` while the view is built.
+/// you can't switch back and forth between `Some` and `None` while the view is built. To do
+/// that, wrap the option in a [`Dynamic`](crate::Dynamic).
 impl<V: View> View for Option<V> {
     type State = Option<V::State>;
 
@@ -422,15 +473,20 @@ pub(crate) fn build_views(world: &mut World) {
         let mut scope = TrackingScope::new(tick);
         root.0.rebuild(world, *root_entity, &mut scope);
         world.entity_mut(*root_entity).insert(scope);
+        TrackingScope::run_mounts(world, *root_entity);
     }
 }
 
-const MAX_DIVERGENCE_CT: usize = 32;
+/// Default number of non-converging reaction passes before the reaction control system panics.
+pub(crate) const DEFAULT_MAX_DIVERGENCE: usize = 32;
 
 /// Reaction control system (RCS)
 pub(crate) fn reaction_control_system(world: &mut World) {
     // Record the changed entities for debugging purposes.
     let is_tracing = world.get_resource_mut::<TrackingScopeTracing>().is_some();
+    let max_divergence = world
+        .get_resource::<QuillConfig>()
+        .map_or(DEFAULT_MAX_DIVERGENCE, |config| config.max_divergence);
     let mut all_reactions: Vec<Entity> = Vec::new();
     let mut iteration_ct: usize = 0;
     let mut divergence_ct: usize = 0;
@@ -456,7 +512,13 @@ pub(crate) fn reaction_control_system(world: &mut World) {
         for root in roots {
             children.iter_descendants(root).for_each(|child| {
                 if let Ok(scope) = scopes.get(child) {
-                    if scope.1.dependencies_changed(world, this_run) {
+                    if scope.1.suspended {
+                        return;
+                    }
+                    // Deferred builds are retried once per frame, on the first pass.
+                    if scope.1.dependencies_changed(world, this_run)
+                        || (iteration_ct == 0 && scope.1.pending)
+                    {
                         changed.push(child);
                     }
                 }
@@ -510,8 +572,34 @@ pub(crate) fn reaction_control_system(world: &mut World) {
         let change_ct = changed.len();
         if change_ct >= prev_change_ct {
             divergence_ct += 1;
-            if divergence_ct > MAX_DIVERGENCE_CT {
-                panic!("Reactions failed to converge, num changes: {}", change_ct);
+            if divergence_ct > max_divergence {
+                let names: Vec<String> = changed
+                    .iter()
+                    .map(|entity| match world.get::<Name>(*entity) {
+                        Some(name) => format!("{} ({})", name, entity),
+                        None => format!("{}", entity),
+                    })
+                    .collect();
+                for name in names.iter() {
+                    warn!("Reaction did not converge: {}", name);
+                }
+                if let Some(mut stats) = world.get_resource_mut::<ReactivityDiagnostics>() {
+                    stats.divergent.clone_from(&changed);
+                }
+                let last = *changed.last().unwrap();
+                let deps = world
+                    .get::<TrackingScope>(last)
+                    .map(|scope| scope.dependency_names(world))
+                    .unwrap_or_default();
+                panic!(
+                    "Reactions failed to converge after {} passes, num changes: {}, \
+                    changing scopes: [{}], last updated scope: {}, dependencies: [{}]",
+                    divergence_ct,
+                    change_ct,
+                    names.join(", "),
+                    last,
+                    deps.join(", ")
+                );
             }
         }
         prev_change_ct = change_ct;
@@ -519,6 +607,11 @@ pub(crate) fn reaction_control_system(world: &mut World) {
         world.flush();
     }
 
+    if let Some(mut stats) = world.get_resource_mut::<ReactivityDiagnostics>() {
+        stats.iterations = iteration_ct;
+        stats.max_iterations = stats.max_iterations.max(iteration_ct);
+    }
+
     // Record the changed entities for diagnostic purposes.
     if let Some(mut tracing) = world.get_resource_mut::<TrackingScopeTracing>() {
         std::mem::swap(&mut tracing.0, &mut all_reactions);
@@ -572,24 +665,60 @@ pub(crate) fn reattach_children(world: &mut World) {
     }
 }
 
-/// Observer which is called when a view root is despawned.
-// pub(crate) fn cleanup_view_roots(
-//     trigger: Trigger<OnRemove, ViewRoot>,
-//     q_roots: Query<&ViewThunk>,
-//     mut commands: Commands,
-// ) {
-//     let entity = trigger.entity();
-//     if let Ok(thunk) = q_roots.get(entity) {
-//         println!("cleanup_view_roots");
-//         thunk.0.raze(&mut commands, entity);
-//     }
-// }
-
+/// Registers hooks which raze the view hierarchy when a [`ViewRoot`] is removed or despawned,
+/// and which release the resources held by each [`TrackingScope`]. This is idempotent: calling
+/// it more than once has no additional effect.
 pub(crate) fn cleanup_view_roots(world: &mut World) {
+    cleanup_tracking_scopes(world);
     world
         .register_component_hooks::<ViewRoot>()
-        .on_remove(|mut world, entity, _component| {
-            let thunk = world.get_mut::<ViewThunk>(entity).unwrap();
-            thunk.0.raze(&mut world, entity);
+        .try_on_remove(|mut world, entity, _component| {
+            if let Some(thunk) = world.get::<ViewThunk>(entity) {
+                let thunk = thunk.0;
+                thunk.raze(&mut world, entity);
+            }
         });
 }
+
+/// Queue a command which detaches `entity` from its parent and despawns it. Used by `raze()`:
+/// unlike `EntityCommands::despawn`, this does nothing if game logic has already despawned the
+/// entity, rather than logging a warning.
+pub(crate) fn despawn_if_exists(world: &mut DeferredWorld, entity: Entity) {
+    world.commands().queue(move |world: &mut World| {
+        if let Ok(mut entt) = world.get_entity_mut(entity) {
+            entt.remove_parent();
+            entt.despawn();
+        }
+    });
+}
+
+/// A command which razes a view hierarchy and then despawns the root entity, along with
+/// its descendants.
+pub struct DespawnViewRoot(pub Entity);
+
+impl Command for DespawnViewRoot {
+    fn apply(self, world: &mut World) {
+        if let Ok(mut root) = world.get_entity_mut(self.0) {
+            // Removing the `ViewRoot` triggers the hook which razes the view.
+            root.remove::<ViewRoot>();
+            world.flush();
+            if let Ok(root) = world.get_entity_mut(self.0) {
+                root.despawn_recursive();
+            }
+        }
+    }
+}
+
+/// System which razes and despawns every view hierarchy in the world. This is intended to
+/// be added as an `OnEnter` or `OnExit` system for states that reload the scene, so that
+/// views are torn down in an orderly fashion before their entities go away. [`QuillPlugin`]
+/// also runs it when the app exits.
+///
+/// [`QuillPlugin`]: crate::QuillPlugin
+pub fn despawn_view_roots(world: &mut World) {
+    let mut roots = world.query_filtered::<Entity, With<ViewRoot>>();
+    let roots: Vec<Entity> = roots.iter(world).collect();
+    for root in roots {
+        DespawnViewRoot(root).apply(world);
+    }
+}
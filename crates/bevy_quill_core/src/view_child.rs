@@ -7,6 +7,83 @@ use bevy::prelude::Entity;
 
 type BoxedState = Box<dyn Any + Send + Sync>;
 
+/// A shared, type-erased view. Building this delegates to the inner view; each place where
+/// it is built gets its own independent state.
+impl View for Arc<dyn AnyView> {
+    type State = BoxedState;
+
+    fn nodes(&self, world: &World, state: &Self::State, out: &mut Vec<Entity>) {
+        AnyView::nodes(self.as_ref(), world, state, out);
+    }
+
+    fn build(&self, cx: &mut Cx) -> Self::State {
+        AnyView::build(self.as_ref(), cx)
+    }
+
+    fn rebuild(&self, cx: &mut Cx, state: &mut Self::State) -> bool {
+        AnyView::rebuild(self.as_ref(), cx, state)
+    }
+
+    fn attach_children(&self, world: &mut World, state: &mut Self::State) -> bool {
+        AnyView::attach_children(self.as_ref(), world, state)
+    }
+
+    fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
+        AnyView::raze(self.as_ref(), world, state)
+    }
+
+    fn view_type_id(&self) -> std::any::TypeId {
+        AnyView::view_type_id(self.as_ref())
+    }
+}
+
+/// A view description which can be placed at multiple locations within the view hierarchy
+/// without cloning the underlying view. Unlike memoization, each placement is built
+/// separately and has its own state.
+#[derive(Clone)]
+pub struct SharedView(Arc<dyn AnyView>);
+
+impl SharedView {
+    /// Construct a new `SharedView` from a view.
+    pub fn new(view: impl View) -> Self {
+        Self(Arc::new(view))
+    }
+}
+
+impl View for SharedView {
+    type State = BoxedState;
+
+    fn nodes(&self, world: &World, state: &Self::State, out: &mut Vec<Entity>) {
+        View::nodes(&self.0, world, state, out);
+    }
+
+    fn build(&self, cx: &mut Cx) -> Self::State {
+        View::build(&self.0, cx)
+    }
+
+    fn rebuild(&self, cx: &mut Cx, state: &mut Self::State) -> bool {
+        View::rebuild(&self.0, cx, state)
+    }
+
+    fn attach_children(&self, world: &mut World, state: &mut Self::State) -> bool {
+        View::attach_children(&self.0, world, state)
+    }
+
+    fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
+        View::raze(&self.0, world, state)
+    }
+
+    fn view_type_id(&self) -> std::any::TypeId {
+        View::view_type_id(&self.0)
+    }
+}
+
+impl PartialEq for SharedView {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 /// A wrapper around a type-erased view. This is useful when passing views as parameters.
 pub struct ViewChild(pub(crate) Arc<dyn AnyView>);
 
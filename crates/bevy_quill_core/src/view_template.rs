@@ -1,11 +1,16 @@
-use crate::{cx::Cx, tracking_scope::TrackingScope, AnyViewAdapter, View, ViewThunk};
+use crate::{
+    cx::Cx, tracking_scope::TrackingScope, AnyViewAdapter, View, ViewBuildResult, ViewThunk,
+};
 use bevy::{
     core::Name,
     ecs::world::DeferredWorld,
     hierarchy::BuildChildren,
     prelude::{Component, Entity, World},
 };
-use std::sync::{Arc, Mutex};
+use std::{
+    any::TypeId,
+    sync::{Arc, Mutex},
+};
 
 #[cfg(feature = "verbose")]
 use bevy::log::info;
@@ -18,6 +23,10 @@ use bevy::log::info;
 /// a clone of the template, the generated view (as produced by `.create()`), and the state produced
 /// by that view (as produced by `.build()`). The entity also contains a tracking scope and a
 /// thunk, so that the template content can be rebuilt independently from the parent view.
+///
+/// The entity is given a [`Name`] based on the type of the template, which is used to identify
+/// it in diagnostic messages. Templates which are instantiated many times can call
+/// [`Cx::set_debug_name`] from `create()` to provide a more specific name.
 pub trait ViewTemplate: Send + Sync + 'static {
     type View: View;
 
@@ -44,32 +53,45 @@ impl<VT: ViewTemplate + Clone + PartialEq> View for VT {
     }
 
     fn build(&self, cx: &mut Cx) -> Self::State {
-        let tick = cx.world_mut().change_tick();
         let parent = cx.owner();
-        let child_entity = cx
-            .world_mut()
-            .spawn_empty()
-            .insert(Name::new(std::any::type_name::<Self>()))
-            .set_parent(parent)
-            .id();
-
-        #[cfg(feature = "verbose")]
-        info!("build() {}", child_entity);
-
-        let mut scope = TrackingScope::new(tick);
-        let mut cx_inner = Cx::new(cx.world_mut(), child_entity, &mut scope);
-        let view = self.create(&mut cx_inner);
-        let state = view.build(&mut cx_inner);
-        let mut nodes: Vec<Entity> = Vec::new();
-        view.nodes(cx.world(), &state, &mut nodes);
-        let cell = ViewTemplateState::new(self.clone(), view, state);
-        let thunk = cell.create_thunk();
-        cx.world_mut().entity_mut(child_entity).insert((
-            ViewTemplateStateCell(Arc::new(Mutex::new(cell))),
-            scope,
-            thunk,
-        ));
-        (child_entity, nodes)
+        cx.with_world_mut(|world| {
+            let tick = world.change_tick();
+            let child_entity = world
+                .spawn_empty()
+                .insert((
+                    Name::new(std::any::type_name::<Self>()),
+                    ViewTemplateType(TypeId::of::<Self>()),
+                ))
+                .set_parent(parent)
+                .id();
+
+            #[cfg(feature = "verbose")]
+            info!("build() {}", child_entity);
+
+            let mut scope = TrackingScope::new(tick);
+            let mut cx_inner = Cx::new(world, child_entity, &mut scope);
+            let view = self.create(&mut cx_inner);
+            let state = match view.try_build(&mut cx_inner) {
+                ViewBuildResult::Ready(state) => Some(state),
+                ViewBuildResult::Pending => {
+                    cx_inner.tracking.borrow_mut().pending = true;
+                    None
+                }
+            };
+            let mut nodes: Vec<Entity> = Vec::new();
+            if let Some(state) = &state {
+                view.nodes(world, state, &mut nodes);
+            }
+            let cell = ViewTemplateState::new(self.clone(), view, state);
+            let thunk = cell.create_thunk();
+            world.entity_mut(child_entity).insert((
+                ViewTemplateStateCell(Arc::new(Mutex::new(cell))),
+                scope,
+                thunk,
+            ));
+            TrackingScope::run_mounts(world, child_entity);
+            (child_entity, nodes)
+        })
     }
 
     fn rebuild(&self, cx: &mut Cx, state: &mut Self::State) -> bool {
@@ -78,16 +100,18 @@ impl<VT: ViewTemplate + Clone + PartialEq> View for VT {
         #[cfg(feature = "verbose")]
         info!("rebuild() {}", entity);
 
-        let mut entt = cx.world_mut().entity_mut(entity);
-        let cell = entt.get::<ViewTemplateStateCell<VT>>().unwrap();
-        let mut inner = cell.0.lock().unwrap();
-        if inner.template != *self {
-            // Update the template and trigger a rebuild on the child template.
-            inner.template = self.clone();
-            drop(inner);
-            let scope = entt.get_mut::<TrackingScope>().unwrap();
-            scope.set_changed();
-        }
+        cx.with_world_mut(|world| {
+            let mut entt = world.entity_mut(entity);
+            let cell = entt.get::<ViewTemplateStateCell<VT>>().unwrap();
+            let mut inner = cell.0.lock().unwrap();
+            if inner.template != *self {
+                // Update the template and trigger a rebuild on the child template.
+                inner.template = self.clone();
+                drop(inner);
+                let scope = entt.get_mut::<TrackingScope>().unwrap();
+                scope.set_changed();
+            }
+        });
 
         // False because we haven't changed the output yet.
         false
@@ -119,22 +143,42 @@ impl<VT: ViewTemplate + Clone + PartialEq> View for VT {
         #[cfg(feature = "verbose")]
         info!("raze() {}", entity);
 
-        let entt = world.entity_mut(entity);
-        let cell = entt.get::<ViewTemplateStateCell<VT>>().unwrap().0.clone();
-        let mut inner = cell.lock().unwrap();
-        inner.raze(world);
-        world.commands().entity(entity).remove_parent().despawn();
+        // The entity may already have been despawned by game logic.
+        let Some(cell) = world
+            .get_entity(entity)
+            .ok()
+            .and_then(|entt| entt.get::<ViewTemplateStateCell<VT>>())
+            .map(|cell| cell.0.clone())
+        else {
+            return;
+        };
+        cell.lock().unwrap().raze(world);
+        // Remove the template state before despawning, so that dropping it doesn't run any
+        // cleanup against a half-despawned entity.
+        world.commands().queue(move |world: &mut World| {
+            TrackingScope::run_unmounts(world, entity);
+            if let Ok(mut entt) = world.get_entity_mut(entity) {
+                entt.remove::<ViewTemplateStateCell<VT>>();
+                entt.remove_parent();
+                entt.despawn();
+            }
+        });
     }
 }
 
+/// Component which records the type of the template whose state is held by an entity.
+#[derive(Component)]
+pub(crate) struct ViewTemplateType(pub(crate) TypeId);
+
 struct ViewTemplateState<VT: ViewTemplate> {
     template: VT,
     view: VT::View,
-    state: <VT::View as View>::State,
+    /// View state, or `None` if the build of the view has been deferred.
+    state: Option<<VT::View as View>::State>,
 }
 
 impl<VT: ViewTemplate> ViewTemplateState<VT> {
-    fn new(template: VT, view: VT::View, state: <VT::View as View>::State) -> Self {
+    fn new(template: VT, view: VT::View, state: Option<<VT::View as View>::State>) -> Self {
         Self {
             template,
             view,
@@ -143,21 +187,40 @@ impl<VT: ViewTemplate> ViewTemplateState<VT> {
     }
 
     fn nodes(&self, world: &World, out: &mut Vec<Entity>) {
-        self.view.nodes(world, &self.state, out);
+        if let Some(state) = &self.state {
+            self.view.nodes(world, state, out);
+        }
     }
 
     fn rebuild(&mut self, cx: &mut Cx) -> bool {
         self.view = self.template.create(cx);
-        self.view.rebuild(cx, &mut self.state)
+        match self.state.as_mut() {
+            Some(state) => self.view.rebuild(cx, state),
+            None => match self.view.try_build(cx) {
+                ViewBuildResult::Ready(state) => {
+                    self.state = Some(state);
+                    true
+                }
+                ViewBuildResult::Pending => {
+                    cx.tracking.borrow_mut().pending = true;
+                    false
+                }
+            },
+        }
     }
 
     fn raze(&mut self, world: &mut DeferredWorld) {
         // println!("Razing View Template: {}", std::any::type_name::<VT>());
-        self.view.raze(world, &mut self.state);
+        if let Some(state) = self.state.as_mut() {
+            self.view.raze(world, state);
+        }
     }
 
     fn attach_children(&mut self, world: &mut World) -> bool {
-        self.view.attach_children(world, &mut self.state)
+        match self.state.as_mut() {
+            Some(state) => self.view.attach_children(world, state),
+            None => false,
+        }
     }
 
     pub fn create_thunk(&self) -> ViewThunk {
@@ -203,11 +266,7 @@ impl<VF: ViewTemplate> AnyViewAdapter for ViewTemplateAdapter<VF> {
         info!("rebuild()");
 
         let mut cx = Cx::new(world, entity, scope);
-        if let Some(view_cell) = cx
-            .world_mut()
-            .entity(entity)
-            .get::<ViewTemplateStateCell<VF>>()
-        {
+        if let Some(view_cell) = cx.world().entity(entity).get::<ViewTemplateStateCell<VF>>() {
             let vs = view_cell.0.clone();
             let mut inner = vs.lock().unwrap();
             inner.rebuild(&mut cx)
@@ -233,8 +292,12 @@ impl<VF: ViewTemplate> AnyViewAdapter for ViewTemplateAdapter<VF> {
         #[cfg(feature = "verbose")]
         info!("raze() {}", entity);
 
-        if let Some(view_cell) = world.entity_mut(entity).get::<ViewTemplateStateCell<VF>>() {
-            let inner = view_cell.0.clone();
+        if let Some(inner) = world
+            .get_entity(entity)
+            .ok()
+            .and_then(|entt| entt.get::<ViewTemplateStateCell<VF>>())
+            .map(|view_cell| view_cell.0.clone())
+        {
             inner.lock().unwrap().raze(world);
         }
     }
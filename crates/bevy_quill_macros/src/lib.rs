@@ -0,0 +1,166 @@
+//! Procedural macros for Quill.
+
+use proc_macro::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, FnArg, Ident, ItemFn, Pat, PatType, ReturnType, Type,
+};
+
+/// Generate a [`ViewTemplate`] from a function.
+///
+/// The first parameter of the function must be the reactive context, `cx: &mut Cx`. The
+/// remaining parameters become the public fields of a struct with the same name and
+/// visibility as the function, which derives `Clone` and `PartialEq`, and the body of the
+/// function becomes the body of [`ViewTemplate::create`]. Within the body, each parameter
+/// is bound to a clone of the corresponding field.
+///
+/// ```ignore
+/// #[view_template]
+/// fn Greeting(cx: &mut Cx, name: String) -> impl View {
+///     format!("Hello, {}!", name)
+/// }
+///
+/// // Used like any other template:
+/// Greeting { name: "World".to_string() }
+/// ```
+///
+/// The generated code refers to `ViewTemplate` by name, so it must be in scope, as it is when
+/// using the Quill prelude.
+///
+/// [`ViewTemplate`]: https://docs.rs/bevy_quill_core/latest/bevy_quill_core/trait.ViewTemplate.html
+/// [`ViewTemplate::create`]: https://docs.rs/bevy_quill_core/latest/bevy_quill_core/trait.ViewTemplate.html#tymethod.create
+#[proc_macro_attribute]
+pub fn view_template(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::TokenStream::from(attr).span(),
+            "#[view_template] does not take any arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let item = parse_macro_input!(item as ItemFn);
+    match expand_view_template(item) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_view_template(item: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+
+    if let Some(asyncness) = sig.asyncness {
+        return Err(syn::Error::new(
+            asyncness.span(),
+            "view templates cannot be async",
+        ));
+    }
+
+    let ReturnType::Type(_, view_type) = &sig.output else {
+        return Err(syn::Error::new(
+            sig.ident.span(),
+            "view template functions must return a view, e.g. `-> impl View`",
+        ));
+    };
+
+    // The first parameter is the reactive context.
+    let mut inputs = sig.inputs.iter();
+    let cx_arg = match inputs.next() {
+        Some(FnArg::Typed(arg)) => arg,
+        Some(FnArg::Receiver(receiver)) => {
+            return Err(syn::Error::new(
+                receiver.span(),
+                "view template functions cannot take `self`",
+            ))
+        }
+        None => {
+            return Err(syn::Error::new(
+                sig.paren_token.span.join(),
+                "view template functions must take `cx: &mut Cx` as their first parameter",
+            ))
+        }
+    };
+    let PatType {
+        pat: cx_pat,
+        ty: cx_type,
+        ..
+    } = cx_arg;
+
+    // The remaining parameters are the properties of the template.
+    let mut props: Vec<(&[syn::Attribute], &Ident, &Type)> = Vec::new();
+    for arg in inputs {
+        let FnArg::Typed(arg) = arg else {
+            unreachable!("receiver can only be the first parameter");
+        };
+        let Pat::Ident(pat) = arg.pat.as_ref() else {
+            return Err(syn::Error::new(
+                arg.pat.span(),
+                "view template parameters must be plain identifiers",
+            ));
+        };
+        if pat.by_ref.is_some() || pat.subpat.is_some() {
+            return Err(syn::Error::new(
+                pat.span(),
+                "view template parameters must be plain identifiers",
+            ));
+        }
+        props.push((&arg.attrs, &pat.ident, &arg.ty));
+    }
+
+    let name = &sig.ident;
+    let (impl_generics, type_generics, where_clause) = sig.generics.split_for_impl();
+    let generics = &sig.generics;
+    let field_attrs = props.iter().map(|(attrs, _, _)| attrs);
+    let field_names: Vec<&Ident> = props.iter().map(|(_, name, _)| *name).collect();
+    let field_types = props.iter().map(|(_, _, ty)| ty);
+
+    // Check each property individually, so that the error points at the offending parameter.
+    let assertions = props.iter().map(|(_, _, ty)| {
+        quote_spanned! {ty.span()=>
+            assert_prop::<#ty>();
+        }
+    });
+
+    // A struct with no fields is declared as a unit struct, so that it can be constructed
+    // by name alone.
+    let struct_body = if props.is_empty() {
+        quote! { #where_clause; }
+    } else {
+        quote! {
+            #where_clause {
+                #(#(#field_attrs)* pub #field_names: #field_types,)*
+            }
+        }
+    };
+
+    Ok(quote! {
+        #(#attrs)*
+        #[derive(Clone, PartialEq)]
+        #vis struct #name #generics #struct_body
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_prop<T: ::core::clone::Clone + ::core::cmp::PartialEq>() {}
+
+            #[allow(dead_code)]
+            fn assert_props #impl_generics () #where_clause {
+                #(#assertions)*
+            }
+        };
+
+        impl #impl_generics ViewTemplate for #name #type_generics #where_clause {
+            type View = #view_type;
+
+            #[allow(unused_variables, clippy::redundant_clone)]
+            fn create(&self, #cx_pat: #cx_type) -> Self::View {
+                #(let #field_names = ::core::clone::Clone::clone(&self.#field_names);)*
+                #block
+            }
+        }
+    })
+}
@@ -1,9 +1,9 @@
 use bevy::prelude::*;
 use bevy::{
-    color::{Mix, Srgba},
+    color::{Alpha, Mix, Srgba},
     ecs::component::Component,
     math::{cubic_splines::CubicSegment, Vec2},
-    ui::{self, BackgroundColor, BorderColor, Style},
+    ui::{self, BackgroundColor, BorderColor, Outline, Style},
 };
 
 /// Trait that represents a property that can be animated, such as background color,
@@ -25,6 +25,12 @@ pub trait AnimatableProperty {
         origin: Self::ValueType,
         target: Self::ValueType,
     );
+
+    /// Convert a value into one which can be interpolated, using the state of the entity at
+    /// the start of the transition. The default implementation returns the value unchanged.
+    fn resolve(_entity: &EntityWorldMut, value: Self::ValueType) -> Self::ValueType {
+        value
+    }
 }
 
 /// Animated background color property.
@@ -57,6 +63,40 @@ impl AnimatableProperty for AnimatedBorderColor {
     }
 }
 
+/// Animated outline color property.
+pub struct AnimatedOutlineColor;
+impl AnimatableProperty for AnimatedOutlineColor {
+    type ValueType = Srgba;
+    type ComponentType = Outline;
+
+    fn current(component: &Self::ComponentType) -> Self::ValueType {
+        component.color.into()
+    }
+
+    fn update(component: &mut Self::ComponentType, value: f32, origin: Srgba, target: Srgba) {
+        component.color = origin.mix(&target, value).into();
+    }
+}
+
+/// Animated outline width property, in pixels.
+pub struct AnimatedOutlineWidth;
+impl AnimatableProperty for AnimatedOutlineWidth {
+    type ValueType = f32;
+    type ComponentType = Outline;
+
+    fn current(component: &Self::ComponentType) -> Self::ValueType {
+        if let ui::Val::Px(value) = component.width {
+            value
+        } else {
+            0.0
+        }
+    }
+
+    fn update(component: &mut Self::ComponentType, value: f32, origin: f32, target: f32) {
+        component.width = ui::Val::Px(origin.lerp(target, value));
+    }
+}
+
 /// Animated pixel width property.
 pub struct AnimatedPxWidth;
 impl AnimatableProperty for AnimatedPxWidth {
@@ -95,6 +135,90 @@ impl AnimatableProperty for AnimatedPxHeight {
     }
 }
 
+/// Animated width property which accepts any [`ui::Val`]. Percentages are resolved to pixels
+/// using the parent's size, and other non-pixel values using the node's own size, at the start
+/// of the transition. The width is set to the unresolved target when the transition ends, so
+/// for example a node animated to `Val::Percent(100.)` continues to track its parent's width.
+pub struct AnimatedPercentWidth;
+impl AnimatableProperty for AnimatedPercentWidth {
+    type ValueType = ui::Val;
+    type ComponentType = Style;
+
+    fn current(component: &Self::ComponentType) -> Self::ValueType {
+        component.width
+    }
+
+    fn update(component: &mut Self::ComponentType, value: f32, origin: ui::Val, target: ui::Val) {
+        component.width = lerp_val(origin, target, value);
+    }
+
+    fn resolve(entity: &EntityWorldMut, value: ui::Val) -> ui::Val {
+        resolve_val(entity, value, |size| size.x)
+    }
+}
+
+/// Animated height property which accepts any [`ui::Val`]. See [`AnimatedPercentWidth`].
+pub struct AnimatedPercentHeight;
+impl AnimatableProperty for AnimatedPercentHeight {
+    type ValueType = ui::Val;
+    type ComponentType = Style;
+
+    fn current(component: &Self::ComponentType) -> Self::ValueType {
+        component.height
+    }
+
+    fn update(component: &mut Self::ComponentType, value: f32, origin: ui::Val, target: ui::Val) {
+        component.height = lerp_val(origin, target, value);
+    }
+
+    fn resolve(entity: &EntityWorldMut, value: ui::Val) -> ui::Val {
+        resolve_val(entity, value, |size| size.y)
+    }
+}
+
+/// Interpolate between two resolved pixel values, or return the target at the end of the
+/// transition.
+fn lerp_val(origin: ui::Val, target: ui::Val, value: f32) -> ui::Val {
+    match (origin, target) {
+        _ if value >= 1.0 => target,
+        (ui::Val::Px(origin), ui::Val::Px(target)) => ui::Val::Px(origin.lerp(target, value)),
+        _ => origin,
+    }
+}
+
+/// Resolve a layout value along one axis to pixels, using the computed size of the parent node
+/// for percentages, and the computed size of the node itself for anything else.
+fn resolve_val(entity: &EntityWorldMut, value: ui::Val, axis: fn(Vec2) -> f32) -> ui::Val {
+    let world = entity.world();
+    match value {
+        ui::Val::Px(_) => value,
+        ui::Val::Percent(percent) => entity
+            .get::<Parent>()
+            .and_then(|parent| world.get::<Node>(parent.get()))
+            .map_or(ui::Val::Px(0.), |node| {
+                ui::Val::Px(axis(node.size()) * percent * 0.01)
+            }),
+        _ => entity
+            .get::<Node>()
+            .map_or(ui::Val::Px(0.), |node| ui::Val::Px(axis(node.size()))),
+    }
+}
+
+/// Animated opacity of the background color.
+pub struct AnimatedOpacity;
+impl AnimatableProperty for AnimatedOpacity {
+    type ValueType = f32;
+    type ComponentType = BackgroundColor;
+
+    fn current(component: &Self::ComponentType) -> Self::ValueType {
+        component.0.alpha()
+    }
+
+    fn update(component: &mut Self::ComponentType, value: f32, origin: f32, target: f32) {
+        component.0.set_alpha(origin.lerp(target, value));
+    }
+}
+
 /// Animated scale.
 pub struct AnimatedScale;
 impl AnimatableProperty for AnimatedScale {
@@ -149,6 +273,8 @@ where
     timing: CubicSegment<Vec2>,
     origin: T::ValueType,
     target: T::ValueType,
+    /// The target value before it was resolved, which is applied when the transition ends.
+    end: T::ValueType,
     delay: f32,
     duration: f32,
     clock: f32,
@@ -164,6 +290,7 @@ where
             timing: CubicSegment::new_bezier(Vec2::new(0.25, 0.1), Vec2::new(0.25, 1.0)),
             origin,
             target,
+            end: target,
             clock: 0.0,
             duration,
             delay,
@@ -175,17 +302,19 @@ where
     /// restarted with the new target value.
     pub fn start(entity: &mut EntityWorldMut, target: T::ValueType, duration: f32) {
         // If we're already animating to the same target, don't restart the animation.
-        if let Some(anim) = entity.get_mut::<Self>() {
-            if anim.target == target {
+        if let Some(anim) = entity.get::<Self>() {
+            if anim.end == target {
                 return;
             }
         }
-        if let Some(mut cmp) = entity.get_mut::<T::ComponentType>() {
-            let origin = T::current(&cmp);
-            let mut transition = Self::new(origin, target, duration, 0.0);
-            transition.advance(&mut cmp, 0.0);
-            entity.insert(transition);
-        }
+        let Some(cmp) = entity.get::<T::ComponentType>() else {
+            return;
+        };
+        let origin = T::resolve(entity, T::current(cmp));
+        let mut transition = Self::new(origin, T::resolve(entity, target), duration, 0.0);
+        transition.end = target;
+        transition.advance(&mut entity.get_mut::<T::ComponentType>().unwrap(), 0.0);
+        entity.insert(transition);
     }
 
     /// Set the initial delay of the effect.
@@ -201,6 +330,7 @@ where
     /// Restart the transition with a new target value.
     pub fn restart(&mut self, target: T::ValueType) {
         self.target = target;
+        self.end = target;
         self.clock = 0.0;
     }
 
@@ -215,8 +345,11 @@ where
         } else {
             1.0
         };
-        let t = self.timing.ease(t);
-        T::update(component, t, self.origin, self.target);
+        if t >= 1.0 {
+            T::update(component, 1.0, self.origin, self.end);
+        } else {
+            T::update(component, self.timing.ease(t), self.origin, self.target);
+        }
     }
 
     pub(crate) fn run_animations(
@@ -243,8 +376,13 @@ impl Plugin for AnimatedTransitionPlugin {
             (
                 AnimatedTransition::<AnimatedBackgroundColor>::run_animations,
                 AnimatedTransition::<AnimatedBorderColor>::run_animations,
+                AnimatedTransition::<AnimatedOutlineColor>::run_animations,
+                AnimatedTransition::<AnimatedOutlineWidth>::run_animations,
                 AnimatedTransition::<AnimatedPxWidth>::run_animations,
                 AnimatedTransition::<AnimatedPxHeight>::run_animations,
+                AnimatedTransition::<AnimatedPercentWidth>::run_animations,
+                AnimatedTransition::<AnimatedPercentHeight>::run_animations,
+                AnimatedTransition::<AnimatedOpacity>::run_animations,
                 AnimatedTransition::<AnimatedScale>::run_animations,
                 AnimatedTransition::<AnimatedRotation>::run_animations,
                 AnimatedTransition::<AnimatedTranslation>::run_animations,
@@ -252,3 +390,81 @@ impl Plugin for AnimatedTransitionPlugin {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn px(value: ui::Val) -> f32 {
+        match value {
+            ui::Val::Px(px) => px,
+            _ => panic!("Expected a pixel value, got {:?}", value),
+        }
+    }
+
+    #[test]
+    fn test_percent_width_ends_at_unresolved_target() {
+        let mut style = Style::default();
+        let mut transition = AnimatedTransition::<AnimatedPercentWidth>::new(
+            ui::Val::Px(0.),
+            ui::Val::Px(200.),
+            1.0,
+            0.0,
+        );
+        transition.end = ui::Val::Percent(100.);
+
+        transition.advance(&mut style, 0.0);
+        assert!(px(style.width).abs() < 0.001);
+
+        transition.advance(&mut style, 0.5);
+        assert!(px(style.width) > 0. && px(style.width) < 200.);
+
+        // The final value is the percentage, so that the node tracks its parent's size.
+        transition.advance(&mut style, 0.5);
+        assert_eq!(style.width, ui::Val::Percent(100.));
+    }
+
+    #[test]
+    fn test_percent_height_starts_from_auto() {
+        let mut world = World::default();
+        let mut entity = world.spawn(NodeBundle::default());
+        assert_eq!(entity.get::<Style>().unwrap().height, ui::Val::Auto);
+
+        // Auto is resolved to the computed size of the node, which is zero before layout.
+        AnimatedTransition::<AnimatedPercentHeight>::start(&mut entity, ui::Val::Px(50.), 1.0);
+        assert!(px(entity.get::<Style>().unwrap().height).abs() < 0.001);
+
+        let mut style = entity.get::<Style>().unwrap().clone();
+        let mut transition = entity
+            .take::<AnimatedTransition<AnimatedPercentHeight>>()
+            .unwrap();
+        transition.advance(&mut style, 1.0);
+        assert_eq!(style.height, ui::Val::Px(50.));
+    }
+
+    #[test]
+    fn test_opacity() {
+        let mut color = BackgroundColor(Color::WHITE);
+        let mut transition = AnimatedTransition::<AnimatedOpacity>::new(1.0, 0.0, 1.0, 0.0);
+
+        transition.advance(&mut color, 0.0);
+        assert!((color.0.alpha() - 1.0).abs() < 0.001);
+
+        transition.advance(&mut color, 1.0);
+        assert_eq!(color.0.alpha(), 0.0);
+    }
+
+    #[test]
+    fn test_outline_color() {
+        let mut outline = Outline::new(ui::Val::Px(2.), ui::Val::Px(2.), Color::NONE);
+        let mut transition =
+            AnimatedTransition::<AnimatedOutlineColor>::new(Srgba::NONE, Srgba::WHITE, 0.1, 0.0);
+
+        transition.advance(&mut outline, 0.05);
+        let alpha = outline.color.alpha();
+        assert!(alpha > 0.0 && alpha < 1.0);
+
+        transition.advance(&mut outline, 0.05);
+        assert_eq!(Srgba::from(outline.color), Srgba::WHITE);
+    }
+}
@@ -7,22 +7,59 @@ var<uniform> num_color_stops: vec4<i32>;
 @group(1) @binding(1)
 var<uniform> color_stops: array<vec4<f32>, 8>;
 
+@group(1) @binding(2)
+var<uniform> gamma_correct: vec4<u32>;
+
 @group(1) @binding(3)
 var<uniform> cap_size: vec4<f32>;
 
+// Stop positions, packed four to a vector.
+@group(1) @binding(4)
+var<uniform> stop_positions: array<vec4<f32>, 2>;
+
+fn stop_position(index: i32) -> f32 {
+    return stop_positions[index / 4][index % 4];
+}
+
 @fragment
 fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
-    let t = (in.uv.x - 0.1) * 1.0 / 0.8 * f32(num_color_stops.x - 1);
-    let color_index_lo = clamp(i32(floor(t)), 0, num_color_stops.x - 1);
-    let color_index_hi = clamp(i32(ceil(t)), 0, num_color_stops.x - 1);
+    let x = clamp((in.uv.x - 0.1) * 1.0 / 0.8, 0.0, 1.0);
+
+    // Find the pair of stops which surround x.
+    let last_index = max(num_color_stops.x - 1, 0);
+    var color_index_lo = 0;
+    for (var i = 1; i <= last_index; i++) {
+        if stop_position(i) <= x {
+            color_index_lo = i;
+        }
+    }
+    let color_index_hi = min(color_index_lo + 1, last_index);
     let color_lo = color_stops[color_index_lo];
     let color_hi = color_stops[color_index_hi];
-    let color = mix(color_lo, color_hi, t - f32(color_index_lo));
+    let position_lo = stop_position(color_index_lo);
+    let position_hi = stop_position(color_index_hi);
+    let t = select(
+        0.0,
+        clamp((x - position_lo) / (position_hi - position_lo), 0.0, 1.0),
+        position_hi > position_lo
+    );
 
     let uv = (in.uv - vec2<f32>(0.5, 0.5)) * in.size / 8.;
     let check = select(0.0, 1.0, (fract(uv.x) < 0.5) != (fract(uv.y) < 0.5));
     let bg = mix(vec3<f32>(0.4, 0.4, 0.4), vec3<f32>(0.6, 0.6, 0.6), check);
-    let c = srgb_to_linear(mix(bg, color.rgb, color.w));
+
+    var c: vec3<f32>;
+    if gamma_correct.x != 0u {
+        // Interpolate in linear space.
+        let lo = vec4<f32>(srgb_to_linear(color_lo.rgb), color_lo.a);
+        let hi = vec4<f32>(srgb_to_linear(color_hi.rgb), color_hi.a);
+        let color = mix(lo, hi, t);
+        c = mix(srgb_to_linear(bg), color.rgb, color.w);
+    } else {
+        // Interpolate in sRGB space.
+        let color = mix(color_lo, color_hi, t);
+        c = srgb_to_linear(mix(bg, color.rgb, color.w));
+    }
 
     let size = vec2<f32>(in.size.x, in.size.y);
     let external_distance = sd_rounded_box((in.uv - 0.5) * size, size, vec4<f32>(size.y * 0.5));
@@ -31,7 +68,7 @@ fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
     return vec4<f32>(c, alpha);
 }
 
-// Convert sRGB to linear color space because we interpolate in sRGB space.
+// Convert sRGB to linear color space, since the output is expected to be linear.
 fn srgb_to_linear(srgb: vec3<f32>) -> vec3<f32> {
     let a = 0.055;
     let srgbLow = srgb / 12.92;
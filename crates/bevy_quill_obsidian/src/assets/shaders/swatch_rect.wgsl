@@ -7,12 +7,20 @@ var<uniform> color: vec4<f32>;
 @group(1) @binding(1)
 var<uniform> radius: vec4<f32>;
 
+@group(1) @binding(2)
+var<uniform> checker_size: vec4<f32>;
+
 @fragment
 fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
-    let uv = (in.uv - vec2<f32>(0.5, 0.5)) * in.size / 8.;
-    let check = select(0.0, 1.0, (fract(uv.x) < 0.5) != (fract(uv.y) < 0.5));
-    let bg = mix(vec3<f32>(0.4, 0.4, 0.4), vec3<f32>(0.6, 0.6, 0.6), check);
-    let c = srgb_to_linear(mix(bg, color.rgb, color.w));
+    var rgb = color.rgb;
+    if color.w < 1.0 {
+        // Blend transparent colors over a checkerboard pattern.
+        let uv = (in.uv - vec2<f32>(0.5, 0.5)) * in.size / (checker_size.x * 2.);
+        let check = select(0.0, 1.0, (fract(uv.x) < 0.5) != (fract(uv.y) < 0.5));
+        let bg = mix(vec3<f32>(0.4, 0.4, 0.4), vec3<f32>(0.6, 0.6, 0.6), check);
+        rgb = mix(bg, color.rgb, color.w);
+    }
+    let c = srgb_to_linear(rgb);
 
     let size = vec2<f32>(in.size.x, in.size.y);
     let external_distance = sd_rounded_box((in.uv - 0.5) * size, size, radius);
@@ -27,3 +27,4 @@ pub const DESTRUCTIVE_ACC: Srgba = Srgba::new(0.733, 0.475, 0.612, 1.0);
 pub const TRANSPARENT: Srgba = Srgba::new(0.0, 0.0, 0.0, 0.0);
 pub const FOCUS: Srgba = Srgba::new(0.055, 0.647, 0.914, 0.15);
 pub const TEXT_SELECT: Srgba = Srgba::new(0.055, 0.647, 0.914, 0.5);
+pub const BADGE: Srgba = Srgba::new(0.863, 0.149, 0.149, 1.0);
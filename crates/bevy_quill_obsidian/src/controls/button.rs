@@ -1,8 +1,10 @@
-use super::{Disabled, IsDisabled};
+use std::sync::Arc;
+
+use super::{Disabled, Icon, IsDisabled};
 use crate::{
     colors,
     cursor::StyleBuilderCursor,
-    focus::{AutoFocus, KeyPressEvent, TabIndex},
+    focus::{AutoFocus, KeyPressEvent, StyleBuilderFocusRing, TabIndex},
     hooks::{UseIsFocus, UseIsHover},
     size::Size,
     typography, RoundedCorners,
@@ -13,13 +15,16 @@ use bevy::{
         AccessibilityNode, Focus,
     },
     color::Luminance,
+    ecs::world::Command,
     prelude::*,
+    state::state::FreelyMutableState,
     ui,
+    window::SystemCursorIcon,
 };
 use bevy_mod_picking::{events::PointerCancel, prelude::*};
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::{
-    Callback, Cx, Element, IntoViewChild, RunCallback, View, ViewChild, ViewTemplate,
+    Callback, Cond, Cx, Element, IntoViewChild, RunCallback, View, ViewChild, ViewTemplate,
 };
 
 /// The variant determines the button's color scheme
@@ -41,15 +46,14 @@ pub enum ButtonVariant {
 
 pub(crate) fn style_button(ss: &mut StyleBuilder) {
     ss.border(1)
-        .display(ui::Display::Flex)
-        .flex_direction(ui::FlexDirection::Row)
+        .flex_row()
         .justify_content(ui::JustifyContent::Center)
         .align_items(ui::AlignItems::Center)
         .align_content(ui::AlignContent::Center)
         .padding((12, 0))
         .border(0)
         .color(colors::FOREGROUND)
-        .cursor(CursorIcon::Pointer);
+        .cursor(SystemCursorIcon::Pointer);
 }
 
 pub(crate) fn style_button_bg(ss: &mut StyleBuilder) {
@@ -61,6 +65,27 @@ pub(crate) fn style_button_bg(ss: &mut StyleBuilder) {
         .bottom(0);
 }
 
+/// A command which is run when a button is clicked. See [`Button::on_click_command`].
+#[derive(Clone)]
+pub struct ClickCommand(Arc<dyn Fn(&mut World) + Send + Sync>);
+
+impl ClickCommand {
+    /// Create a click command which applies a clone of `command` each time it is run.
+    pub fn new<C: Command + Clone + Sync>(command: C) -> Self {
+        Self(Arc::new(move |world| command.clone().apply(world)))
+    }
+
+    fn run(&self, world: &mut World) {
+        (self.0)(world);
+    }
+}
+
+impl PartialEq for ClickCommand {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 /// Button widget
 #[derive(Default, PartialEq, Clone)]
 pub struct Button {
@@ -76,12 +101,21 @@ pub struct Button {
     /// The content to display inside the button.
     pub children: ViewChild,
 
+    /// Optional icon displayed to the left of the button content.
+    pub icon_left: Option<HandleOrOwnedPath<Image>>,
+
+    /// Optional icon displayed to the right of the button content.
+    pub icon_right: Option<HandleOrOwnedPath<Image>>,
+
     /// Additional styles to be applied to the button.
     pub style: StyleHandle,
 
     /// Callback called when clicked
     pub on_click: Option<Callback>,
 
+    /// Command run when clicked, after `on_click`.
+    pub on_click_command: Option<ClickCommand>,
+
     /// The tab index of the button (default 0).
     pub tab_index: i32,
 
@@ -142,6 +176,18 @@ impl Button {
         self
     }
 
+    /// Set an icon to display to the left of the button content.
+    pub fn icon_left(mut self, icon: impl Into<HandleOrOwnedPath<Image>>) -> Self {
+        self.icon_left = Some(icon.into());
+        self
+    }
+
+    /// Set an icon to display to the right of the button content.
+    pub fn icon_right(mut self, icon: impl Into<HandleOrOwnedPath<Image>>) -> Self {
+        self.icon_right = Some(icon.into());
+        self
+    }
+
     /// Set the additional styles for the button.
     pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
         self.style = style.into_handle();
@@ -154,6 +200,23 @@ impl Button {
         self
     }
 
+    /// Set a command to run when clicked. The command is cloned each time the button is
+    /// clicked. This is an alternative to [`Button::on_click`] for buttons which perform a
+    /// fixed action.
+    pub fn on_click_command<C: Command + Clone + Sync>(mut self, command: C) -> Self {
+        self.on_click_command = Some(ClickCommand::new(command));
+        self
+    }
+
+    /// Make the button act as a navigation link: clicking it transitions to the given state.
+    pub fn navigate_to<S: FreelyMutableState + Clone>(self, target_state: S) -> Self {
+        self.on_click_command(move |world: &mut World| {
+            world
+                .resource_mut::<NextState<S>>()
+                .set(target_state.clone());
+        })
+    }
+
     /// Set the tab index of the button.
     pub fn tab_index(mut self, tab_index: i32) -> Self {
         self.tab_index = tab_index;
@@ -188,6 +251,16 @@ impl ViewTemplate for Button {
 
         let size = self.size;
         let on_click = self.on_click;
+        let on_click_command = self.on_click_command.clone();
+
+        // Icons use the same color as the button text.
+        let icon_color = if self.disabled {
+            colors::FOREGROUND.with_alpha(0.2)
+        } else {
+            colors::FOREGROUND
+        };
+        let icon_size = button_icon_size(size);
+        let icon_gap = size.font_size() * 0.4;
 
         Element::<NodeBundle>::for_entity(id)
             .named("Button")
@@ -223,7 +296,8 @@ impl ViewTemplate for Button {
             .insert_if(self.disabled, || Disabled)
             .insert_if(self.autofocus, || AutoFocus)
             .insert_dyn(
-                move |_| {
+                move |on_click_command| {
+                    let on_click_command2 = on_click_command.clone();
                     (
                         AccessibilityNode::from(NodeBuilder::new(Role::Button)),
                         On::<Pointer<Click>>::run(move |world: &mut World| {
@@ -237,6 +311,9 @@ impl ViewTemplate for Button {
                                 if let Some(on_click) = on_click {
                                     world.run_callback(on_click, ());
                                 }
+                                if let Some(ref command) = on_click_command {
+                                    command.run(world);
+                                }
                             }
                         }),
                         On::<Pointer<DragStart>>::run(move |world: &mut World| {
@@ -278,12 +355,15 @@ impl ViewTemplate for Button {
                                     if let Some(on_click) = on_click {
                                         world.run_callback(on_click, ());
                                     }
+                                    if let Some(ref command) = on_click_command2 {
+                                        command.run(world);
+                                    }
                                 }
                             }
                         }),
                     )
                 },
-                (),
+                on_click_command,
             )
             .children((
                 Element::<NodeBundle>::new()
@@ -306,24 +386,48 @@ impl ViewTemplate for Button {
                     )
                     .style_dyn(
                         move |focused, sb| {
-                            match focused {
-                                true => {
-                                    sb.outline_color(colors::FOCUS)
-                                        .outline_width(2)
-                                        .outline_offset(2);
-                                }
-                                false => {
-                                    sb.outline_color(Option::<Color>::None);
-                                }
-                            };
+                            sb.focus_ring_animated(focused);
                         },
                         focused,
                     ),
+                Cond::new(
+                    self.icon_left.is_some(),
+                    Icon::new(self.icon_left.clone().unwrap_or_default())
+                        .color(icon_color)
+                        .size(icon_size)
+                        .style(move |sb: &mut StyleBuilder| {
+                            sb.margin_inline_end(icon_gap).flex_shrink(0.);
+                        }),
+                    (),
+                ),
                 self.children.clone(),
+                Cond::new(
+                    self.icon_right.is_some(),
+                    Icon::new(self.icon_right.clone().unwrap_or_default())
+                        .color(icon_color)
+                        .size(icon_size)
+                        .style(move |sb: &mut StyleBuilder| {
+                            sb.margin_inline_start(icon_gap).flex_shrink(0.);
+                        }),
+                    (),
+                ),
             ))
     }
 }
 
+/// Returns the size of icons displayed within a button of the given size.
+fn button_icon_size(size: Size) -> Vec2 {
+    match size {
+        Size::Xl => Vec2::splat(20.),
+        Size::Lg => Vec2::splat(18.),
+        Size::Md => Vec2::splat(16.),
+        Size::Sm => Vec2::splat(14.),
+        Size::Xs => Vec2::splat(12.),
+        Size::Xxs => Vec2::splat(11.),
+        Size::Xxxs => Vec2::splat(10.),
+    }
+}
+
 pub(crate) fn button_bg_color(
     variant: ButtonVariant,
     is_disabled: bool,
@@ -0,0 +1,241 @@
+use crate::{size::Size, RoundedCorners};
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use super::{Button, ButtonVariant};
+
+fn style_button_group(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Stretch)
+        .gap(1);
+}
+
+fn style_group_button(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.);
+}
+
+#[derive(Component)]
+struct ButtonGroupContext<T: Send + Sync + 'static> {
+    selected: Option<T>,
+    on_select: Callback<In<T>>,
+    size: Size,
+}
+
+/// A row of buttons, at most one of which is selected at a time. The selected button is shown
+/// using the [`ButtonVariant::Selected`] style. Buttons within the group should be
+/// [`GroupButton`]s with the same value type.
+#[derive(Clone, PartialEq)]
+pub struct ButtonGroup<T: PartialEq + Clone + Send + Sync + 'static> {
+    /// The currently selected value.
+    pub value: Option<T>,
+
+    /// Button size.
+    pub size: Size,
+
+    /// The buttons to display.
+    pub children: ViewChild,
+
+    /// Additional styles to be applied to the group.
+    pub style: StyleHandle,
+
+    /// Callback called when a different button is selected.
+    pub on_change: Option<Callback<In<T>>>,
+}
+
+impl<T: PartialEq + Clone + Send + Sync + 'static> Default for ButtonGroup<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            size: Size::default(),
+            children: ViewChild::default(),
+            style: StyleHandle::default(),
+            on_change: None,
+        }
+    }
+}
+
+impl<T: PartialEq + Clone + Send + Sync + 'static> ButtonGroup<T> {
+    /// Create a new button group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the currently selected value.
+    pub fn value(mut self, value: T) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Set the button size.
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the child views for this element.
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    /// Set additional styles to be applied to the group.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when a different button is selected. The callback receives the
+    /// value of the selected button as `In<T>`.
+    pub fn on_change(mut self, on_change: Callback<In<T>>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+}
+
+impl<T: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for ButtonGroup<T> {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let selection = cx.create_mutable::<Option<T>>(self.value.clone());
+        let on_change = self.on_change;
+
+        // Keep the selection in sync if the parent changes the value.
+        cx.create_effect_ext(
+            move |world, value| {
+                selection.set_clone(world, value);
+            },
+            self.value.clone(),
+            EffectOptions {
+                run_immediately: false,
+            },
+        );
+
+        let on_select = cx.create_callback(move |value: In<T>, world: &mut World| {
+            let value = value.0;
+            if selection.get_clone(world).as_ref() != Some(&value) {
+                selection.set_clone(world, Some(value.clone()));
+                if let Some(on_change) = on_change {
+                    world.run_callback(on_change, value);
+                }
+            }
+        });
+
+        cx.insert(ButtonGroupContext::<T> {
+            selected: selection.get_clone(cx),
+            on_select,
+            size: self.size,
+        });
+
+        Element::<NodeBundle>::new()
+            .named("ButtonGroup")
+            .style((style_button_group, self.style.clone()))
+            .insert(AccessibilityNode::from(NodeBuilder::new(Role::RadioGroup)))
+            .children(self.children.clone())
+    }
+}
+
+/// A button within a [`ButtonGroup`]. Clicking the button selects its value.
+#[derive(Clone, PartialEq)]
+pub struct GroupButton<T: PartialEq + Clone + Send + Sync + 'static> {
+    /// The value which is selected when this button is clicked.
+    pub value: Option<T>,
+
+    /// Whether the button is disabled.
+    pub disabled: bool,
+
+    /// The content to display inside the button.
+    pub children: ViewChild,
+
+    /// Additional styles to be applied to the button.
+    pub style: StyleHandle,
+
+    /// Which corners to render rounded.
+    pub corners: RoundedCorners,
+}
+
+impl<T: PartialEq + Clone + Send + Sync + 'static> Default for GroupButton<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            disabled: false,
+            children: ViewChild::default(),
+            style: StyleHandle::default(),
+            corners: RoundedCorners::default(),
+        }
+    }
+}
+
+impl<T: PartialEq + Clone + Send + Sync + 'static> GroupButton<T> {
+    /// Create a new group button.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value which is selected when this button is clicked.
+    pub fn value(mut self, value: T) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Set the button disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the child views for this element.
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    /// Set additional styles to be applied to the button.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set which corners to render rounded.
+    pub fn corners(mut self, corners: RoundedCorners) -> Self {
+        self.corners = corners;
+        self
+    }
+}
+
+impl<T: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for GroupButton<T> {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let context = cx
+            .use_inherited_component::<ButtonGroupContext<T>>()
+            .expect("GroupButton must be placed within a ButtonGroup");
+        let selected = self.value.is_some() && context.selected == self.value;
+        let on_select = context.on_select;
+        let size = context.size;
+        let value = self.value.clone();
+
+        Button::new()
+            .variant(if selected {
+                ButtonVariant::Selected
+            } else {
+                ButtonVariant::Default
+            })
+            .size(size)
+            .disabled(self.disabled)
+            .corners(self.corners)
+            .style((style_group_button, self.style.clone()))
+            .children(self.children.clone())
+            .on_click(cx.create_callback(move |world: &mut World| {
+                if let Some(value) = value.clone() {
+                    world.run_callback(on_select, value);
+                }
+            }))
+    }
+}
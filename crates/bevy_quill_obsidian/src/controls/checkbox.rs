@@ -1,11 +1,12 @@
 use bevy::{
     a11y::{
-        accesskit::{NodeBuilder, Role},
+        accesskit::{Checked as AccessChecked, NodeBuilder, Role},
         AccessibilityNode, Focus,
     },
     color::Luminance,
     prelude::*,
     ui,
+    window::SystemCursorIcon,
 };
 use bevy_mod_picking::{events::PointerCancel, prelude::*};
 use bevy_mod_stylebuilder::*;
@@ -14,7 +15,7 @@ use bevy_quill_core::*;
 use crate::{
     colors,
     cursor::StyleBuilderCursor,
-    focus::{KeyPressEvent, TabIndex},
+    focus::{KeyPressEvent, StyleBuilderFocusRing, TabIndex},
     hooks::{UseIsFocus, UseIsHover},
     typography,
 };
@@ -22,8 +23,7 @@ use crate::{
 use super::{Disabled, IsDisabled};
 
 fn style_checkbox(ss: &mut StyleBuilder) {
-    ss.display(ui::Display::Flex)
-        .flex_direction(ui::FlexDirection::Row)
+    ss.flex_row()
         .justify_content(ui::JustifyContent::Center)
         .align_items(ui::AlignItems::Center)
         .align_content(ui::AlignContent::Center)
@@ -36,7 +36,7 @@ fn style_checkbox_border(ss: &mut StyleBuilder) {
         .width(16)
         .height(16)
         .border_radius(3.0)
-        .cursor(CursorIcon::Pointer);
+        .cursor(SystemCursorIcon::Pointer);
 }
 
 fn style_checkbox_inner(ss: &mut StyleBuilder) {
@@ -50,20 +50,46 @@ fn style_checkbox_inner(ss: &mut StyleBuilder) {
         .height(12);
 }
 
+fn style_checkbox_dash(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .left(4)
+        .top(7)
+        .width(8)
+        .height(2)
+        .background_color(colors::FOREGROUND);
+}
+
 fn style_checkbox_label(ss: &mut StyleBuilder) {
-    ss.display(ui::Display::Flex)
-        .flex_direction(ui::FlexDirection::Row)
+    ss.flex_row()
         .justify_content(ui::JustifyContent::FlexStart)
         .align_items(ui::AlignItems::Center)
         .color(colors::FOREGROUND);
 }
 
+/// The state of a [`Checkbox`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CheckboxState {
+    /// The checkbox is not checked.
+    #[default]
+    Unchecked,
+
+    /// The checkbox is checked.
+    Checked,
+
+    /// The checkbox is partially checked, for example when it controls a group of items only
+    /// some of which are selected.
+    Indeterminate,
+}
+
 /// A checkbox widget.
 #[derive(Default, Clone, PartialEq)]
 pub struct Checkbox {
     /// Whether the checkbox is checked.
     pub checked: bool,
 
+    /// Whether the checkbox is in the indeterminate state. This takes precedence over `checked`.
+    pub indeterminate: bool,
+
     /// Whether the checkbox is disabled.
     pub disabled: bool,
 
@@ -73,8 +99,9 @@ pub struct Checkbox {
     /// Additional styles to be applied to the button.
     pub style: StyleHandle,
 
-    /// Callback called when clicked
-    pub on_change: Option<Callback<bool>>,
+    /// Callback called when the checkbox is toggled, which receives the new checked state.
+    /// Clicking an indeterminate checkbox checks it.
+    pub on_change: Option<Callback<In<bool>>>,
 
     /// The tab index of the checkbox (default 0).
     pub tab_index: i32,
@@ -89,6 +116,14 @@ impl Checkbox {
     /// Set the checked state of the checkbox.
     pub fn checked(mut self, checked: bool) -> Self {
         self.checked = checked;
+        self.indeterminate = false;
+        self
+    }
+
+    /// Set the state of the checkbox, which may be indeterminate.
+    pub fn state(mut self, state: CheckboxState) -> Self {
+        self.checked = state == CheckboxState::Checked;
+        self.indeterminate = state == CheckboxState::Indeterminate;
         self
     }
 
@@ -110,8 +145,10 @@ impl Checkbox {
         self
     }
 
-    /// Set the on_change callback of the checkbox.
-    pub fn on_change(mut self, on_change: Callback<bool>) -> Self {
+    /// Set the on_change callback of the checkbox. The callback is a one-shot system which
+    /// receives the new checked state as `In<bool>`, for example
+    /// `cx.create_callback(|checked: In<bool>, world: &mut World| { ... })`.
+    pub fn on_change(mut self, on_change: Callback<In<bool>>) -> Self {
         self.on_change = Some(on_change);
         self
     }
@@ -126,6 +163,15 @@ impl Checkbox {
 #[derive(Component)]
 pub(crate) struct Checked;
 
+/// Marker component for a checkbox in the indeterminate state.
+#[derive(Component)]
+struct Indeterminate;
+
+/// Return the checked state that a click on the checkbox should produce.
+fn next_checked(world: &World, id: Entity) -> bool {
+    world.get::<Indeterminate>(id).is_some() || world.get::<Checked>(id).is_none()
+}
+
 impl ViewTemplate for Checkbox {
     type View = impl View;
 
@@ -135,7 +181,11 @@ impl ViewTemplate for Checkbox {
         let pressed = cx.create_mutable::<bool>(false);
         let hovering = cx.is_hovered(id);
         let focused = cx.is_focus_visible(id);
-        let checked = self.checked;
+        let state = match (self.indeterminate, self.checked) {
+            (true, _) => CheckboxState::Indeterminate,
+            (false, true) => CheckboxState::Checked,
+            (false, false) => CheckboxState::Unchecked,
+        };
         let on_change = self.on_change;
 
         Element::<NodeBundle>::for_entity(id)
@@ -147,17 +197,29 @@ impl ViewTemplate for Checkbox {
             // them every time the checked or disabled state changes.
             .insert_if(self.disabled, || Disabled)
             .insert_if(self.checked, || Checked)
+            .insert_if(self.indeterminate, || Indeterminate)
+            .insert_dyn(
+                |state| {
+                    let mut node = NodeBuilder::new(Role::CheckBox);
+                    node.set_checked(match state {
+                        CheckboxState::Unchecked => AccessChecked::False,
+                        CheckboxState::Checked => AccessChecked::True,
+                        CheckboxState::Indeterminate => AccessChecked::Mixed,
+                    });
+                    AccessibilityNode::from(node)
+                },
+                state,
+            )
             .insert_dyn(
                 move |_| {
                     (
-                        AccessibilityNode::from(NodeBuilder::new(Role::CheckBox)),
                         On::<Pointer<Click>>::run(move |world: &mut World| {
                             let mut focus = world.get_resource_mut::<Focus>().unwrap();
                             focus.0 = Some(id);
                             if !world.is_disabled(id) {
-                                let next_checked = world.get::<Checked>(id).is_some();
+                                let next_checked = next_checked(world, id);
                                 if let Some(on_click) = on_change {
-                                    world.run_callback(on_click, !next_checked);
+                                    world.run_callback(on_click, next_checked);
                                 }
                             }
                         }),
@@ -197,9 +259,9 @@ impl ViewTemplate for Checkbox {
                                         || event.key_code == KeyCode::Space)
                                 {
                                     event.stop_propagation();
-                                    let next_checked = world.get::<Checked>(id).is_some();
+                                    let next_checked = next_checked(world, id);
                                     if let Some(on_click) = on_change {
-                                        world.run_callback(on_click, !next_checked);
+                                        world.run_callback(on_click, next_checked);
                                     }
                                 }
                             }
@@ -224,26 +286,25 @@ impl ViewTemplate for Checkbox {
                             };
                             sb.background_color(color);
                         },
-                        (checked, pressed.get(cx), hovering),
+                        (state != CheckboxState::Unchecked, pressed.get(cx), hovering),
                     )
                     .style_dyn(
                         |focused, sb| {
-                            if focused {
-                                sb.outline_color(colors::FOCUS)
-                                    .outline_offset(1.0)
-                                    .outline_width(2.0);
-                            } else {
-                                sb.outline_color(Option::<Color>::None);
-                            }
+                            sb.focus_ring_animated(focused);
                         },
                         focused,
                     )
-                    .children(Cond::new(
-                        checked,
-                        // (),
-                        Element::<NodeBundle>::new().style(style_checkbox_inner),
-                        (),
-                    )),
+                    .children(
+                        Switch::new(state)
+                            .case(
+                                CheckboxState::Checked,
+                                Element::<NodeBundle>::new().style(style_checkbox_inner),
+                            )
+                            .case(
+                                CheckboxState::Indeterminate,
+                                Element::<NodeBundle>::new().style(style_checkbox_dash),
+                            ),
+                    ),
                 Element::<NodeBundle>::new()
                     .style(style_checkbox_label)
                     .style_dyn(
@@ -10,7 +10,9 @@ use bevy_quill_core::*;
 
 use crate::RoundedCorners;
 
-use super::{Button, ButtonVariant, ColorGradient, GradientSlider, Swatch, SwatchGrid};
+use super::{
+    Button, ButtonVariant, ColorGradient, GradientSlider, NumberInput, Swatch, SwatchGrid,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum ColorMode {
@@ -276,6 +278,15 @@ impl ViewTemplate for RgbSliders {
         let state = self.state;
         let rgb = state.get(cx).rgb;
         let on_change = self.on_change;
+        let on_red = cx.create_callback(move |value: In<f32>, world: &mut World| {
+            world.run_callback(on_change, state.get(world).set_red(*value / 255.0));
+        });
+        let on_green = cx.create_callback(move |value: In<f32>, world: &mut World| {
+            world.run_callback(on_change, state.get(world).set_green(*value / 255.0));
+        });
+        let on_blue = cx.create_callback(move |value: In<f32>, world: &mut World| {
+            world.run_callback(on_change, state.get(world).set_blue(*value / 255.0));
+        });
 
         Element::<NodeBundle>::new().style(style_sliders).children((
             GradientSlider::new()
@@ -288,14 +299,13 @@ impl ViewTemplate for RgbSliders {
                 .value(rgb.red * 255.0)
                 .style(style_slider)
                 .precision(1)
-                .on_change(
-                    cx.create_callback(move |value: In<f32>, world: &mut World| {
-                        world.run_callback(on_change, state.get(world).set_red(*value / 255.0));
-                    }),
-                ),
-            Element::<NodeBundle>::new()
+                .on_change(on_red),
+            NumberInput::new()
+                .min(0.)
+                .max(255.)
+                .value(rgb.red * 255.0)
                 .style(style_numeric_input)
-                .children(format!("{:.0}", rgb.red * 255.0)),
+                .on_change(on_red),
             GradientSlider::new()
                 .gradient(ColorGradient::new(&[
                     Srgba::new(rgb.red, 0.0, rgb.blue, 1.0),
@@ -306,14 +316,13 @@ impl ViewTemplate for RgbSliders {
                 .value(rgb.green * 255.0)
                 .style(style_slider)
                 .precision(1)
-                .on_change(
-                    cx.create_callback(move |value: In<f32>, world: &mut World| {
-                        world.run_callback(on_change, state.get(world).set_green(*value / 255.0));
-                    }),
-                ),
-            Element::<NodeBundle>::new()
+                .on_change(on_green),
+            NumberInput::new()
+                .min(0.)
+                .max(255.)
+                .value(rgb.green * 255.0)
                 .style(style_numeric_input)
-                .children(format!("{:.0}", rgb.green * 255.0)),
+                .on_change(on_green),
             GradientSlider::new()
                 .gradient(ColorGradient::new(&[
                     Srgba::new(rgb.red, rgb.green, 0.0, 1.0),
@@ -324,14 +333,13 @@ impl ViewTemplate for RgbSliders {
                 .value(rgb.blue * 255.0)
                 .style(style_slider)
                 .precision(1)
-                .on_change(
-                    cx.create_callback(move |value: In<f32>, world: &mut World| {
-                        world.run_callback(on_change, state.get(world).set_blue(*value / 255.0));
-                    }),
-                ),
-            Element::<NodeBundle>::new()
+                .on_change(on_blue),
+            NumberInput::new()
+                .min(0.)
+                .max(255.)
+                .value(rgb.blue * 255.0)
                 .style(style_numeric_input)
-                .children(format!("{:.0}", rgb.blue * 255.0)),
+                .on_change(on_blue),
             AlphaSlider { state, on_change },
         ))
     }
@@ -349,6 +357,15 @@ impl ViewTemplate for HslSliders {
         let state = self.state;
         let hsl = state.get(cx).hsl;
         let on_change = self.on_change;
+        let on_hue = cx.create_callback(move |value: In<f32>, world: &mut World| {
+            world.run_callback(on_change, state.get(world).set_hue(*value));
+        });
+        let on_saturation = cx.create_callback(move |value: In<f32>, world: &mut World| {
+            world.run_callback(on_change, state.get(world).set_saturation(*value / 100.0));
+        });
+        let on_lightness = cx.create_callback(move |value: In<f32>, world: &mut World| {
+            world.run_callback(on_change, state.get(world).set_lightness(*value / 100.0));
+        });
 
         Element::<NodeBundle>::new().style(style_sliders).children((
             GradientSlider::new()
@@ -366,14 +383,13 @@ impl ViewTemplate for HslSliders {
                 .value(hsl.hue)
                 .style(style_slider)
                 .precision(1)
-                .on_change(
-                    cx.create_callback(move |value: In<f32>, world: &mut World| {
-                        world.run_callback(on_change, state.get(world).set_hue(*value));
-                    }),
-                ),
-            Element::<NodeBundle>::new()
+                .on_change(on_hue),
+            NumberInput::new()
+                .min(0.)
+                .max(360.)
+                .value(hsl.hue)
                 .style(style_numeric_input)
-                .children(format!("{:.0}", hsl.hue)),
+                .on_change(on_hue),
             GradientSlider::new()
                 .gradient(ColorGradient::new(&[
                     Srgba::from(Hsla::new(hsl.hue, 0.0, hsl.lightness, 1.0)),
@@ -385,17 +401,13 @@ impl ViewTemplate for HslSliders {
                 .value(hsl.saturation * 100.0)
                 .style(style_slider)
                 .precision(1)
-                .on_change(
-                    cx.create_callback(move |value: In<f32>, world: &mut World| {
-                        world.run_callback(
-                            on_change,
-                            state.get(world).set_saturation(*value / 100.0),
-                        );
-                    }),
-                ),
-            Element::<NodeBundle>::new()
+                .on_change(on_saturation),
+            NumberInput::new()
+                .min(0.)
+                .max(100.)
+                .value(hsl.saturation * 100.0)
                 .style(style_numeric_input)
-                .children(format!("{:.0}", hsl.saturation * 100.0)),
+                .on_change(on_saturation),
             GradientSlider::new()
                 .gradient(ColorGradient::new(&[
                     Srgba::from(Hsla::new(hsl.hue, hsl.saturation, 0.0, 1.0)),
@@ -407,17 +419,13 @@ impl ViewTemplate for HslSliders {
                 .value(hsl.lightness * 100.0)
                 .style(style_slider)
                 .precision(1)
-                .on_change(
-                    cx.create_callback(move |value: In<f32>, world: &mut World| {
-                        world.run_callback(
-                            on_change,
-                            state.get(world).set_lightness(*value / 100.0),
-                        );
-                    }),
-                ),
-            Element::<NodeBundle>::new()
+                .on_change(on_lightness),
+            NumberInput::new()
+                .min(0.)
+                .max(100.)
+                .value(hsl.lightness * 100.0)
                 .style(style_numeric_input)
-                .children(format!("{:.0}", hsl.lightness * 100.0)),
+                .on_change(on_lightness),
             AlphaSlider { state, on_change },
         ))
     }
@@ -435,6 +443,9 @@ impl ViewTemplate for AlphaSlider {
         let state = self.state;
         let rgb = state.get(cx).rgb;
         let on_change = self.on_change;
+        let on_alpha = cx.create_callback(move |value: In<f32>, world: &mut World| {
+            world.run_callback(on_change, state.get(world).set_alpha(*value / 255.0));
+        });
 
         (
             GradientSlider::new()
@@ -445,16 +456,16 @@ impl ViewTemplate for AlphaSlider {
                 .min(0.)
                 .max(255.)
                 .value(rgb.alpha * 255.0)
+                .linear_gradient(true)
                 .style(style_slider)
                 .precision(1)
-                .on_change(
-                    cx.create_callback(move |value: In<f32>, world: &mut World| {
-                        world.run_callback(on_change, state.get(world).set_alpha(*value / 255.0));
-                    }),
-                ),
-            Element::<NodeBundle>::new()
+                .on_change(on_alpha),
+            NumberInput::new()
+                .min(0.)
+                .max(255.)
+                .value(rgb.alpha * 255.0)
                 .style(style_numeric_input)
-                .children(format!("{:.0}", rgb.alpha * 255.0)),
+                .on_change(on_alpha),
         )
     }
 }
@@ -19,6 +19,8 @@ use crate::{
     typography::text_default,
 };
 
+use super::ScrollView;
+
 // Dialog background overlay
 fn style_dialog_overlay(ss: &mut StyleBuilder) {
     ss.position(PositionType::Absolute)
@@ -29,7 +31,6 @@ fn style_dialog_overlay(ss: &mut StyleBuilder) {
         .top(0)
         .right(0)
         .bottom(0)
-        .z_index(100)
         .background_color(colors::U1.with_alpha(0.0));
 }
 
@@ -42,7 +43,6 @@ fn style_dialog(ss: &mut StyleBuilder) {
         .justify_content(ui::JustifyContent::Center)
         .align_items(ui::AlignItems::Stretch)
         .border_color(colors::U1)
-        .width(400)
         .border(3);
     // .scale(0.5)
     // .transition(&[Transition {
@@ -56,13 +56,129 @@ fn style_dialog(ss: &mut StyleBuilder) {
 
 const TRANSITION_DURATION: f32 = 0.3;
 
+/// Global z-index of the bottom-most dialog. Each nested dialog is placed one level higher.
+/// This is below the z-index of menus and tooltips, so that they appear above dialogs.
+const DIALOG_Z_INDEX: i32 = 50;
+
+/// Resource which tracks the dialogs that are currently visible, in the order that they were
+/// opened. Dialogs are stacked in this order, so that a dialog opened from within another
+/// dialog appears above it.
+#[derive(Resource, Default, Debug)]
+pub struct DialogStack {
+    dialogs: Vec<Entity>,
+}
+
+impl DialogStack {
+    /// Returns the number of visible dialogs.
+    pub fn len(&self) -> usize {
+        self.dialogs.len()
+    }
+
+    /// Returns true if no dialogs are visible.
+    pub fn is_empty(&self) -> bool {
+        self.dialogs.is_empty()
+    }
+
+    /// Returns the position of the dialog in the stack, where 0 is the bottom-most dialog.
+    pub fn position(&self, dialog: Entity) -> Option<usize> {
+        self.dialogs.iter().position(|d| *d == dialog)
+    }
+
+    /// Returns the global z-index for the dialog.
+    fn z_index(&self, dialog: Entity) -> i32 {
+        DIALOG_Z_INDEX + self.position(dialog).unwrap_or(self.dialogs.len()) as i32
+    }
+}
+
+/// Add or remove a dialog from the [`DialogStack`]. The resource is only modified if the
+/// dialog's membership actually changes, so that other dialogs don't react needlessly.
+fn set_dialog_visible(world: &mut World, dialog: Entity, visible: bool) {
+    let Some(stack) = world.get_resource::<DialogStack>() else {
+        return;
+    };
+    match (stack.position(dialog), visible) {
+        (None, true) => world.resource_mut::<DialogStack>().dialogs.push(dialog),
+        (Some(index), false) => {
+            world.resource_mut::<DialogStack>().dialogs.remove(index);
+        }
+        _ => {}
+    }
+}
+
+/// Standard dialog sizes, for use with [`Dialog::size`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DialogSize {
+    /// 400 pixels wide.
+    Small,
+    /// 600 pixels wide.
+    #[default]
+    Medium,
+    /// 800 pixels wide.
+    Large,
+    /// Fills the window.
+    Fullscreen,
+}
+
+/// Resource which is passed to a dialog's `on_close_request` callback. The callback can call
+/// [`CloseRequest::prevent_close`] to keep the dialog open, for example to ask the user whether
+/// to save their changes first.
+#[derive(Resource, Default, Debug)]
+pub struct CloseRequest {
+    prevented: bool,
+}
+
+impl CloseRequest {
+    /// Cancel the close; `on_close` will not be called.
+    pub fn prevent_close(&mut self) {
+        self.prevented = true;
+    }
+
+    /// Returns true if the close has been cancelled.
+    pub fn is_prevented(&self) -> bool {
+        self.prevented
+    }
+}
+
+/// Ask whether the dialog may close, and if so call `on_close`.
+fn request_close(
+    world: &mut World,
+    on_close_request: Option<Callback>,
+    on_close: Option<Callback>,
+) {
+    if let Some(on_close_request) = on_close_request {
+        world.insert_resource(CloseRequest::default());
+        world.run_callback(on_close_request, ());
+        if world.resource::<CloseRequest>().is_prevented() {
+            return;
+        }
+    }
+    if let Some(on_close) = on_close {
+        world.run_callback(on_close, ());
+    }
+}
+
 /// Displays a modal dialog box. This will display the dialog frame and the backdrop overlay.
 /// Use the dialog header/body/footer controls to get the standard layout.
-#[derive(Default, Clone, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct Dialog {
-    /// The width of the dialog, one of several standard widths.
+    /// The width of the dialog. Defaults to fitting the content.
     pub width: ui::Val,
 
+    /// The height of the dialog. Defaults to fitting the content.
+    pub height: ui::Val,
+
+    /// The minimum width of the dialog.
+    pub min_width: ui::Val,
+
+    /// The minimum height of the dialog.
+    pub min_height: ui::Val,
+
+    /// The maximum width of the dialog, defaults to 90% of the window.
+    pub max_width: ui::Val,
+
+    /// The maximum height of the dialog, defaults to 90% of the window.
+    pub max_height: ui::Val,
+
     /// Signal that controls whether the dialog is open. Note that when this becomes false,
     /// the dialog will still remain visible until it completes its closing animation.
     pub open: bool,
@@ -73,10 +189,32 @@ pub struct Dialog {
     /// Callback called when the dialog's close button is clicked.
     pub on_close: Option<Callback>,
 
+    /// Callback called when the user tries to dismiss the dialog, before `on_close`. The
+    /// callback can cancel the close via the [`CloseRequest`] resource.
+    pub on_close_request: Option<Callback>,
+
     /// Callback called when the dialog has completed it's closing animation.
     pub on_exited: Option<Callback>,
 }
 
+impl Default for Dialog {
+    fn default() -> Self {
+        Self {
+            width: ui::Val::Auto,
+            height: ui::Val::Auto,
+            min_width: ui::Val::Auto,
+            min_height: ui::Val::Auto,
+            max_width: ui::Val::Percent(90.),
+            max_height: ui::Val::Percent(90.),
+            open: false,
+            children: ViewChild::default(),
+            on_close: None,
+            on_close_request: None,
+            on_exited: None,
+        }
+    }
+}
+
 impl Dialog {
     /// Creates a new `Dialog`.
     pub fn new() -> Self {
@@ -89,6 +227,53 @@ impl Dialog {
         self
     }
 
+    /// Sets the height of the dialog.
+    pub fn height(mut self, height: ui::Val) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the minimum width of the dialog.
+    pub fn min_width(mut self, min_width: ui::Val) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the minimum height of the dialog.
+    pub fn min_height(mut self, min_height: ui::Val) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// Sets the maximum width of the dialog.
+    pub fn max_width(mut self, max_width: ui::Val) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the dialog.
+    pub fn max_height(mut self, max_height: ui::Val) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the size of the dialog to one of the standard sizes. The standard sizes only
+    /// set the width, except for [`DialogSize::Fullscreen`] which fills the window.
+    pub fn size(mut self, size: DialogSize) -> Self {
+        match size {
+            DialogSize::Small => self.width = ui::Val::Px(400.),
+            DialogSize::Medium => self.width = ui::Val::Px(600.),
+            DialogSize::Large => self.width = ui::Val::Px(800.),
+            DialogSize::Fullscreen => {
+                self.width = ui::Val::Percent(100.);
+                self.height = ui::Val::Percent(100.);
+                self.max_width = ui::Val::Percent(100.);
+                self.max_height = ui::Val::Percent(100.);
+            }
+        }
+        self
+    }
+
     /// Sets the signal that controls whether the dialog is open.
     pub fn open(mut self, open: bool) -> Self {
         self.open = open;
@@ -107,6 +292,13 @@ impl Dialog {
         self
     }
 
+    /// Sets the callback called when the user tries to dismiss the dialog. The callback can
+    /// prevent the dialog from closing by calling [`CloseRequest::prevent_close`].
+    pub fn on_close_request(mut self, on_close_request: Callback) -> Self {
+        self.on_close_request = Some(on_close_request);
+        self
+    }
+
     /// Sets the callback called when the dialog has completed it's closing animation.
     pub fn on_exited(mut self, on_exited: Callback) -> Self {
         self.on_exited = Some(on_exited);
@@ -118,12 +310,31 @@ impl ViewTemplate for Dialog {
     type View = impl View;
     fn create(&self, cx: &mut Cx) -> Self::View {
         let on_close = self.on_close;
+        let on_close_request = self.on_close_request;
         let on_exited = self.on_exited;
         let state = cx.create_bistable_transition(self.open, TRANSITION_DURATION);
         let children = self.children.clone();
         let width = self.width;
+        let height = self.height;
+        let min_width = self.min_width;
+        let min_height = self.min_height;
+        let max_width = self.max_width;
+        let max_height = self.max_height;
         let save_focus = cx.create_mutable::<Option<Entity>>(None);
 
+        // Register the dialog in the stack for as long as it is visible.
+        let dialog_id = cx.owner();
+        cx.create_effect(
+            move |world, visible| set_dialog_visible(world, dialog_id, visible),
+            state != BistableTransitionState::Exited,
+        );
+        cx.on_unmount(move |world, _| set_dialog_visible(world, dialog_id, false));
+        let stack = cx.use_resource::<DialogStack>();
+        let z_index = stack.z_index(dialog_id);
+        // Only the bottom-most dialog darkens the backdrop; the overlays of nested dialogs are
+        // transparent, but still block clicks on the dialogs beneath them.
+        let is_bottom = stack.position(dialog_id).unwrap_or(0) == 0;
+
         cx.create_effect(
             |world, (open, save_focus)| {
                 if open {
@@ -138,10 +349,12 @@ impl ViewTemplate for Dialog {
             let saved_focus = save_focus
                 .get(cx.world())
                 .filter(|&focus| cx.world().get_entity(focus).is_some());
-            let mut focus = cx.world_mut().get_resource_mut::<Focus>().unwrap();
-            if focus.0 != saved_focus {
-                focus.0 = saved_focus;
-            }
+            cx.with_world_mut(|world| {
+                let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                if focus.0 != saved_focus {
+                    focus.0 = saved_focus;
+                }
+            });
             if let Some(on_exited) = on_exited {
                 cx.run_callback(on_exited, ());
             }
@@ -153,6 +366,7 @@ impl ViewTemplate for Dialog {
                 Element::<NodeBundle>::new()
                     .named("Dialog::Overlay")
                     .style(style_dialog_overlay)
+                    .insert_dyn(ZIndex::Global, z_index)
                     .insert_dyn(
                         move |_| {
                             (
@@ -163,9 +377,7 @@ impl ViewTemplate for Dialog {
                                         .get_resource_mut::<ListenerInput<Pointer<Click>>>()
                                         .unwrap();
                                     event.stop_propagation();
-                                    if let Some(on_close) = on_close {
-                                        world.run_callback(on_close, ());
-                                    }
+                                    request_close(world, on_close_request, on_close);
                                 }),
                                 On::<KeyPressEvent>::run({
                                     move |world: &mut World| {
@@ -174,9 +386,7 @@ impl ViewTemplate for Dialog {
                                             .unwrap();
                                         if !event.repeat && event.key_code == KeyCode::Escape {
                                             event.stop_propagation();
-                                            if let Some(on_close) = on_close {
-                                                world.run_callback(on_close, ());
-                                            }
+                                            request_close(world, on_close_request, on_close);
                                         }
                                     }
                                 }),
@@ -185,23 +395,27 @@ impl ViewTemplate for Dialog {
                         (),
                     )
                     .effect(
-                        move |cx, ent, state| {
-                            let mut entt = cx.world_mut().entity_mut(ent);
-                            let target = match state {
-                                BistableTransitionState::Entering
-                                | BistableTransitionState::Entered
-                                | BistableTransitionState::ExitStart => colors::U1.with_alpha(0.7),
-                                BistableTransitionState::EnterStart
-                                | BistableTransitionState::Exiting
-                                | BistableTransitionState::Exited => colors::U1.with_alpha(0.0),
-                            };
-                            AnimatedTransition::<AnimatedBackgroundColor>::start(
-                                &mut entt,
-                                target,
-                                TRANSITION_DURATION,
-                            );
+                        move |cx, ent, (state, is_bottom)| {
+                            cx.with_world_mut(|world| {
+                                let mut entt = world.entity_mut(ent);
+                                let target = match state {
+                                    BistableTransitionState::Entering
+                                    | BistableTransitionState::Entered
+                                    | BistableTransitionState::ExitStart
+                                        if is_bottom =>
+                                    {
+                                        colors::U1.with_alpha(0.7)
+                                    }
+                                    _ => colors::U1.with_alpha(0.0),
+                                };
+                                AnimatedTransition::<AnimatedBackgroundColor>::start(
+                                    &mut entt,
+                                    target,
+                                    TRANSITION_DURATION,
+                                );
+                            });
                         },
-                        state,
+                        (state, is_bottom),
                     )
                     .children(
                         Element::<NodeBundle>::new()
@@ -210,7 +424,12 @@ impl ViewTemplate for Dialog {
                                 modal: true,
                             })
                             .style((text_default, style_dialog, move |ss: &mut StyleBuilder| {
-                                ss.width(width);
+                                ss.width(width)
+                                    .height(height)
+                                    .min_width(min_width)
+                                    .min_height(min_height)
+                                    .max_width(max_width)
+                                    .max_height(max_height);
                             }))
                             .insert(Transform {
                                 scale: Vec3::splat(0.1),
@@ -230,20 +449,24 @@ impl ViewTemplate for Dialog {
                             )
                             .effect(
                                 move |cx, ent, state| {
-                                    let mut entt = cx.world_mut().entity_mut(ent);
-                                    let target = match state {
-                                        BistableTransitionState::EnterStart
-                                        | BistableTransitionState::Exiting
-                                        | BistableTransitionState::Exited => Vec3::splat(0.1),
-                                        BistableTransitionState::Entering
-                                        | BistableTransitionState::Entered
-                                        | BistableTransitionState::ExitStart => Vec3::splat(1.0),
-                                    };
-                                    AnimatedTransition::<AnimatedScale>::start(
-                                        &mut entt,
-                                        target,
-                                        TRANSITION_DURATION,
-                                    );
+                                    cx.with_world_mut(|world| {
+                                        let mut entt = world.entity_mut(ent);
+                                        let target = match state {
+                                            BistableTransitionState::EnterStart
+                                            | BistableTransitionState::Exiting
+                                            | BistableTransitionState::Exited => Vec3::splat(0.1),
+                                            BistableTransitionState::Entering
+                                            | BistableTransitionState::Entered
+                                            | BistableTransitionState::ExitStart => {
+                                                Vec3::splat(1.0)
+                                            }
+                                        };
+                                        AnimatedTransition::<AnimatedScale>::start(
+                                            &mut entt,
+                                            target,
+                                            TRANSITION_DURATION,
+                                        );
+                                    });
                                 },
                                 state,
                             )
@@ -256,8 +479,7 @@ impl ViewTemplate for Dialog {
 }
 
 fn style_dialog_header(ss: &mut StyleBuilder) {
-    ss.display(ui::Display::Flex)
-        .flex_direction(ui::FlexDirection::Row)
+    ss.flex_row()
         .justify_content(ui::JustifyContent::SpaceBetween)
         .font_size(18)
         .border_color(colors::U2.darker(0.01))
@@ -295,14 +517,29 @@ impl ViewTemplate for DialogHeader {
 }
 
 fn style_dialog_body(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .flex_grow(1.)
+        .min_height(0)
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_dialog_body_scroll(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.).min_height(0);
+}
+
+fn style_dialog_body_content(ss: &mut StyleBuilder) {
     ss.display(ui::Display::Flex)
         .flex_direction(ui::FlexDirection::Column)
         .align_items(ui::AlignItems::Stretch)
         .justify_content(ui::JustifyContent::FlexStart)
+        .min_width(ui::Val::Percent(100.))
         .padding((12, 6));
 }
 
-/// Displays a standard dialog body.
+/// Displays a standard dialog body. The body takes up the space between the header and
+/// footer, and scrolls vertically if the content is too tall to fit.
 #[derive(Default, Clone, PartialEq)]
 pub struct DialogBody {
     /// The content of the dialog header.
@@ -326,14 +563,23 @@ impl ViewTemplate for DialogBody {
     type View = impl View;
     fn create(&self, _cx: &mut Cx) -> Self::View {
         Element::<NodeBundle>::new()
+            .named("Dialog::Body")
             .style(style_dialog_body)
-            .children(self.children.clone())
+            .children(
+                ScrollView::new()
+                    .scroll_enable_y(true)
+                    .style(style_dialog_body_scroll)
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .style(style_dialog_body_content)
+                            .children(self.children.clone()),
+                    ),
+            )
     }
 }
 
 fn style_dialog_footer(ss: &mut StyleBuilder) {
-    ss.display(ui::Display::Flex)
-        .flex_direction(ui::FlexDirection::Row)
+    ss.flex_row()
         .justify_content(ui::JustifyContent::FlexEnd)
         .align_items(ui::AlignItems::Center)
         .border_color(colors::U2.darker(0.01))
@@ -3,7 +3,7 @@ use crate::{
     animation::{AnimatedRotation, AnimatedTransition},
     colors,
     cursor::StyleBuilderCursor,
-    focus::{KeyPressEvent, TabIndex},
+    focus::{KeyPressEvent, StyleBuilderFocusRing, TabIndex},
     hooks::{UseIsFocus, UseIsHover},
     size::Size,
 };
@@ -14,19 +14,19 @@ use bevy::{
     },
     prelude::*,
     ui,
+    window::SystemCursorIcon,
 };
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
 fn style_toggle(ss: &mut StyleBuilder) {
-    ss.display(ui::Display::Flex)
-        .flex_direction(ui::FlexDirection::Row)
+    ss.flex_row()
         .justify_content(ui::JustifyContent::Center)
         .align_items(ui::AlignItems::Center)
         .align_content(ui::AlignContent::Center)
         .color(colors::FOREGROUND)
-        .cursor(CursorIcon::Pointer);
+        .cursor(SystemCursorIcon::Pointer);
 }
 
 /// A widget which displays small toggleable chevron that can be used to control whether
@@ -85,7 +85,7 @@ impl DisclosureToggle {
         self
     }
 
-    /// Set callback when clicked
+    /// Set callback when clicked. The callback receives the new expanded state as `In<bool>`.
     pub fn on_change(mut self, callback: Callback<bool>) -> Self {
         self.on_change = Some(callback);
         self
@@ -157,29 +157,22 @@ impl ViewTemplate for DisclosureToggle {
             )
             .effect(
                 move |cx, en, checked| {
-                    let mut entt = cx.world_mut().entity_mut(en);
-                    let angle = if checked {
-                        std::f32::consts::PI * 0.5
-                    } else {
-                        0.
-                    };
-                    let target = Quat::from_rotation_z(angle);
-                    AnimatedTransition::<AnimatedRotation>::start(&mut entt, target, 0.3);
+                    cx.with_world_mut(|world| {
+                        let mut entt = world.entity_mut(en);
+                        let angle = if checked {
+                            std::f32::consts::PI * 0.5
+                        } else {
+                            0.
+                        };
+                        let target = Quat::from_rotation_z(angle);
+                        AnimatedTransition::<AnimatedRotation>::start(&mut entt, target, 0.3);
+                    });
                 },
                 self.expanded,
             )
             .style_dyn(
                 move |focused, sb| {
-                    match focused {
-                        true => {
-                            sb.outline_color(colors::FOCUS)
-                                .outline_width(2)
-                                .outline_offset(2);
-                        }
-                        false => {
-                            sb.outline_color(Option::<Color>::None);
-                        }
-                    };
+                    sb.focus_ring(focused);
                 },
                 focused,
             )
@@ -202,7 +195,7 @@ impl ViewTemplate for DisclosureToggle {
                         Size::Xxxs => Vec2::splat(11.),
                     })
                     .style(|ss: &mut StyleBuilder| {
-                        ss.margin_right(2);
+                        ss.margin_inline_end(2);
                     }),
             )
     }
@@ -19,75 +19,73 @@ struct SliderState {
     precision: usize,
 }
 
-/// Struct representing a sequence of color stops, evenly spaced. Up to 8 stops are supported.
-#[derive(Debug, Copy, Clone)]
-pub struct ColorGradient {
-    /// Number of color stops.
-    pub num_colors: usize,
+/// Maximum number of color stops supported by [`ColorGradient`].
+pub const MAX_COLOR_STOPS: usize = 8;
 
-    /// Array of color stops.
-    pub colors: [Srgba; 8],
+/// Struct representing a sequence of color stops. Each stop has a position in the range
+/// `0.0..=1.0`, and stops should be sorted by position. Up to [`MAX_COLOR_STOPS`] stops are
+/// supported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorGradient {
+    /// List of `(position, color)` stops.
+    pub stops: Vec<(f32, Srgba)>,
 }
 
 impl ColorGradient {
-    /// Construct a new color gradient from an array of colors.
+    /// Construct a new color gradient from an array of evenly-spaced colors.
     pub fn new(colors: &[Srgba]) -> Self {
-        assert!(colors.len() <= 8);
-        let mut result = Self {
-            num_colors: colors.len(),
-            colors: [Srgba::default(); 8],
+        assert!(colors.len() <= MAX_COLOR_STOPS);
+        let step = if colors.len() > 1 {
+            1.0 / (colors.len() - 1) as f32
+        } else {
+            0.0
         };
-        for (i, color) in colors.iter().enumerate() {
-            result.colors[i] = *color;
+        Self {
+            stops: colors
+                .iter()
+                .enumerate()
+                .map(|(i, color)| (i as f32 * step, *color))
+                .collect(),
+        }
+    }
+
+    /// Construct a new color gradient from an array of `(position, color)` stops.
+    pub fn with_stops(stops: &[(f32, Srgba)]) -> Self {
+        assert!(stops.len() <= MAX_COLOR_STOPS);
+        Self {
+            stops: stops.to_vec(),
         }
-        result
     }
 
     /// Return the first color in the gradient, if any.
     pub fn first(&self) -> Option<Srgba> {
-        if self.num_colors > 0 {
-            Some(self.colors[0])
-        } else {
-            None
-        }
+        self.stops.first().map(|(_, color)| *color)
     }
 
     /// Return the last color in the gradient, if any.
     pub fn last(&self) -> Option<Srgba> {
-        if self.num_colors > 0 {
-            Some(self.colors[self.num_colors - 1])
-        } else {
-            None
-        }
+        self.stops.last().map(|(_, color)| *color)
     }
 
     /// Return the number of color stops in the gradient.
     pub fn len(&self) -> usize {
-        self.num_colors
+        self.stops.len()
     }
 
     /// Check if the gradient is empty.
     pub fn is_empty(&self) -> bool {
-        self.num_colors == 0
+        self.stops.is_empty()
     }
 }
 
 impl Default for ColorGradient {
     fn default() -> Self {
         Self {
-            num_colors: 1,
-            colors: [Srgba::BLACK; 8],
+            stops: vec![(0.0, Srgba::BLACK)],
         }
     }
 }
 
-impl PartialEq for ColorGradient {
-    fn eq(&self, other: &Self) -> bool {
-        self.num_colors == other.num_colors
-            && self.colors[0..self.num_colors] == other.colors[0..other.num_colors]
-    }
-}
-
 #[derive(Component, Clone, PartialEq, Default, Copy)]
 struct DragState {
     dragging: bool,
@@ -143,6 +141,9 @@ pub struct GradientSlider {
     /// Whether the slider is disabled.
     pub disabled: bool,
 
+    /// Whether the gradient should be interpolated in linear color space rather than sRGB.
+    pub linear_gradient: bool,
+
     /// Style handle for slider root element.
     pub style: StyleHandle,
 
@@ -199,13 +200,21 @@ impl GradientSlider {
         self
     }
 
+    /// Set whether the gradient should be interpolated in linear color space. This is more
+    /// accurate for values which represent physically-linear quantities, such as alpha.
+    pub fn linear_gradient(mut self, linear_gradient: bool) -> Self {
+        self.linear_gradient = linear_gradient;
+        self
+    }
+
     /// Set the style handle for the slider root element.
     pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
         self.style = style.into_handle();
         self
     }
 
-    /// Set the callback called when the value changes.
+    /// Set the callback called when the value changes. The callback receives the new value as
+    /// `In<f32>`.
     pub fn on_change(mut self, on_change: Callback<f32>) -> Self {
         self.on_change = Some(on_change);
         self
@@ -221,6 +230,7 @@ impl Default for GradientSlider {
             max: 1.,
             precision: 0,
             disabled: false,
+            linear_gradient: false,
             style: StyleHandle::default(),
             on_change: None,
         }
@@ -235,16 +245,18 @@ impl ViewTemplate for GradientSlider {
 
         let color_stops = cx.create_memo(
             move |_, g| {
-                let mut result: [Vec4; 8] = [Vec4::default(); 8];
-                let num_color_stops = g.len();
-                for (i, color) in g.colors[0..num_color_stops].iter().enumerate() {
-                    // Note that we do *not* convert to linear here, because interpolating
-                    // linear looks bad. That gets done in the shader.
-                    result[i] = Vec4::new(color.red, color.green, color.blue, color.alpha);
+                let mut colors: [Vec4; MAX_COLOR_STOPS] = [Vec4::default(); MAX_COLOR_STOPS];
+                let mut positions: [Vec4; MAX_COLOR_STOPS / 4] =
+                    [Vec4::default(); MAX_COLOR_STOPS / 4];
+                for (i, (position, color)) in g.stops.iter().take(MAX_COLOR_STOPS).enumerate() {
+                    // Note that we do *not* convert to linear here, because by default we
+                    // interpolate in sRGB space. That gets done in the shader.
+                    colors[i] = Vec4::new(color.red, color.green, color.blue, color.alpha);
+                    positions[i / 4][i % 4] = *position;
                 }
-                (g.len(), result)
+                (g.len().min(MAX_COLOR_STOPS), colors, positions)
             },
-            self.gradient,
+            self.gradient.clone(),
         );
 
         let gradient_material = cx.create_memo(
@@ -253,8 +265,10 @@ impl ViewTemplate for GradientSlider {
                     .get_resource_mut::<Assets<GradientRectMaterial>>()
                     .unwrap();
                 gradient_material_assets.add(GradientRectMaterial {
-                    color_stops: [Srgba::default().to_vec4(); 8],
+                    color_stops: [Srgba::default().to_vec4(); MAX_COLOR_STOPS],
+                    stop_positions: [Vec4::default(); MAX_COLOR_STOPS / 4],
                     num_color_stops: IVec4::new(2, 0, 0, 0),
+                    gamma_correct: UVec4::ZERO,
                     cap_size: Vec4::new(THUMB_WIDTH * 0.5, 0., 0., 0.),
                 })
             },
@@ -263,26 +277,30 @@ impl ViewTemplate for GradientSlider {
 
         // Effect to update the material handle.
         cx.create_effect(
-            move |world, (material, color_stops)| {
-                let (num_color_stops, color_stops) = color_stops;
+            move |world, (material, color_stops, linear_gradient)| {
+                let (num_color_stops, color_stops, stop_positions) = color_stops;
                 let mut ui_materials = world
                     .get_resource_mut::<Assets<GradientRectMaterial>>()
                     .unwrap();
                 let material = ui_materials.get_mut(material.id()).unwrap();
                 material.num_color_stops.x = num_color_stops as i32;
                 material.color_stops = color_stops;
+                material.stop_positions = stop_positions;
+                material.gamma_correct.x = linear_gradient as u32;
             },
-            (gradient_material.clone(), color_stops),
+            (gradient_material.clone(), color_stops, self.linear_gradient),
         );
 
         // Ensure DragState component exists before rendering.
-        let mut entt = cx.world_mut().entity_mut(slider_id);
-        if !entt.contains::<DragState>() {
-            entt.insert(DragState {
-                dragging: false,
-                offset: 0.,
-            });
-        }
+        cx.with_world_mut(|world| {
+            let mut entt = world.entity_mut(slider_id);
+            if !entt.contains::<DragState>() {
+                entt.insert(DragState {
+                    dragging: false,
+                    offset: 0.,
+                });
+            }
+        });
 
         Element::<NodeBundle>::for_entity(slider_id)
             .named("GradientSlider")
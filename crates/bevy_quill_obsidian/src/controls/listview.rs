@@ -13,8 +13,9 @@ use bevy_quill_core::*;
 
 use crate::{
     colors,
-    focus::TabIndex,
-    hooks::{UseIsFocus, UseIsHover},
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseElementRect, UseIsFocus, UseIsHover},
+    scrolling::ScrollArea,
     typography,
 };
 
@@ -82,6 +83,150 @@ impl ViewTemplate for ListView {
     }
 }
 
+fn style_list_section(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch);
+}
+
+fn style_list_section_header(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Relative)
+        .z_index(1)
+        .background_color(colors::U1);
+}
+
+/// A group of list items with a header, used by [`SectionedListView`].
+#[derive(Clone, PartialEq, Default)]
+pub struct Section {
+    /// The content of the section header.
+    pub header: ViewChild,
+
+    /// The items within the section.
+    pub items: Vec<ViewChild>,
+}
+
+impl Section {
+    /// Create a new section with the given header.
+    pub fn new(header: impl IntoViewChild) -> Self {
+        Self {
+            header: header.into_view_child(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Set the items within the section.
+    pub fn items(mut self, items: Vec<ViewChild>) -> Self {
+        self.items = items;
+        self
+    }
+}
+
+/// A scrollable list of items which are grouped into sections. The header of each section
+/// sticks to the top of the viewport while the section is scrolled past, until it is pushed
+/// out of view by the next section.
+#[derive(Clone, PartialEq, Default)]
+pub struct SectionedListView {
+    /// Additional styles to be applied to the list view.
+    pub style: StyleHandle,
+
+    /// The sections within the list.
+    pub sections: Vec<Section>,
+}
+
+impl SectionedListView {
+    /// Create a new sectioned list view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set additional styles to be applied to the list view.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the sections within the list.
+    pub fn sections(mut self, sections: Vec<Section>) -> Self {
+        self.sections = sections;
+        self
+    }
+}
+
+impl ViewTemplate for SectionedListView {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id_scroll_area = cx.create_entity();
+        let id_content = cx.create_entity();
+
+        ScrollView::new()
+            .entity(Some(id_scroll_area))
+            .children(
+                Element::<NodeBundle>::for_entity(id_content)
+                    .named("SectionedListView")
+                    .insert(AccessibilityNode::from(NodeBuilder::new(Role::ListBox)))
+                    .style(style_listview_inner)
+                    .children(For::index(&self.sections, move |section, _| {
+                        ListSection {
+                            section: section.clone(),
+                            id_scroll_area,
+                            id_content,
+                        }
+                    })),
+            )
+            .style((style_listview, self.style.clone()))
+            .scroll_enable_y(true)
+    }
+}
+
+/// A single section of a [`SectionedListView`], which positions its header.
+#[derive(Clone, PartialEq)]
+struct ListSection {
+    section: Section,
+    id_scroll_area: Entity,
+    id_content: Entity,
+}
+
+impl ViewTemplate for ListSection {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id_section = cx.create_entity();
+        let id_header = cx.create_entity();
+        let scroll_top = cx
+            .use_component::<ScrollArea>(self.id_scroll_area)
+            .map_or(0., |scroll| scroll.scroll_top);
+        let section_rect = cx.use_element_rect(id_section);
+        let content_rect = cx.use_element_rect(self.id_content);
+        let header_height = cx.use_element_size(id_header).y;
+
+        // Offset the header so that it stays at the top of the viewport, but never extends
+        // past the bottom of its own section.
+        let section_top = section_rect.min.y - content_rect.min.y;
+        let sticky_offset = (scroll_top - section_top)
+            .min(section_rect.height() - header_height)
+            .max(0.);
+
+        Element::<NodeBundle>::for_entity(id_section)
+            .named("SectionedListView::Section")
+            .style(style_list_section)
+            .children((
+                Element::<NodeBundle>::for_entity(id_header)
+                    .named("SectionedListView::Header")
+                    .style(style_list_section_header)
+                    .style_dyn(
+                        |offset, sb| {
+                            sb.top(offset);
+                        },
+                        sticky_offset,
+                    )
+                    .children(self.section.header.clone()),
+                self.section.items.clone(),
+            ))
+    }
+}
+
+/// Maximum time between clicks, in seconds, for them to count as a double-click.
+const DOUBLE_CLICK_TIME: f32 = 0.4;
+
 /// A scrollable list of items.
 #[derive(Clone, PartialEq)]
 pub struct ListRow<K: PartialEq + Clone> {
@@ -99,6 +244,10 @@ pub struct ListRow<K: PartialEq + Clone> {
 
     /// Callback called when row clicked
     pub on_click: Option<Callback<K>>,
+
+    /// Callback called when row is activated, by double-clicking it or pressing Enter while
+    /// it has focus.
+    pub on_activate: Option<Callback<K>>,
 }
 
 impl<K: PartialEq + Clone> ListRow<K> {
@@ -110,6 +259,7 @@ impl<K: PartialEq + Clone> ListRow<K> {
             selected: false,
             children: ViewChild::default(),
             on_click: None,
+            on_activate: None,
         }
     }
 
@@ -131,11 +281,19 @@ impl<K: PartialEq + Clone> ListRow<K> {
         self
     }
 
-    /// Set callback when clicked
+    /// Set callback when clicked. The callback receives the key of the row as `In<K>`.
     pub fn on_click(mut self, callback: Callback<K>) -> Self {
         self.on_click = Some(callback);
         self
     }
+
+    /// Set callback when the row is activated, by double-clicking it or pressing Enter while
+    /// it has focus. The callback receives the key of the row as `In<K>`. A double-click
+    /// also calls the `on_click` callback for the first click.
+    pub fn on_activate(mut self, callback: Callback<K>) -> Self {
+        self.on_activate = Some(callback);
+        self
+    }
 }
 
 impl<K: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for ListRow<K> {
@@ -145,7 +303,10 @@ impl<K: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for ListRow<K> {
         let hovering = cx.is_hovered(id);
         let focused = cx.is_focused(id);
         let on_click = self.on_click;
+        let on_activate = self.on_activate;
         let key = self.key.clone();
+        // Time of the previous click, used to detect double-clicks.
+        let last_click = cx.create_mutable::<Option<f32>>(None);
 
         // TODO: Disabled
 
@@ -178,19 +339,48 @@ impl<K: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for ListRow<K> {
             .insert_dyn(
                 move |_| {
                     let key = key.clone();
-                    On::<Pointer<Click>>::run(move |world: &mut World| {
-                        let mut focus = world.get_resource_mut::<Focus>().unwrap();
-                        focus.0 = Some(id);
-                        if !world.is_disabled(id) {
-                            let mut event = world
-                                .get_resource_mut::<ListenerInput<Pointer<Click>>>()
-                                .unwrap();
-                            event.stop_propagation();
-                            if let Some(on_click) = on_click {
-                                world.run_callback(on_click, key.clone());
+                    let key2 = key.clone();
+                    (
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                            focus.0 = Some(id);
+                            if !world.is_disabled(id) {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                    .unwrap();
+                                event.stop_propagation();
+                                let now = world.resource::<Time>().elapsed_seconds();
+                                let is_double_click = last_click
+                                    .get(world)
+                                    .is_some_and(|last| now - last < DOUBLE_CLICK_TIME);
+                                match (is_double_click, on_activate) {
+                                    (true, Some(on_activate)) => {
+                                        last_click.set(world, None);
+                                        world.run_callback(on_activate, key.clone());
+                                    }
+                                    _ => {
+                                        last_click.set(world, Some(now));
+                                        if let Some(on_click) = on_click {
+                                            world.run_callback(on_click, key.clone());
+                                        }
+                                    }
+                                }
                             }
-                        }
-                    })
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .unwrap();
+                                if !event.repeat && event.key_code == KeyCode::Enter {
+                                    if let Some(on_activate) = on_activate {
+                                        event.stop_propagation();
+                                        world.run_callback(on_activate, key2.clone());
+                                    }
+                                }
+                            }
+                        }),
+                    )
                 },
                 (),
             )
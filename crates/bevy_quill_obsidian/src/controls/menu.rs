@@ -1,7 +1,10 @@
 use crate::{
     colors,
     floating::{FloatAlign, FloatPosition, FloatSide, Floating},
-    focus::{AutoFocus, KeyPressEvent, NavAction, TabGroup, TabIndex, TabNavigation},
+    focus::{
+        AutoFocus, KeyPressEvent, NavAction, StyleBuilderFocusRing, TabGroup, TabIndex,
+        TabNavigation,
+    },
     hooks::{BistableTransitionState, CreateBistableTransition, UseIsFocus, UseIsHover},
     size::Size,
     typography, RoundedCorners,
@@ -33,6 +36,83 @@ pub(crate) struct MenuCloseEvent {
     /// The target of the event
     #[target]
     pub target: Entity,
+
+    /// When the event is sent to a [`MenuBar`], the menu which should remain open.
+    pub except: Option<Entity>,
+}
+
+/// View context component which identifies the [`MenuBar`] that a menu button belongs to.
+#[derive(Component)]
+struct MenuBarContext(Entity);
+
+fn style_menu_bar(ss: &mut StyleBuilder) {
+    ss.flex_row().align_items(ui::AlignItems::Center).gap(2);
+}
+
+/// A horizontal row of [`MenuButton`]s. Opening one of the menus closes any other menu in the
+/// same bar.
+#[derive(Default, Clone, PartialEq)]
+pub struct MenuBar {
+    /// The menu buttons to display.
+    pub children: ViewChild,
+
+    /// Additional styles to be applied to the menu bar.
+    pub style: StyleHandle,
+}
+
+impl MenuBar {
+    /// Create a new menu bar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the menu bar children.
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    /// Set the menu bar style.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for MenuBar {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let owner_id = cx.owner();
+        cx.insert(MenuBarContext(owner_id));
+        cx.insert(On::<MenuCloseEvent>::run(move |world: &mut World| {
+            let mut event = world
+                .get_resource_mut::<ListenerInput<MenuCloseEvent>>()
+                .unwrap();
+            event.stop_propagation();
+            let except = event.except;
+            // Forward the event to every menu in the bar, other than the one being opened.
+            let mut st: SystemState<(Query<&Children>, Query<(), With<MenuAnchor>>)> =
+                SystemState::new(world);
+            let (q_children, q_menus) = st.get(world);
+            let menus: Vec<Entity> = q_children
+                .iter_descendants(owner_id)
+                .filter(|e| Some(*e) != except && q_menus.contains(*e))
+                .collect();
+            for menu in menus {
+                world.send_event(MenuCloseEvent {
+                    target: menu,
+                    except: None,
+                });
+            }
+        }));
+
+        Element::<NodeBundle>::new()
+            .named("MenuBar")
+            .style((style_menu_bar, self.style.clone()))
+            .insert(AccessibilityNode::from(NodeBuilder::new(Role::MenuBar)))
+            .children(self.children.clone())
+    }
 }
 
 // Dialog background overlay
@@ -164,7 +244,8 @@ impl MenuButton {
         self
     }
 
-    /// Set a callback to be called when the popup opens or closes.
+    /// Set a callback to be called when the popup opens or closes. The callback receives the
+    /// new open state as `In<bool>`.
     pub fn on_state_change(mut self, callback: Callback<bool>) -> Self {
         self.on_state_change = Some(callback);
         self
@@ -187,6 +268,10 @@ impl ViewTemplate for MenuButton {
         let size = self.size;
         let popup = self.popup.clone();
         let on_state_change = self.on_state_change;
+        let owner_id = cx.owner();
+        let menu_bar = cx
+            .use_inherited_component::<MenuBarContext>()
+            .map(|bar| bar.0);
 
         cx.create_effect_ext(
             |world, open| {
@@ -219,7 +304,7 @@ impl ViewTemplate for MenuButton {
                     if minimal {
                         ss.padding(0);
                     } else {
-                        ss.padding_left(6).padding_right(0);
+                        ss.padding_inline_start(6).padding_inline_end(0);
                     }
                 },
                 self.style.clone(),
@@ -240,6 +325,15 @@ impl ViewTemplate for MenuButton {
                                 open.update(world, |mut state| {
                                     *state = !*state;
                                 });
+                                // Close any other menus in the same menu bar.
+                                if let Some(bar) = menu_bar {
+                                    if open.get(world) {
+                                        world.send_event(MenuCloseEvent {
+                                            target: bar,
+                                            except: Some(owner_id),
+                                        });
+                                    }
+                                }
                             }
                         }),
                         On::<KeyPressEvent>::run({
@@ -292,16 +386,7 @@ impl ViewTemplate for MenuButton {
                     )
                     .style_dyn(
                         move |is_focused, sb| {
-                            match is_focused {
-                                true => {
-                                    sb.outline_color(colors::FOCUS)
-                                        .outline_width(2)
-                                        .outline_offset(2);
-                                }
-                                false => {
-                                    sb.outline_color(Option::<Color>::None);
-                                }
-                            };
+                            sb.focus_ring_animated(is_focused);
                         },
                         focused,
                     ),
@@ -314,7 +399,7 @@ impl ViewTemplate for MenuButton {
                         Icon::new("embedded://bevy_quill_obsidian/assets/icons/chevron_down.png")
                             .color(Color::from(colors::DIM))
                             .style(|ss: &mut StyleBuilder| {
-                                ss.margin_right(4);
+                                ss.margin_inline_end(4);
                             }),
                     ),
                 ),
@@ -481,7 +566,10 @@ impl ViewTemplate for MenuPopup {
                                 match event.key_code {
                                     KeyCode::Escape => {
                                         event.stop_propagation();
-                                        world.send_event(MenuCloseEvent { target: owner_id });
+                                        world.send_event(MenuCloseEvent {
+                                            target: owner_id,
+                                            except: None,
+                                        });
                                     }
                                     KeyCode::ArrowUp => {
                                         event.stop_propagation();
@@ -511,10 +599,12 @@ impl ViewTemplate for MenuPopup {
             .effect(
                 move |cx, ent, state| {
                     if state == BistableTransitionState::Entered {
-                        let mut st: SystemState<(ResMut<Focus>, TabNavigation)> =
-                            SystemState::new(cx.world_mut());
-                        let (mut focus, nav) = st.get_mut(cx.world_mut());
-                        focus.0 = nav.navigate(Some(ent), NavAction::First);
+                        cx.with_world_mut(|world| {
+                            let mut st: SystemState<(ResMut<Focus>, TabNavigation)> =
+                                SystemState::new(world);
+                            let (mut focus, nav) = st.get_mut(world);
+                            focus.0 = nav.navigate(Some(ent), NavAction::First);
+                        });
                     }
                 },
                 state,
@@ -524,8 +614,7 @@ impl ViewTemplate for MenuPopup {
 
 fn style_menu_item(ss: &mut StyleBuilder) {
     ss.height(24)
-        .display(ui::Display::Flex)
-        .flex_direction(ui::FlexDirection::Row)
+        .flex_row()
         .justify_content(ui::JustifyContent::FlexStart)
         .align_items(ui::AlignItems::Center)
         .padding((6, 0))
@@ -618,7 +707,10 @@ impl ViewTemplate for MenuItem {
                                 let (mut writer, mut focus) = st.get_mut(world);
                                 focus.0 = Some(id);
                                 if let Some(on_click) = on_click {
-                                    writer.send(MenuCloseEvent { target: owner_id });
+                                    writer.send(MenuCloseEvent {
+                                        target: owner_id,
+                                        except: None,
+                                    });
                                     world.run_callback(on_click, ());
                                 }
                             }
@@ -667,7 +759,10 @@ impl ViewTemplate for MenuItem {
                                 {
                                     event.stop_propagation();
                                     if let Some(on_click) = on_click {
-                                        writer.send(MenuCloseEvent { target: owner_id });
+                                        writer.send(MenuCloseEvent {
+                                            target: owner_id,
+                                            except: None,
+                                        });
                                         world.run_callback(on_click, ());
                                     }
                                 }
@@ -1,4 +1,5 @@
 mod button;
+mod button_group;
 mod checkbox;
 mod color_edit;
 mod dialog;
@@ -10,6 +11,7 @@ mod icon;
 mod icon_button;
 mod listview;
 mod menu;
+mod number_input;
 mod scrollview;
 mod slider;
 mod spacer;
@@ -17,9 +19,16 @@ mod spinbox;
 mod splitter;
 mod swatch;
 mod swatch_grid;
+mod tab_bar;
+mod text_input;
+mod toggle;
 mod tool_palette;
+mod tooltip;
+mod tree_view;
+mod virtual_list;
 
 pub use button::*;
+pub use button_group::*;
 pub use checkbox::*;
 pub use color_edit::{ColorEdit, ColorEditState, ColorMode, RecentColors};
 pub use dialog::*;
@@ -29,8 +38,9 @@ pub use flex::*;
 pub use gradient_slider::*;
 pub use icon::*;
 pub use icon_button::*;
-pub use listview::{ListRow, ListView};
+pub use listview::{ListRow, ListView, Section, SectionedListView};
 pub use menu::*;
+pub use number_input::NumberInput;
 pub use scrollview::*;
 pub use slider::*;
 pub use spacer::*;
@@ -38,4 +48,11 @@ pub use spinbox::*;
 pub use splitter::*;
 pub use swatch::Swatch;
 pub use swatch_grid::SwatchGrid;
+pub use tab_bar::{TabBar, TabDescriptor};
+pub use text_input::TextInput;
+pub use toggle::Toggle;
 pub use tool_palette::*;
+pub(crate) use tooltip::update_tooltip_timers;
+pub use tooltip::{ElementTooltip, Tooltip};
+pub use tree_view::{NodeId, TreeNode, TreeView};
+pub use virtual_list::VirtualList;
@@ -0,0 +1,383 @@
+use std::ops::RangeInclusive;
+
+use bevy::{a11y::Focus, prelude::*, ui, window::SystemCursorIcon};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    colors,
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, StyleBuilderFocusRing, TabIndex},
+    hooks::UseIsFocus,
+    typography,
+};
+
+use super::{
+    slider::{format_value, round_value},
+    Disabled, IsDisabled, TextInput,
+};
+
+#[derive(Component, Clone, PartialEq, Default, Copy)]
+struct DragState {
+    dragging: bool,
+    offset: f32,
+    was_dragged: bool,
+}
+
+fn style_number_input(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .min_width(40)
+        .height(20)
+        .border_radius(5)
+        .background_color(colors::U1);
+}
+
+fn style_number_input_label(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.)
+        .display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .justify_content(ui::JustifyContent::Center)
+        .height(ui::Val::Percent(100.))
+        .padding((6, 0))
+        .color(colors::FOREGROUND)
+        .cursor(SystemCursorIcon::ColResize);
+}
+
+fn style_number_input_text(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.).height(20);
+}
+
+/// Component used to hold the number input params so that they can be accessed by the
+/// callbacks without capturing.
+#[derive(Component, Copy, Clone)]
+struct NumberInputState {
+    value: f32,
+    min: f32,
+    max: f32,
+    precision: usize,
+    step: f32,
+    drag_speed: f32,
+    on_change: Option<Callback<f32>>,
+}
+
+impl NumberInputState {
+    /// Round and clamp a new value, and call `on_change` if it differs from the current value.
+    fn change(&self, world: &mut World, value: f32) {
+        let value = round_value(value, self.precision).clamp(self.min, self.max);
+        if value != self.value {
+            if let Some(on_change) = self.on_change {
+                world.run_callback(on_change, value);
+            }
+        }
+    }
+}
+
+/// A numeric field which can be scrubbed by dragging horizontally, or edited as text by
+/// clicking on it (or pressing Enter while it has focus). While editing, Enter commits the
+/// typed value and Escape reverts to the previous value; moving the focus elsewhere also
+/// reverts.
+#[derive(Clone, PartialEq)]
+pub struct NumberInput {
+    /// Current value.
+    pub value: f32,
+
+    /// Minimum value.
+    pub min: f32,
+
+    /// Maximum value.
+    pub max: f32,
+
+    /// Number of decimal places to round to (0 = integer).
+    pub precision: usize,
+
+    /// Amount to increment when using arrow keys.
+    pub step: f32,
+
+    /// Amount the value changes for each pixel the pointer is dragged.
+    pub drag_speed: f32,
+
+    /// Whether the number input is disabled.
+    pub disabled: bool,
+
+    /// The tab index of the number input (default 0).
+    pub tab_index: i32,
+
+    /// Style handle for the number input root element.
+    pub style: StyleHandle,
+
+    /// Callback called when value changes
+    pub on_change: Option<Callback<f32>>,
+}
+
+impl NumberInput {
+    /// Create a new number input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the current value.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the minimum value.
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum value.
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the minimum and maximum values from a range.
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.min = *range.start();
+        self.max = *range.end();
+        self
+    }
+
+    /// Set the number of decimal places to round to (0 = integer).
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Set the amount to increment when using arrow keys.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the amount the value changes for each pixel the pointer is dragged.
+    pub fn drag_speed(mut self, drag_speed: f32) -> Self {
+        self.drag_speed = drag_speed;
+        self
+    }
+
+    /// Set whether the number input is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the tab index of the number input.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Set the style handle for the number input root element.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when value changes. The callback receives the new value as
+    /// `In<f32>`.
+    pub fn on_change(mut self, on_change: Callback<f32>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+}
+
+impl Default for NumberInput {
+    fn default() -> Self {
+        Self {
+            value: 0.,
+            min: f32::MIN,
+            max: f32::MAX,
+            precision: 0,
+            step: 1.,
+            drag_speed: 1.,
+            disabled: false,
+            tab_index: 0,
+            style: StyleHandle::default(),
+            on_change: None,
+        }
+    }
+}
+
+impl ViewTemplate for NumberInput {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let focused = cx.is_focus_visible(id);
+        let focus_within = cx.is_focus_within(id);
+        let editing = cx.create_mutable::<bool>(false);
+        let is_editing = editing.get(cx);
+
+        // Stop editing, discarding the text, if the focus moves outside of the widget.
+        cx.create_effect(
+            move |world, (is_editing, focus_within)| {
+                if is_editing && !focus_within {
+                    editing.set(world, false);
+                }
+            },
+            (is_editing, focus_within),
+        );
+
+        let submit = cx.create_callback(move |text: In<String>, world: &mut World| {
+            let state = *world.get::<NumberInputState>(id).unwrap();
+            if let Ok(value) = text.trim().parse::<f32>() {
+                state.change(world, value);
+            }
+            editing.set(world, false);
+            world.resource_mut::<Focus>().0 = Some(id);
+        });
+
+        // Ensure DragState component exists before rendering.
+        cx.with_world_mut(|world| {
+            let mut entt = world.entity_mut(id);
+            if !entt.contains::<DragState>() {
+                entt.insert(DragState::default());
+            }
+        });
+
+        let formatted = format_value(self.value, self.precision);
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("NumberInput")
+            .style((
+                typography::text_default,
+                style_number_input,
+                self.style.clone(),
+            ))
+            .insert_dyn(TabIndex, self.tab_index)
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(
+                |(value, min, max, precision, step, drag_speed, on_change)| NumberInputState {
+                    value,
+                    min,
+                    max,
+                    precision,
+                    step,
+                    drag_speed,
+                    on_change,
+                },
+                (
+                    self.value,
+                    self.min,
+                    self.max,
+                    self.precision,
+                    self.step,
+                    self.drag_speed,
+                    self.on_change,
+                ),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                            if world.is_disabled(id) || editing.get(world) {
+                                return;
+                            }
+                            // Save initial value to use as drag offset.
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<DragStart>>>()
+                                .unwrap();
+                            event.stop_propagation();
+                            world.resource_mut::<Focus>().0 = Some(id);
+                            let mut entt = world.entity_mut(id);
+                            let value = entt.get::<NumberInputState>().unwrap().value;
+                            entt.insert(DragState {
+                                dragging: true,
+                                offset: value,
+                                was_dragged: false,
+                            });
+                        }),
+                        On::<Pointer<DragEnd>>::run(move |world: &mut World| {
+                            let mut entt = world.entity_mut(id);
+                            let mut ds = entt.get_mut::<DragState>().unwrap();
+                            // Leave `was_dragged` set, so that the click which ends the drag
+                            // doesn't start editing.
+                            ds.dragging = false;
+                        }),
+                        On::<Pointer<Drag>>::run(move |world: &mut World| {
+                            let ds = *world.get::<DragState>(id).unwrap();
+                            if !ds.dragging {
+                                return;
+                            }
+                            let delta = world
+                                .get_resource::<ListenerInput<Pointer<Drag>>>()
+                                .unwrap()
+                                .distance
+                                .x;
+                            if delta != 0. && !ds.was_dragged {
+                                world.entity_mut(id).insert(DragState {
+                                    was_dragged: true,
+                                    ..ds
+                                });
+                            }
+                            let state = *world.get::<NumberInputState>(id).unwrap();
+                            state.change(world, ds.offset + delta * state.drag_speed);
+                        }),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut entt = world.entity_mut(id);
+                            let mut ds = entt.get_mut::<DragState>().unwrap();
+                            let was_dragged = ds.was_dragged;
+                            ds.was_dragged = false;
+                            if !was_dragged && !world.is_disabled(id) && !editing.get(world) {
+                                world.resource_mut::<Focus>().0 = Some(id);
+                                editing.set(world, true);
+                            }
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if world.is_disabled(id) {
+                                return;
+                            }
+                            let key_code =
+                                world.resource::<ListenerInput<KeyPressEvent>>().key_code;
+                            let state = *world.get::<NumberInputState>(id).unwrap();
+                            match (editing.get(world), key_code) {
+                                // Keys which aren't handled by the text input bubble up to here.
+                                (true, KeyCode::Escape) => {
+                                    editing.set(world, false);
+                                    world.resource_mut::<Focus>().0 = Some(id);
+                                }
+                                (false, KeyCode::Enter) => {
+                                    editing.set(world, true);
+                                }
+                                (false, KeyCode::ArrowLeft | KeyCode::ArrowDown) => {
+                                    state.change(world, state.value - state.step);
+                                }
+                                (false, KeyCode::ArrowRight | KeyCode::ArrowUp) => {
+                                    state.change(world, state.value + state.step);
+                                }
+                                _ => return,
+                            }
+                            world
+                                .resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .stop_propagation();
+                        }),
+                    )
+                },
+                (),
+            )
+            .style_dyn(
+                |focused, sb| {
+                    sb.focus_ring(focused);
+                },
+                focused,
+            )
+            .children(Cond::new(
+                is_editing,
+                TextInput::new()
+                    .value(&formatted)
+                    .autofocus(true)
+                    .validator(|text| text.trim().parse::<f32>().is_ok())
+                    .style(style_number_input_text)
+                    .on_submit(submit),
+                Element::<NodeBundle>::new()
+                    .style(style_number_input_label)
+                    .children(formatted.clone()),
+            ))
+    }
+}
@@ -74,7 +74,7 @@ struct DragState {
 }
 
 /// The scroll view widget.
-#[derive(Default, Clone, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct ScrollView {
     /// Views for the scrolling content
     pub children: ViewChild,
@@ -84,11 +84,27 @@ pub struct ScrollView {
     pub scroll_enable_x: bool,
     /// Whether to enable vertical scrolling.
     pub scroll_enable_y: bool,
+    /// Whether to collapse the scrollbars when the content fits within the visible area,
+    /// allowing the scrolling region to expand into the freed space. Defaults to `true`.
+    pub hide_when_not_needed: bool,
     /// Optional entity id to use for the scrolling element. This is useful for querying the
     /// current scroll position.
     pub entity: Option<Entity>,
 }
 
+impl Default for ScrollView {
+    fn default() -> Self {
+        Self {
+            children: Default::default(),
+            style: Default::default(),
+            scroll_enable_x: false,
+            scroll_enable_y: false,
+            hide_when_not_needed: true,
+            entity: None,
+        }
+    }
+}
+
 impl ScrollView {
     /// Create a new `ScrollView`.
     pub fn new() -> Self {
@@ -119,6 +135,12 @@ impl ScrollView {
         self
     }
 
+    /// Set whether the scrollbars are collapsed when the content fits within the visible area.
+    pub fn hide_when_not_needed(mut self, hide: bool) -> Self {
+        self.hide_when_not_needed = hide;
+        self
+    }
+
     /// Set the entity id to use for the scrolling element.
     /// This is useful for querying the current scroll position.
     pub fn entity(mut self, entity: Option<Entity>) -> Self {
@@ -133,6 +155,7 @@ impl ViewTemplate for ScrollView {
         // A widget which displays a scrolling view of its children.
         let enable_x = self.scroll_enable_x;
         let enable_y = self.scroll_enable_y;
+        let hide_when_not_needed = self.hide_when_not_needed;
         let id_scroll_area = if let Some(entity) = self.entity {
             entity
         } else {
@@ -184,6 +207,7 @@ impl ViewTemplate for ScrollView {
                         id_scrollbar: id_scrollbar_x,
                         drag_state,
                         vertical: false,
+                        hide_when_not_needed,
                     }),
                     (),
                 ),
@@ -195,6 +219,7 @@ impl ViewTemplate for ScrollView {
                         id_scrollbar: id_scrollbar_y,
                         drag_state,
                         vertical: true,
+                        hide_when_not_needed,
                     }),
                     (),
                 ),
@@ -209,6 +234,7 @@ pub struct ScrollbarProps {
     id_scrollbar: Entity,
     drag_state: Mutable<DragState>,
     vertical: bool,
+    hide_when_not_needed: bool,
 }
 
 /// Scrollbar widget.
@@ -226,6 +252,7 @@ impl ViewTemplate for Scrollbar {
     type View = impl View;
     fn create(&self, cx: &mut Cx) -> Self::View {
         let vertical = self.0.vertical;
+        let hide_when_not_needed = self.0.hide_when_not_needed;
         let drag_state = self.0.drag_state;
         let id_scroll_area = self.0.id_scroll_area;
         let id_thumb = cx.create_entity();
@@ -238,6 +265,8 @@ impl ViewTemplate for Scrollbar {
                             id_scroll_area,
                             vertical,
                             min_thumb_size: 10.,
+                            min_thumb_size_fraction: 0.05,
+                            hide_when_not_needed,
                         },
                         // Click outside of thumb
                         On::<Pointer<DragStart>>::run(
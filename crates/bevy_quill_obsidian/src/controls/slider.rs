@@ -1,12 +1,16 @@
 use std::ops::RangeInclusive;
 
-use bevy::{color::LinearRgba, prelude::*, ui};
+use bevy::{a11y::Focus, color::LinearRgba, prelude::*, ui, window::SystemCursorIcon};
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
 use crate::{
-    colors, cursor::StyleBuilderCursor, hooks::UseElementRect, materials::SliderRectMaterial,
+    colors,
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, StyleBuilderFocusRing, TabIndex},
+    hooks::{UseElementRect, UseIsFocus},
+    materials::SliderRectMaterial,
     RoundedCorners,
 };
 
@@ -39,7 +43,7 @@ fn style_overlay(ss: &mut StyleBuilder) {
         .top(0)
         .bottom(0)
         .right(0)
-        .cursor(CursorIcon::ColResize);
+        .cursor(SystemCursorIcon::ColResize);
 }
 
 fn style_label(ss: &mut StyleBuilder) {
@@ -70,6 +74,17 @@ struct SliderState {
     step: f32,
 }
 
+/// Round a value to the given number of decimal places.
+pub(crate) fn round_value(value: f32, precision: usize) -> f32 {
+    let rounding = f32::powi(10., precision as i32);
+    (value * rounding).round() / rounding
+}
+
+/// Format a value with the given number of decimal places.
+pub(crate) fn format_value(value: f32, precision: usize) -> String {
+    format!("{:.*}", precision, value)
+}
+
 /// Horizontal slider widget
 #[derive(Clone, PartialEq)]
 pub struct Slider {
@@ -85,12 +100,15 @@ pub struct Slider {
     /// Number of decimal places to round to (0 = integer).
     pub precision: usize,
 
-    /// Amount to increment when using arrow buttons.
+    /// Amount to increment when using arrow buttons or arrow keys.
     pub step: f32,
 
     /// Whether the slider is disabled.
     pub disabled: bool,
 
+    /// The tab index of the slider (default 0).
+    pub tab_index: i32,
+
     /// Signal which returns the value formatted as a string. It `None`, then a default
     /// formatter will be used.
     pub formatted_value: Option<String>,
@@ -142,7 +160,9 @@ impl Slider {
         self
     }
 
-    /// Set the amount to increment when using arrow buttons.
+    /// Set the amount to increment when using arrow buttons or arrow keys. Holding shift
+    /// while pressing an arrow key uses a step 10 times larger; holding control uses a step
+    /// 10 times smaller.
     pub fn step(mut self, step: f32) -> Self {
         self.step = step;
         self
@@ -154,6 +174,12 @@ impl Slider {
         self
     }
 
+    /// Set the tab index of the slider.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Set the signal which returns the value formatted as a string. If `None`, then a default
     /// formatter will be used.
     pub fn formatted_value(mut self, formatted_value: String) -> Self {
@@ -173,7 +199,8 @@ impl Slider {
         self
     }
 
-    /// Set the callback called when value changes.
+    /// Set the callback called when value changes. The callback receives the new value as
+    /// `In<f32>`.
     pub fn on_change(mut self, on_change: Callback<f32>) -> Self {
         self.on_change = Some(on_change);
         self
@@ -189,6 +216,7 @@ impl Default for Slider {
             precision: 0,
             step: 1.,
             disabled: false,
+            tab_index: 0,
             formatted_value: None,
             style: StyleHandle::default(),
             label: None,
@@ -204,6 +232,8 @@ impl ViewTemplate for Slider {
         // let hovering = cx.is_hovered(slider_id);
         let rect = cx.use_element_rect(slider_id);
         let show_buttons = rect.width() >= 70.;
+        let focused = cx.is_focus_visible(slider_id);
+        let disabled = self.disabled;
 
         let on_change = self.on_change;
 
@@ -243,18 +273,21 @@ impl ViewTemplate for Slider {
         );
 
         // Ensure DragState component exists before rendering.
-        let mut entt = cx.world_mut().entity_mut(slider_id);
-        if !entt.contains::<DragState>() {
-            entt.insert(DragState {
-                dragging: DragType::None,
-                was_dragged: false,
-                offset: 0.,
-            });
-        }
+        cx.with_world_mut(|world| {
+            let mut entt = world.entity_mut(slider_id);
+            if !entt.contains::<DragState>() {
+                entt.insert(DragState {
+                    dragging: DragType::None,
+                    was_dragged: false,
+                    offset: 0.,
+                });
+            }
+        });
 
         Element::<MaterialNodeBundle<SliderRectMaterial>>::for_entity(slider_id)
             .style((style_slider, self.style.clone()))
             .insert(material.clone())
+            .insert_dyn(TabIndex, self.tab_index)
             .insert_dyn(
                 |(value, min, max, precision, step)| SliderState {
                     value,
@@ -274,6 +307,8 @@ impl ViewTemplate for Slider {
                                 .get_resource_mut::<ListenerInput<Pointer<DragStart>>>()
                                 .unwrap();
                             event.stop_propagation();
+                            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                            focus.0 = Some(slider_id);
                             let mut entt = world.entity_mut(slider_id);
                             let value = entt.get::<SliderState>().unwrap().value;
                             entt.insert(DragState {
@@ -322,8 +357,7 @@ impl ViewTemplate for Slider {
                                     } else {
                                         state.min + range * 0.5
                                     };
-                                    let rounding = f32::powi(10., state.precision as i32);
-                                    let new_value = (new_value * rounding).round() / rounding;
+                                    let new_value = round_value(new_value, state.precision);
                                     if state.value != new_value {
                                         if !ds.was_dragged {
                                             entt.insert(DragState {
@@ -341,9 +375,45 @@ impl ViewTemplate for Slider {
                                 }
                             }
                         }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if disabled {
+                                return;
+                            }
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .unwrap();
+                            let state = *world.entity(slider_id).get::<SliderState>().unwrap();
+                            let step = if event.shift {
+                                state.step * 10.
+                            } else if event.ctrl {
+                                state.step * 0.1
+                            } else {
+                                state.step
+                            };
+                            let new_value = match event.key_code {
+                                KeyCode::ArrowLeft | KeyCode::ArrowDown => state.value - step,
+                                KeyCode::ArrowRight | KeyCode::ArrowUp => state.value + step,
+                                KeyCode::Home => state.min,
+                                KeyCode::End => state.max,
+                                _ => return,
+                            };
+                            event.stop_propagation();
+                            let new_value = new_value.clamp(state.min, state.max);
+                            if state.value != new_value {
+                                if let Some(on_change) = on_change {
+                                    world.run_callback(on_change, new_value);
+                                }
+                            }
+                        }),
                     )
                 },
-                (),
+                disabled,
+            )
+            .style_dyn(
+                |focused, sb| {
+                    sb.focus_ring(focused);
+                },
+                focused,
             )
             .effect(
                 move |cx, _ent, (min, max, value, material)| {
@@ -353,12 +423,13 @@ impl ViewTemplate for Slider {
                         0.
                     };
 
-                    let mut ui_materials = cx
-                        .world_mut()
-                        .get_resource_mut::<Assets<SliderRectMaterial>>()
-                        .unwrap();
-                    let material = ui_materials.get_mut(material.id()).unwrap();
-                    material.value.x = pos;
+                    cx.with_world_mut(|world| {
+                        let mut ui_materials = world
+                            .get_resource_mut::<Assets<SliderRectMaterial>>()
+                            .unwrap();
+                        let material = ui_materials.get_mut(material.id()).unwrap();
+                        material.value.x = pos;
+                    });
                 },
                 (self.min, self.max, self.value, material.clone()),
             )
@@ -386,7 +457,7 @@ impl ViewTemplate for Slider {
                         ),
                         match self.formatted_value {
                             Some(ref formatted_value) => formatted_value.clone(),
-                            None => format!("{:.*}", self.precision, self.value),
+                            None => format_value(self.value, self.precision),
                         },
                     )),
                     Cond::new(
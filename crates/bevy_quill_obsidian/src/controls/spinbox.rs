@@ -1,6 +1,6 @@
 use super::IconButton;
 use crate::{colors, cursor::StyleBuilderCursor, hooks::UseElementRect, RoundedCorners};
-use bevy::{prelude::*, ui};
+use bevy::{prelude::*, ui, window::SystemCursorIcon};
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
@@ -36,7 +36,7 @@ fn style_overlay(ss: &mut StyleBuilder) {
         .top(0)
         .bottom(0)
         .right(0)
-        .cursor(CursorIcon::ColResize);
+        .cursor(SystemCursorIcon::ColResize);
 }
 
 fn style_spinbox_label(ss: &mut StyleBuilder) {
@@ -166,7 +166,8 @@ impl SpinBox {
         self
     }
 
-    /// Set the callback called when value changes.
+    /// Set the callback called when value changes. The callback receives the new value as
+    /// `In<f32>`.
     pub fn on_change(mut self, on_change: Callback<f32>) -> Self {
         self.on_change = Some(on_change);
         self
@@ -217,14 +218,16 @@ impl ViewTemplate for SpinBox {
         });
 
         // Ensure DragState component exists before rendering.
-        let mut entt = cx.world_mut().entity_mut(spinbox_id);
-        if !entt.contains::<DragState>() {
-            entt.insert(DragState {
-                dragging: DragType::None,
-                was_dragged: false,
-                offset: 0.,
-            });
-        }
+        cx.with_world_mut(|world| {
+            let mut entt = world.entity_mut(spinbox_id);
+            if !entt.contains::<DragState>() {
+                entt.insert(DragState {
+                    dragging: DragType::None,
+                    was_dragged: false,
+                    offset: 0.,
+                });
+            }
+        });
 
         Element::<NodeBundle>::for_entity(spinbox_id)
             .style((style_spinbox, self.style.clone()))
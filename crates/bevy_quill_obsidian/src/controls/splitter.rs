@@ -1,10 +1,12 @@
-use bevy::{color::Luminance, prelude::*, ui};
+use bevy::{color::Luminance, prelude::*, ui, window::SystemCursorIcon};
 use bevy_mod_picking::{events::PointerCancel, prelude::*};
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
 use crate::{colors, cursor::StyleBuilderCursor, hooks::UseIsHover};
 
+use super::Icon;
+
 /// The direction of the splitter. Represents the direction of the bar, not the items being split.
 #[derive(Clone, Copy, PartialEq, Default)]
 pub enum SplitterDirection {
@@ -16,10 +18,56 @@ pub enum SplitterDirection {
     Vertical,
 }
 
+impl SplitterDirection {
+    /// The default minimum split value for this direction.
+    pub fn default_min_size(&self) -> f32 {
+        match self {
+            SplitterDirection::Horizontal => 0.,
+            SplitterDirection::Vertical => 0.,
+        }
+    }
+
+    /// The default maximum split value for this direction.
+    pub fn default_max_size(&self) -> f32 {
+        match self {
+            SplitterDirection::Horizontal => f32::INFINITY,
+            SplitterDirection::Vertical => f32::INFINITY,
+        }
+    }
+}
+
+/// Maximum time between clicks, in seconds, for them to count as a double-click.
+const DOUBLE_CLICK_TIME: f32 = 0.4;
+
 #[derive(Component, Clone)]
 struct DragState {
     dragging: bool,
     offset: f32,
+    /// Time of the previous click, used to detect double-clicks.
+    last_click: Option<f32>,
+}
+
+/// The size constraints of the two panes, used to clamp the split value.
+#[derive(Clone, Copy, PartialEq)]
+struct SplitterLimits {
+    min_primary: f32,
+    max_primary: f32,
+    min_secondary: f32,
+    max_secondary: f32,
+}
+
+impl SplitterLimits {
+    /// Clamp a split value. `available` is the space shared by the two panes, if known; the
+    /// secondary pane receives whatever the primary pane does not.
+    fn clamp(&self, value: f32, available: Option<f32>) -> f32 {
+        let (mut lower, mut upper) = (self.min_primary, self.max_primary);
+        if let Some(available) = available {
+            lower = lower.max(available - self.max_secondary);
+            upper = upper.min(available - self.min_secondary).min(available);
+        }
+        // If the constraints conflict, the minimum sizes take precedence.
+        value.min(upper).max(lower).max(0.)
+    }
 }
 
 #[derive(Component)]
@@ -33,7 +81,7 @@ fn style_vsplitter(ss: &mut StyleBuilder) {
         .gap(8)
         .width(9)
         .background_color(colors::U1)
-        .cursor(CursorIcon::ColResize);
+        .cursor(SystemCursorIcon::ColResize);
 }
 
 // The decorative handle inside the splitter.
@@ -52,7 +100,7 @@ fn style_hsplitter(ss: &mut StyleBuilder) {
         .gap(8)
         .height(9)
         .background_color(colors::U2)
-        .cursor(CursorIcon::RowResize);
+        .cursor(SystemCursorIcon::RowResize);
 }
 
 // The decorative handle inside the splitter.
@@ -74,6 +122,22 @@ pub struct Splitter {
 
     /// Callback involved with the new split value.
     pub on_change: Option<Callback<f32>>,
+
+    /// Minimum split value. If `None`, the default for the splitter direction is used.
+    pub min_size: Option<f32>,
+
+    /// Maximum split value. If `None`, the default for the splitter direction is used.
+    pub max_size: Option<f32>,
+
+    /// Minimum size of the secondary pane, the one whose size is not given by the split value.
+    pub min_secondary: Option<f32>,
+
+    /// Maximum size of the secondary pane.
+    pub max_secondary: Option<f32>,
+
+    /// Value which the splitter is reset to when double-clicked. If `None`, double-clicking
+    /// does nothing.
+    pub default_value: Option<f32>,
 }
 
 impl Splitter {
@@ -94,11 +158,54 @@ impl Splitter {
         self
     }
 
-    /// Set the callback to be invoked when the split value changes.
+    /// Set the callback to be invoked when the split value changes. The callback receives the
+    /// new value as `In<f32>`.
     pub fn on_change(mut self, on_change: Callback<f32>) -> Self {
         self.on_change = Some(on_change);
         self
     }
+
+    /// Set the minimum split value. Values passed to `on_change` are clamped to this.
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    /// Set the maximum split value. Values passed to `on_change` are clamped to this.
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Set the minimum size of the primary pane, the one whose size is the split value. This
+    /// is the same as [`Splitter::min_size`].
+    pub fn min_primary(self, min_primary: f32) -> Self {
+        self.min_size(min_primary)
+    }
+
+    /// Set the maximum size of the primary pane. This is the same as [`Splitter::max_size`].
+    pub fn max_primary(self, max_primary: f32) -> Self {
+        self.max_size(max_primary)
+    }
+
+    /// Set the minimum size of the secondary pane. The space available to the two panes is
+    /// measured from the splitter's parent node.
+    pub fn min_secondary(mut self, min_secondary: f32) -> Self {
+        self.min_secondary = Some(min_secondary);
+        self
+    }
+
+    /// Set the maximum size of the secondary pane.
+    pub fn max_secondary(mut self, max_secondary: f32) -> Self {
+        self.max_secondary = Some(max_secondary);
+        self
+    }
+
+    /// Set the value which the splitter is reset to when double-clicked.
+    pub fn default_value(mut self, default_value: f32) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
 }
 
 impl Default for Splitter {
@@ -107,6 +214,11 @@ impl Default for Splitter {
             value: 0.,
             direction: SplitterDirection::Vertical,
             on_change: None,
+            min_size: None,
+            max_size: None,
+            min_secondary: None,
+            max_secondary: None,
+            default_value: None,
         }
     }
 }
@@ -118,6 +230,30 @@ impl ViewTemplate for Splitter {
         let hovering = cx.is_hovered(id);
         let on_change = self.on_change;
         let direction = self.direction;
+        let min_size = self.min_size.unwrap_or(direction.default_min_size());
+        let max_size = self
+            .max_size
+            .unwrap_or(direction.default_max_size())
+            .max(min_size);
+        let limits = SplitterLimits {
+            min_primary: min_size,
+            max_primary: max_size,
+            min_secondary: self.min_secondary.unwrap_or(0.),
+            max_secondary: self.max_secondary.unwrap_or(f32::INFINITY),
+        };
+        let default_value = self.default_value;
+        let at_min = self.value <= min_size;
+        let at_max = self.value >= max_size;
+        let (min_icon, max_icon) = match direction {
+            SplitterDirection::Horizontal => (
+                "embedded://bevy_quill_obsidian/assets/icons/chevron_up.png",
+                "embedded://bevy_quill_obsidian/assets/icons/chevron_down.png",
+            ),
+            SplitterDirection::Vertical => (
+                "embedded://bevy_quill_obsidian/assets/icons/chevron_left.png",
+                "embedded://bevy_quill_obsidian/assets/icons/chevron_right.png",
+            ),
+        };
         let style_splitter = match self.direction {
             SplitterDirection::Horizontal => style_hsplitter,
             SplitterDirection::Vertical => style_vsplitter,
@@ -128,13 +264,16 @@ impl ViewTemplate for Splitter {
         };
 
         // Ensure the entity has a DragState component before we render anything.
-        let mut entt = cx.world_mut().entity_mut(id);
-        if !entt.contains::<DragState>() {
-            entt.insert(DragState {
-                dragging: false,
-                offset: 0.,
-            });
-        }
+        cx.with_world_mut(|world| {
+            let mut entt = world.entity_mut(id);
+            if !entt.contains::<DragState>() {
+                entt.insert(DragState {
+                    dragging: false,
+                    offset: 0.,
+                    last_click: None,
+                });
+            }
+        });
 
         Element::<NodeBundle>::for_entity(id)
             .named("Splitter")
@@ -149,6 +288,23 @@ impl ViewTemplate for Splitter {
                             drag_state.dragging = true;
                             drag_state.offset = current_offset;
                         }),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let now = world.resource::<Time>().elapsed_seconds();
+                            let mut drag_state = world.get_mut::<DragState>(id).unwrap();
+                            let is_double_click = drag_state
+                                .last_click
+                                .is_some_and(|last| now - last < DOUBLE_CLICK_TIME);
+                            drag_state.last_click = if is_double_click { None } else { Some(now) };
+                            if let (true, Some(default_value), Some(on_change)) =
+                                (is_double_click, default_value, on_change)
+                            {
+                                let available = available_size(world, id, direction);
+                                world.run_callback(
+                                    on_change,
+                                    limits.clamp(default_value, available),
+                                );
+                            }
+                        }),
                         On::<Pointer<DragEnd>>::listener_component_mut::<DragState>(
                             move |_, drag_state| {
                                 drag_state.dragging = false;
@@ -163,20 +319,17 @@ impl ViewTemplate for Splitter {
                                 let drag_state = world.get_mut::<DragState>(id).unwrap().clone();
                                 if let Some(on_change) = on_change {
                                     if drag_state.dragging {
-                                        match direction {
+                                        let value = match direction {
                                             SplitterDirection::Horizontal => {
-                                                world.run_callback(
-                                                    on_change,
-                                                    drag_state.offset - ev.y,
-                                                );
-                                            }
-                                            SplitterDirection::Vertical => {
-                                                world.run_callback(
-                                                    on_change,
-                                                    ev.x + drag_state.offset,
-                                                );
+                                                drag_state.offset - ev.y
                                             }
-                                        }
+                                            SplitterDirection::Vertical => ev.x + drag_state.offset,
+                                        };
+                                        let available = available_size(world, id, direction);
+                                        world.run_callback(
+                                            on_change,
+                                            limits.clamp(value, available),
+                                        );
                                     }
                                 }
                             }
@@ -190,7 +343,13 @@ impl ViewTemplate for Splitter {
                 },
                 (),
             )
-            .children(
+            .children((
+                // Hint indicating that the splitter is at its minimum value.
+                Cond::new(
+                    at_min,
+                    Icon::new(min_icon).size(Vec2::splat(8.)).color(colors::DIM),
+                    (),
+                ),
                 Element::<NodeBundle>::new()
                     .style(style_splitter_inner)
                     .style_dyn(
@@ -208,6 +367,25 @@ impl ViewTemplate for Splitter {
                             cx.use_component::<DragState>(id).unwrap().dragging,
                         ),
                     ),
-            )
+                // Hint indicating that the splitter is at its maximum value.
+                Cond::new(
+                    at_max,
+                    Icon::new(max_icon).size(Vec2::splat(8.)).color(colors::DIM),
+                    (),
+                ),
+            ))
     }
 }
+
+/// Return the space shared by the two panes on either side of the splitter: the size of the
+/// splitter's parent along the split axis, minus the splitter itself.
+fn available_size(world: &World, splitter: Entity, direction: SplitterDirection) -> Option<f32> {
+    let axis = |size: Vec2| match direction {
+        SplitterDirection::Horizontal => size.y,
+        SplitterDirection::Vertical => size.x,
+    };
+    let own_size = axis(world.get::<Node>(splitter)?.size());
+    let parent = world.get::<Parent>(splitter)?.get();
+    let parent_size = axis(world.get::<Node>(parent)?.size());
+    Some((parent_size - own_size).max(0.))
+}
@@ -3,7 +3,7 @@ use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
-use crate::materials::SwatchRectMaterial;
+use crate::materials::{SwatchRectMaterial, DEFAULT_CHECKER_SIZE};
 
 use crate::colors;
 
@@ -62,7 +62,8 @@ impl Swatch {
         self
     }
 
-    /// Set the callback called when clicked.
+    /// Set the callback called when clicked. The callback receives the swatch color as
+    /// `In<Srgba>`.
     pub fn on_click(mut self, on_click: Callback<Srgba>) -> Self {
         self.on_click = Some(on_click);
         self
@@ -90,6 +91,7 @@ impl ViewTemplate for Swatch {
                 ui_materials.add(SwatchRectMaterial {
                     color: LinearRgba::from(colors::U1).to_vec4(),
                     border_radius: Vec4::splat(0.),
+                    checker_size: Vec4::new(DEFAULT_CHECKER_SIZE, 0., 0., 0.),
                 })
             },
             (),
@@ -126,12 +128,13 @@ impl ViewTemplate for Swatch {
                     let radius = cx.use_component::<BorderRadius>(ent);
                     if let Some(radius) = radius {
                         let radius = Vec4::from_array(resolve_border_radius(radius));
-                        let mut ui_materials = cx
-                            .world_mut()
-                            .get_resource_mut::<Assets<SwatchRectMaterial>>()
-                            .unwrap();
-                        let material = ui_materials.get_mut(material.id()).unwrap();
-                        material.border_radius = radius;
+                        cx.with_world_mut(|world| {
+                            let mut ui_materials = world
+                                .get_resource_mut::<Assets<SwatchRectMaterial>>()
+                                .unwrap();
+                            let material = ui_materials.get_mut(material.id()).unwrap();
+                            material.border_radius = radius;
+                        });
                     }
                 },
                 (),
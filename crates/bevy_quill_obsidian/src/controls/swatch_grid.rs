@@ -1,10 +1,11 @@
-use bevy::{color::Srgba, prelude::*, ui};
+use bevy::{color::Srgba, prelude::*, ui, window::SystemCursorIcon};
+use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
-use crate::colors;
+use crate::{colors, cursor::StyleBuilderCursor};
 
-use super::Swatch;
+use super::{Icon, Swatch};
 
 fn style_swatch_grid(ss: &mut StyleBuilder) {
     ss.border(1)
@@ -25,9 +26,26 @@ fn style_empty_slot(ss: &mut StyleBuilder) {
     ss.border(1)
         .min_width(16)
         .min_height(16)
+        .display(ui::Display::Grid)
         .border_color(colors::U2.lighter(0.01));
 }
 
+fn style_empty_slot_add(ss: &mut StyleBuilder) {
+    ss.cursor(SystemCursorIcon::Pointer);
+}
+
+fn style_empty_slot_content(ss: &mut StyleBuilder) {
+    ss.grid_row(ui::GridPlacement::start(1))
+        .grid_column(ui::GridPlacement::start(1));
+}
+
+fn style_add_icon(ss: &mut StyleBuilder) {
+    ss.grid_row(ui::GridPlacement::start(1))
+        .grid_column(ui::GridPlacement::start(1))
+        .align_self(ui::AlignSelf::Center)
+        .justify_self(ui::JustifySelf::Center);
+}
+
 /// Color swatch widget. This displays a solid color, and can also display a checkerboard
 /// pattern behind the color if it has an alpha of less than 1.
 #[derive(Clone, PartialEq)]
@@ -47,6 +65,17 @@ pub struct SwatchGrid {
 
     /// Callback called when a swatch is clicked
     pub on_change: Option<Callback<Srgba>>,
+
+    /// Whether to render empty cells with a checkerboard pattern.
+    pub empty_placeholder: bool,
+
+    /// Callback called when an empty cell is clicked. The argument is the (column, row)
+    /// position of the cell within the grid.
+    pub on_add: Option<Callback<Vec2>>,
+
+    /// Maximum number of colors; once the color list reaches this length, empty cells are
+    /// no longer clickable. If `None`, the limit is the number of cells in the grid.
+    pub max_colors: Option<usize>,
 }
 
 impl SwatchGrid {
@@ -79,11 +108,31 @@ impl SwatchGrid {
         self
     }
 
-    /// Set the callback called when clicked.
+    /// Set the callback called when a swatch is clicked. The callback receives the color of the
+    /// swatch as `In<Srgba>`.
     pub fn on_change(mut self, on_click: Callback<Srgba>) -> Self {
         self.on_change = Some(on_click);
         self
     }
+
+    /// Set whether empty cells should be rendered with a checkerboard pattern.
+    pub fn empty_placeholder(mut self, empty_placeholder: bool) -> Self {
+        self.empty_placeholder = empty_placeholder;
+        self
+    }
+
+    /// Set the callback called when an empty cell is clicked. This also displays an "add"
+    /// icon in each empty cell. The callback receives the position of the click as `In<Vec2>`.
+    pub fn on_add(mut self, on_add: Callback<Vec2>) -> Self {
+        self.on_add = Some(on_add);
+        self
+    }
+
+    /// Set the maximum number of colors, beyond which the "add" icon is hidden.
+    pub fn max_colors(mut self, max_colors: usize) -> Self {
+        self.max_colors = Some(max_colors);
+        self
+    }
 }
 
 impl Default for SwatchGrid {
@@ -94,6 +143,9 @@ impl Default for SwatchGrid {
             selected: Srgba::default(),
             style: Default::default(),
             on_change: None,
+            empty_placeholder: false,
+            on_add: None,
+            max_colors: None,
         }
     }
 }
@@ -113,7 +165,22 @@ impl ViewTemplate for SwatchGrid {
         });
 
         let num_colors = self.colors.len().min(num_cells);
-        let padding_len = num_cells - num_colors;
+        let empty_placeholder = self.empty_placeholder;
+        let max_colors = self.max_colors.unwrap_or(num_cells).min(num_cells);
+        let on_add = if num_colors < max_colors {
+            self.on_add
+        } else {
+            None
+        };
+
+        // One entry per grid cell; `None` represents an empty cell.
+        let cells: Vec<Option<Srgba>> = self.colors[0..num_colors]
+            .iter()
+            .copied()
+            .map(Some)
+            .chain(std::iter::repeat(None))
+            .take(num_cells)
+            .collect();
 
         Element::<NodeBundle>::new()
             .named("SwatchGrid")
@@ -128,18 +195,53 @@ impl ViewTemplate for SwatchGrid {
                 },
                 self.style.clone(),
             ))
-            .children((
-                // Generate cells for each color
-                For::each(self.colors[0..num_colors].to_owned(), move |color| {
-                    Swatch::new(*color)
-                        .selected(selected == *color)
+            .children(For::index(&cells, move |cell, index| {
+                let position = Vec2::new(
+                    (index as u32 % grid_size.x) as f32,
+                    (index as u32 / grid_size.x) as f32,
+                );
+                Cond::new(
+                    cell.is_some(),
+                    Swatch::new(cell.unwrap_or(Srgba::NONE))
+                        .selected(*cell == Some(selected))
                         .style(style_swatch)
-                        .on_click(on_click)
-                }),
-                // Pad with empty cells
-                For::each(0..padding_len, move |_| {
-                    Element::<NodeBundle>::new().style(style_empty_slot)
-                }),
-            ))
+                        .on_click(on_click),
+                    Element::<NodeBundle>::new()
+                        .style(style_empty_slot)
+                        .style_dyn(
+                            |can_add, sb| {
+                                if can_add {
+                                    style_empty_slot_add(sb);
+                                }
+                            },
+                            on_add.is_some(),
+                        )
+                        .insert_if(on_add.is_some(), move || {
+                            let on_add = on_add.unwrap();
+                            On::<Pointer<Click>>::run(move |world: &mut World| {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                    .unwrap();
+                                event.stop_propagation();
+                                world.run_callback(on_add, position);
+                            })
+                        })
+                        .children((
+                            Cond::new(
+                                empty_placeholder,
+                                Swatch::new(Srgba::NONE).style(style_empty_slot_content),
+                                (),
+                            ),
+                            Cond::new(
+                                on_add.is_some(),
+                                Icon::new("embedded://bevy_quill_obsidian/assets/icons/add.png")
+                                    .color(colors::DIM)
+                                    .size(Vec2::splat(12.))
+                                    .style(style_add_icon),
+                                (),
+                            ),
+                        )),
+                )
+            }))
     }
 }
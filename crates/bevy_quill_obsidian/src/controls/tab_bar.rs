@@ -0,0 +1,351 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    colors,
+    focus::{KeyPressEvent, TabGroup},
+    scrolling::{ScrollArea, ScrollWheel},
+    size::Size,
+    typography, RoundedCorners,
+};
+
+use super::{Button, IconButton};
+
+/// Distance to scroll when one of the scroll arrows is clicked, in pixels.
+const SCROLL_STEP: f32 = 100.;
+
+fn style_tab_bar(ss: &mut StyleBuilder) {
+    ss.flex_row()
+        .align_items(ui::AlignItems::FlexEnd)
+        .border_bottom(1)
+        .border_color(colors::U3)
+        .color(colors::FOREGROUND);
+}
+
+fn style_tab_bar_scroll_area(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.)
+        .flex_basis(0)
+        .min_width(0)
+        .height(Size::Md.height())
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_tab_bar_tabs(ss: &mut StyleBuilder) {
+    ss.flex_row()
+        .align_items(ui::AlignItems::FlexEnd)
+        .height(ui::Val::Percent(100.))
+        .gap(1);
+}
+
+fn style_tab_bar_arrow(ss: &mut StyleBuilder) {
+    ss.flex_shrink(0.).align_self(ui::AlignSelf::Center);
+}
+
+fn style_tab(ss: &mut StyleBuilder) {
+    ss.flex_shrink(0.).height(ui::Val::Percent(100.));
+}
+
+fn style_tab_close(ss: &mut StyleBuilder) {
+    ss.margin_left(4).margin_right(-6);
+}
+
+/// Describes a single tab within a [`TabBar`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct TabDescriptor {
+    /// Identifier for the tab, passed to the `on_change` and `on_close` callbacks.
+    pub id: usize,
+
+    /// The text displayed in the tab.
+    pub label: String,
+
+    /// Whether the tab has a close button.
+    pub closable: bool,
+}
+
+impl TabDescriptor {
+    /// Create a new tab descriptor.
+    pub fn new(id: usize, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            closable: false,
+        }
+    }
+
+    /// Set whether the tab has a close button.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// Component used to hold the tab bar params so that the event handlers and the individual
+/// tabs can access them without capturing.
+#[derive(Component, Clone)]
+struct TabBarState {
+    tabs: Vec<usize>,
+    active_tab: usize,
+    on_change: Option<Callback<usize>>,
+    on_close: Option<Callback<usize>>,
+}
+
+/// A row of tabs used to switch between named panels. If there are more tabs than will fit,
+/// the tabs can be scrolled horizontally using the arrow buttons at either end.
+///
+/// The tab bar does not display the panels itself; the owner should use the `active_tab` to
+/// decide which panel to show.
+#[derive(Clone, PartialEq, Default)]
+pub struct TabBar {
+    /// The list of tabs.
+    pub tabs: Vec<TabDescriptor>,
+
+    /// The id of the currently selected tab.
+    pub active_tab: usize,
+
+    /// Additional styles to be applied to the tab bar.
+    pub style: StyleHandle,
+
+    /// Callback called when a tab is selected, which receives the id of the tab.
+    pub on_change: Option<Callback<usize>>,
+
+    /// Callback called when a tab's close button is clicked, which receives the id of the tab.
+    pub on_close: Option<Callback<usize>>,
+}
+
+impl TabBar {
+    /// Create a new tab bar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the list of tabs.
+    pub fn tabs(mut self, tabs: Vec<TabDescriptor>) -> Self {
+        self.tabs = tabs;
+        self
+    }
+
+    /// Set the id of the currently selected tab.
+    pub fn active_tab(mut self, active_tab: usize) -> Self {
+        self.active_tab = active_tab;
+        self
+    }
+
+    /// Set additional styles to be applied to the tab bar.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when a tab is selected. The callback receives the id of the
+    /// tab as `In<usize>`.
+    pub fn on_change(mut self, on_change: Callback<usize>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Set the callback called when a tab's close button is clicked. The callback receives the
+    /// id of the tab as `In<usize>`.
+    pub fn on_close(mut self, on_close: Callback<usize>) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+}
+
+impl ViewTemplate for TabBar {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let id_scroll_area = cx.create_entity();
+
+        // Scroll position and overflow, used to decide whether to show the arrows.
+        let (scroll_left, overflow) =
+            cx.use_component::<ScrollArea>(id_scroll_area)
+                .map_or((0., 0.), |scroll| {
+                    (
+                        scroll.scroll_left,
+                        scroll.content_size.x - scroll.visible_size.x,
+                    )
+                });
+
+        let scroll_left_click = cx.create_callback(move |world: &mut World| {
+            if let Some(mut scroll) = world.get_mut::<ScrollArea>(id_scroll_area) {
+                scroll.scroll_by(-SCROLL_STEP, 0.);
+            }
+        });
+        let scroll_right_click = cx.create_callback(move |world: &mut World| {
+            if let Some(mut scroll) = world.get_mut::<ScrollArea>(id_scroll_area) {
+                scroll.scroll_by(SCROLL_STEP, 0.);
+            }
+        });
+
+        let active_tab = self.active_tab;
+        let tabs: Vec<(TabDescriptor, bool)> = self
+            .tabs
+            .iter()
+            .map(|tab| (tab.clone(), tab.id == active_tab))
+            .collect();
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("TabBar")
+            .style((typography::text_default, style_tab_bar, self.style.clone()))
+            .insert(TabGroup::default())
+            .insert_dyn(
+                |(tabs, active_tab, on_change, on_close)| TabBarState {
+                    tabs,
+                    active_tab,
+                    on_change,
+                    on_close,
+                },
+                (
+                    self.tabs.iter().map(|tab| tab.id).collect::<Vec<_>>(),
+                    self.active_tab,
+                    self.on_change,
+                    self.on_close,
+                ),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::TabList)),
+                        // Left and right arrow keys select the adjacent tab.
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            let key_code =
+                                world.resource::<ListenerInput<KeyPressEvent>>().key_code;
+                            let offset: isize = match key_code {
+                                KeyCode::ArrowLeft => -1,
+                                KeyCode::ArrowRight => 1,
+                                _ => return,
+                            };
+                            world
+                                .resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .stop_propagation();
+                            let state = world.get::<TabBarState>(id).unwrap().clone();
+                            let Some(index) =
+                                state.tabs.iter().position(|t| *t == state.active_tab)
+                            else {
+                                return;
+                            };
+                            let next = index as isize + offset;
+                            if next >= 0 && (next as usize) < state.tabs.len() {
+                                if let Some(on_change) = state.on_change {
+                                    world.run_callback(on_change, state.tabs[next as usize]);
+                                }
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                Cond::new(
+                    overflow > 0.,
+                    IconButton::new("embedded://bevy_quill_obsidian/assets/icons/chevron_left.png")
+                        .minimal(true)
+                        .size(Size::Xs)
+                        .style(style_tab_bar_arrow)
+                        .tab_index(-1)
+                        .disabled(scroll_left <= 0.)
+                        .on_click(scroll_left_click),
+                    (),
+                ),
+                Element::<NodeBundle>::for_entity(id_scroll_area)
+                    .named("TabBar::ScrollArea")
+                    .style(style_tab_bar_scroll_area)
+                    .insert_dyn(
+                        |_| {
+                            (
+                                ScrollArea::default(),
+                                On::<ScrollWheel>::listener_component_mut::<ScrollArea>(
+                                    |ev, scrolling| {
+                                        ev.stop_propagation();
+                                        // Vertical wheel motion also scrolls horizontally.
+                                        scrolling.scroll_by(-ev.delta.x - ev.delta.y, 0.);
+                                    },
+                                ),
+                            )
+                        },
+                        (),
+                    )
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .named("TabBar::Tabs")
+                            .style(style_tab_bar_tabs)
+                            .children(For::each(tabs, move |(tab, selected)| Tab {
+                                tab: tab.clone(),
+                                selected: *selected,
+                                tab_bar: id,
+                            })),
+                    ),
+                Cond::new(
+                    overflow > 0.,
+                    IconButton::new(
+                        "embedded://bevy_quill_obsidian/assets/icons/chevron_right.png",
+                    )
+                    .minimal(true)
+                    .size(Size::Xs)
+                    .style(style_tab_bar_arrow)
+                    .tab_index(-1)
+                    .disabled(scroll_left >= overflow)
+                    .on_click(scroll_right_click),
+                    (),
+                ),
+            ))
+    }
+}
+
+/// A single tab within a [`TabBar`].
+#[derive(Clone, PartialEq)]
+struct Tab {
+    tab: TabDescriptor,
+    selected: bool,
+    tab_bar: Entity,
+}
+
+impl ViewTemplate for Tab {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        // Tabs are keyed by their descriptor, so the tab id never changes for a given instance.
+        let tab_id = self.tab.id;
+        let tab_bar = self.tab_bar;
+        let on_click = cx.create_callback(move |world: &mut World| {
+            let state = world.get::<TabBarState>(tab_bar).unwrap();
+            if let Some(on_change) = state.on_change {
+                world.run_callback(on_change, tab_id);
+            }
+        });
+        let on_close = cx.create_callback(move |world: &mut World| {
+            let state = world.get::<TabBarState>(tab_bar).unwrap();
+            if let Some(on_close) = state.on_close {
+                world.run_callback(on_close, tab_id);
+            }
+        });
+
+        Button::new()
+            .selected(self.selected)
+            .corners(RoundedCorners::Top)
+            .style(style_tab)
+            .on_click(on_click)
+            .children((
+                self.tab.label.clone(),
+                Cond::new(
+                    self.tab.closable,
+                    IconButton::new("embedded://bevy_quill_obsidian/assets/icons/close.png")
+                        .minimal(true)
+                        .size(Size::Xxs)
+                        .style(style_tab_close)
+                        .tab_index(-1)
+                        .on_click(on_close),
+                    (),
+                ),
+            ))
+    }
+}
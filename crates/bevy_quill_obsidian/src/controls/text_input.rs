@@ -0,0 +1,374 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::*,
+    ui,
+    window::SystemCursorIcon,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    colors,
+    cursor::StyleBuilderCursor,
+    focus::{AutoFocus, KeyCharEvent, KeyPressEvent, StyleBuilderFocusRing, TabIndex},
+    hooks::UseIsFocus,
+    typography,
+};
+
+use super::{Disabled, IsDisabled};
+
+fn style_text_input(ss: &mut StyleBuilder) {
+    ss.flex_row()
+        .align_items(ui::AlignItems::Center)
+        .min_width(48)
+        .height(24)
+        .padding((6, 0))
+        .border(1)
+        .border_radius(5.0)
+        .background_color(colors::U1)
+        .overflow(ui::OverflowAxis::Clip)
+        .cursor(SystemCursorIcon::Text);
+}
+
+fn style_text_input_cursor(ss: &mut StyleBuilder) {
+    ss.width(1)
+        .height(14)
+        .flex_shrink(0.)
+        .background_color(colors::FOREGROUND);
+}
+
+fn style_text_input_placeholder(ss: &mut StyleBuilder) {
+    ss.color(colors::DIM);
+}
+
+/// Component used to hold the text input params so that the event handlers can access them
+/// without capturing.
+#[derive(Component, Clone)]
+struct TextInputState {
+    max_length: Option<usize>,
+    validator: Option<fn(&str) -> bool>,
+    on_change: Option<Callback<String>>,
+    on_submit: Option<Callback<String>>,
+}
+
+impl TextInputState {
+    fn is_valid(&self, text: &str) -> bool {
+        self.validator.map_or(true, |validator| validator(text))
+    }
+}
+
+/// A single-line editable text field.
+///
+/// The text being edited is held by the widget, and is reset whenever `value` changes. Each
+/// edit calls `on_change` with the new text, unless the `validator` rejects it, in which case
+/// the field is displayed with an error border until the text is valid again.
+#[derive(Clone, PartialEq, Default)]
+pub struct TextInput {
+    /// The current text value.
+    pub value: String,
+
+    /// Text to display when the value is empty.
+    pub placeholder: String,
+
+    /// Whether the text input is disabled.
+    pub disabled: bool,
+
+    /// Additional styles to be applied to the text input.
+    pub style: StyleHandle,
+
+    /// Maximum number of characters which can be entered.
+    pub max_length: Option<usize>,
+
+    /// Function which returns false if the text is invalid.
+    pub validator: Option<fn(&str) -> bool>,
+
+    /// Callback called when the text is edited, which receives the new text.
+    pub on_change: Option<Callback<String>>,
+
+    /// Callback called when the Enter key is pressed, which receives the current text.
+    pub on_submit: Option<Callback<String>>,
+
+    /// The tab index of the text input (default 0).
+    pub tab_index: i32,
+
+    /// Whether the text input should receive focus when it is first displayed.
+    pub autofocus: bool,
+}
+
+impl TextInput {
+    /// Create a new text input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the current text value.
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = value.to_string();
+        self
+    }
+
+    /// Set the text to display when the value is empty.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    /// Set whether the text input is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set additional styles to be applied to the text input.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the maximum number of characters which can be entered.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set the function used to validate the text. Edits which produce invalid text do not
+    /// call `on_change` or `on_submit`.
+    pub fn validator(mut self, validator: fn(&str) -> bool) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Set the callback called when the text is edited. The callback receives the new text
+    /// as `In<String>`.
+    pub fn on_change(mut self, on_change: Callback<String>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Set the callback called when the Enter key is pressed. The callback receives the
+    /// current text as `In<String>`.
+    pub fn on_submit(mut self, on_submit: Callback<String>) -> Self {
+        self.on_submit = Some(on_submit);
+        self
+    }
+
+    /// Set the tab index of the text input.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Set whether the text input should receive focus when it is first displayed.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+}
+
+impl ViewTemplate for TextInput {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let focused = cx.is_focused(id);
+        let focus_visible = cx.is_focus_visible(id);
+        let text = cx.create_mutable::<String>(self.value.clone());
+        // Cursor position, in characters.
+        let cursor = cx.create_mutable::<usize>(self.value.chars().count());
+
+        // Discard any edits in progress when the value is changed by the owner.
+        cx.create_effect(
+            move |world, value: String| {
+                let len = value.chars().count();
+                if text.get_clone(world) != value {
+                    text.set_clone(world, value);
+                    cursor.set(world, len);
+                }
+            },
+            self.value.clone(),
+        );
+
+        let current = text.get_clone(cx);
+        let split = byte_offset(&current, cursor.get(cx));
+        let (before, after) = current.split_at(split);
+        let is_valid = self.validator.map_or(true, |validator| validator(&current));
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("TextInput")
+            .style((
+                typography::text_default,
+                style_text_input,
+                self.style.clone(),
+            ))
+            .insert_dyn(TabIndex, self.tab_index)
+            .insert_if(self.disabled, || Disabled)
+            .insert_if(self.autofocus, || AutoFocus)
+            .insert_dyn(
+                |(max_length, validator, on_change, on_submit)| TextInputState {
+                    max_length,
+                    validator,
+                    on_change,
+                    on_submit,
+                },
+                (
+                    self.max_length,
+                    self.validator,
+                    self.on_change,
+                    self.on_submit,
+                ),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::TextInput)),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                            focus.0 = Some(id);
+                        }),
+                        On::<KeyCharEvent>::run(move |world: &mut World| {
+                            if world.is_disabled(id) {
+                                return;
+                            }
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<KeyCharEvent>>()
+                                .unwrap();
+                            let ch = event.key;
+                            if !ch.is_control() {
+                                event.stop_propagation();
+                                insert_char(world, id, text, cursor, ch);
+                            }
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if world.is_disabled(id) {
+                                return;
+                            }
+                            let key_code =
+                                world.resource::<ListenerInput<KeyPressEvent>>().key_code;
+                            let mut value = text.get_clone(world);
+                            let len = value.chars().count();
+                            let pos = cursor.get(world).min(len);
+                            match key_code {
+                                KeyCode::ArrowLeft => {
+                                    cursor.set(world, pos.saturating_sub(1));
+                                }
+                                KeyCode::ArrowRight => {
+                                    cursor.set(world, (pos + 1).min(len));
+                                }
+                                KeyCode::Home => {
+                                    cursor.set(world, 0);
+                                }
+                                KeyCode::End => {
+                                    cursor.set(world, len);
+                                }
+                                KeyCode::Backspace if pos > 0 => {
+                                    value.remove(byte_offset(&value, pos - 1));
+                                    update_text(world, id, text, cursor, value, pos - 1);
+                                }
+                                KeyCode::Delete if pos < len => {
+                                    value.remove(byte_offset(&value, pos));
+                                    update_text(world, id, text, cursor, value, pos);
+                                }
+                                KeyCode::Space => {
+                                    insert_char(world, id, text, cursor, ' ');
+                                }
+                                KeyCode::Enter => {
+                                    let state = world.get::<TextInputState>(id).unwrap();
+                                    if let Some(on_submit) = state.on_submit {
+                                        if state.is_valid(&value) {
+                                            world.run_callback(on_submit, value);
+                                        }
+                                    }
+                                }
+                                KeyCode::Backspace | KeyCode::Delete => {}
+                                _ => return,
+                            }
+                            world
+                                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .unwrap()
+                                .stop_propagation();
+                        }),
+                    )
+                },
+                (),
+            )
+            .style_dyn(
+                |(is_valid, focus_visible, disabled), sb| {
+                    sb.border_color(if is_valid {
+                        colors::U3
+                    } else {
+                        colors::DESTRUCTIVE_ACC
+                    })
+                    .focus_ring(focus_visible)
+                    .color(if disabled {
+                        colors::FOREGROUND.with_alpha(0.3)
+                    } else {
+                        colors::FOREGROUND
+                    });
+                },
+                (is_valid, focus_visible, self.disabled),
+            )
+            .children(Cond::new(
+                current.is_empty() && !focused,
+                Element::<NodeBundle>::new()
+                    .style(style_text_input_placeholder)
+                    .children(self.placeholder.clone()),
+                (
+                    before.to_string(),
+                    Cond::new(
+                        focused,
+                        Element::<NodeBundle>::new().style(style_text_input_cursor),
+                        (),
+                    ),
+                    after.to_string(),
+                ),
+            ))
+    }
+}
+
+/// Insert a character at the cursor position, unless the text is at its maximum length.
+fn insert_char(
+    world: &mut World,
+    id: Entity,
+    text: Mutable<String>,
+    cursor: Mutable<usize>,
+    ch: char,
+) {
+    let mut value = text.get_clone(world);
+    let len = value.chars().count();
+    let max_length = world.get::<TextInputState>(id).unwrap().max_length;
+    if max_length.is_some_and(|max| len >= max) {
+        return;
+    }
+    let pos = cursor.get(world).min(len);
+    value.insert(byte_offset(&value, pos), ch);
+    update_text(world, id, text, cursor, value, pos + 1);
+}
+
+/// Replace the text being edited, and call `on_change` if the new text is valid.
+fn update_text(
+    world: &mut World,
+    id: Entity,
+    text: Mutable<String>,
+    cursor: Mutable<usize>,
+    value: String,
+    pos: usize,
+) {
+    text.set_clone(world, value.clone());
+    cursor.set(world, pos);
+    let state = world.get::<TextInputState>(id).unwrap();
+    if let Some(on_change) = state.on_change {
+        if state.is_valid(&value) {
+            world.run_callback(on_change, value);
+        }
+    }
+}
+
+/// Convert a position in characters to a byte offset within the string.
+fn byte_offset(text: &str, pos: usize) -> usize {
+    text.char_indices()
+        .nth(pos)
+        .map_or(text.len(), |(offset, _)| offset)
+}
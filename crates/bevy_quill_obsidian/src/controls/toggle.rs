@@ -0,0 +1,294 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    color::Luminance,
+    prelude::*,
+    ui,
+    window::SystemCursorIcon,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    animation::{AnimatedBackgroundColor, AnimatedPxWidth, AnimatedTransition},
+    colors,
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, StyleBuilderFocusRing, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+    size::Size,
+    typography,
+};
+
+use super::{Checked, Disabled, IsDisabled};
+
+/// Duration of the thumb and track animations, in seconds.
+const TRANSITION_DURATION: f32 = 0.1;
+
+fn style_toggle(ss: &mut StyleBuilder) {
+    ss.flex_row()
+        .justify_content(ui::JustifyContent::Center)
+        .align_items(ui::AlignItems::Center)
+        .align_content(ui::AlignContent::Center)
+        .gap(4)
+        .color(colors::FOREGROUND);
+}
+
+fn style_toggle_track(ss: &mut StyleBuilder) {
+    ss.flex_row()
+        .align_items(ui::AlignItems::Center)
+        .padding(2)
+        .cursor(SystemCursorIcon::Pointer);
+}
+
+fn style_toggle_thumb(ss: &mut StyleBuilder) {
+    ss.flex_shrink(0.).background_color(colors::FOREGROUND);
+}
+
+fn style_toggle_label(ss: &mut StyleBuilder) {
+    ss.flex_row()
+        .justify_content(ui::JustifyContent::FlexStart)
+        .align_items(ui::AlignItems::Center)
+        .color(colors::FOREGROUND);
+}
+
+/// A toggle switch widget: an alternative to [`Checkbox`](super::Checkbox) which displays the
+/// checked state as a thumb which slides from one end of a track to the other.
+#[derive(Default, Clone, PartialEq)]
+pub struct Toggle {
+    /// Whether the toggle is checked.
+    pub checked: bool,
+
+    /// Whether the toggle is disabled.
+    pub disabled: bool,
+
+    /// The content to display next to the toggle.
+    pub label: ViewChild,
+
+    /// Size of the toggle.
+    pub size: Size,
+
+    /// Additional styles to be applied to the toggle.
+    pub style: StyleHandle,
+
+    /// Callback called when the toggle is clicked, which receives the new checked state.
+    pub on_change: Option<Callback<bool>>,
+
+    /// The tab index of the toggle (default 0).
+    pub tab_index: i32,
+}
+
+impl Toggle {
+    /// Create a new toggle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the checked state of the toggle.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Set the disabled state of the toggle.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the label of the toggle.
+    pub fn label(mut self, label: impl IntoViewChild) -> Self {
+        self.label = label.into_view_child();
+        self
+    }
+
+    /// Set the size of the toggle.
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the style of the toggle.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the on_change callback of the toggle. The callback receives the new checked state
+    /// as `In<bool>`.
+    pub fn on_change(mut self, on_change: Callback<bool>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Set the tab index of the toggle.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+}
+
+impl ViewTemplate for Toggle {
+    type View = impl View;
+
+    /// Construct a toggle widget.
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let pressed = cx.create_mutable::<bool>(false);
+        let hovering = cx.is_hovered(id);
+        let focused = cx.is_focus_visible(id);
+        let checked = self.checked;
+        let on_change = self.on_change;
+
+        // The track is a rounded pill, and the thumb is a circle inset by the track padding.
+        let track_height = (self.size.height() * 0.7).round();
+        let track_width = (track_height * 1.8).round();
+        let thumb_size = track_height - 4.;
+        let travel = track_width - track_height;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Toggle")
+            .style((typography::text_default, style_toggle, self.style.clone()))
+            .insert_dyn(TabIndex, self.tab_index)
+            // As with the checkbox, the checked and disabled states are stored as components
+            // so that the event handlers don't need to capture them.
+            .insert_if(self.disabled, || Disabled)
+            .insert_if(self.checked, || Checked)
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::Switch)),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                            focus.0 = Some(id);
+                            if !world.is_disabled(id) {
+                                let next_checked = world.get::<Checked>(id).is_none();
+                                if let Some(on_change) = on_change {
+                                    world.run_callback(on_change, next_checked);
+                                }
+                            }
+                        }),
+                        On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                pressed.set(world, true);
+                            }
+                        }),
+                        On::<Pointer<DragEnd>>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                pressed.set(world, false);
+                            }
+                        }),
+                        On::<Pointer<DragEnter>>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                pressed.set(world, true);
+                            }
+                        }),
+                        On::<Pointer<DragLeave>>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                pressed.set(world, false);
+                            }
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .unwrap();
+                                if !event.repeat
+                                    && (event.key_code == KeyCode::Enter
+                                        || event.key_code == KeyCode::Space)
+                                {
+                                    event.stop_propagation();
+                                    let next_checked = world.get::<Checked>(id).is_none();
+                                    if let Some(on_change) = on_change {
+                                        world.run_callback(on_change, next_checked);
+                                    }
+                                }
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("Toggle::Track")
+                    .style(style_toggle_track)
+                    .style_dyn(
+                        |(width, height), sb| {
+                            sb.width(width).height(height).border_radius(height * 0.5);
+                        },
+                        (track_width, track_height),
+                    )
+                    .effect(
+                        |cx, ent, (checked, pressed, hovering)| {
+                            let color = match (checked, pressed, hovering) {
+                                (true, true, _) => colors::ACCENT.darker(0.1),
+                                (true, false, true) => colors::ACCENT.darker(0.15),
+                                (true, _, _) => colors::ACCENT.darker(0.2),
+                                (false, true, _) => colors::U3.lighter(0.05),
+                                (false, false, true) => colors::U3.lighter(0.02),
+                                (false, false, false) => colors::U3,
+                            };
+                            cx.with_world_mut(|world| {
+                                AnimatedTransition::<AnimatedBackgroundColor>::start(
+                                    &mut world.entity_mut(ent),
+                                    color,
+                                    TRANSITION_DURATION,
+                                );
+                            });
+                        },
+                        (checked, pressed.get(cx), hovering),
+                    )
+                    .style_dyn(
+                        |focused, sb| {
+                            sb.focus_ring(focused);
+                        },
+                        focused,
+                    )
+                    .children((
+                        // Spacer which pushes the thumb to the right when checked.
+                        Element::<NodeBundle>::new()
+                            .named("Toggle::Spacer")
+                            .style(|ss: &mut StyleBuilder| {
+                                ss.flex_shrink(0.);
+                            })
+                            .effect(
+                                |cx, ent, (checked, travel)| {
+                                    cx.with_world_mut(|world| {
+                                        AnimatedTransition::<AnimatedPxWidth>::start(
+                                            &mut world.entity_mut(ent),
+                                            if checked { travel } else { 0. },
+                                            TRANSITION_DURATION,
+                                        );
+                                    });
+                                },
+                                (checked, travel),
+                            ),
+                        Element::<NodeBundle>::new()
+                            .named("Toggle::Thumb")
+                            .style(style_toggle_thumb)
+                            .style_dyn(
+                                |size, sb| {
+                                    sb.width(size).height(size).border_radius(size * 0.5);
+                                },
+                                thumb_size,
+                            ),
+                    )),
+                Element::<NodeBundle>::new()
+                    .style(style_toggle_label)
+                    .style_dyn(
+                        |disabled, sb| {
+                            sb.color(if disabled {
+                                colors::FOREGROUND.with_alpha(0.3)
+                            } else {
+                                colors::FOREGROUND
+                            });
+                        },
+                        self.disabled,
+                    )
+                    .children(self.label.clone()),
+            ))
+    }
+}
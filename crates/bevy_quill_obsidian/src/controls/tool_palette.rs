@@ -1,4 +1,9 @@
-use crate::{colors, size::Size, RoundedCorners};
+use crate::{
+    animation::{AnimatedScale, AnimatedTransition},
+    colors,
+    size::Size,
+    RoundedCorners,
+};
 use bevy::{
     a11y::{
         accesskit::{NodeBuilder, Role},
@@ -21,6 +26,28 @@ fn style_tool_palette(ss: &mut StyleBuilder) {
         .grid_auto_rows(vec![ui::GridTrack::default()]);
 }
 
+fn style_badge(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .top(-4)
+        .right(-4)
+        .display(ui::Display::Flex)
+        .justify_content(ui::JustifyContent::Center)
+        .align_items(ui::AlignItems::Center)
+        .min_width(14)
+        .height(14)
+        .padding((3, 0))
+        .border_radius(7.0)
+        .background_color(colors::BADGE)
+        .color(Color::WHITE)
+        .font_size(10);
+}
+
+/// Scale of the badge at the start of the pulse animation.
+const BADGE_PULSE_SCALE: f32 = 1.4;
+
+/// Duration of the badge pulse animation.
+const BADGE_PULSE_DURATION: f32 = 0.3;
+
 #[derive(Clone, Debug, Default, Component)]
 struct ToolPaletteContext {
     size: Size,
@@ -120,6 +147,9 @@ pub struct ToolButton {
 
     /// If true, set focus to this button when it's added to the UI.
     pub(crate) autofocus: bool,
+
+    /// Optional short text, such as a count, displayed in the top-right corner of the button.
+    pub(crate) badge: Option<String>,
 }
 
 impl ToolButton {
@@ -186,6 +216,13 @@ impl ToolButton {
         self.autofocus = autofocus;
         self
     }
+
+    /// Set the badge text, such as a count of pending notifications. The badge is hidden if
+    /// this is `None`.
+    pub fn badge(mut self, badge: Option<String>) -> Self {
+        self.badge = badge;
+        self
+    }
 }
 
 impl Default for ToolButton {
@@ -199,6 +236,7 @@ impl Default for ToolButton {
             tab_index: 0,
             corners: RoundedCorners::None,
             autofocus: false,
+            badge: None,
         }
     }
 }
@@ -212,7 +250,14 @@ impl ViewTemplate for ToolButton {
             .style(self.style.clone())
             .variant(self.variant)
             .disabled(self.disabled)
-            .children(self.children.clone())
+            .children((
+                self.children.clone(),
+                Cond::new(
+                    self.badge.is_some(),
+                    ToolButtonBadge(self.badge.clone().unwrap_or_default()),
+                    (),
+                ),
+            ))
             .tab_index(self.tab_index)
             .autofocus(self.autofocus)
             .corners(self.corners);
@@ -221,6 +266,34 @@ impl ViewTemplate for ToolButton {
     }
 }
 
+/// The badge displayed in the corner of a [`ToolButton`]. Pulses when the text changes.
+#[derive(Clone, PartialEq)]
+struct ToolButtonBadge(String);
+
+impl ViewTemplate for ToolButtonBadge {
+    type View = impl View;
+    fn create(&self, _cx: &mut Cx) -> Self::View {
+        Element::<NodeBundle>::new()
+            .named("ToolButton::Badge")
+            .style(style_badge)
+            .effect(
+                |cx, ent, _| {
+                    cx.with_world_mut(|world| {
+                        let mut entt = world.entity_mut(ent);
+                        entt.insert(AnimatedTransition::<AnimatedScale>::new(
+                            Vec3::splat(BADGE_PULSE_SCALE),
+                            Vec3::ONE,
+                            BADGE_PULSE_DURATION,
+                            0.0,
+                        ));
+                    });
+                },
+                self.0.clone(),
+            )
+            .children(self.0.clone())
+    }
+}
+
 /// A button in a ToolPalette.
 #[derive(Clone, PartialEq)]
 pub struct ToolIconButton {
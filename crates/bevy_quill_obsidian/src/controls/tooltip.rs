@@ -0,0 +1,261 @@
+use bevy::{
+    ecs::world::DeferredWorld,
+    prelude::*,
+    ui::{self, node_bundles::NodeBundle},
+};
+use bevy_mod_picking::prelude::Pickable;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    animation::{AnimatedBackgroundColor, AnimatedTransition},
+    colors,
+    floating::{FloatAlign, FloatPosition, FloatSide, Floating},
+    hooks::{BistableTransitionState, CreateBistableTransition, UseIsHover},
+    typography,
+};
+
+/// Default delay before a tooltip appears, in seconds.
+const DEFAULT_DELAY: f32 = 0.5;
+
+/// Duration of the fade-in and fade-out animation, in seconds.
+const TRANSITION_DURATION: f32 = 0.1;
+
+fn style_tooltip(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .display(ui::Display::Flex)
+        .padding((6, 3))
+        .border_radius(4.0)
+        .border(1)
+        .border_color(colors::U3)
+        .color(colors::FOREGROUND)
+        .font_size(14);
+}
+
+/// A tooltip which is displayed next to a widget while the pointer hovers over it. Tooltips
+/// are attached to an [`Element`] using [`ElementTooltip::tooltip`].
+#[derive(Clone, PartialEq)]
+pub struct Tooltip {
+    /// The content of the tooltip.
+    pub content: ViewChild,
+
+    /// How long the pointer must hover over the widget before the tooltip appears, in seconds.
+    pub delay: f32,
+
+    /// Which side of the widget the tooltip should be placed. The tooltip will flip to the
+    /// opposite side if it doesn't fit.
+    pub side: FloatSide,
+}
+
+impl Default for Tooltip {
+    fn default() -> Self {
+        Self {
+            content: ViewChild::default(),
+            delay: DEFAULT_DELAY,
+            side: FloatSide::Top,
+        }
+    }
+}
+
+impl Tooltip {
+    /// Create a new tooltip.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the content of the tooltip.
+    pub fn content(mut self, content: impl IntoViewChild) -> Self {
+        self.content = content.into_view_child();
+        self
+    }
+
+    /// Set how long the pointer must hover over the widget before the tooltip appears, in
+    /// seconds.
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Set which side of the widget the tooltip should be placed.
+    pub fn side(mut self, side: FloatSide) -> Self {
+        self.side = side;
+        self
+    }
+}
+
+/// Trait which adds a `tooltip` method to [`Element`].
+pub trait ElementTooltip: View + Sized {
+    /// Display a tooltip while the pointer hovers over this element.
+    fn tooltip(self, tooltip: Tooltip) -> WithTooltip<Self>;
+}
+
+impl<B: Bundle + Default, C: View, E: EffectTuple + 'static> ElementTooltip for Element<B, C, E> {
+    fn tooltip(self, tooltip: Tooltip) -> WithTooltip<Self> {
+        WithTooltip {
+            inner: self,
+            tooltip,
+        }
+    }
+}
+
+/// A view which displays a [`Tooltip`] for the entity produced by the inner view.
+pub struct WithTooltip<V: View> {
+    inner: V,
+    tooltip: Tooltip,
+}
+
+impl<V: View> WithTooltip<V> {
+    /// Return the popup for the entity displayed by the inner view.
+    fn popup(&self, world: &World, state: &V::State) -> TooltipPopup {
+        let mut nodes: Vec<Entity> = Vec::new();
+        self.inner.nodes(world, state, &mut nodes);
+        TooltipPopup {
+            tooltip: self.tooltip.clone(),
+            anchor: nodes[0],
+        }
+    }
+}
+
+impl<V: View> View for WithTooltip<V> {
+    type State = (V::State, <TooltipPopup as View>::State);
+
+    fn nodes(&self, world: &World, state: &Self::State, out: &mut Vec<Entity>) {
+        self.inner.nodes(world, &state.0, out);
+    }
+
+    fn build(&self, cx: &mut Cx) -> Self::State {
+        let inner = self.inner.build(cx);
+        let popup = self.popup(cx.world(), &inner).build(cx);
+        (inner, popup)
+    }
+
+    fn rebuild(&self, cx: &mut Cx, state: &mut Self::State) -> bool {
+        let changed = self.inner.rebuild(cx, &mut state.0);
+        self.popup(cx.world(), &state.0).rebuild(cx, &mut state.1);
+        changed
+    }
+
+    fn attach_children(&self, world: &mut World, state: &mut Self::State) -> bool {
+        self.inner.attach_children(world, &mut state.0)
+    }
+
+    fn raze(&self, world: &mut DeferredWorld, state: &mut Self::State) {
+        self.popup(world, &state.0).raze(world, &mut state.1);
+        self.inner.raze(world, &mut state.0);
+    }
+}
+
+/// Component which counts down the delay before a tooltip is shown.
+#[derive(Component)]
+pub(crate) struct TooltipTimer {
+    remaining: f32,
+    visible: Mutable<bool>,
+}
+
+/// System which shows tooltips whose delay has expired.
+pub(crate) fn update_tooltip_timers(world: &mut World) {
+    let delta = world.resource::<Time>().delta_seconds();
+    let mut q_timers = world.query::<(Entity, &mut TooltipTimer)>();
+    let mut expired: Vec<(Entity, Mutable<bool>)> = Vec::new();
+    for (entity, mut timer) in q_timers.iter_mut(world) {
+        timer.remaining -= delta;
+        if timer.remaining <= 0. {
+            expired.push((entity, timer.visible));
+        }
+    }
+    for (entity, visible) in expired {
+        world.entity_mut(entity).remove::<TooltipTimer>();
+        visible.set(world, true);
+    }
+}
+
+/// The floating popup which displays a tooltip for an anchor entity.
+#[derive(Clone, PartialEq)]
+pub struct TooltipPopup {
+    tooltip: Tooltip,
+    anchor: Entity,
+}
+
+impl ViewTemplate for TooltipPopup {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let hovering = cx.is_hovered(self.anchor);
+        let visible = cx.create_mutable::<bool>(false);
+        let timer_id = cx.create_entity();
+
+        // Start the timer when the pointer enters the anchor, and hide the tooltip
+        // immediately when it leaves.
+        cx.create_effect(
+            move |world, (hovering, delay)| {
+                let mut entt = world.entity_mut(timer_id);
+                if hovering {
+                    entt.insert(TooltipTimer {
+                        remaining: delay,
+                        visible,
+                    });
+                } else {
+                    entt.remove::<TooltipTimer>();
+                    visible.set(world, false);
+                }
+            },
+            (hovering, self.tooltip.delay),
+        );
+
+        let state = cx.create_bistable_transition(visible.get(cx), TRANSITION_DURATION);
+        let side = self.tooltip.side;
+        let anchor = self.anchor;
+
+        Cond::new(
+            state != BistableTransitionState::Exited,
+            Portal::new(
+                Element::<NodeBundle>::new()
+                    .named("Tooltip")
+                    .style((typography::text_default, style_tooltip))
+                    .insert((Pickable::IGNORE, ZIndex::Global(200)))
+                    .insert_dyn(
+                        |(side, anchor)| Floating {
+                            anchor,
+                            position: vec![
+                                FloatPosition {
+                                    side,
+                                    align: FloatAlign::Center,
+                                    stretch: false,
+                                    gap: 4.0,
+                                },
+                                FloatPosition {
+                                    side: side.mirror(),
+                                    align: FloatAlign::Center,
+                                    stretch: false,
+                                    gap: 4.0,
+                                },
+                            ],
+                        },
+                        (side, anchor),
+                    )
+                    .effect(
+                        move |cx, ent, state| {
+                            cx.with_world_mut(|world| {
+                                let mut entt = world.entity_mut(ent);
+                                let target = match state {
+                                    BistableTransitionState::Entering
+                                    | BistableTransitionState::Entered
+                                    | BistableTransitionState::ExitStart => colors::U2,
+                                    BistableTransitionState::EnterStart
+                                    | BistableTransitionState::Exiting
+                                    | BistableTransitionState::Exited => colors::U2.with_alpha(0.0),
+                                };
+                                AnimatedTransition::<AnimatedBackgroundColor>::start(
+                                    &mut entt,
+                                    target,
+                                    TRANSITION_DURATION,
+                                );
+                            });
+                        },
+                        state,
+                    )
+                    .children(self.tooltip.content.clone()),
+            ),
+            (),
+        )
+    }
+}
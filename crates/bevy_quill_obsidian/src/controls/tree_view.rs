@@ -0,0 +1,529 @@
+use std::collections::HashMap;
+
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::*,
+    ui::{self, node_bundles::NodeBundle},
+    window::SystemCursorIcon,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    animation::{AnimatedPxHeight, AnimatedRotation, AnimatedTransition},
+    colors,
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+    typography,
+};
+
+use super::{listview::row_bg_color, Icon, ScrollView};
+
+/// Height of each row in the tree, in pixels.
+const ROW_HEIGHT: f32 = 22.;
+
+/// Indentation of each level of the tree, in pixels.
+const INDENT: f32 = 14.;
+
+/// Duration of the expand and collapse animations, in seconds.
+const TRANSITION_DURATION: f32 = 0.15;
+
+fn style_tree_view(ss: &mut StyleBuilder) {
+    ss.background_color(colors::U1)
+        .border_radius(5.0)
+        .padding(3);
+}
+
+fn style_tree_view_inner(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .align_self(ui::AlignSelf::Stretch)
+        .justify_self(ui::JustifySelf::Stretch)
+        .min_width(ui::Val::Percent(100.));
+}
+
+fn style_tree_row(ss: &mut StyleBuilder) {
+    ss.flex_row()
+        .align_items(ui::AlignItems::Center)
+        .flex_shrink(0.)
+        .padding_right(6)
+        .border(1)
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_tree_row_chevron(ss: &mut StyleBuilder) {
+    ss.flex_row()
+        .flex_shrink(0.)
+        .width(16)
+        .height(16)
+        .margin_right(2)
+        .cursor(SystemCursorIcon::Pointer);
+}
+
+/// Trait for hierarchical data which can be displayed in a [`TreeView`].
+pub trait TreeNode: Clone + PartialEq + Send + Sync + 'static {
+    /// The child nodes of this node.
+    fn children(&self) -> &[Self];
+
+    /// The text displayed for this node.
+    fn label(&self) -> &str;
+}
+
+/// Identifies a node within a [`TreeView`] by its path from the root: each element is the
+/// index of a node within its parent's children. The root node has an empty path.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct NodeId(pub Vec<usize>);
+
+impl NodeId {
+    /// Return the id of the parent node, or `None` if this is the root.
+    pub fn parent(&self) -> Option<NodeId> {
+        let (_, parent) = self.0.split_last()?;
+        Some(NodeId(parent.to_vec()))
+    }
+
+    fn child(&self, index: usize) -> NodeId {
+        let mut path = self.0.clone();
+        path.push(index);
+        NodeId(path)
+    }
+}
+
+/// A single visible row of the flattened tree.
+#[derive(Clone, PartialEq)]
+struct TreeRow {
+    id: NodeId,
+    label: String,
+    depth: usize,
+    has_children: bool,
+    expanded: bool,
+    selected: bool,
+    /// True if this row has the keyboard cursor and the tree has focus.
+    cursor: bool,
+    /// True if this row was just revealed by expanding its parent, and should animate in.
+    reveal: bool,
+    /// True if this row was just expanded or collapsed, and the chevron should animate.
+    toggled: bool,
+}
+
+impl TreeRow {
+    /// Compare two rows, ignoring the animation flags, so that rows are only rebuilt when
+    /// their content changes.
+    fn same_content(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.label == other.label
+            && self.depth == other.depth
+            && self.has_children == other.has_children
+            && self.expanded == other.expanded
+            && self.selected == other.selected
+            && self.cursor == other.cursor
+    }
+}
+
+/// Component used to hold the tree view params so that the event handlers can access them
+/// without capturing.
+#[derive(Component, Clone)]
+struct TreeViewState {
+    /// The visible rows: id, whether the node has children, and whether it is expanded.
+    rows: Vec<(NodeId, bool, bool)>,
+    on_select: Option<Callback<NodeId>>,
+}
+
+/// The mutable state of a tree view, shared with the rows.
+#[derive(Clone, Copy, PartialEq)]
+struct TreeMutables {
+    expanded: Mutable<HashMap<NodeId, bool>>,
+    selection: Mutable<Option<NodeId>>,
+    cursor: Mutable<Option<NodeId>>,
+    toggled: Mutable<Option<NodeId>>,
+}
+
+impl TreeMutables {
+    /// Expand or collapse a node.
+    fn set_expanded(&self, world: &mut World, id: &NodeId, expand: bool) {
+        let id = id.clone();
+        self.toggled.set_clone(world, Some(id.clone()));
+        self.expanded.update(world, |mut expanded| {
+            expanded.insert(id, expand);
+        });
+    }
+
+    /// Move the keyboard cursor to a node.
+    fn move_cursor(&self, world: &mut World, id: NodeId) {
+        self.toggled.set_clone(world, None);
+        self.cursor.set_clone(world, Some(id));
+    }
+
+    /// Select a node and call the `on_select` callback.
+    fn select(&self, world: &mut World, tree: Entity, id: NodeId) {
+        self.move_cursor(world, id.clone());
+        self.selection.set_clone(world, Some(id.clone()));
+        if let Some(on_select) = world.get::<TreeViewState>(tree).unwrap().on_select {
+            world.run_callback(on_select, id);
+        }
+    }
+}
+
+/// A scrollable view of hierarchical data, in which each node with children can be expanded
+/// or collapsed.
+///
+/// The visible nodes are flattened into a single list of rows, rather than being nested, so
+/// collapsed nodes have no entities at all.
+#[derive(Clone, PartialEq)]
+pub struct TreeView<T: TreeNode> {
+    /// The root node of the tree.
+    pub root: Option<T>,
+
+    /// Additional styles to be applied to the tree view.
+    pub style: StyleHandle,
+
+    /// Callback called when a node is selected, which receives the id of the node.
+    pub on_select: Option<Callback<NodeId>>,
+
+    /// The tab index of the tree view (default 0).
+    pub tab_index: i32,
+}
+
+impl<T: TreeNode> Default for TreeView<T> {
+    fn default() -> Self {
+        Self {
+            root: None,
+            style: StyleHandle::default(),
+            on_select: None,
+            tab_index: 0,
+        }
+    }
+}
+
+impl<T: TreeNode> TreeView<T> {
+    /// Create a new tree view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the root node of the tree.
+    pub fn root(mut self, root: T) -> Self {
+        self.root = Some(root);
+        self
+    }
+
+    /// Set additional styles to be applied to the tree view.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when a node is selected. The callback receives the id of the
+    /// node as `In<NodeId>`.
+    pub fn on_select(mut self, on_select: Callback<NodeId>) -> Self {
+        self.on_select = Some(on_select);
+        self
+    }
+
+    /// Set the tab index of the tree view.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+}
+
+impl<T: TreeNode> ViewTemplate for TreeView<T> {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let focused = cx.is_focused(id);
+        let mutables = TreeMutables {
+            expanded: cx.create_mutable::<HashMap<NodeId, bool>>(HashMap::new()),
+            selection: cx.create_mutable::<Option<NodeId>>(None),
+            cursor: cx.create_mutable::<Option<NodeId>>(None),
+            toggled: cx.create_mutable::<Option<NodeId>>(None),
+        };
+
+        let expanded = mutables.expanded.get_clone(cx);
+        let selection = mutables.selection.get_clone(cx);
+        let cursor = mutables.cursor.get_clone(cx);
+        let toggled = mutables.toggled.get_clone(cx);
+
+        let mut rows: Vec<TreeRow> = Vec::new();
+        if let Some(ref root) = self.root {
+            flatten(
+                root,
+                NodeId::default(),
+                0,
+                &Flatten {
+                    expanded: &expanded,
+                    selection: &selection,
+                    cursor: if focused { &cursor } else { &None },
+                    toggled: &toggled,
+                },
+                &mut rows,
+            );
+        }
+
+        ScrollView::new()
+            .children(
+                Element::<NodeBundle>::for_entity(id)
+                    .named("TreeView")
+                    .style((typography::text_default, style_tree_view_inner))
+                    .insert_dyn(TabIndex, self.tab_index)
+                    .insert_dyn(
+                        |(rows, on_select)| TreeViewState { rows, on_select },
+                        (
+                            rows.iter()
+                                .map(|row| (row.id.clone(), row.has_children, row.expanded))
+                                .collect::<Vec<_>>(),
+                            self.on_select,
+                        ),
+                    )
+                    .insert_dyn(
+                        move |_| {
+                            (
+                                AccessibilityNode::from(NodeBuilder::new(Role::Tree)),
+                                On::<KeyPressEvent>::run(move |world: &mut World| {
+                                    let key_code =
+                                        world.resource::<ListenerInput<KeyPressEvent>>().key_code;
+                                    if handle_key(world, id, mutables, key_code) {
+                                        world
+                                            .resource_mut::<ListenerInput<KeyPressEvent>>()
+                                            .stop_propagation();
+                                    }
+                                }),
+                            )
+                        },
+                        (),
+                    )
+                    .children(For::each_cmp(rows, TreeRow::same_content, move |row| {
+                        TreeRowView {
+                            row: row.clone(),
+                            tree: id,
+                            mutables,
+                        }
+                    })),
+            )
+            .style((style_tree_view, self.style.clone()))
+            .scroll_enable_y(true)
+    }
+}
+
+/// State which is passed down while flattening the tree.
+struct Flatten<'a> {
+    expanded: &'a HashMap<NodeId, bool>,
+    selection: &'a Option<NodeId>,
+    cursor: &'a Option<NodeId>,
+    toggled: &'a Option<NodeId>,
+}
+
+/// Append the visible rows for a node and its expanded descendants.
+fn flatten<T: TreeNode>(
+    node: &T,
+    id: NodeId,
+    depth: usize,
+    state: &Flatten,
+    out: &mut Vec<TreeRow>,
+) {
+    let children = node.children();
+    let expanded = state.expanded.get(&id).copied().unwrap_or(false);
+    let reveal = match (id.parent(), state.toggled) {
+        (Some(parent), Some(toggled)) => parent == *toggled,
+        _ => false,
+    };
+    out.push(TreeRow {
+        label: node.label().to_string(),
+        depth,
+        has_children: !children.is_empty(),
+        expanded,
+        selected: state.selection.as_ref() == Some(&id),
+        cursor: state.cursor.as_ref() == Some(&id),
+        reveal,
+        toggled: state.toggled.as_ref() == Some(&id),
+        id: id.clone(),
+    });
+    if expanded {
+        for (index, child) in children.iter().enumerate() {
+            flatten(child, id.child(index), depth + 1, state, out);
+        }
+    }
+}
+
+/// Handle a key press on the tree view. Returns true if the key was handled.
+fn handle_key(world: &mut World, tree: Entity, mutables: TreeMutables, key_code: KeyCode) -> bool {
+    let rows = world.get::<TreeViewState>(tree).unwrap().rows.clone();
+    if rows.is_empty() {
+        return false;
+    }
+    let current = mutables
+        .cursor
+        .get_clone(world)
+        .or_else(|| mutables.selection.get_clone(world));
+    let Some(index) = current.and_then(|id| rows.iter().position(|row| row.0 == id)) else {
+        // Nothing has the cursor yet, so any navigation key moves it to the first row.
+        if matches!(
+            key_code,
+            KeyCode::ArrowUp | KeyCode::ArrowDown | KeyCode::ArrowLeft | KeyCode::ArrowRight
+        ) {
+            mutables.move_cursor(world, rows[0].0.clone());
+            return true;
+        }
+        return false;
+    };
+    let (ref id, has_children, expanded) = rows[index];
+    match key_code {
+        KeyCode::ArrowUp if index > 0 => {
+            mutables.move_cursor(world, rows[index - 1].0.clone());
+        }
+        KeyCode::ArrowDown if index + 1 < rows.len() => {
+            mutables.move_cursor(world, rows[index + 1].0.clone());
+        }
+        KeyCode::ArrowRight if has_children && !expanded => {
+            mutables.set_expanded(world, id, true);
+        }
+        KeyCode::ArrowRight if has_children => {
+            // The first child immediately follows its parent.
+            mutables.move_cursor(world, rows[index + 1].0.clone());
+        }
+        KeyCode::ArrowLeft if expanded => {
+            mutables.set_expanded(world, id, false);
+        }
+        KeyCode::ArrowLeft => {
+            if let Some(parent) = id.parent() {
+                mutables.move_cursor(world, parent);
+            }
+        }
+        KeyCode::Enter => {
+            mutables.select(world, tree, id.clone());
+        }
+        KeyCode::ArrowUp | KeyCode::ArrowDown | KeyCode::ArrowRight => {}
+        _ => return false,
+    }
+    true
+}
+
+/// A single row of a [`TreeView`].
+#[derive(Clone, PartialEq)]
+struct TreeRowView {
+    row: TreeRow,
+    tree: Entity,
+    mutables: TreeMutables,
+}
+
+impl ViewTemplate for TreeRowView {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let hovering = cx.is_hovered(id);
+        let tree = self.tree;
+        let mutables = self.mutables;
+        let row = &self.row;
+        let node_id = row.id.clone();
+        let node_id2 = row.id.clone();
+        let expanded = row.expanded;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("TreeView::Row")
+            .style(style_tree_row)
+            .style_dyn(
+                |(depth, reveal), sb| {
+                    sb.padding_left(depth as f32 * INDENT);
+                    // Rows which are being revealed start at zero height and animate open.
+                    if !reveal {
+                        sb.height(ROW_HEIGHT);
+                    }
+                },
+                (row.depth, row.reveal),
+            )
+            .style_dyn(
+                |(hovering, selected, cursor), sb| {
+                    sb.background_color(row_bg_color(false, selected, hovering))
+                        .border_color(if cursor {
+                            colors::FOCUS
+                        } else {
+                            colors::TRANSPARENT
+                        });
+                },
+                (hovering, row.selected, row.cursor),
+            )
+            .effect(
+                |cx, ent, reveal| {
+                    if reveal {
+                        cx.with_world_mut(|world| {
+                            AnimatedTransition::<AnimatedPxHeight>::start(
+                                &mut world.entity_mut(ent),
+                                ROW_HEIGHT,
+                                TRANSITION_DURATION,
+                            );
+                        });
+                    }
+                },
+                row.reveal,
+            )
+            .insert_dyn(
+                move |_| {
+                    let node_id = node_id.clone();
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::TreeItem)),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            world.resource_mut::<Focus>().0 = Some(tree);
+                            mutables.select(world, tree, node_id.clone());
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("TreeView::Chevron")
+                    .style(style_tree_row_chevron)
+                    .insert_if(row.has_children, move || {
+                        let node_id = node_id2.clone();
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            world
+                                .resource_mut::<ListenerInput<Pointer<Click>>>()
+                                .stop_propagation();
+                            world.resource_mut::<Focus>().0 = Some(tree);
+                            mutables.set_expanded(world, &node_id, !expanded);
+                        })
+                    })
+                    .effect(
+                        |cx, ent, (expanded, toggled)| {
+                            let angle = |expanded: bool| {
+                                if expanded {
+                                    std::f32::consts::PI * 0.5
+                                } else {
+                                    0.
+                                }
+                            };
+                            cx.with_world_mut(|world| {
+                                let mut entt = world.entity_mut(ent);
+                                if toggled {
+                                    // Start from the previous angle, since this is a new entity.
+                                    entt.get_mut::<Transform>().unwrap().rotation =
+                                        Quat::from_rotation_z(angle(!expanded));
+                                    AnimatedTransition::<AnimatedRotation>::start(
+                                        &mut entt,
+                                        Quat::from_rotation_z(angle(expanded)),
+                                        TRANSITION_DURATION,
+                                    );
+                                } else {
+                                    entt.get_mut::<Transform>().unwrap().rotation =
+                                        Quat::from_rotation_z(angle(expanded));
+                                }
+                            });
+                        },
+                        (row.expanded, row.toggled),
+                    )
+                    .children(Cond::new(
+                        row.has_children,
+                        Icon::new("embedded://bevy_quill_obsidian/assets/icons/chevron_right.png")
+                            .color(Color::from(colors::DIM))
+                            .size(Vec2::splat(16.)),
+                        (),
+                    )),
+                row.label.clone(),
+            ))
+    }
+}
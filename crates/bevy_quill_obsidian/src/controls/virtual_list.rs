@@ -0,0 +1,245 @@
+use std::{ops::Range, sync::Arc};
+
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    ui::{self, node_bundles::NodeBundle},
+};
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{colors, scrolling::ScrollArea};
+
+use super::ScrollView;
+
+/// Default number of rows to build above and below the visible area.
+const DEFAULT_OVERSCAN: usize = 4;
+
+fn style_virtual_list(ss: &mut StyleBuilder) {
+    ss.background_color(colors::U1)
+        .border_radius(5.0)
+        .padding(3);
+}
+
+fn style_virtual_list_inner(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .align_self(ui::AlignSelf::Stretch)
+        .justify_self(ui::JustifySelf::Stretch)
+        .min_width(ui::Val::Percent(100.));
+}
+
+fn style_virtual_row(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .justify_content(ui::JustifyContent::Center)
+        .flex_shrink(0.);
+}
+
+/// A scrollable list which only builds the rows that are within the visible area of the
+/// scroll view, plus a number of "overscan" rows above and below. This makes it practical to
+/// display lists with many thousands of items. All rows must have the same height.
+///
+/// Rows are built and razed as they scroll into and out of view, so any state held by a row
+/// is lost when it is scrolled out of view; off-screen rows have no tracking scope.
+pub struct VirtualList<Item: Clone + PartialEq + Send + Sync + 'static, V: View> {
+    /// Additional styles to be applied to the list view.
+    pub style: StyleHandle,
+
+    /// The items in the list.
+    pub items: Arc<Vec<Item>>,
+
+    /// The height of each row, in pixels.
+    pub item_height: f32,
+
+    /// Number of rows to build above and below the visible area.
+    pub overscan: usize,
+
+    /// Function which produces the view for an item.
+    pub each: Arc<dyn Fn(&Item) -> V + Send + Sync>,
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> VirtualList<Item, V> {
+    /// Create a new virtual list.
+    ///
+    /// Arguments:
+    /// * `items`: The items in the list.
+    /// * `item_height`: The height of each row, in pixels.
+    /// * `each`: Function which produces the view for an item.
+    pub fn new<F: Fn(&Item) -> V + Send + Sync + 'static>(
+        items: &[Item],
+        item_height: f32,
+        each: F,
+    ) -> Self {
+        Self {
+            style: StyleHandle::default(),
+            items: Arc::new(items.to_vec()),
+            item_height,
+            overscan: DEFAULT_OVERSCAN,
+            each: Arc::new(each),
+        }
+    }
+
+    /// Set additional styles to be applied to the list view.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the number of rows to build above and below the visible area.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> Clone for VirtualList<Item, V> {
+    fn clone(&self) -> Self {
+        Self {
+            style: self.style.clone(),
+            items: self.items.clone(),
+            item_height: self.item_height,
+            overscan: self.overscan,
+            each: self.each.clone(),
+        }
+    }
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> PartialEq for VirtualList<Item, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.style == other.style
+            && (Arc::ptr_eq(&self.items, &other.items) || self.items == other.items)
+            && self.item_height == other.item_height
+            && self.overscan == other.overscan
+            && Arc::ptr_eq(&self.each, &other.each)
+    }
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> ViewTemplate
+    for VirtualList<Item, V>
+{
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id_scroll_area = cx.create_entity();
+        let range = cx
+            .use_component::<ScrollArea>(id_scroll_area)
+            .map_or(0..0, |scroll| {
+                visible_range(
+                    scroll.scroll_top,
+                    scroll.visible_size.y,
+                    self.item_height,
+                    self.items.len(),
+                    self.overscan,
+                )
+            });
+        let item_height = self.item_height;
+
+        ScrollView::new()
+            .entity(Some(id_scroll_area))
+            .children(
+                Element::<NodeBundle>::new()
+                    .named("VirtualList")
+                    .insert(AccessibilityNode::from(NodeBuilder::new(Role::ListBox)))
+                    .style(style_virtual_list_inner)
+                    .style_dyn(
+                        move |(len, first), sb| {
+                            // The content is sized as if all rows were present, and the built
+                            // rows are pushed down to their position by the padding.
+                            sb.height(len as f32 * item_height)
+                                .padding_top(first as f32 * item_height);
+                        },
+                        (self.items.len(), range.start),
+                    )
+                    .children(VirtualRows {
+                        items: self.items.clone(),
+                        item_height,
+                        range,
+                        each: self.each.clone(),
+                    }),
+            )
+            .style((style_virtual_list, self.style.clone()))
+            .scroll_enable_y(true)
+    }
+}
+
+/// The rows of a [`VirtualList`] which are currently built. This is a separate template so
+/// that the rows are only updated when the visible range changes, rather than every time the
+/// scroll position does.
+struct VirtualRows<Item: Clone + PartialEq + Send + Sync + 'static, V: View> {
+    items: Arc<Vec<Item>>,
+    item_height: f32,
+    range: Range<usize>,
+    each: Arc<dyn Fn(&Item) -> V + Send + Sync>,
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> Clone for VirtualRows<Item, V> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            item_height: self.item_height,
+            range: self.range.clone(),
+            each: self.each.clone(),
+        }
+    }
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> PartialEq for VirtualRows<Item, V> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.items, &other.items)
+            && self.item_height == other.item_height
+            && self.range == other.range
+            && Arc::ptr_eq(&self.each, &other.each)
+    }
+}
+
+impl<Item: Clone + PartialEq + Send + Sync + 'static, V: View> ViewTemplate
+    for VirtualRows<Item, V>
+{
+    type View = impl View;
+    fn create(&self, _cx: &mut Cx) -> Self::View {
+        // Rows are keyed by their index as well as their value, so that rows which remain
+        // visible keep their entities when the list is scrolled.
+        let rows: Vec<(usize, Item)> = self.items[self.range.clone()]
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, item)| (i + self.range.start, item))
+            .collect();
+        let each = self.each.clone();
+        let item_height = self.item_height;
+        For::each(rows, move |(_, item)| {
+            Element::<NodeBundle>::new()
+                .named("VirtualList::Row")
+                .style(style_virtual_row)
+                .style_dyn(
+                    |height, sb| {
+                        sb.height(height);
+                    },
+                    item_height,
+                )
+                .children(each(item))
+        })
+    }
+}
+
+/// Compute the range of rows which need to be built, given the scroll position and the height
+/// of the visible area.
+fn visible_range(
+    scroll_top: f32,
+    visible_height: f32,
+    item_height: f32,
+    len: usize,
+    overscan: usize,
+) -> Range<usize> {
+    if item_height <= 0. {
+        return 0..len;
+    }
+    let first = (scroll_top / item_height).floor().max(0.) as usize;
+    let last = ((scroll_top + visible_height) / item_height).ceil().max(0.) as usize;
+    let start = first.saturating_sub(overscan).min(len);
+    let end = (last + overscan).min(len);
+    start..end
+}
@@ -1,10 +1,16 @@
 use bevy::{
-    ecs::{component::Component, entity::Entity, query::Without, system::Query},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::Without,
+        system::{Query, Res},
+    },
     math::Rect,
     transform::components::GlobalTransform,
     ui::{self, Node, Style},
     window::Window,
 };
+use bevy_mod_stylebuilder::LayoutDirection;
 
 /// Which side of the anchor element the floating element should be placed.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -32,7 +38,9 @@ impl FloatSide {
     }
 }
 
-/// How the floating element should be aligned to the anchor element.
+/// How the floating element should be aligned to the anchor element. For floating elements
+/// placed above or below the anchor, the start and end edges follow the [`LayoutDirection`]:
+/// when it is right-to-left, the start edge is the right edge.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum FloatAlign {
     /// The floating element should be aligned to the starting edge of the anchor.
@@ -85,7 +93,9 @@ pub(crate) fn position_floating(
     mut query: Query<(&mut Style, &Node, &Floating, &GlobalTransform)>,
     anchor_query: Query<(&Node, &GlobalTransform), Without<Floating>>,
     windows: Query<&Window>,
+    direction: Option<Res<LayoutDirection>>,
 ) {
+    let is_rtl = direction.is_some_and(|direction| *direction == LayoutDirection::Rtl);
     let Ok(window) = windows.get_single() else {
         return;
     };
@@ -149,8 +159,14 @@ pub(crate) fn position_floating(
                 }
             }
 
-            // Position along secondary axis.
-            match position.align {
+            // Position along secondary axis. Horizontal alignment is mirrored for right-to-left
+            // layouts.
+            let align = match (position.align, position.side, is_rtl) {
+                (FloatAlign::Start, FloatSide::Top | FloatSide::Bottom, true) => FloatAlign::End,
+                (FloatAlign::End, FloatSide::Top | FloatSide::Bottom, true) => FloatAlign::Start,
+                (align, _, _) => align,
+            };
+            match align {
                 FloatAlign::Start => match position.side {
                     FloatSide::Top | FloatSide::Bottom => {
                         rect.min.x = anchor_rect.min.x;
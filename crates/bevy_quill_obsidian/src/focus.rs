@@ -1,23 +1,35 @@
 use bevy::{
     a11y::Focus,
-    app::{App, Plugin, Update},
+    app::{App, Plugin, PostUpdate, Update},
+    color::{Alpha, Color, Srgba},
     ecs::{
         component::Component,
         entity::Entity,
         event::{Event, EventReader, EventWriter},
         query::{Added, With, Without},
         system::{Query, Res, ResMut, Resource, SystemParam},
+        world::Ref,
     },
-    hierarchy::{Children, Parent},
+    hierarchy::{Children, DespawnRecursiveExt, Parent},
     input::{
         keyboard::{KeyCode, KeyboardInput},
         ButtonInput, ButtonState,
     },
     log::*,
-    prelude::Commands,
-    ui::Node,
+    math::Vec3,
+    prelude::{default, Commands, NodeBundle},
+    render::view::{InheritedVisibility, Visibility},
+    transform::components::GlobalTransform,
+    ui::{BorderColor, BorderRadius, Node, Outline, PositionType, Style, UiRect, Val, ZIndex},
+    window::Ime,
+};
+use bevy_mod_picking::prelude::{EntityEvent, EventListenerPlugin, Pickable};
+use bevy_mod_stylebuilder::{StyleBuilder, StyleBuilderOutline};
+
+use crate::{
+    animation::{AnimatedOutlineColor, AnimatedScale, AnimatedTransition},
+    colors,
 };
-use bevy_mod_picking::prelude::{EntityEvent, EventListenerPlugin};
 
 /// Bubbling event for key character input.
 #[derive(Clone, Event, EntityEvent)]
@@ -47,6 +59,9 @@ pub struct KeyPressEvent {
 
     /// Whether the shift key is held down.
     pub shift: bool,
+
+    /// Whether the control key is held down.
+    pub ctrl: bool,
 }
 
 /// A component which indicates that an entity wants to participate in tab navigation.
@@ -78,6 +93,275 @@ pub struct DefaultKeyListener;
 #[derive(Debug, Default, Resource, Copy, Clone)]
 pub struct FocusVisible(pub bool);
 
+/// Resource which controls the appearance of the focus ring drawn around focused widgets.
+/// Widgets read this when they are styled, so changes take effect the next time a widget's
+/// focus state changes.
+#[derive(Debug, Resource, Copy, Clone)]
+pub struct FocusRingStyle {
+    /// Color of the focus ring.
+    pub color: Srgba,
+    /// Width of the focus ring, in pixels.
+    pub width: f32,
+    /// Distance between the widget's border and the focus ring, in pixels.
+    pub offset: f32,
+}
+
+impl Default for FocusRingStyle {
+    fn default() -> Self {
+        Self {
+            color: colors::FOCUS,
+            width: 2.,
+            offset: 2.,
+        }
+    }
+}
+
+/// Component which draws a custom focus ring around an entity while it has visible focus. The
+/// ring is a separate overlay entity with a border, which is drawn by [`render_focus_rings`].
+/// The overlay is a root node rather than a child of the entity, so that it doesn't interfere
+/// with the entity's own children. Unlike the outline drawn by
+/// [`StyleBuilderFocusRing::focus_ring`], the ring is animated when it appears.
+#[derive(Debug, Component, Copy, Clone, PartialEq)]
+pub struct FocusRing {
+    /// Color of the focus ring.
+    pub color: Srgba,
+    /// Width of the focus ring, in pixels.
+    pub width: f32,
+    /// Distance between the entity's border and the focus ring, in pixels. This is also added
+    /// to the entity's border radius, so that the corners of the ring follow the entity.
+    pub radius_offset: f32,
+}
+
+/// Holds the overlay entity which displays the [`FocusRing`], while it is shown.
+#[derive(Component)]
+pub(crate) struct FocusRingEntity(Entity);
+
+/// Placed on the overlay entity of a [`FocusRing`], pointing back to the entity it surrounds.
+#[derive(Component)]
+pub(crate) struct FocusRingOwner {
+    owner: Entity,
+    distance: f32,
+}
+
+/// Initial scale of the focus ring when it appears.
+const FOCUS_RING_SCALE: f32 = 1.15;
+
+/// Duration of the focus ring animation, in seconds.
+const FOCUS_RING_DURATION: f32 = 0.15;
+
+/// Duration of the fade of an animated focus outline, in seconds.
+const FOCUS_OUTLINE_DURATION: f32 = 0.1;
+
+/// Z-index of focus ring overlays, so that they are drawn above other widgets.
+const FOCUS_RING_Z_INDEX: i32 = 150;
+
+/// System which displays the [`FocusRing`] of the focused entity while focus is visible, and
+/// removes the rings of all other entities.
+#[allow(clippy::type_complexity)]
+pub fn render_focus_rings(
+    mut commands: Commands,
+    focus: Res<Focus>,
+    focus_visible: Res<FocusVisible>,
+    q_rings: Query<(
+        Entity,
+        Ref<FocusRing>,
+        Option<&FocusRingEntity>,
+        Option<&BorderRadius>,
+    )>,
+    q_removed: Query<(Entity, &FocusRingEntity), Without<FocusRing>>,
+) {
+    for (entity, ring, ring_entity, radius) in q_rings.iter() {
+        let show = focus_visible.0 && focus.0 == Some(entity);
+        match (show, ring_entity) {
+            (true, None) => {
+                let ring_id = spawn_focus_ring(&mut commands, entity, &ring, radius);
+                commands.entity(entity).insert(FocusRingEntity(ring_id));
+            }
+            (true, Some(ring_entity)) if ring.is_changed() => {
+                commands.entity(ring_entity.0).despawn_recursive();
+                let ring_id = spawn_focus_ring(&mut commands, entity, &ring, radius);
+                commands.entity(entity).insert(FocusRingEntity(ring_id));
+            }
+            (false, Some(ring_entity)) => {
+                commands.entity(ring_entity.0).despawn_recursive();
+                commands.entity(entity).remove::<FocusRingEntity>();
+            }
+            _ => {}
+        }
+    }
+
+    for (entity, ring_entity) in q_removed.iter() {
+        commands.entity(ring_entity.0).despawn_recursive();
+        commands.entity(entity).remove::<FocusRingEntity>();
+    }
+}
+
+fn spawn_focus_ring(
+    commands: &mut Commands,
+    owner: Entity,
+    ring: &FocusRing,
+    radius: Option<&BorderRadius>,
+) -> Entity {
+    let distance = ring.radius_offset + ring.width;
+    let grow = |val: Val| match val {
+        Val::Px(px) => Val::Px(px + distance),
+        val => val,
+    };
+    let border_radius = radius.map_or(BorderRadius::default(), |radius| BorderRadius {
+        top_left: grow(radius.top_left),
+        top_right: grow(radius.top_right),
+        bottom_left: grow(radius.bottom_left),
+        bottom_right: grow(radius.bottom_right),
+    });
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    border: UiRect::all(Val::Px(ring.width)),
+                    ..default()
+                },
+                border_color: BorderColor(ring.color.into()),
+                border_radius,
+                // Hidden until it has been positioned over the owner.
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(FOCUS_RING_Z_INDEX),
+                ..default()
+            },
+            FocusRingOwner { owner, distance },
+            Pickable::IGNORE,
+            AnimatedTransition::<AnimatedScale>::new(
+                Vec3::splat(FOCUS_RING_SCALE),
+                Vec3::ONE,
+                FOCUS_RING_DURATION,
+                0.0,
+            ),
+        ))
+        .id()
+}
+
+/// System which moves each focus ring overlay so that it surrounds its owner, and despawns
+/// the overlays whose owner no longer exists.
+pub(crate) fn position_focus_rings(
+    mut commands: Commands,
+    mut q_rings: Query<(Entity, &FocusRingOwner, &mut Style, &mut Visibility)>,
+    q_owners: Query<(&Node, &GlobalTransform, &InheritedVisibility), Without<FocusRingOwner>>,
+) {
+    for (ring_id, ring, mut style, mut visibility) in q_rings.iter_mut() {
+        let Ok((node, transform, owner_visibility)) = q_owners.get(ring.owner) else {
+            commands.entity(ring_id).despawn_recursive();
+            continue;
+        };
+        let rect = node.logical_rect(transform).inflate(ring.distance);
+        let left = Val::Px(rect.min.x);
+        let top = Val::Px(rect.min.y);
+        let width = Val::Px(rect.width());
+        let height = Val::Px(rect.height());
+        if style.left != left || style.top != top || style.width != width || style.height != height
+        {
+            style.left = left;
+            style.top = top;
+            style.width = width;
+            style.height = height;
+        }
+        let next = if owner_visibility.get() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != next {
+            *visibility = next;
+        }
+    }
+}
+
+/// Trait which adds a `focus_ring` method to [`StyleBuilder`].
+pub trait StyleBuilderFocusRing {
+    /// Draw the focus ring, using the current [`FocusRingStyle`], if `is_focused` is true;
+    /// otherwise remove it.
+    fn focus_ring(&mut self, is_focused: bool) -> &mut Self;
+
+    /// Like [`StyleBuilderFocusRing::focus_ring`], but fades the outline in and out instead of
+    /// showing and hiding it instantly.
+    fn focus_ring_animated(&mut self, is_focused: bool) -> &mut Self;
+
+    /// Use a custom [`FocusRing`] with the given color and width, instead of an outline.
+    /// The distance from the widget is taken from the current [`FocusRingStyle`].
+    fn focus_ring_custom(&mut self, color: Srgba, width: f32) -> &mut Self;
+}
+
+impl<'a, 'w> StyleBuilderFocusRing for StyleBuilder<'a, 'w> {
+    fn focus_ring(&mut self, is_focused: bool) -> &mut Self {
+        if is_focused {
+            let style = self
+                .target
+                .world()
+                .get_resource::<FocusRingStyle>()
+                .copied()
+                .unwrap_or_default();
+            self.outline_color(style.color)
+                .outline_width(style.width)
+                .outline_offset(style.offset)
+        } else {
+            self.outline_color(Option::<Color>::None)
+        }
+    }
+
+    fn focus_ring_animated(&mut self, is_focused: bool) -> &mut Self {
+        let style = self
+            .target
+            .world()
+            .get_resource::<FocusRingStyle>()
+            .copied()
+            .unwrap_or_default();
+        // Keep a transparent outline while unfocused, so that there is something to animate.
+        match self.target.get_mut::<Outline>() {
+            Some(mut outline) => {
+                outline.width = Val::Px(style.width);
+                outline.offset = Val::Px(style.offset);
+            }
+            None => {
+                self.target.insert(Outline {
+                    width: Val::Px(style.width),
+                    offset: Val::Px(style.offset),
+                    color: style.color.with_alpha(0.).into(),
+                });
+            }
+        }
+        let color = if is_focused {
+            style.color
+        } else {
+            style.color.with_alpha(0.)
+        };
+        AnimatedTransition::<AnimatedOutlineColor>::start(
+            self.target,
+            color,
+            FOCUS_OUTLINE_DURATION,
+        );
+        self
+    }
+
+    fn focus_ring_custom(&mut self, color: Srgba, width: f32) -> &mut Self {
+        let offset = self
+            .target
+            .world()
+            .get_resource::<FocusRingStyle>()
+            .copied()
+            .unwrap_or_default()
+            .offset;
+        let ring = FocusRing {
+            color,
+            width,
+            radius_offset: offset,
+        };
+        // Avoid marking the ring as changed, which would restart its animation.
+        if self.target.get::<FocusRing>() != Some(&ring) {
+            self.target.insert(ring);
+        }
+        self
+    }
+}
+
 /// A component used to mark a tree of entities as containing tabbable elements.
 #[derive(Debug, Default, Component, Copy, Clone)]
 pub struct TabGroup {
@@ -297,13 +581,14 @@ fn handle_tab(
 
 fn handle_text_input(
     mut key_events: EventReader<KeyboardInput>,
+    mut ime_events: EventReader<Ime>,
     key: Res<ButtonInput<KeyCode>>,
     focus: ResMut<Focus>,
     mut press_writer: EventWriter<KeyPressEvent>,
     mut char_writer: EventWriter<KeyCharEvent>,
     default_listener: Query<Entity, With<DefaultKeyListener>>,
 ) {
-    if key_events.is_empty() {
+    if key_events.is_empty() && ime_events.is_empty() {
         return;
     }
 
@@ -325,15 +610,23 @@ fn handle_text_input(
                 key_code: ev.key_code,
                 repeat: !key.just_pressed(ev.key_code),
                 shift: key.pressed(KeyCode::ShiftLeft) || key.pressed(KeyCode::ShiftRight),
+                ctrl: key.pressed(KeyCode::ControlLeft) || key.pressed(KeyCode::ControlRight),
             };
             press_writer.send(press_event);
 
             if let bevy::input::keyboard::Key::Character(ref ch) = ev.logical_key {
-                let ev = KeyCharEvent {
-                    target,
-                    key: ch.chars().next().unwrap(),
-                };
-                char_writer.send(ev);
+                for key in ch.chars() {
+                    char_writer.send(KeyCharEvent { target, key });
+                }
+            }
+        }
+    }
+
+    // Text which was composed using an input method. This requires `Window::ime_enabled`.
+    for ev in ime_events.read() {
+        if let Ime::Commit { value, .. } = ev {
+            for key in value.chars() {
+                char_writer.send(KeyCharEvent { target, key });
             }
         }
     }
@@ -349,11 +642,19 @@ impl Plugin for KeyboardInputPlugin {
             EventListenerPlugin::<KeyPressEvent>::default(),
         ))
         .init_resource::<FocusVisible>()
+        .init_resource::<FocusRingStyle>()
         .add_event::<KeyPressEvent>()
         .add_event::<KeyCharEvent>()
         .add_systems(
             Update,
-            (fix_focus, handle_auto_focus, handle_tab, handle_text_input),
-        );
+            (
+                fix_focus,
+                handle_auto_focus,
+                handle_tab,
+                handle_text_input,
+                render_focus_rings,
+            ),
+        )
+        .add_systems(PostUpdate, position_focus_rings);
     }
 }
@@ -82,24 +82,26 @@ impl<'w, 'p> CreateBistableTransition for Cx<'w, 'p> {
         let entity = self.create_entity();
 
         // Effect which updates the state machine when the `open` signal changes.
-        let mut entt = self.world_mut().entity_mut(entity);
-        match entt.get_mut::<BistableTransitionStateMachine>() {
-            Some(mut ee) => {
-                if ee.open != open {
-                    ee.open = open;
+        self.with_world_mut(|world| {
+            let mut entt = world.entity_mut(entity);
+            match entt.get_mut::<BistableTransitionStateMachine>() {
+                Some(mut ee) => {
+                    if ee.open != open {
+                        ee.open = open;
+                    }
                 }
-            }
-            None => {
-                entt.insert((
-                    BistableTransitionStateMachine {
-                        open,
-                        delay,
-                        ..default()
-                    },
-                    TransitionTimer { ..default() },
-                ));
-            }
-        };
+                None => {
+                    entt.insert((
+                        BistableTransitionStateMachine {
+                            open,
+                            delay,
+                            ..default()
+                        },
+                        TransitionTimer { ..default() },
+                    ));
+                }
+            };
+        });
 
         // Derived signal which returns the current state.
         self.use_component::<BistableTransitionStateMachine>(entity)
@@ -36,10 +36,12 @@ pub trait UseIsHover {
 
 impl<'p, 'w> UseIsHover for Cx<'p, 'w> {
     fn is_hovered(&mut self, target: Entity) -> bool {
-        let mut entt = self.world_mut().entity_mut(target);
-        if !entt.contains::<Hovering>() {
-            entt.insert(Hovering(false));
-        }
+        self.with_world_mut(|world| {
+            let mut entt = world.entity_mut(target);
+            if !entt.contains::<Hovering>() {
+                entt.insert(Hovering(false));
+            }
+        });
         self.use_component::<Hovering>(target)
             .map(|h| h.0)
             .unwrap_or(false)
@@ -2,6 +2,7 @@ mod bistable_transition;
 mod element_rect;
 mod is_focus;
 pub(crate) mod is_hover;
+pub(crate) mod rate_limit;
 
 pub use bistable_transition::{
     BistableTransitionPlugin, BistableTransitionState, CreateBistableTransition,
@@ -9,3 +10,4 @@ pub use bistable_transition::{
 pub use element_rect::UseElementRect;
 pub use is_focus::UseIsFocus;
 pub use is_hover::UseIsHover;
+pub use rate_limit::UseRateLimit;
@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+use bevy_quill_core::Cx;
+
+/// The value currently emitted by a debounced or throttled hook. This is the only component
+/// that the hook subscribes to, so that the view reacts only when a value is emitted.
+#[derive(Component)]
+struct RateLimited<T: Send + Sync + 'static> {
+    value: T,
+}
+
+/// The value waiting to be emitted, when it is due, and the time of the last emit.
+#[derive(Component)]
+struct RateLimitPending<T: Send + Sync + 'static> {
+    value: Option<T>,
+    deadline: f32,
+    last_emit: f32,
+}
+
+/// Timer which emits the pending value once the deadline is reached.
+#[derive(Component)]
+struct RateLimitTimer {
+    /// Time at which the pending value should be emitted, or `None` if nothing is scheduled.
+    deadline: Option<f32>,
+
+    /// Function which emits the pending value. This erases the value type, so that a single
+    /// system can run the timers for all hooks.
+    emit: fn(&mut World, Entity),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum RateLimitMode {
+    Debounce,
+    Throttle,
+}
+
+/// Trait which adds `use_debounce` and `use_throttle` to [`Cx`].
+pub trait UseRateLimit {
+    /// Return `value`, but only once it has stopped changing for `millis` milliseconds. Until
+    /// then, the previously emitted value is returned. A delay of zero passes the value
+    /// through unchanged.
+    fn use_debounce<T: Clone + PartialEq + Send + Sync + 'static>(
+        &mut self,
+        value: T,
+        millis: u64,
+    ) -> T;
+
+    /// Return `value`, but let through at most one change every `millis` milliseconds. A change
+    /// which arrives too soon after the previous one is held back until the interval has
+    /// elapsed, so the latest value is never lost. An interval of zero passes the value
+    /// through unchanged.
+    fn use_throttle<T: Clone + PartialEq + Send + Sync + 'static>(
+        &mut self,
+        value: T,
+        millis: u64,
+    ) -> T;
+}
+
+impl<'w, 'p> UseRateLimit for Cx<'w, 'p> {
+    fn use_debounce<T: Clone + PartialEq + Send + Sync + 'static>(
+        &mut self,
+        value: T,
+        millis: u64,
+    ) -> T {
+        use_rate_limit(self, value, millis, RateLimitMode::Debounce)
+    }
+
+    fn use_throttle<T: Clone + PartialEq + Send + Sync + 'static>(
+        &mut self,
+        value: T,
+        millis: u64,
+    ) -> T {
+        use_rate_limit(self, value, millis, RateLimitMode::Throttle)
+    }
+}
+
+fn use_rate_limit<T: Clone + PartialEq + Send + Sync + 'static>(
+    cx: &mut Cx,
+    value: T,
+    millis: u64,
+    mode: RateLimitMode,
+) -> T {
+    // Create an entity to hold the timer state.
+    let entity = cx.create_entity();
+    let now = cx.world().resource::<Time>().elapsed_seconds();
+    let interval = millis as f32 * 0.001;
+
+    cx.with_world_mut(|world| {
+        let mut entt = world.entity_mut(entity);
+        if !entt.contains::<RateLimited<T>>() {
+            entt.insert((
+                RateLimited {
+                    value: value.clone(),
+                },
+                RateLimitPending::<T> {
+                    value: None,
+                    deadline: now,
+                    last_emit: now,
+                },
+                RateLimitTimer {
+                    deadline: None,
+                    emit: emit_pending::<T>,
+                },
+            ));
+        } else {
+            let is_current = entt.get::<RateLimited<T>>().unwrap().value == value;
+            let mut pending = entt.get_mut::<RateLimitPending<T>>().unwrap();
+            let mut emit_now = false;
+            if is_current {
+                // The value has returned to the one already emitted.
+                pending.value = None;
+            } else if interval <= 0. {
+                emit_now = true;
+            } else if pending.value.as_ref() != Some(&value) {
+                match mode {
+                    RateLimitMode::Debounce => {
+                        // Each new value restarts the delay.
+                        pending.value = Some(value.clone());
+                        pending.deadline = now + interval;
+                    }
+                    RateLimitMode::Throttle if now - pending.last_emit >= interval => {
+                        emit_now = true;
+                    }
+                    RateLimitMode::Throttle => {
+                        pending.value = Some(value.clone());
+                        pending.deadline = pending.last_emit + interval;
+                    }
+                }
+            }
+
+            if emit_now {
+                pending.value = None;
+                pending.last_emit = now;
+                entt.get_mut::<RateLimited<T>>().unwrap().value = value.clone();
+            }
+
+            // (Re)schedule the timer, which is cancelled by the cleanup below each time the view
+            // reacts.
+            let pending = entt.get::<RateLimitPending<T>>().unwrap();
+            let deadline = pending.value.as_ref().map(|_| pending.deadline);
+            entt.get_mut::<RateLimitTimer>().unwrap().deadline = deadline;
+        }
+    });
+
+    cx.on_cleanup(move |world| {
+        if let Some(mut timer) = world.get_mut::<RateLimitTimer>(entity) {
+            timer.deadline = None;
+        }
+    });
+
+    cx.use_component::<RateLimited<T>>(entity)
+        .map(|limited| limited.value.clone())
+        .unwrap_or(value)
+}
+
+/// Move the pending value of a rate-limited hook into the emitted value.
+fn emit_pending<T: Clone + PartialEq + Send + Sync + 'static>(world: &mut World, entity: Entity) {
+    let now = world.resource::<Time>().elapsed_seconds();
+    let mut entt = world.entity_mut(entity);
+    let mut pending = entt.get_mut::<RateLimitPending<T>>().unwrap();
+    let Some(value) = pending.value.take() else {
+        return;
+    };
+    pending.last_emit = now;
+    entt.get_mut::<RateLimited<T>>().unwrap().value = value;
+}
+
+/// System which emits the pending values of debounced and throttled hooks whose deadline has
+/// passed.
+pub(crate) fn update_rate_limit_timers(world: &mut World) {
+    let now = world.resource::<Time>().elapsed_seconds();
+    let mut q_timers = world.query::<(Entity, &mut RateLimitTimer)>();
+    let mut expired: Vec<(Entity, fn(&mut World, Entity))> = Vec::new();
+    for (entity, mut timer) in q_timers.iter_mut(world) {
+        if timer.deadline.is_some_and(|deadline| deadline <= now) {
+            timer.deadline = None;
+            expired.push((entity, timer.emit));
+        }
+    }
+    for (entity, emit) in expired {
+        emit(world, entity);
+    }
+}
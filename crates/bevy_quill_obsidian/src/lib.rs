@@ -47,7 +47,8 @@ pub mod prelude {
 }
 
 use bevy_mod_picking::prelude::EventListenerPlugin;
-use controls::{MenuCloseEvent, RecentColors};
+use bevy_mod_stylebuilder::LayoutDirection;
+use controls::{DialogStack, MenuCloseEvent, RecentColors};
 use materials::{GradientRectMaterial, SliderRectMaterial, SwatchRectMaterial};
 pub use rounded_corners::RoundedCorners;
 
@@ -106,10 +107,13 @@ impl Plugin for ObsidianUiPlugin {
                 scrolling::handle_scroll_events,
                 scrolling::update_scroll_positions,
                 hooks::is_hover::update_hover_states,
-                cursor::update_cursor,
+                hooks::rate_limit::update_rate_limit_timers,
+                controls::update_tooltip_timers,
             ),
         )
         .init_resource::<RecentColors>()
+        .init_resource::<DialogStack>()
+        .init_resource::<LayoutDirection>()
         .add_systems(PostUpdate, floating::position_floating);
     }
 }
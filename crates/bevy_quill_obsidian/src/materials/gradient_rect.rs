@@ -8,8 +8,14 @@ pub(crate) struct GradientRectMaterial {
     pub(crate) num_color_stops: IVec4,
     #[uniform(1)]
     pub(crate) color_stops: [Vec4; 8],
+    /// If `x` is non-zero, interpolate the gradient in linear space rather than sRGB.
+    #[uniform(2)]
+    pub(crate) gamma_correct: UVec4,
     #[uniform(3)]
     pub(crate) cap_size: Vec4,
+    /// Positions of the color stops, packed four to a vector.
+    #[uniform(4)]
+    pub(crate) stop_positions: [Vec4; 2],
 }
 
 impl UiMaterial for GradientRectMaterial {
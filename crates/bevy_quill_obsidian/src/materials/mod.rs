@@ -8,4 +8,4 @@ mod swatch_rect;
 // pub(crate) use draw_path::*;
 pub(crate) use gradient_rect::GradientRectMaterial;
 pub(crate) use slider_rect::SliderRectMaterial;
-pub(crate) use swatch_rect::SwatchRectMaterial;
+pub(crate) use swatch_rect::{SwatchRectMaterial, DEFAULT_CHECKER_SIZE};
@@ -8,8 +8,15 @@ pub(crate) struct SwatchRectMaterial {
     pub(crate) color: Vec4,
     #[uniform(1)]
     pub(crate) border_radius: Vec4,
+    /// Size of the checkerboard squares shown behind transparent colors, in pixels. Only the
+    /// `x` component is used; the rest is padding.
+    #[uniform(2)]
+    pub(crate) checker_size: Vec4,
 }
 
+/// Default size of the checkerboard squares, in pixels.
+pub(crate) const DEFAULT_CHECKER_SIZE: f32 = 4.;
+
 impl UiMaterial for SwatchRectMaterial {
     fn fragment_shader() -> ShaderRef {
         "embedded://bevy_quill_obsidian/assets/shaders/swatch_rect.wgsl".into()
@@ -79,8 +79,26 @@ pub struct ScrollBar {
     /// Entity id of the scroll area.
     pub id_scroll_area: Entity,
 
-    /// Minimum thumb size.
+    /// Minimum thumb size, in pixels.
     pub min_thumb_size: f32,
+
+    /// Minimum thumb size, as a fraction of the track length. The thumb is never smaller
+    /// than either minimum.
+    pub min_thumb_size_fraction: f32,
+
+    /// If true, the scrollbar is collapsed (`Display::None`) when the content fits within the
+    /// visible area; otherwise only the thumb is hidden and the track keeps its space.
+    pub hide_when_not_needed: bool,
+}
+
+impl ScrollBar {
+    /// Compute the size of the thumb as a fraction of the track length.
+    fn thumb_size(&self, visible_size: f32, content_size: f32) -> f32 {
+        (visible_size / content_size)
+            .max(self.min_thumb_size / visible_size)
+            .max(self.min_thumb_size_fraction)
+            .min(1.)
+    }
 }
 
 /// Marker component indicating this entity is a scrollbar thumb.
@@ -92,9 +110,13 @@ pub(crate) fn update_scroll_positions(
     mut query: Query<(&Node, &mut ScrollArea, &GlobalTransform, &Children)>,
     mut query_content: Query<
         (&Node, &mut Style, &GlobalTransform),
-        (Without<ScrollArea>, Without<ScrollBarThumb>),
+        (
+            Without<ScrollArea>,
+            Without<ScrollBar>,
+            Without<ScrollBarThumb>,
+        ),
     >,
-    query_scrollbar: Query<(&ScrollBar, &Children)>,
+    mut query_scrollbar: Query<(&ScrollBar, &mut Style, &Children), Without<ScrollBarThumb>>,
     mut query_scrollbar_thumb: Query<(&mut Style, &mut Visibility), With<ScrollBarThumb>>,
 ) {
     for (node, mut scrolling, gt, children) in query.iter_mut() {
@@ -132,16 +154,15 @@ pub(crate) fn update_scroll_positions(
 
         // Adjust horizontal scrollbar
         if let Some(sid) = scrolling.id_scrollbar_x {
-            if let Ok((scrollbar, children)) = query_scrollbar.get(sid) {
+            if let Ok((scrollbar, mut scrollbar_style, children)) = query_scrollbar.get_mut(sid) {
                 if let Some(child_id) = children.first() {
                     if let Ok((mut style, mut visibility)) =
                         query_scrollbar_thumb.get_mut(*child_id)
                     {
                         // Thumb should be equal to proportion of scroll width / content width.
                         // Thumb should be no smaller than min size, and no bigger than full size.
-                        let thumb_size = (scrolling.visible_size.x / scrolling.content_size.x)
-                            .max(scrollbar.min_thumb_size / scrolling.visible_size.x)
-                            .min(1.);
+                        let thumb_size = scrollbar
+                            .thumb_size(scrolling.visible_size.x, scrolling.content_size.x);
                         let range = scrolling.content_size.x - scrolling.visible_size.x;
                         let scroll_pos = if range > 0. {
                             scrolling.scroll_left * (1. - thumb_size) / range
@@ -154,6 +175,15 @@ pub(crate) fn update_scroll_positions(
                             visibility::Visibility::Visible
                         } else {
                             visibility::Visibility::Hidden
+                        };
+                        // Collapse the scrollbar entirely when the content fits.
+                        let display = if thumb_size < 1. || !scrollbar.hide_when_not_needed {
+                            ui::Display::Flex
+                        } else {
+                            ui::Display::None
+                        };
+                        if scrollbar_style.display != display {
+                            scrollbar_style.display = display;
                         }
                     }
                 }
@@ -162,14 +192,13 @@ pub(crate) fn update_scroll_positions(
 
         // Adjust vertical scrollbar
         if let Some(sid) = scrolling.id_scrollbar_y {
-            if let Ok((scrollbar, children)) = query_scrollbar.get(sid) {
+            if let Ok((scrollbar, mut scrollbar_style, children)) = query_scrollbar.get_mut(sid) {
                 if let Some(child_id) = children.first() {
                     if let Ok((mut style, mut visibility)) =
                         query_scrollbar_thumb.get_mut(*child_id)
                     {
-                        let thumb_size = (scrolling.visible_size.y / scrolling.content_size.y)
-                            .max(scrollbar.min_thumb_size / scrolling.visible_size.y)
-                            .min(1.);
+                        let thumb_size = scrollbar
+                            .thumb_size(scrolling.visible_size.y, scrolling.content_size.y);
                         let range = scrolling.content_size.y - scrolling.visible_size.y;
                         let scroll_pos = if range > 0. {
                             scrolling.scroll_top * (1. - thumb_size) / range
@@ -182,6 +211,15 @@ pub(crate) fn update_scroll_positions(
                             visibility::Visibility::Visible
                         } else {
                             visibility::Visibility::Hidden
+                        };
+                        // Collapse the scrollbar entirely when the content fits.
+                        let display = if thumb_size < 1. || !scrollbar.hide_when_not_needed {
+                            ui::Display::Flex
+                        } else {
+                            ui::Display::None
+                        };
+                        if scrollbar_style.display != display {
+                            scrollbar_style.display = display;
                         }
                     }
                 }
@@ -74,7 +74,13 @@ fn distance_to_path(pt: vec2<f32>) -> f32 {
 fn distance_sq_to_line(pt: vec2<f32>, a: vec2<f32>, b: vec2<f32>) -> f32 {
     let pa = pt - a;
     let ba = b - a;
-    let h = clamp(dot(pa, ba) / dot(ba, ba), 0.0, 1.0);
+    let len_sq = dot(ba, ba);
+    if (len_sq < 1e-6) {
+        // Zero-length segment, such as the vertical step of an orthogonal edge between
+        // terminals at the same height.
+        return dot2(pa);
+    }
+    let h = clamp(dot(pa, ba) / len_sq, 0.0, 1.0);
     return dot2(pa - ba * h);
 }
 
@@ -1,17 +1,20 @@
 use std::ops::Mul;
 
-use bevy::{prelude::*, ui};
+use bevy::{prelude::*, ui, window::SystemCursorIcon};
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::{
-    StyleBuilder, StyleBuilderLayout, StyleBuilderPointerEvents, StyleBuilderVisibility,
+    StyleBuilder, StyleBuilderBackground, StyleBuilderFont, StyleBuilderLayout,
+    StyleBuilderPointerEvents, StyleBuilderVisibility,
 };
-use bevy_quill_core::prelude::*;
-use bevy_quill_obsidian::cursor::StyleBuilderCursor;
+use bevy_quill_core::{prelude::*, IntoViewChild, ViewChild};
+use bevy_quill_obsidian::{colors, cursor::StyleBuilderCursor};
 
 use crate::{
+    graph_display::GraphDisplayContext,
     materials::{DrawPathMaterial, DrawablePath},
     relative_pos::RelativeWorldPositions,
-    ConnectionAnchor, ConnectionTarget, DragAction, DragMode, Gesture, GestureState, GraphEvent,
+    ConnectionAnchor, ConnectionTarget, DragAction, DragMode, EdgeRouting, Gesture, GestureState,
+    GraphEvent,
 };
 
 fn style_edge(ss: &mut StyleBuilder) {
@@ -25,7 +28,27 @@ fn style_edge_hitbox(ss: &mut StyleBuilder) {
         .pointer_events(true)
         // .border(2)
         // .border_color(colors::Y_GREEN)
-        .cursor(CursorIcon::Grab);
+        .cursor(SystemCursorIcon::Grab);
+}
+
+/// Zero-sized anchor at the midpoint of the edge, which centers the label on itself.
+fn style_edge_label_anchor(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .width(0)
+        .height(0)
+        .display(ui::Display::Flex)
+        .align_items(ui::AlignItems::Center)
+        .justify_content(ui::JustifyContent::Center)
+        .pointer_events(false);
+}
+
+fn style_edge_label(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_shrink(0.)
+        .padding((4, 1))
+        .font_size(12)
+        .color(colors::DIM)
+        .background_color(colors::U1.with_alpha(0.7));
 }
 
 /// Displays a stroked path between two nodes.
@@ -48,6 +71,17 @@ pub struct EdgeDisplay {
 
     /// If true, the edge should not be displayed, but the display entities should still exist.
     pub hidden: bool,
+
+    /// Optional label displayed at the midpoint of the edge.
+    pub label: Option<ViewChild>,
+}
+
+impl EdgeDisplay {
+    /// Set the label displayed at the midpoint of the edge.
+    pub fn label(mut self, label: impl IntoViewChild) -> Self {
+        self.label = Some(label.into_view_child());
+        self
+    }
 }
 
 impl ViewTemplate for EdgeDisplay {
@@ -65,6 +99,9 @@ impl ViewTemplate for EdgeDisplay {
             (),
         );
         let material_id = material.id();
+        let routing = cx
+            .use_inherited_component::<GraphDisplayContext>()
+            .map_or(EdgeRouting::default(), |context| context.edge_routing);
 
         (
             Element::<MaterialNodeBundle<DrawPathMaterial>>::for_entity(display_id)
@@ -78,53 +115,44 @@ impl ViewTemplate for EdgeDisplay {
                     self.hidden,
                 )
                 .effect(
-                    move |cx, ent, (src, dst, src_color, dst_color)| {
+                    move |cx, ent, (src, dst, src_color, dst_color, routing)| {
                         let mut path = DrawablePath::new(1.7);
-                        let dx = (dst.x - src.x).abs().mul(0.3).min(20.);
-                        let src1 = src + Vec2::new(dx, 0.);
-                        let dst1 = dst - Vec2::new(dx, 0.);
                         path.move_to(src);
                         // TODO: Marker
-                        let mlen = src1.distance(dst1);
-                        if mlen > 40. {
-                            let src2 = src1.lerp(dst1, 20. / mlen);
-                            let dst2 = src1.lerp(dst1, (mlen - 20.) / mlen);
-                            path.quadratic_to(src1, src2);
-                            path.line_to(dst2);
-                            path.quadratic_to(dst1, dst);
-                        } else {
-                            let mid = src1.lerp(dst1, 0.5);
-                            path.quadratic_to(src1, mid);
-                            path.quadratic_to(dst1, dst);
+                        match routing {
+                            EdgeRouting::Bezier => bezier_edge_path(&mut path, src, dst),
+                            EdgeRouting::Orthogonal => orthogonal_edge_path(&mut path, src, dst),
                         }
                         // TODO: Marker
                         let bounds = path.bounds();
 
-                        let mut style = cx.world_mut().get_mut::<Style>(ent).unwrap();
-                        style.left = ui::Val::Px(bounds.min.x);
-                        style.top = ui::Val::Px(bounds.min.y);
-                        style.width = ui::Val::Px(bounds.width());
-                        style.height = ui::Val::Px(bounds.height());
-                        style.position_type = ui::PositionType::Absolute;
+                        cx.with_world_mut(|world| {
+                            let mut style = world.get_mut::<Style>(ent).unwrap();
+                            style.left = ui::Val::Px(bounds.min.x);
+                            style.top = ui::Val::Px(bounds.min.y);
+                            style.width = ui::Val::Px(bounds.width());
+                            style.height = ui::Val::Px(bounds.height());
+                            style.position_type = ui::PositionType::Absolute;
 
-                        let mut materials = cx
-                            .world_mut()
-                            .get_resource_mut::<Assets<DrawPathMaterial>>()
-                            .unwrap();
-                        let material = materials.get_mut(material_id).unwrap();
-                        material.update_path(&path);
-                        material.update_color(
-                            src_color,
-                            src - bounds.min,
-                            dst_color,
-                            dst - bounds.min,
-                        );
+                            let mut materials = world
+                                .get_resource_mut::<Assets<DrawPathMaterial>>()
+                                .unwrap();
+                            let material = materials.get_mut(material_id).unwrap();
+                            material.update_path(&path);
+                            material.update_color(
+                                src_color,
+                                src - bounds.min,
+                                dst_color,
+                                dst - bounds.min,
+                            );
+                        });
                     },
                     (
                         self.src_pos.as_vec2(),
                         self.dst_pos.as_vec2(),
                         self.src_color,
                         self.dst_color,
+                        routing,
                     ),
                 ),
             Cond::new(
@@ -151,10 +179,57 @@ impl ViewTemplate for EdgeDisplay {
                 ),
                 (),
             ),
+            Cond::new(
+                self.label.is_some(),
+                Element::<NodeBundle>::new()
+                    .named("NodeGraph::EdgeLabel")
+                    .style(style_edge_label_anchor)
+                    .style_dyn(
+                        |(src, dst, hidden), sb| {
+                            let mid = (src + dst) * 0.5;
+                            sb.left(mid.x).top(mid.y).visible(!hidden);
+                        },
+                        (self.src_pos.as_vec2(), self.dst_pos.as_vec2(), self.hidden),
+                    )
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .style(style_edge_label)
+                            .children(self.label.clone().unwrap_or_default()),
+                    ),
+                (),
+            ),
         )
     }
 }
 
+/// Append a smooth curve from `src` to `dst` to the path.
+fn bezier_edge_path(path: &mut DrawablePath, src: Vec2, dst: Vec2) {
+    let dx = (dst.x - src.x).abs().mul(0.3).min(20.);
+    let src1 = src + Vec2::new(dx, 0.);
+    let dst1 = dst - Vec2::new(dx, 0.);
+    let mlen = src1.distance(dst1);
+    if mlen > 40. {
+        let src2 = src1.lerp(dst1, 20. / mlen);
+        let dst2 = src1.lerp(dst1, (mlen - 20.) / mlen);
+        path.quadratic_to(src1, src2);
+        path.line_to(dst2);
+        path.quadratic_to(dst1, dst);
+    } else {
+        let mid = src1.lerp(dst1, 0.5);
+        path.quadratic_to(src1, mid);
+        path.quadratic_to(dst1, dst);
+    }
+}
+
+/// Append a stepped path from `src` to `dst` to the path: horizontally to the midpoint,
+/// vertically to the destination height, then horizontally to the destination.
+fn orthogonal_edge_path(path: &mut DrawablePath, src: Vec2, dst: Vec2) {
+    let mid_x = (src.x + dst.x) * 0.5;
+    path.h_line_to(mid_x);
+    path.v_line_to(dst.y);
+    path.h_line_to(dst.x);
+}
+
 #[allow(clippy::type_complexity)]
 fn edge_event_handlers(
     args: (Option<Entity>, Entity, bool),
@@ -93,12 +93,14 @@ impl ViewTemplate for EdgeDisplay {
                     // style.height = ui::Val::Px(bounds.height());
                     // style.position_type = ui::PositionType::Absolute;
 
-                    let mut meshes = cx.world_mut().get_resource_mut::<Assets<Mesh>>().unwrap();
-                    let mesh = meshes.add(
-                        Mesh::new(PrimitiveTopology::LineStrip, RenderAssetUsages::default())
-                            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, position),
-                    );
-                    cx.world_mut().entity_mut(ent).insert(Mesh2dHandle(mesh));
+                    cx.with_world_mut(|world| {
+                        let mut meshes = world.get_resource_mut::<Assets<Mesh>>().unwrap();
+                        let mesh = meshes.add(
+                            Mesh::new(PrimitiveTopology::LineStrip, RenderAssetUsages::default())
+                                .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, position),
+                        );
+                        world.entity_mut(ent).insert(Mesh2dHandle(mesh));
+                    });
 
                     // let mut materials = cx
                     //     .world_mut()
@@ -23,6 +23,10 @@ pub enum ConnectionTarget {
     OutputTerminal(Entity),
     /// Dragging the source end (connected to an output) of an existing edge.
     Location(Vec2),
+    /// Hovering over the body of a node rather than a specific terminal. This is only
+    /// reported when dragging from the input side of a connection, so that the app can offer
+    /// a choice of which output terminal on the node to connect to. The entity is the node id.
+    NodeBody(Entity),
     /// Not dragging
     #[default]
     None,
@@ -34,6 +38,10 @@ pub enum Gesture {
     /// The arguments are the drag vector, and whether this is the final drag value.
     Move(Vec2, DragAction),
 
+    /// Resize a node by dragging its resize handle. The arguments are the node id, the new
+    /// size of the node (no smaller than the minimum node size), and the drag action.
+    Resize(Entity, Vec2, DragAction),
+
     /// Drag a node onto the graph to create it.
     Create(Vec2),
 
@@ -62,6 +70,9 @@ pub enum Gesture {
     /// Remove all nodes from the selection.
     SelectClear,
 
+    /// Double-click on a node, for example to expand a node which contains other nodes.
+    Open(Entity),
+
     /// Cancel the current action.
     Cancel,
 }
@@ -82,6 +93,8 @@ pub(crate) enum DragMode {
     #[default]
     None,
     Move,
+    /// Resizing a node; the argument is the size of the node when the drag started.
+    Resize(Vec2),
     RectSelect(Vec2),
     Connect,
 }
@@ -100,4 +113,6 @@ pub(crate) struct GestureState {
     pub(crate) mode: DragMode,
     pub(crate) anchor: Option<ConnectionAnchor>,
     pub(crate) target: ConnectionTarget,
+    /// The node which was last clicked, and when, for detecting double-clicks.
+    pub(crate) last_click: Option<(Entity, f32)>,
 }
@@ -4,10 +4,18 @@ use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 use bevy_quill_obsidian::{colors, controls::ScrollView};
 
-use crate::{materials::DotGridMaterial, DragAction, DragMode, Gesture, GestureState, GraphEvent};
+use crate::{
+    materials::DotGridMaterial, DragAction, DragMode, Gesture, GestureState, GraphEvent,
+    GraphMinimap,
+};
 
 fn style_node_graph(ss: &mut StyleBuilder) {
-    ss.background_color(colors::U1);
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column);
+}
+
+fn style_node_graph_view(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.).min_height(0).background_color(colors::U1);
 }
 
 fn style_node_graph_scroll(ss: &mut StyleBuilder) {
@@ -15,6 +23,25 @@ fn style_node_graph_scroll(ss: &mut StyleBuilder) {
         .min_height(ui::Val::Percent(100.));
 }
 
+/// How edges between nodes are routed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum EdgeRouting {
+    /// Draw edges as smooth curves.
+    #[default]
+    Bezier,
+
+    /// Draw edges as stepped paths consisting of horizontal and vertical segments.
+    Orthogonal,
+}
+
+/// Context component which makes graph-wide display options available to nested templates.
+#[derive(Clone, Debug, Default, Component)]
+pub(crate) struct GraphDisplayContext {
+    pub(crate) edge_routing: EdgeRouting,
+    /// The scrolling element of the graph.
+    pub(crate) scroll_area: Option<Entity>,
+}
+
 /// An editable graph of nodes, connected by edges.
 #[derive(Default, Clone, PartialEq)]
 pub struct GraphDisplay {
@@ -27,6 +54,12 @@ pub struct GraphDisplay {
     /// Optional entity id to use for the scrolling element. This is useful for querying the
     /// current scroll position.
     pub entity: Option<Entity>,
+
+    /// How edges within the graph are routed.
+    pub edge_routing: EdgeRouting,
+
+    /// Optional minimap, displayed in the corner of the graph.
+    pub minimap: Option<GraphMinimap>,
 }
 
 impl GraphDisplay {
@@ -53,11 +86,32 @@ impl GraphDisplay {
         self.entity = Some(entity);
         self
     }
+
+    /// Set how edges within the graph are routed.
+    pub fn edge_routing(mut self, edge_routing: EdgeRouting) -> Self {
+        self.edge_routing = edge_routing;
+        self
+    }
+
+    /// Set the minimap to display in the corner of the graph.
+    pub fn minimap(mut self, minimap: GraphMinimap) -> Self {
+        self.minimap = Some(minimap);
+        self
+    }
 }
 
 impl ViewTemplate for GraphDisplay {
     type View = impl View;
     fn create(&self, cx: &mut Cx) -> Self::View {
+        let id_scroll_area = match self.entity {
+            Some(entity) => entity,
+            None => cx.create_entity(),
+        };
+        cx.insert(GraphDisplayContext {
+            edge_routing: self.edge_routing,
+            scroll_area: Some(id_scroll_area),
+        });
+
         let material = cx.create_memo(
             |world, _| {
                 let mut ui_materials = world.get_resource_mut::<Assets<DotGridMaterial>>().unwrap();
@@ -69,8 +123,8 @@ impl ViewTemplate for GraphDisplay {
             (),
         );
 
-        ScrollView::new()
-            .entity(self.entity)
+        let scroll_view = ScrollView::new()
+            .entity(Some(id_scroll_area))
             .children(
                 Element::<MaterialNodeBundle<DotGridMaterial>>::new()
                     .named("NodeGraph::Scroll")
@@ -142,8 +196,17 @@ impl ViewTemplate for GraphDisplay {
                     .style(style_node_graph_scroll)
                     .children(self.children.clone()),
             )
-            .style((style_node_graph, self.style.clone()))
+            .style(style_node_graph_view)
             .scroll_enable_x(true)
-            .scroll_enable_y(true)
+            .scroll_enable_y(true);
+
+        Element::<NodeBundle>::new()
+            .named("NodeGraph")
+            .style((style_node_graph, self.style.clone()))
+            .children((
+                scroll_view,
+                // The minimap is outside of the scroll view, so that it stays in place.
+                self.minimap.clone(),
+            ))
     }
 }
@@ -5,6 +5,7 @@ mod edge_display_ls;
 mod events;
 mod graph_display;
 mod materials;
+mod minimap;
 mod node_display;
 mod relative_pos;
 mod terminal_display;
@@ -19,8 +20,9 @@ use bevy::{
 use bevy_mod_picking::prelude::EventListenerPlugin;
 pub use edge_display::EdgeDisplay;
 pub use events::*;
-pub use graph_display::GraphDisplay;
+pub use graph_display::{EdgeRouting, GraphDisplay};
 use materials::{DotGridMaterial, DrawPathMaterial, LineMaterial};
+pub use minimap::GraphMinimap;
 pub use node_display::NodeDisplay;
 pub use terminal_display::{InputTerminalDisplay, NoTerminalDisplay, OutputTerminalDisplay};
 
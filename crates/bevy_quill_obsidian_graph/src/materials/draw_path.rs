@@ -41,6 +41,28 @@ impl DrawablePath {
             .push(DrawablePathSegment::Quadratic((control, point)));
     }
 
+    /// Draw a horizontal line from the current position to the given x-coordinate.
+    pub fn h_line_to(&mut self, x: f32) {
+        let current = self.current_point();
+        self.line_to(Vec2::new(x, current.y));
+    }
+
+    /// Draw a vertical line from the current position to the given y-coordinate.
+    pub fn v_line_to(&mut self, y: f32) {
+        let current = self.current_point();
+        self.line_to(Vec2::new(current.x, y));
+    }
+
+    /// Returns the end point of the last command in the path.
+    fn current_point(&self) -> Vec2 {
+        match self.commands.last() {
+            Some(DrawablePathSegment::Move(point))
+            | Some(DrawablePathSegment::Line(point))
+            | Some(DrawablePathSegment::Quadratic((_, point))) => *point,
+            None => Vec2::ZERO,
+        }
+    }
+
     pub fn bounds(&self) -> Rect {
         if self.commands.is_empty() {
             return Rect::default();
@@ -0,0 +1,175 @@
+use bevy::{prelude::*, ui};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+use bevy_quill_obsidian::{colors, scrolling::ScrollArea};
+
+use crate::{graph_display::GraphDisplayContext, relative_pos::RelativeWorldPositions};
+
+fn style_minimap(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .right(16)
+        .bottom(16)
+        .border(1)
+        .border_color(colors::U4)
+        .background_color(colors::U2.with_alpha(0.85))
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_minimap_node(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .background_color(colors::Y_GREEN)
+        .pointer_events(false);
+}
+
+fn style_minimap_viewport(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .border(1)
+        .border_color(colors::FOREGROUND.with_alpha(0.7))
+        .background_color(colors::FOCUS)
+        .pointer_events(false);
+}
+
+/// A scaled-down overview of an entire [`GraphDisplay`](crate::GraphDisplay), showing the
+/// location of each node and the currently visible region. Clicking on the minimap scrolls
+/// the graph so that the clicked position is centered.
+///
+/// The minimap is added to a graph using [`GraphDisplay::minimap`](crate::GraphDisplay::minimap).
+#[derive(Clone, PartialEq)]
+pub struct GraphMinimap {
+    /// The bounds of each node, in graph coordinates.
+    pub nodes: Vec<Rect>,
+
+    /// The size of the minimap, in pixels.
+    pub size: Vec2,
+
+    /// Additional styles to be applied to the minimap.
+    pub style: StyleHandle,
+}
+
+impl Default for GraphMinimap {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            size: Vec2::new(200., 150.),
+            style: StyleHandle::default(),
+        }
+    }
+}
+
+impl GraphMinimap {
+    /// Create a new graph minimap.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the bounds of the nodes to display, in graph coordinates.
+    pub fn nodes(mut self, nodes: Vec<Rect>) -> Self {
+        self.nodes = nodes;
+        self
+    }
+
+    /// Set the size of the minimap, in pixels.
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set additional styles to be applied to the minimap.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for GraphMinimap {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let scroll_area = cx
+            .use_inherited_component::<GraphDisplayContext>()
+            .and_then(|context| context.scroll_area);
+        let (scroll_pos, content_size, visible_size) = scroll_area
+            .and_then(|id| cx.use_component::<ScrollArea>(id))
+            .map_or((Vec2::ZERO, Vec2::ZERO, Vec2::ZERO), |scroll| {
+                (
+                    scroll.scroll_position(),
+                    scroll.content_size(),
+                    scroll.visible_size(),
+                )
+            });
+
+        // The minimap covers the scrollable content, enlarged to include any nodes that lie
+        // outside of it.
+        let bounds = self.nodes.iter().fold(
+            Rect::from_corners(Vec2::ZERO, content_size),
+            |bounds, node| bounds.union(*node),
+        );
+        let scale = if bounds.is_empty() {
+            1.
+        } else {
+            (self.size.x / bounds.width()).min(self.size.y / bounds.height())
+        };
+        let origin = bounds.min;
+        let to_minimap = move |rect: Rect| {
+            Rect::from_corners((rect.min - origin) * scale, (rect.max - origin) * scale)
+        };
+
+        let nodes: Vec<Rect> = self.nodes.iter().map(|node| to_minimap(*node)).collect();
+        let viewport = to_minimap(Rect::from_corners(scroll_pos, scroll_pos + visible_size));
+
+        Element::<NodeBundle>::new()
+            .named("NodeGraph::Minimap")
+            .style((style_minimap, self.style.clone()))
+            .style_dyn(
+                |size, sb| {
+                    sb.width(size.x).height(size.y);
+                },
+                self.size,
+            )
+            .insert_dyn(
+                move |(origin, scale)| {
+                    On::<Pointer<Click>>::run(
+                        move |mut event: ListenerMut<Pointer<Click>>,
+                              rel: RelativeWorldPositions,
+                              mut q_scroll: Query<&mut ScrollArea>| {
+                            event.stop_propagation();
+                            let Some(mut scroll) =
+                                scroll_area.and_then(|id| q_scroll.get_mut(id).ok())
+                            else {
+                                return;
+                            };
+                            let pos = rel.transform_relative(
+                                event.listener(),
+                                event.pointer_location.position,
+                                0,
+                            );
+                            let center = origin + pos / scale;
+                            let visible = scroll.visible_size();
+                            scroll
+                                .scroll_to(center.x - visible.x * 0.5, center.y - visible.y * 0.5);
+                        },
+                    )
+                },
+                (origin, scale),
+            )
+            .children((
+                For::each(nodes, |node| {
+                    Element::<NodeBundle>::new()
+                        .style(style_minimap_node)
+                        .style_dyn(place_rect, *node)
+                }),
+                Element::<NodeBundle>::new()
+                    .named("NodeGraph::Minimap::Viewport")
+                    .style(style_minimap_viewport)
+                    .style_dyn(place_rect, viewport),
+            ))
+    }
+}
+
+/// Position an absolutely-positioned element so that it covers `rect`.
+fn place_rect(rect: Rect, sb: &mut StyleBuilder) {
+    sb.left(rect.min.x)
+        .top(rect.min.y)
+        .width(rect.width())
+        .height(rect.height());
+}
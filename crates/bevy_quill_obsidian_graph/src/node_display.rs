@@ -1,6 +1,6 @@
 use std::ops::Mul;
 
-use bevy::{prelude::*, ui};
+use bevy::{prelude::*, ui, window::SystemCursorIcon};
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::{prelude::*, IntoViewChild, ViewChild};
@@ -10,7 +10,9 @@ use bevy_quill_obsidian::{
     hooks::{UseElementRect, UseIsHover},
 };
 
-use crate::{DragAction, DragMode, Gesture, GestureState, GraphEvent};
+use crate::{
+    ConnectionAnchor, ConnectionTarget, DragAction, DragMode, Gesture, GestureState, GraphEvent,
+};
 
 fn style_node_graph_node(ss: &mut StyleBuilder) {
     ss.display(ui::Display::Flex)
@@ -21,7 +23,11 @@ fn style_node_graph_node(ss: &mut StyleBuilder) {
 }
 
 const NODE_BORDER_RADIUS: f32 = 5.;
+/// Maximum time between clicks, in seconds, for them to count as a double-click.
+const DOUBLE_CLICK_TIME: f32 = 0.4;
 const NODE_BORDER_WIDTH: f32 = 1.;
+/// Minimum size of a resizable node.
+const MIN_NODE_SIZE: Vec2 = Vec2::new(60., 40.);
 
 fn style_node_graph_node_title(ss: &mut StyleBuilder) {
     ss.border(1)
@@ -40,7 +46,7 @@ fn style_node_graph_node_title(ss: &mut StyleBuilder) {
         })
         .background_color(colors::Y_GREEN.darker(0.05))
         .padding((6, 2))
-        .cursor(CursorIcon::Grab);
+        .cursor(SystemCursorIcon::Grab);
 }
 
 fn style_node_graph_node_content(ss: &mut StyleBuilder) {
@@ -63,9 +69,32 @@ fn style_node_graph_node_content(ss: &mut StyleBuilder) {
             bottom_right: ui::Val::Px(NODE_BORDER_RADIUS),
         })
         .background_color(colors::U2)
+        .flex_grow(1.)
         .padding((0, 6));
 }
 
+fn style_node_graph_node_resize_handle(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .right(0)
+        .bottom(0)
+        .width(10)
+        .height(10)
+        .border(ui::UiRect {
+            left: ui::Val::Px(0.),
+            right: ui::Val::Px(2.),
+            top: ui::Val::Px(0.),
+            bottom: ui::Val::Px(2.),
+        })
+        .border_color(colors::U4)
+        .border_radius(ui::BorderRadius {
+            top_left: ui::Val::Px(0.),
+            top_right: ui::Val::Px(0.),
+            bottom_left: ui::Val::Px(0.),
+            bottom_right: ui::Val::Px(NODE_BORDER_RADIUS),
+        })
+        .cursor(SystemCursorIcon::NwseResize);
+}
+
 fn style_node_graph_node_shadow(ss: &mut StyleBuilder) {
     ss.position(ui::PositionType::Absolute)
         .left(-3)
@@ -104,6 +133,10 @@ pub struct NodeDisplay {
     pub title: String,
     /// Whether the node is currently selected.
     pub selected: bool,
+    /// Whether the node displays a handle which lets the user resize it.
+    pub resizable: bool,
+    /// Explicit size of the node. If `None`, the size is determined by the content.
+    pub size: Option<Vec2>,
     /// The content of the node.
     pub children: ViewChild,
 }
@@ -118,6 +151,8 @@ impl NodeDisplay {
             title: default(),
             width: ui::Val::Auto,
             selected: false,
+            resizable: false,
+            size: None,
             children: default(),
         }
     }
@@ -140,6 +175,20 @@ impl NodeDisplay {
         self
     }
 
+    /// Set whether the node can be resized by the user. Resizing sends a [`Gesture::Resize`]
+    /// event; the app is expected to store the new size and pass it back via
+    /// [`NodeDisplay::size`].
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Set the explicit size of the node.
+    pub fn size(mut self, size: Option<Vec2>) -> Self {
+        self.size = size;
+        self
+    }
+
     /// Set the title of the node.
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = title.into();
@@ -165,15 +214,28 @@ impl ViewTemplate for NodeDisplay {
         Element::<NodeBundle>::for_entity(display_id)
             .named("NodeGraph::Node")
             .style(style_node_graph_node)
+            .style_dyn(
+                |size, sb| match size {
+                    Some(size) => {
+                        sb.width(size.x).height(size.y);
+                    }
+                    None => {
+                        sb.width(ui::Val::Auto).height(ui::Val::Auto);
+                    }
+                },
+                self.size,
+            )
             .insert_dyn(move |_| node_event_handlers(display_id, node_id), ())
             .effect(
                 move |cx, ent, (position, size)| {
                     if size.x > 0 && size.y > 0 {
-                        let mut style = cx.world_mut().get_mut::<Style>(ent).unwrap();
-                        style.left = ui::Val::Px((position.x - size.x) as f32);
-                        style.top = ui::Val::Px((position.y - size.y) as f32);
-                        let mut visibility = cx.world_mut().get_mut::<Visibility>(ent).unwrap();
-                        *visibility = Visibility::Visible;
+                        cx.with_world_mut(|world| {
+                            let mut style = world.get_mut::<Style>(ent).unwrap();
+                            style.left = ui::Val::Px((position.x - size.x) as f32);
+                            style.top = ui::Val::Px((position.y - size.y) as f32);
+                            let mut visibility = world.get_mut::<Visibility>(ent).unwrap();
+                            *visibility = Visibility::Visible;
+                        });
                     }
                 },
                 (position, rect.size().mul(0.5).as_ivec2()),
@@ -216,6 +278,17 @@ impl ViewTemplate for NodeDisplay {
                         self.width,
                     )
                     .children(self.children.clone()),
+                Cond::new(
+                    self.resizable,
+                    Element::<NodeBundle>::new()
+                        .named("NodeGraph::Node::ResizeHandle")
+                        .style(style_node_graph_node_resize_handle)
+                        .insert_dyn(
+                            move |_| resize_handle_event_handlers(display_id, node_id),
+                            (),
+                        ),
+                    (),
+                ),
                 Cond::new(
                     hovering,
                     Element::<NodeBundle>::new()
@@ -228,13 +301,35 @@ impl ViewTemplate for NodeDisplay {
 }
 
 #[allow(clippy::type_complexity)]
-fn node_event_handlers(id: Entity, node_id: Entity) -> (On<Pointer<Down>>, On<Pointer<DragStart>>) {
+fn node_event_handlers(
+    id: Entity,
+    node_id: Entity,
+) -> (
+    On<Pointer<Down>>,
+    On<Pointer<DragStart>>,
+    On<Pointer<DragEnter>>,
+    On<Pointer<DragLeave>>,
+) {
     (
         On::<Pointer<Down>>::run(
             move |mut event: ListenerMut<Pointer<Down>>,
                   mut writer: EventWriter<GraphEvent>,
-                  keys: Res<ButtonInput<KeyCode>>| {
+                  mut gesture_state: ResMut<GestureState>,
+                  keys: Res<ButtonInput<KeyCode>>,
+                  time: Res<Time>| {
                 event.stop_propagation();
+                let now = time.elapsed_seconds();
+                if let Some((last_node, last_time)) = gesture_state.last_click {
+                    if last_node == node_id && now - last_time < DOUBLE_CLICK_TIME {
+                        gesture_state.last_click = None;
+                        writer.send(GraphEvent {
+                            target: id,
+                            gesture: Gesture::Open(node_id),
+                        });
+                        return;
+                    }
+                }
+                gesture_state.last_click = Some((node_id, now));
                 let is_shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
                 let is_ctrl =
                     keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight, KeyCode::Meta]);
@@ -259,6 +354,59 @@ fn node_event_handlers(id: Entity, node_id: Entity) -> (On<Pointer<Down>>, On<Po
         On::<Pointer<DragStart>>::run(move |mut event: ListenerMut<Pointer<DragStart>>| {
             event.stop_propagation();
         }),
+        On::<Pointer<DragEnter>>::run(
+            move |mut event: ListenerMut<Pointer<DragEnter>>,
+                  mut gesture_state: ResMut<GestureState>,
+                  mut writer: EventWriter<GraphEvent>| {
+                if gesture_state.mode != DragMode::Connect {
+                    return;
+                }
+                event.stop_propagation();
+                // Dropping a connection on the node body lets the user pick an output terminal.
+                if let Some(
+                    anchor @ (ConnectionAnchor::InputTerminal(_) | ConnectionAnchor::EdgeSource(_)),
+                ) = gesture_state.anchor
+                {
+                    #[cfg(feature = "verbose")]
+                    info!("Node::DragEnter: {}", event.target());
+                    gesture_state.target = ConnectionTarget::NodeBody(node_id);
+                    writer.send(GraphEvent {
+                        target: id,
+                        gesture: Gesture::Connect(anchor, gesture_state.target, DragAction::Update),
+                    });
+                }
+            },
+        ),
+        On::<Pointer<DragLeave>>::run(
+            move |mut event: ListenerMut<Pointer<DragLeave>>,
+                  mut gesture_state: ResMut<GestureState>,
+                  mut writer: EventWriter<GraphEvent>,
+                  rel: crate::relative_pos::RelativeWorldPositions| {
+                if gesture_state.mode != DragMode::Connect {
+                    return;
+                }
+                event.stop_propagation();
+                if gesture_state.target == ConnectionTarget::NodeBody(node_id) {
+                    #[cfg(feature = "verbose")]
+                    info!("Node::DragLeave: {}", event.target());
+                    gesture_state.target = ConnectionTarget::Location(rel.transform_relative(
+                        id,
+                        event.pointer_location.position,
+                        1,
+                    ));
+                    if let Some(anchor) = gesture_state.anchor {
+                        writer.send(GraphEvent {
+                            target: id,
+                            gesture: Gesture::Connect(
+                                anchor,
+                                gesture_state.target,
+                                DragAction::Update,
+                            ),
+                        });
+                    }
+                }
+            },
+        ),
     )
 }
 
@@ -312,3 +460,65 @@ fn title_event_handlers(
         }),
     )
 }
+
+#[allow(clippy::type_complexity)]
+fn resize_handle_event_handlers(
+    id: Entity,
+    node_id: Entity,
+) -> (
+    On<Pointer<DragStart>>,
+    On<Pointer<DragEnd>>,
+    On<Pointer<Drag>>,
+) {
+    (
+        On::<Pointer<DragStart>>::run(
+            move |mut event: ListenerMut<Pointer<DragStart>>,
+                  mut gesture_state: ResMut<GestureState>,
+                  mut writer: EventWriter<GraphEvent>,
+                  query_node: Query<&Node>| {
+                event.stop_propagation();
+                let size = query_node.get(id).map_or(MIN_NODE_SIZE, |node| node.size());
+                gesture_state.mode = DragMode::Resize(size);
+                writer.send(GraphEvent {
+                    target: id,
+                    gesture: Gesture::Resize(node_id, size.max(MIN_NODE_SIZE), DragAction::Start),
+                });
+            },
+        ),
+        On::<Pointer<DragEnd>>::run(
+            move |mut event: ListenerMut<Pointer<DragEnd>>,
+                  mut gesture_state: ResMut<GestureState>,
+                  mut writer: EventWriter<GraphEvent>| {
+                event.stop_propagation();
+                if let DragMode::Resize(size) = gesture_state.mode {
+                    gesture_state.mode = DragMode::None;
+                    writer.send(GraphEvent {
+                        target: id,
+                        gesture: Gesture::Resize(
+                            node_id,
+                            (size + event.distance).max(MIN_NODE_SIZE),
+                            DragAction::Finish,
+                        ),
+                    });
+                }
+            },
+        ),
+        On::<Pointer<Drag>>::run(
+            move |mut event: ListenerMut<Pointer<Drag>>,
+                  gesture_state: Res<GestureState>,
+                  mut writer: EventWriter<GraphEvent>| {
+                event.stop_propagation();
+                if let DragMode::Resize(size) = gesture_state.mode {
+                    writer.send(GraphEvent {
+                        target: id,
+                        gesture: Gesture::Resize(
+                            node_id,
+                            (size + event.distance).max(MIN_NODE_SIZE),
+                            DragAction::Update,
+                        ),
+                    });
+                }
+            },
+        ),
+    )
+}
@@ -1,4 +1,4 @@
-use bevy::{prelude::*, ui};
+use bevy::{prelude::*, ui, window::SystemCursorIcon};
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::{prelude::*, ViewChild};
@@ -28,7 +28,7 @@ fn style_terminal_hitbox(ss: &mut StyleBuilder) {
         .bottom(-3)
         .border_radius(8)
         .pointer_events(true)
-        .cursor(CursorIcon::Copy);
+        .cursor(SystemCursorIcon::Copy);
 }
 
 fn style_input_connector(ss: &mut StyleBuilder) {
@@ -37,6 +37,13 @@ fn style_input_connector(ss: &mut StyleBuilder) {
         .padding((8, 0));
 }
 
+fn style_input_label(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .min_height(20);
+}
+
 fn style_input_terminal(ss: &mut StyleBuilder) {
     ss.position(ui::PositionType::Absolute)
         .left(-4)
@@ -56,6 +63,58 @@ pub struct InputTerminalDisplay {
     pub color: Srgba,
     /// Control rendered when the input is not connected.
     pub control: ViewChild,
+    /// Label rendered in place of the control when the input is connected.
+    pub label: String,
+    /// Whether the input is connected. When connected, the inline control is hidden, since
+    /// the value comes from the connection.
+    pub connected: bool,
+    /// If true, the terminal is grayed out to indicate that it cannot accept the connection
+    /// which is currently being dragged.
+    pub incompatible: bool,
+}
+
+impl InputTerminalDisplay {
+    /// Create a new input terminal display.
+    pub fn new(id: Entity) -> Self {
+        Self {
+            id,
+            color: colors::U4,
+            control: default(),
+            label: default(),
+            connected: false,
+            incompatible: false,
+        }
+    }
+
+    /// Set the color of the connector terminal.
+    pub fn color(mut self, color: Srgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the control rendered when the input is not connected.
+    pub fn control(mut self, control: impl IntoViewChild) -> Self {
+        self.control = control.into_view_child();
+        self
+    }
+
+    /// Set the label rendered in place of the control when the input is connected.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Set whether the input is connected.
+    pub fn connected(mut self, connected: bool) -> Self {
+        self.connected = connected;
+        self
+    }
+
+    /// Set whether the terminal is incompatible with the connection being dragged.
+    pub fn incompatible(mut self, incompatible: bool) -> Self {
+        self.incompatible = incompatible;
+        self
+    }
 }
 
 impl ViewTemplate for InputTerminalDisplay {
@@ -63,8 +122,8 @@ impl ViewTemplate for InputTerminalDisplay {
 
     fn create(&self, cx: &mut Cx) -> Self::View {
         let id = self.id;
-        let is_hover = cx.is_hovered(id);
-        let color = self.color;
+        let is_hover = cx.is_hovered(id) && !self.incompatible;
+        let color = terminal_color(self.color, self.incompatible);
         Element::<NodeBundle>::new()
             .named("InputTerminal")
             .style(style_input_connector)
@@ -82,7 +141,13 @@ impl ViewTemplate for InputTerminalDisplay {
                             (),
                         ),
                     )),
-                self.control.clone(),
+                Cond::new(
+                    self.connected,
+                    Element::<NodeBundle>::new()
+                        .style(style_input_label)
+                        .children(self.label.clone()),
+                    self.control.clone(),
+                ),
             ))
     }
 }
@@ -115,6 +180,9 @@ pub struct OutputTerminalDisplay {
     pub color: Srgba,
     /// The name of the output.
     pub label: String,
+    /// If true, the terminal is grayed out to indicate that it cannot accept the connection
+    /// which is currently being dragged.
+    pub incompatible: bool,
 }
 
 impl ViewTemplate for OutputTerminalDisplay {
@@ -122,8 +190,8 @@ impl ViewTemplate for OutputTerminalDisplay {
 
     fn create(&self, cx: &mut Cx) -> Self::View {
         let id = self.id;
-        let is_hover = cx.is_hovered(id);
-        let color = self.color;
+        let is_hover = cx.is_hovered(id) && !self.incompatible;
+        let color = terminal_color(self.color, self.incompatible);
         Element::<NodeBundle>::new()
             .named("OutputTerminal")
             .style(style_output_connector)
@@ -146,6 +214,15 @@ impl ViewTemplate for OutputTerminalDisplay {
     }
 }
 
+/// Returns the display color of a terminal, grayed out if it is not a valid drop target.
+fn terminal_color(color: Srgba, incompatible: bool) -> Srgba {
+    if incompatible {
+        color.mix(&colors::U2, 0.7).with_alpha(0.5)
+    } else {
+        color
+    }
+}
+
 fn style_no_connector(ss: &mut StyleBuilder) {
     ss.display(ui::Display::Flex)
         .flex_direction(ui::FlexDirection::Row)
@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use bevy::prelude::{In, World};
+use bevy::prelude::{In, Transform, World};
 use bevy::reflect::{ParsedPath, ReflectKind};
 use bevy_quill_core::*;
 use bevy_quill_obsidian::{
@@ -9,6 +9,7 @@ use bevy_quill_obsidian::{
 use crate::{
     inspectors::{
         r#struct::{StructFieldList, StructInspectorHeaderControls},
+        transform::TransformFieldList,
         tuple_struct::TupleStructElements,
     },
     templates::inspector_panel::InspectorPanel,
@@ -34,7 +35,11 @@ impl Inspector {
 
     fn create_fields(&self, cx: &mut Cx, inspectable: Arc<Inspectable>) -> ViewChild {
         let access = inspectable.clone();
-        let field_type = access.reflect(cx).unwrap().reflect_kind().to_owned();
+        let reflect = access.reflect(cx).unwrap();
+        if reflect.is::<Transform>() {
+            return TransformFieldList(inspectable.clone()).into_view_child();
+        }
+        let field_type = reflect.reflect_kind().to_owned();
         match field_type {
             ReflectKind::Struct => StructFieldList(inspectable.clone()).into_view_child(),
             ReflectKind::TupleStruct => TupleStructElements(inspectable.clone()).into_view_child(),
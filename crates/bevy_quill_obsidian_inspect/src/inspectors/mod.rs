@@ -5,5 +5,6 @@ pub mod r#f32;
 pub mod fallback;
 pub mod list;
 pub mod r#struct;
+pub mod transform;
 pub mod tuple_struct;
 pub mod vec3;
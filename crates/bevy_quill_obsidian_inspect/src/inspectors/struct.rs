@@ -30,7 +30,7 @@ impl PartialEq for NestedStruct {
     }
 }
 
-fn style_field_list(ss: &mut StyleBuilder) {
+pub(crate) fn style_field_list(ss: &mut StyleBuilder) {
     ss.display(ui::Display::Grid)
         .grid_auto_flow(ui::GridAutoFlow::Row)
         .grid_template_columns(vec![
@@ -0,0 +1,344 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::system::SystemState,
+    prelude::*,
+    reflect::{Access, OffsetAccess},
+    ui,
+};
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+use bevy_quill_obsidian::{
+    colors,
+    controls::{Button, DisclosureToggle, SpinBox},
+    size::Size,
+};
+
+use crate::{
+    inspectors::r#struct::style_field_list,
+    templates::field_label::{FieldLabel, FieldLabelWide},
+    Inspectable, InspectorFactory,
+};
+
+/// Inspector factory which creates a [`TransformInspector`] for fields of type [`Transform`].
+/// This must be registered before the default factory, otherwise transforms will be displayed
+/// as ordinary structs.
+#[derive(Default)]
+pub struct TransformInspectorFactory;
+
+impl InspectorFactory for TransformInspectorFactory {
+    fn create_inspector(&self, cx: &Cx, field: Arc<Inspectable>) -> Option<ViewChild> {
+        if field.reflect(cx)?.is::<Transform>() {
+            Some(TransformInspector(field.clone()).into_view_child())
+        } else {
+            None
+        }
+    }
+}
+
+/// Component which draws the axes of an inspected [`Transform`] in the 3D viewport. Spawned by
+/// the "Show Gizmo" button of the [`TransformInspector`].
+#[derive(Component)]
+pub struct TransformGizmo {
+    /// The transform being visualized.
+    pub(crate) target: Arc<Inspectable>,
+}
+
+/// System which draws all active [`TransformGizmo`]s.
+pub(crate) fn draw_transform_gizmos(world: &mut World) {
+    let mut q_gizmos = world.query::<&TransformGizmo>();
+    let transforms: Vec<Transform> = q_gizmos
+        .iter(world)
+        .filter_map(|gizmo| {
+            gizmo
+                .target
+                .reflect_untracked(world)
+                .and_then(|reflect| reflect.downcast_ref::<Transform>())
+                .copied()
+        })
+        .collect();
+    if transforms.is_empty() {
+        return;
+    }
+    let mut st: SystemState<Gizmos> = SystemState::new(world);
+    let mut gizmos = st.get_mut(world);
+    for transform in transforms {
+        gizmos.axes(transform, 1.0);
+    }
+    st.apply(world);
+}
+
+/// Inspector for a [`Transform`] field: displays translation, rotation as euler angles in
+/// degrees, and scale.
+#[derive(Clone)]
+pub struct TransformInspector(pub(crate) Arc<Inspectable>);
+
+impl PartialEq for TransformInspector {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl ViewTemplate for TransformInspector {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let field = self.0.clone();
+        let expanded = cx.create_mutable(false);
+
+        (
+            FieldLabelWide::new(field.clone()).name((
+                DisclosureToggle::new()
+                    .size(Size::Xs)
+                    .expanded(expanded.get(cx))
+                    .on_change(
+                        cx.create_callback(move |value: In<bool>, world: &mut World| {
+                            expanded.set(world, *value);
+                        }),
+                    ),
+                field.name.clone(),
+            )),
+            Cond::new(
+                expanded.get(cx),
+                Element::<NodeBundle>::new()
+                    .style(style_field_list)
+                    .children(TransformFieldList(self.0.clone())),
+                (),
+            ),
+        )
+    }
+}
+
+fn style_spinbox_group(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .justify_content(ui::JustifyContent::FlexStart)
+        .column_gap(3);
+}
+
+fn style_spinbox(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.);
+}
+
+fn style_axis_label(ss: &mut StyleBuilder) {
+    ss.font_size(12).color(colors::DIM);
+}
+
+fn style_gizmo_row(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .justify_content(ui::JustifyContent::FlexEnd)
+        .grid_column_span(2);
+}
+
+const AXIS_NAMES: [&str; 3] = ["X", "Y", "Z"];
+
+/// The rows of a [`TransformInspector`]. Also used to display a [`Transform`] at the top
+/// level of an [`Inspector`](crate::Inspector).
+#[derive(Clone)]
+pub struct TransformFieldList(pub Arc<Inspectable>);
+
+impl PartialEq for TransformFieldList {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl ViewTemplate for TransformFieldList {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let field = self.0.clone();
+        let transform = match field.reflect(cx) {
+            Some(value) if value.is::<Transform>() => *value.downcast_ref::<Transform>().unwrap(),
+            _ => Transform::IDENTITY,
+        };
+        let capture = cx.create_capture(transform);
+        let (rx, ry, rz) = transform.rotation.to_euler(EulerRot::XYZ);
+        let scale_locked = cx.create_mutable(
+            transform.scale.x == transform.scale.y && transform.scale.y == transform.scale.z,
+        );
+        let locked = scale_locked.get(cx);
+
+        // Entity which carries the gizmo while it is shown; despawned with the inspector.
+        let gizmo_id = cx.create_entity();
+        let show_gizmo = cx.use_component::<TransformGizmo>(gizmo_id).is_some();
+        let toggle_gizmo = cx.create_callback({
+            let field = field.clone();
+            move |world: &mut World| {
+                let mut entt = world.entity_mut(gizmo_id);
+                if entt.contains::<TransformGizmo>() {
+                    entt.remove::<TransformGizmo>();
+                } else {
+                    entt.insert(TransformGizmo {
+                        target: field.clone(),
+                    });
+                }
+            }
+        });
+
+        let translation_row = Element::<NodeBundle>::new()
+            .style(style_spinbox_group)
+            .children(vec3_fields(
+                cx,
+                &field,
+                capture,
+                transform.translation,
+                2,
+                0.1,
+                |t, axis, v| t.translation[axis] = v,
+            ));
+
+        let rotation_row = Element::<NodeBundle>::new()
+            .style(style_spinbox_group)
+            .children(vec3_fields(
+                cx,
+                &field,
+                capture,
+                Vec3::new(rx.to_degrees(), ry.to_degrees(), rz.to_degrees()),
+                1,
+                1.0,
+                set_euler_angle,
+            ));
+
+        let scale_fields = vec3_fields(
+            cx,
+            &field,
+            capture,
+            transform.scale,
+            2,
+            0.1,
+            |t, axis, v| t.scale[axis] = v,
+        );
+        let scale_row = Element::<NodeBundle>::new()
+            .style(style_spinbox_group)
+            .children((
+                Cond::new(
+                    locked,
+                    SpinBox::new()
+                        .style(style_spinbox)
+                        .precision(2)
+                        .step(0.1)
+                        .value(transform.scale.x)
+                        .on_change(update_callback(cx, &field, capture, |t, v| {
+                            // Scale all axes proportionally, preserving the aspect ratio.
+                            if t.scale.x.abs() > f32::EPSILON {
+                                t.scale *= v / t.scale.x;
+                            } else {
+                                t.scale = Vec3::splat(v);
+                            }
+                        })),
+                    Element::<NodeBundle>::new()
+                        .style((style_spinbox_group, style_spinbox))
+                        .children(scale_fields),
+                ),
+                Button::new()
+                    .size(Size::Xs)
+                    .selected(locked)
+                    .icon_left("embedded://bevy_quill_obsidian/assets/icons/lock.png")
+                    .on_click(cx.create_callback(move |world: &mut World| {
+                        scale_locked.update(world, |mut locked| {
+                            *locked = !*locked;
+                        });
+                    })),
+            ));
+
+        (
+            FieldLabel {
+                field: child_field(&field, "translation"),
+            },
+            translation_row,
+            FieldLabel {
+                field: child_field(&field, "rotation"),
+            },
+            rotation_row,
+            FieldLabel {
+                field: child_field(&field, "scale"),
+            },
+            scale_row,
+            Element::<NodeBundle>::new()
+                .style(style_gizmo_row)
+                .children(
+                    Button::new()
+                        .size(Size::Xs)
+                        .selected(show_gizmo)
+                        .children(if show_gizmo {
+                            "Hide Gizmo"
+                        } else {
+                            "Show Gizmo"
+                        })
+                        .on_click(toggle_gizmo),
+                ),
+        )
+    }
+}
+
+/// Three labeled spinboxes for editing the X, Y and Z components of a vector within the
+/// transform. `set` writes a single component back into the transform.
+fn vec3_fields(
+    cx: &mut Cx,
+    field: &Arc<Inspectable>,
+    capture: Mutable<Transform>,
+    value: Vec3,
+    precision: usize,
+    step: f32,
+    set: fn(&mut Transform, usize, f32),
+) -> impl View {
+    let mut axis_field = |axis: usize| {
+        (
+            Element::<NodeBundle>::new()
+                .style(style_axis_label)
+                .children(AXIS_NAMES[axis]),
+            SpinBox::new()
+                .style(style_spinbox)
+                .precision(precision)
+                .step(step)
+                .value(value[axis])
+                .on_change(update_callback(cx, field, capture, move |t, v| {
+                    set(t, axis, v)
+                })),
+        )
+    };
+    (axis_field(0), axis_field(1), axis_field(2))
+}
+
+/// Create a callback which modifies the inspected transform.
+fn update_callback(
+    cx: &mut Cx,
+    field: &Arc<Inspectable>,
+    capture: Mutable<Transform>,
+    update: impl Fn(&mut Transform, f32) + Send + Sync + 'static,
+) -> Callback<f32> {
+    let field = field.clone();
+    cx.create_callback(move |value: In<f32>, world: &mut World| {
+        let mut transform = capture.get(world);
+        update(&mut transform, *value);
+        field.update(world, &|reflect| {
+            reflect.apply(transform.as_reflect());
+        });
+    })
+}
+
+/// Replace one of the euler angles (in degrees) of a transform's rotation.
+fn set_euler_angle(transform: &mut Transform, axis: usize, degrees: f32) {
+    let (x, y, z) = transform.rotation.to_euler(EulerRot::XYZ);
+    let mut angles = [x, y, z];
+    angles[axis] = degrees.to_radians();
+    transform.rotation = Quat::from_euler(EulerRot::XYZ, angles[0], angles[1], angles[2]);
+}
+
+/// Returns an inspectable for a field of the transform, used for the row labels.
+fn child_field(field: &Arc<Inspectable>, name: &str) -> Arc<Inspectable> {
+    let mut path = field.value_path.clone();
+    path.0.push(OffsetAccess {
+        access: Access::Field(name.to_string().into()),
+        offset: None,
+    });
+    Arc::new(Inspectable {
+        root: field.root.clone(),
+        name: name.to_string(),
+        value_path: path.clone(),
+        field_path: path,
+        can_remove: false,
+        attributes: None,
+    })
+}
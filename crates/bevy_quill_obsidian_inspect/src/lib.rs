@@ -7,18 +7,22 @@ mod inspector_factory;
 mod inspectors;
 mod templates;
 
-use bevy::app::{App, Plugin};
+use bevy::app::{App, Plugin, Update};
 use default_factory::DefaultInspectorFactory;
+use inspectors::transform::draw_transform_gizmos;
 
 pub use attributes::*;
 pub use inspectable::*;
 pub use inspector::*;
 pub use inspector_factory::*;
+pub use inspectors::transform::{TransformGizmo, TransformInspector, TransformInspectorFactory};
 
 pub struct InspectorPlugin;
 
 impl Plugin for InspectorPlugin {
     fn build(&self, app: &mut App) {
-        app.register_inspector::<DefaultInspectorFactory>();
+        app.register_inspector::<TransformInspectorFactory>()
+            .register_inspector::<DefaultInspectorFactory>()
+            .add_systems(Update, draw_transform_gizmos);
     }
 }
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+/// Distance from the camera at which a label is drawn at its nominal font size. Labels which
+/// are further away are drawn smaller, and labels which are closer are drawn larger.
+const LABEL_REFERENCE_DISTANCE: f32 = 10.;
+
+/// Component which attaches a text label to an overlay. The label is rendered as a UI text
+/// node, positioned at the screen-space projection of the overlay's origin plus `offset`, so
+/// it always faces the camera.
+#[derive(Component, Clone, PartialEq, Debug)]
+pub struct OverlayLabel {
+    /// The text of the label.
+    pub text: String,
+    /// Offset of the label from the overlay origin, in the overlay's local coordinates.
+    pub offset: Vec3,
+    /// Font size of the label at the reference distance.
+    pub size: f32,
+    /// Color of the label text.
+    pub color: Color,
+}
+
+/// Links the text node which displays a label to the overlay entity that it annotates.
+#[derive(Component)]
+pub(crate) struct OverlayLabelNode {
+    anchor: Entity,
+}
+
+/// System which spawns, positions and despawns the text nodes for overlay labels.
+pub(crate) fn update_overlay_labels(
+    mut commands: Commands,
+    q_labels: Query<(Entity, Ref<OverlayLabel>, &GlobalTransform)>,
+    mut q_nodes: Query<(
+        Entity,
+        &OverlayLabelNode,
+        &mut Node,
+        &mut Text,
+        &mut TextFont,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+    q_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+) {
+    let camera = q_camera.iter().find(|(camera, _)| camera.is_active);
+    let mut has_node: Vec<Entity> = Vec::new();
+
+    for (node_id, label_node, mut node, mut text, mut font, mut color, mut visibility) in
+        q_nodes.iter_mut()
+    {
+        // Despawn text nodes whose overlay has gone away.
+        let Ok((_, label, transform)) = q_labels.get(label_node.anchor) else {
+            commands.entity(node_id).despawn();
+            continue;
+        };
+        has_node.push(label_node.anchor);
+
+        if label.is_changed() {
+            text.0.clone_from(&label.text);
+            color.0 = label.color;
+        }
+
+        let world_pos = transform.transform_point(label.offset);
+        let projected = camera.and_then(|(camera, camera_transform)| {
+            camera
+                .world_to_viewport(camera_transform, world_pos)
+                .ok()
+                .map(|pos| (pos, camera_transform.translation().distance(world_pos)))
+        });
+        match projected {
+            Some((pos, distance)) => {
+                node.left = Val::Px(pos.x);
+                node.top = Val::Px(pos.y);
+                font.font_size = label.size * LABEL_REFERENCE_DISTANCE / distance.max(0.01);
+                *visibility = Visibility::Inherited;
+            }
+            None => {
+                // Label is behind the camera, or there is no camera.
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+
+    // Spawn text nodes for new labels. They will be positioned on the next update.
+    for (anchor, label, _) in q_labels.iter() {
+        if !has_node.contains(&anchor) {
+            commands.spawn((
+                Text(label.text.clone()),
+                TextFont {
+                    font_size: label.size,
+                    ..default()
+                },
+                TextColor(label.color),
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                Visibility::Hidden,
+                OverlayLabelNode { anchor },
+            ));
+        }
+    }
+}
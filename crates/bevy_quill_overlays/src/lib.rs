@@ -1,10 +1,18 @@
+mod label;
 mod lines_builder;
 mod mesh_builder;
 mod overlay;
 mod overlay_material;
 mod shape_builder;
 
-use bevy::{app::Plugin, asset::embedded_asset, pbr::MaterialPlugin};
+use bevy::{
+    app::{Plugin, PostUpdate},
+    asset::embedded_asset,
+    pbr::MaterialPlugin,
+    prelude::IntoSystemConfigs,
+    transform::TransformSystem,
+};
+pub use label::OverlayLabel;
 pub use lines_builder::LinesBuilder;
 pub use overlay::Overlay;
 pub use shape_builder::{PolygonOptions, ShapeBuilder, ShapeOrientation, StrokeMarker};
@@ -22,6 +30,10 @@ impl Plugin for QuillOverlaysPlugin {
         app.add_plugins((
             MaterialPlugin::<OverlayMaterial>::default(),
             MaterialPlugin::<UnderlayMaterial>::default(),
-        ));
+        ))
+        .add_systems(
+            PostUpdate,
+            label::update_overlay_labels.after(TransformSystem::TransformPropagate),
+        );
     }
 }
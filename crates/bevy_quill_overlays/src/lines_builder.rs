@@ -55,6 +55,7 @@ impl LinesBuilder {
     /// `a` - The start of the line segment.
     /// `b` - The end of the line segment.
     pub fn line(&mut self, a: Vec3, b: Vec3) -> &mut Self {
+        self.reserve(2, 2);
         let i1 = self.push_vec3_index(a);
         let i2 = self.push_vec3_index(b);
         self.push_index(i1);
@@ -67,6 +68,8 @@ impl LinesBuilder {
     /// Arguments:
     /// `vertices` - The list of vertices.
     pub fn polyline(&mut self, vertices: &[Vec3]) -> &mut Self {
+        let segments = vertices.len().saturating_sub(1);
+        self.reserve(segments * 2, segments * 2);
         for i in 0..(vertices.len() - 1) {
             let i1 = self.push_vec3_index(vertices[i]);
             let i2 = self.push_vec3_index(vertices[i + 1]);
@@ -89,9 +92,15 @@ impl MeshBuilder for LinesBuilder {
         PrimitiveTopology::LineList
     }
 
-    /// Copy the shape into a [`Mesh`]. This will consume the builder and return a mesh.
-    fn build(self, mesh: &mut Mesh) {
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.vertices);
-        mesh.insert_indices(Indices::U32(self.indices));
+    fn clear(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+    }
+
+    /// Copy the shape into a [`Mesh`]. The builder keeps its buffers, so it can be cleared
+    /// and reused for the next rebuild.
+    fn build(&self, mesh: &mut Mesh) {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.vertices.clone());
+        mesh.insert_indices(Indices::U32(self.indices.clone()));
     }
 }
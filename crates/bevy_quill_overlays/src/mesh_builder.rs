@@ -4,6 +4,11 @@ use bevy::render::mesh::{Mesh, PrimitiveTopology};
 pub trait MeshBuilder {
     fn topology() -> PrimitiveTopology;
 
-    /// Build the mesh, consuming the builder.
-    fn build(self, mesh: &mut Mesh);
+    /// Remove all vertices and indices, keeping the allocated capacity so that the builder
+    /// can be reused.
+    fn clear(&mut self);
+
+    /// Copy the shape into a [`Mesh`]. Builders which support vertex colors write them to
+    /// the `ATTRIBUTE_COLOR` buffer, and remove that buffer if no colors were used.
+    fn build(&self, mesh: &mut Mesh);
 }
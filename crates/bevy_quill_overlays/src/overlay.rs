@@ -4,12 +4,15 @@ use bevy::{
     prelude::*,
     render::{mesh::PrimitiveTopology, render_asset::RenderAssetUsages},
 };
-use bevy_mod_picking::backends::raycast::RaycastPickable;
+use bevy_mod_picking::{
+    backends::raycast::RaycastPickable,
+    prelude::{Click, ListenerInput, On, Pointer},
+};
 use bevy_quill_core::{effects::*, insert::*, prelude::*};
 
 use crate::{
     overlay_material::{OverlayMaterial, UnderlayMaterial},
-    ShapeBuilder,
+    OverlayLabel, ShapeBuilder,
 };
 
 use super::mesh_builder::MeshBuilder;
@@ -113,6 +116,25 @@ impl<C: View, E: EffectTuple> Overlay<C, E> {
         self
     }
 
+    /// Set a callback to be invoked when the overlay is clicked. This also makes the overlay
+    /// pickable, and stops propagation of the click event to the overlay's ancestors.
+    ///
+    /// For other picking events, use [`Overlay::insert`] or [`Overlay::insert_dyn`] to add
+    /// an `On::<Pointer<E>>` handler directly.
+    pub fn on_click(
+        self,
+        callback: Callback,
+    ) -> Overlay<C, <E as AppendEffect<OverlayClickEffect>>::Result>
+    where
+        E: AppendEffect<OverlayClickEffect>,
+    {
+        Self {
+            pickable: true,
+            ..self
+        }
+        .add_effect(OverlayClickEffect { callback })
+    }
+
     /// Set the color for this overlay.
     pub fn color(
         self,
@@ -126,6 +148,29 @@ impl<C: View, E: EffectTuple> Overlay<C, E> {
         })
     }
 
+    /// Attach a text label to this overlay. The label is displayed at `offset` relative to the
+    /// overlay's origin, always faces the camera, and is scaled based on its distance from
+    /// the camera.
+    pub fn label(
+        self,
+        text: impl Into<String>,
+        offset: Vec3,
+        size: f32,
+        color: impl Into<Color>,
+    ) -> Overlay<C, <E as AppendEffect<OverlayLabelEffect>>::Result>
+    where
+        E: AppendEffect<OverlayLabelEffect>,
+    {
+        self.add_effect(OverlayLabelEffect {
+            label: OverlayLabel {
+                text: text.into(),
+                offset,
+                size,
+                color: color.into(),
+            },
+        })
+    }
+
     /// Set the transform for this overlay.
     pub fn transform(
         self,
@@ -244,7 +289,8 @@ impl<C: View, E: EffectTuple> Overlay<C, E> {
     }
 
     /// Compute the mesh vertices. This will be re-run whenever the
-    /// dependencies change.
+    /// dependencies change. Vertex colors are rebuilt along with the geometry, so any colors
+    /// passed to [`ShapeBuilder::with_vertex_color`] should be included in the dependencies.
     ///
     /// Arguments:
     /// - shape_fn: A function which computes the mesh vertices.
@@ -342,77 +388,72 @@ impl<C: View, E: EffectTuple + 'static> View for Overlay<C, E> {
 
     fn build(&self, cx: &mut Cx) -> Self::State {
         let owner = cx.owner();
-        if self.debug_name.is_empty() {
-            cx.world_mut()
-                .entity_mut(owner)
-                .insert(Name::new("Overlay"));
-        } else {
-            cx.world_mut()
-                .entity_mut(owner)
-                .insert(Name::new(format!("Overlay::{}", self.debug_name)));
-        }
-
-        let mesh = Mesh::new(self.topology, RenderAssetUsages::default());
-        let mut meshes = cx.world_mut().get_resource_mut::<Assets<Mesh>>().unwrap();
-        let mesh_handle = meshes.add(mesh);
-
-        let mut materials = cx
-            .world_mut()
-            .get_resource_mut::<Assets<OverlayMaterial>>()
-            .unwrap();
-        let material = materials.add(OverlayMaterial {
-            ..Default::default()
-        });
-
-        // TODO: only insert an underlay material if the underlay is between 0 and 1 (exclusive).
-        // If it's zero, the underly is invisible.
-        // If it's one, then we can just disable the depth test on the primary material.
-        // if self.underlay > 0.0 && self.underlay < 1.0 {}
-        let mut underlay_materials = cx
-            .world_mut()
-            .get_resource_mut::<Assets<UnderlayMaterial>>()
-            .unwrap();
-        let underlay_material = underlay_materials.add(UnderlayMaterial::default());
-
-        let mesh_state = OverlayMeshState {
-            material: material.clone(),
-            underlay_material: underlay_material.clone(),
-            mesh: mesh_handle.clone(),
-            underlay: self.underlay,
-        };
+        let display = cx.with_world_mut(|world| {
+            if self.debug_name.is_empty() {
+                world.entity_mut(owner).insert(Name::new("Overlay"));
+            } else {
+                world
+                    .entity_mut(owner)
+                    .insert(Name::new(format!("Overlay::{}", self.debug_name)));
+            }
 
-        let bundle = (
-            mesh_state,
-            MaterialMeshBundle::<OverlayMaterial> {
+            let mesh = Mesh::new(self.topology, RenderAssetUsages::default());
+            let mut meshes = world.get_resource_mut::<Assets<Mesh>>().unwrap();
+            let mesh_handle = meshes.add(mesh);
+
+            let mut materials = world.get_resource_mut::<Assets<OverlayMaterial>>().unwrap();
+            let material = materials.add(OverlayMaterial {
+                ..Default::default()
+            });
+
+            // TODO: only insert an underlay material if the underlay is between 0 and 1
+            // (exclusive). If it's zero, the underly is invisible.
+            // If it's one, then we can just disable the depth test on the primary material.
+            // if self.underlay > 0.0 && self.underlay < 1.0 {}
+            let mut underlay_materials = world
+                .get_resource_mut::<Assets<UnderlayMaterial>>()
+                .unwrap();
+            let underlay_material = underlay_materials.add(UnderlayMaterial::default());
+
+            let mesh_state = OverlayMeshState {
                 material: material.clone(),
+                underlay_material: underlay_material.clone(),
                 mesh: mesh_handle.clone(),
-                ..default()
-            },
-            NotShadowCaster,
-            NotShadowReceiver,
-        );
-
-        // Build display entity if it doesn't already exist.
-        let display = match self.display {
-            Some(display) => {
-                cx.world_mut()
-                    .entity_mut(display)
-                    .insert((bundle, Name::new(self.debug_name.clone())));
-                display
+                underlay: self.underlay,
+            };
+
+            let bundle = (
+                mesh_state,
+                MaterialMeshBundle::<OverlayMaterial> {
+                    material: material.clone(),
+                    mesh: mesh_handle.clone(),
+                    ..default()
+                },
+                NotShadowCaster,
+                NotShadowReceiver,
+            );
+
+            // Build display entity if it doesn't already exist.
+            let display = match self.display {
+                Some(display) => {
+                    world
+                        .entity_mut(display)
+                        .insert((bundle, Name::new(self.debug_name.clone())));
+                    display
+                }
+                None => world
+                    .spawn((bundle, Name::new(self.debug_name.clone())))
+                    .id(),
+            };
+
+            world.entity_mut(display).insert(underlay_material.clone());
+
+            if self.pickable && self.topology == PrimitiveTopology::TriangleList {
+                world.entity_mut(display).insert(RaycastPickable);
             }
-            None => cx
-                .world_mut()
-                .spawn((bundle, Name::new(self.debug_name.clone())))
-                .id(),
-        };
-
-        cx.world_mut()
-            .entity_mut(display)
-            .insert(underlay_material.clone());
-
-        if self.pickable && self.topology == PrimitiveTopology::TriangleList {
-            cx.world_mut().entity_mut(display).insert(RaycastPickable);
-        }
+
+            display
+        });
 
         // Run attached effects.
         let eff_state = EffectTuple::apply(&self.effects, cx, display);
@@ -422,9 +463,9 @@ impl<C: View, E: EffectTuple + 'static> View for Overlay<C, E> {
         let mut nodes: Vec<Entity> = Vec::new();
         self.children.nodes(cx.world(), &children, &mut nodes);
 
-        cx.world_mut()
-            .entity_mut(display)
-            .replace_children(&nodes.to_vec());
+        cx.with_world_mut(|world| {
+            world.entity_mut(display).replace_children(&nodes.to_vec());
+        });
 
         OverlayState {
             entity: display,
@@ -436,25 +477,28 @@ impl<C: View, E: EffectTuple + 'static> View for Overlay<C, E> {
     fn rebuild(&self, cx: &mut Cx, state: &mut Self::State) -> bool {
         EffectTuple::reapply(&self.effects, cx, state.entity, &mut state.effect_states);
         if self.children.rebuild(cx, &mut state.child_states) {
-            View::attach_children(self, cx.world_mut(), state);
+            cx.with_world_mut(|world| View::attach_children(self, world, state));
         }
         // Note that we always return false, since the Overlay entity doesn't change.
         false
     }
 
     fn raze(&self, world: &mut bevy::ecs::world::DeferredWorld, state: &mut Self::State) {
-        // Delete the display node.
-        world.commands().entity(state.entity).remove_parent();
-        if self.display.is_none() {
-            // Only despawn the display entity if we created it. If we got it from the outside,
-            // then it's the responsibility of the caller to clean it up.
-            world.commands().entity(state.entity).despawn();
-        } else {
-            world
-                .commands()
-                .entity(state.entity)
-                .remove::<MaterialMeshBundle<OverlayMaterial>>();
-        }
+        // Delete the display node. The entity may already have been despawned by game logic.
+        let entity = state.entity;
+        let created = self.display.is_none();
+        world.commands().add(move |world: &mut World| {
+            if let Some(mut entt) = world.get_entity_mut(entity) {
+                entt.remove_parent();
+                if created {
+                    // Only despawn the display entity if we created it. If we got it from the
+                    // outside, then it's the responsibility of the caller to clean it up.
+                    entt.despawn();
+                } else {
+                    entt.remove::<(MaterialMeshBundle<OverlayMaterial>, OverlayLabel)>();
+                }
+            }
+        });
         self.children.raze(world, &mut state.child_states);
     }
 
@@ -478,26 +522,24 @@ pub struct OverlayColorEffect {
 impl EntityEffect for OverlayColorEffect {
     type State = LinearRgba;
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let target = cx.world_mut().entity_mut(target);
-        let mesh_state = target.get::<OverlayMeshState>().unwrap().clone();
-        let mut materials = cx
-            .world_mut()
-            .get_resource_mut::<Assets<OverlayMaterial>>()
-            .unwrap();
-        let material = materials.get_mut(mesh_state.material.id()).unwrap();
-        material.color = self.color;
-
-        let mut underlay_materials = cx
-            .world_mut()
-            .get_resource_mut::<Assets<UnderlayMaterial>>()
-            .unwrap();
-        if let Some(underlay_material) =
-            underlay_materials.get_mut(mesh_state.underlay_material.id())
-        {
-            underlay_material.color = self
-                .color
-                .with_alpha(self.color.alpha * mesh_state.underlay);
-        }
+        cx.with_world_mut(|world| {
+            let target = world.entity_mut(target);
+            let mesh_state = target.get::<OverlayMeshState>().unwrap().clone();
+            let mut materials = world.get_resource_mut::<Assets<OverlayMaterial>>().unwrap();
+            let material = materials.get_mut(mesh_state.material.id()).unwrap();
+            material.color = self.color;
+
+            let mut underlay_materials = world
+                .get_resource_mut::<Assets<UnderlayMaterial>>()
+                .unwrap();
+            if let Some(underlay_material) =
+                underlay_materials.get_mut(mesh_state.underlay_material.id())
+            {
+                underlay_material.color = self
+                    .color
+                    .with_alpha(self.color.alpha * mesh_state.underlay);
+            }
+        });
 
         self.color
     }
@@ -509,6 +551,26 @@ impl EntityEffect for OverlayColorEffect {
     }
 }
 
+pub struct OverlayLabelEffect {
+    label: OverlayLabel,
+}
+
+impl EntityEffect for OverlayLabelEffect {
+    type State = OverlayLabel;
+    fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
+        cx.with_world_mut(|world| {
+            world.entity_mut(target).insert(self.label.clone());
+        });
+        self.label.clone()
+    }
+
+    fn reapply(&self, cx: &mut Cx, target: Entity, state: &mut Self::State) {
+        if *state != self.label {
+            *state = EntityEffect::apply(self, cx, target);
+        }
+    }
+}
+
 pub struct OverlayTransformEffect {
     transform: Transform,
 }
@@ -516,15 +578,17 @@ pub struct OverlayTransformEffect {
 impl EntityEffect for OverlayTransformEffect {
     type State = Transform;
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let mut target = cx.world_mut().entity_mut(target);
-        match target.get_mut::<Transform>() {
-            Some(mut t) => {
-                t.clone_from(&self.transform);
-            }
-            None => {
-                target.insert(self.transform);
-            }
-        };
+        cx.with_world_mut(|world| {
+            let mut target = world.entity_mut(target);
+            match target.get_mut::<Transform>() {
+                Some(mut t) => {
+                    t.clone_from(&self.transform);
+                }
+                None => {
+                    target.insert(self.transform);
+                }
+            };
+        });
         self.transform
     }
 
@@ -545,13 +609,15 @@ impl<M: MeshBuilder + Default + Sync + Send, F: Fn(&mut M) + Send + Sync> Entity
 {
     type State = ();
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let target = cx.world_mut().entity_mut(target);
-        let mut builder = M::default();
-        (self.shape_fn)(&mut builder);
-        let mesh_state = target.get::<OverlayMeshState>().unwrap().clone();
-        let mut meshes = cx.world_mut().get_resource_mut::<Assets<Mesh>>().unwrap();
-        let mesh = meshes.get_mut(mesh_state.mesh.id()).unwrap();
-        builder.build(mesh);
+        cx.with_world_mut(|world| {
+            let target = world.entity_mut(target);
+            let mut builder = M::default();
+            (self.shape_fn)(&mut builder);
+            let mesh_state = target.get::<OverlayMeshState>().unwrap().clone();
+            let mut meshes = world.get_resource_mut::<Assets<Mesh>>().unwrap();
+            let mesh = meshes.get_mut(mesh_state.mesh.id()).unwrap();
+            builder.build(mesh);
+        });
     }
 
     fn reapply(&self, _cx: &mut Cx, _target: Entity, _state: &mut Self::State) {}
@@ -563,30 +629,71 @@ pub struct DynamicMeshEffect<M, F: Fn(D, &mut M), D: PartialEq + Clone> {
     marker: std::marker::PhantomData<M>,
 }
 
+impl<M: MeshBuilder, F: Fn(D, &mut M), D: PartialEq + Clone> DynamicMeshEffect<M, F, D> {
+    /// Run the shape function and copy the result into the overlay's mesh.
+    fn update_mesh(&self, cx: &mut Cx, target: Entity, builder: &mut M) {
+        (self.shape_fn)(self.deps.clone(), builder);
+        cx.with_world_mut(|world| {
+            let target_ent = world.entity_mut(target);
+            let mesh_state = target_ent.get::<OverlayMeshState>().unwrap().clone();
+            let mut meshes = world.get_resource_mut::<Assets<Mesh>>().unwrap();
+            let mesh = meshes.get_mut(mesh_state.mesh.id()).unwrap();
+            builder.build(mesh);
+            if let Some(aabb) = mesh.compute_aabb() {
+                let mut target_ent = world.entity_mut(target);
+                target_ent.insert(aabb);
+            }
+        });
+    }
+}
+
 impl<
         M: MeshBuilder + Default + Sync + Send,
         F: Fn(D, &mut M) + Send + Sync,
         D: PartialEq + Clone + Send + Sync,
     > EntityEffect for DynamicMeshEffect<M, F, D>
 {
-    type State = D;
+    /// The dependencies, and the builder, which is kept so that its buffers can be reused.
+    type State = (D, M);
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let target_ent = cx.world_mut().entity_mut(target);
         let mut builder = M::default();
-        (self.shape_fn)(self.deps.clone(), &mut builder);
-        let mesh_state = target_ent.get::<OverlayMeshState>().unwrap().clone();
-        let mut meshes = cx.world_mut().get_resource_mut::<Assets<Mesh>>().unwrap();
-        let mesh = meshes.get_mut(mesh_state.mesh.id()).unwrap();
-        builder.build(mesh);
-        if let Some(aabb) = mesh.compute_aabb() {
-            let mut target_ent = cx.world_mut().entity_mut(target);
-            target_ent.insert(aabb);
+        self.update_mesh(cx, target, &mut builder);
+        (self.deps.clone(), builder)
+    }
+
+    fn reapply(&self, cx: &mut Cx, target: Entity, state: &mut Self::State) {
+        if state.0 != self.deps {
+            state.0 = self.deps.clone();
+            state.1.clear();
+            self.update_mesh(cx, target, &mut state.1);
         }
-        self.deps.clone()
+    }
+}
+
+pub struct OverlayClickEffect {
+    callback: Callback,
+}
+
+impl EntityEffect for OverlayClickEffect {
+    type State = Callback;
+    fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
+        let callback = self.callback;
+        cx.with_world_mut(|world| {
+            world
+                .entity_mut(target)
+                .insert(On::<Pointer<Click>>::run(move |world: &mut World| {
+                    let mut event = world
+                        .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                        .unwrap();
+                    event.stop_propagation();
+                    world.run_callback(callback, ());
+                }));
+        });
+        self.callback
     }
 
     fn reapply(&self, cx: &mut Cx, target: Entity, state: &mut Self::State) {
-        if *state != self.deps {
+        if *state != self.callback {
             *state = EntityEffect::apply(self, cx, target);
         }
     }
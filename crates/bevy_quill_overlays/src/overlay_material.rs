@@ -13,7 +13,8 @@ use bevy::{
     },
 };
 
-/// Material for overlays
+/// Material for overlays. If the mesh has vertex colors, they are multiplied by the
+/// material color.
 #[derive(Debug, Clone, AsBindGroup, Asset, TypePath, Default)]
 pub struct OverlayMaterial {
     #[uniform(100)]
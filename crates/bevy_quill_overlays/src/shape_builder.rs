@@ -1,4 +1,10 @@
+use std::{
+    f32::consts::{FRAC_PI_2, PI, TAU},
+    ops::{Add, Mul},
+};
+
 use bevy::{
+    color::LinearRgba,
     math::{Rect, Vec2, Vec3},
     render::mesh::{Indices, Mesh, PrimitiveTopology},
 };
@@ -71,9 +77,13 @@ pub enum StrokeMarker {
 #[derive(Clone, Debug, Default)]
 pub struct ShapeBuilder {
     vertices: Vec<Vec3>,
+    /// Per-vertex colors. This is empty until the first colored vertex is added, after which
+    /// it has the same length as `vertices`.
+    colors: Vec<LinearRgba>,
     indices: Vec<u32>,
     stroke_width: f32,
     orientation: ShapeOrientation,
+    vertex_color: Option<LinearRgba>,
 }
 
 /// Options for drawing a polygon or polyline stroke.
@@ -112,9 +122,11 @@ impl ShapeBuilder {
     pub fn new() -> Self {
         Self {
             vertices: Vec::new(),
+            colors: Vec::new(),
             indices: Vec::new(),
             stroke_width: 1.0,
             orientation: ShapeOrientation::ZPositive,
+            vertex_color: None,
         }
     }
 
@@ -132,9 +144,20 @@ impl ShapeBuilder {
         self
     }
 
+    /// Set the vertex color for the vertices added after this call. The vertex color is
+    /// multiplied by the overlay color, so use white to restore the overlay color.
+    #[inline]
+    pub fn with_vertex_color(&mut self, color: impl Into<LinearRgba>) -> &mut Self {
+        self.vertex_color = Some(color.into());
+        self
+    }
+
     /// Reserve space for vertices and indices.
     pub fn reserve(&mut self, vertices: usize, indices: usize) -> &mut Self {
         self.vertices.reserve(vertices);
+        if !self.colors.is_empty() || self.vertex_color.is_some() {
+            self.colors.reserve(vertices);
+        }
         self.indices.reserve(indices);
         self
     }
@@ -142,14 +165,21 @@ impl ShapeBuilder {
     /// Add a vertex to the shape.
     #[inline]
     pub fn push_vertex(&mut self, x: f32, y: f32, z: f32) -> &mut Self {
-        self.vertices.push(Vec3::new(x, y, z));
+        self.push_vec3(Vec3::new(x, y, z), self.vertex_color);
         self
     }
 
     /// Add a vertex to the shape.
     #[inline]
     pub fn push_xy(&mut self, x: f32, y: f32) -> &mut Self {
-        self.vertices.push(self.orientation.vec3(Vec2::new(x, y)));
+        self.push_vec3(self.orientation.vec3(Vec2::new(x, y)), self.vertex_color);
+        self
+    }
+
+    /// Add a vertex with an explicit color to the shape.
+    #[inline]
+    pub fn push_xy_color(&mut self, x: f32, y: f32, color: LinearRgba) -> &mut Self {
+        self.push_vec3(self.orientation.vec3(Vec2::new(x, y)), Some(color));
         self
     }
 
@@ -306,9 +336,9 @@ impl ShapeBuilder {
     pub fn fill_triangle_3d(&mut self, a: Vec3, b: Vec3, c: Vec3) -> &mut Self {
         self.reserve(3, 3);
         let start = self.vertices.len() as u32;
-        self.vertices.push(a);
-        self.vertices.push(b);
-        self.vertices.push(c);
+        self.push_vec3(a, self.vertex_color);
+        self.push_vec3(b, self.vertex_color);
+        self.push_vec3(c, self.vertex_color);
         self.push_index(start);
         self.push_index(start + 2);
         self.push_index(start + 1);
@@ -334,6 +364,7 @@ impl ShapeBuilder {
 
     /// Draw a line segment.
     pub fn stroke_line_segment(&mut self, p0: Vec2, p1: Vec2) -> &mut Self {
+        self.reserve(4, 6);
         let lw = self.stroke_width * 0.5;
         let v_dir = p1 - p0;
         let v_perp = Vec2::new(v_dir.y, -v_dir.x).normalize() * lw;
@@ -351,6 +382,7 @@ impl ShapeBuilder {
     /// Draw a line segment in 3d space, with the face of the stroke oriented along the orientation
     /// axis. This generally works best if the line is mostly parallel to the orientation plane.
     pub fn stroke_line_segment_3d(&mut self, p0: Vec3, p1: Vec3) -> &mut Self {
+        self.reserve(4, 6);
         let norm = self.orientation.normal();
         let lw = self.stroke_width * 0.5;
         let v_dir = (p1 - p0).normalize();
@@ -374,6 +406,7 @@ impl ShapeBuilder {
         let closed = options.closed && vertices.len() > 2;
         let lw = self.stroke_width * 0.5;
         let count = vertices.len();
+        self.reserve(count * 2, count * 6);
 
         let mut dash_end = options.dash_length;
 
@@ -493,6 +526,7 @@ impl ShapeBuilder {
         let closed = options.closed && vertices.len() > 2;
         let lw = self.stroke_width * 0.5;
         let count = vertices.len();
+        self.reserve(count * 2, count * 6);
 
         let mut dash_end = options.dash_length;
 
@@ -603,6 +637,173 @@ impl ShapeBuilder {
         self
     }
 
+    /// Draw a filled rectangle with rounded corners.
+    ///
+    /// Arguments:
+    /// `rect` - The bounds of the rectangle.
+    /// `radius` - The corner radius, which is clamped to half the width or height.
+    /// `corner_segments` - The number of segments used to draw each corner.
+    pub fn fill_rounded_rect(
+        &mut self,
+        rect: Rect,
+        radius: f32,
+        corner_segments: u32,
+    ) -> &mut Self {
+        let points = rounded_rect_points(rect, radius, corner_segments);
+        self.reserve(points.len() + 1, points.len() * 3);
+        let start = self.push_vec2_index(rect.center());
+        for pt in points.iter() {
+            self.push_xy(pt.x, pt.y);
+        }
+        self.fill_fan(start, points.len() as u32);
+        self
+    }
+
+    /// Draw a filled rectangle with rounded corners in 3d space. The rectangle lies in the
+    /// plane of the current orientation, and `rect` is relative to `origin`. See
+    /// [`ShapeBuilder::fill_rounded_rect`].
+    pub fn fill_rounded_rect_3d(
+        &mut self,
+        origin: Vec3,
+        rect: Rect,
+        radius: f32,
+        corner_segments: u32,
+    ) -> &mut Self {
+        let points = rounded_rect_points(rect, radius, corner_segments);
+        self.reserve(points.len() + 1, points.len() * 3);
+        let start = self.push_vec3_index(origin + self.orientation.vec3(rect.center()));
+        for pt in points.iter() {
+            self.push_vec3_index(origin + self.orientation.vec3(*pt));
+        }
+        self.fill_fan(start, points.len() as u32);
+        self
+    }
+
+    /// Draw a stroke in the shape of a rectangle with rounded corners.
+    ///
+    /// Arguments:
+    /// `rect` - The outer bounds of the rectangle.
+    /// `radius` - The outer corner radius, which is clamped to half the width or height.
+    /// `corner_segments` - The number of segments used to draw each corner.
+    pub fn stroke_rounded_rect(
+        &mut self,
+        rect: Rect,
+        radius: f32,
+        corner_segments: u32,
+    ) -> &mut Self {
+        // Like `stroke_rect`, the stroke is drawn inside the bounds.
+        let inset = self.stroke_width * 0.5;
+        let points = rounded_rect_points(rect.inflate(-inset), radius - inset, corner_segments);
+        self.stroke_polygon(
+            &points,
+            PolygonOptions {
+                closed: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Draw a stroke in the shape of a rectangle with rounded corners in 3d space. The
+    /// rectangle lies in the plane of the current orientation, and `rect` is relative to
+    /// `origin`. See [`ShapeBuilder::stroke_rounded_rect`].
+    pub fn stroke_rounded_rect_3d(
+        &mut self,
+        origin: Vec3,
+        rect: Rect,
+        radius: f32,
+        corner_segments: u32,
+    ) -> &mut Self {
+        let inset = self.stroke_width * 0.5;
+        let points: Vec<Vec3> =
+            rounded_rect_points(rect.inflate(-inset), radius - inset, corner_segments)
+                .iter()
+                .map(|pt| origin + self.orientation.vec3(*pt))
+                .collect();
+        self.stroke_polygon_3d(
+            &points,
+            PolygonOptions {
+                closed: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Draw a circular arc, going counter-clockwise from `start_angle` to `end_angle`
+    /// (in radians). If `end_angle` is less than `start_angle`, the arc wraps around past
+    /// zero. The arc is drawn as a polyline, so `options` controls the dashes and markers.
+    pub fn stroke_arc(
+        &mut self,
+        center: Vec2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        segments: u32,
+        options: PolygonOptions,
+    ) -> &mut Self {
+        let points: Vec<Vec2> = arc_angles(start_angle, end_angle, segments)
+            .map(|angle| center + Vec2::new(angle.cos(), angle.sin()) * radius)
+            .collect();
+        self.stroke_polygon(&points, options)
+    }
+
+    /// Draw a circular arc in 3d space. The arc lies in the plane of the current orientation,
+    /// centered on `center`. See [`ShapeBuilder::stroke_arc`].
+    pub fn stroke_arc_3d(
+        &mut self,
+        center: Vec3,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        segments: u32,
+        options: PolygonOptions,
+    ) -> &mut Self {
+        let points: Vec<Vec3> = arc_angles(start_angle, end_angle, segments)
+            .map(|angle| {
+                center
+                    + self
+                        .orientation
+                        .vec3(Vec2::new(angle.cos(), angle.sin()) * radius)
+            })
+            .collect();
+        self.stroke_polygon_3d(&points, options)
+    }
+
+    /// Draw a cubic Bezier curve from `p0` to `p3`, with control points `p1` and `p2`. The
+    /// curve is approximated by `segments` line segments, so `options` controls the dashes and
+    /// markers.
+    pub fn stroke_cubic_bezier(
+        &mut self,
+        p0: Vec2,
+        p1: Vec2,
+        p2: Vec2,
+        p3: Vec2,
+        segments: u32,
+        options: PolygonOptions,
+    ) -> &mut Self {
+        let segments = segments.max(1);
+        let points: Vec<Vec2> = (0..=segments)
+            .map(|i| cubic_bezier(p0, p1, p2, p3, i as f32 / segments as f32))
+            .collect();
+        self.stroke_polygon(&points, options)
+    }
+
+    /// Draw a cubic Bezier curve in 3d space. See [`ShapeBuilder::stroke_cubic_bezier`].
+    pub fn stroke_cubic_bezier_3d(
+        &mut self,
+        p0: Vec3,
+        p1: Vec3,
+        p2: Vec3,
+        p3: Vec3,
+        segments: u32,
+        options: PolygonOptions,
+    ) -> &mut Self {
+        let segments = segments.max(1);
+        let points: Vec<Vec3> = (0..=segments)
+            .map(|i| cubic_bezier(p0, p1, p2, p3, i as f32 / segments as f32))
+            .collect();
+        self.stroke_polygon_3d(&points, options)
+    }
+
     /// Add a vertex to the shape, and return the index of that vertex.
     #[inline]
     fn push_vec2_index(&mut self, v: Vec2) -> u32 {
@@ -615,10 +816,36 @@ impl ShapeBuilder {
     #[inline]
     fn push_vec3_index(&mut self, v: Vec3) -> u32 {
         let index = self.vertices.len() as u32;
-        self.vertices.push(v);
+        self.push_vec3(v, self.vertex_color);
         index
     }
 
+    /// Add a vertex to the shape, along with its color. Vertices without a color are white,
+    /// but the color buffer is only allocated once a colored vertex is added.
+    fn push_vec3(&mut self, v: Vec3, color: Option<LinearRgba>) {
+        match color {
+            Some(color) => {
+                self.colors.resize(self.vertices.len(), LinearRgba::WHITE);
+                self.colors.push(color);
+            }
+            None if !self.colors.is_empty() => {
+                self.colors.push(LinearRgba::WHITE);
+            }
+            None => {}
+        }
+        self.vertices.push(v);
+    }
+
+    /// Add the triangles for a fan around the vertex at `center`, whose rim is made up of
+    /// the `count` vertices which follow it.
+    fn fill_fan(&mut self, center: u32, count: u32) {
+        for i in 0..count {
+            self.push_index(center);
+            self.push_index(center + (i + 1).rem_euclid(count) + 1);
+            self.push_index(center + i + 1);
+        }
+    }
+
     fn fill_marker(&mut self, marker: StrokeMarker, position: Vec2, direction: Vec2, length: f32) {
         #[allow(clippy::single_match)]
         match marker {
@@ -663,14 +890,98 @@ impl ShapeBuilder {
     }
 }
 
+/// Returns the angles of the `segments + 1` points along an arc. The sweep is always
+/// counter-clockwise, so an `end_angle` less than `start_angle` wraps around.
+fn arc_angles(start_angle: f32, end_angle: f32, segments: u32) -> impl Iterator<Item = f32> {
+    let segments = segments.max(1);
+    let mut sweep = (end_angle - start_angle).rem_euclid(TAU);
+    if sweep == 0. && end_angle != start_angle {
+        // A whole number of turns, so draw the complete circle.
+        sweep = TAU;
+    }
+    let step = sweep / segments as f32;
+    (0..=segments).map(move |i| start_angle + step * i as f32)
+}
+
+/// Returns the outline of a rounded rectangle, counter-clockwise starting at the bottom right
+/// corner. The radius is clamped so that the corners don't overlap.
+fn rounded_rect_points(rect: Rect, radius: f32, corner_segments: u32) -> Vec<Vec2> {
+    let radius = radius
+        .min(rect.width() * 0.5)
+        .min(rect.height() * 0.5)
+        .max(0.);
+    let corner_segments = if radius > 0. {
+        corner_segments.max(1)
+    } else {
+        0
+    };
+    let corners = [
+        (
+            Vec2::new(rect.max.x - radius, rect.min.y + radius),
+            -FRAC_PI_2,
+        ),
+        (Vec2::new(rect.max.x - radius, rect.max.y - radius), 0.),
+        (
+            Vec2::new(rect.min.x + radius, rect.max.y - radius),
+            FRAC_PI_2,
+        ),
+        (Vec2::new(rect.min.x + radius, rect.min.y + radius), PI),
+    ];
+    let step = FRAC_PI_2 / corner_segments.max(1) as f32;
+    let mut points: Vec<Vec2> = Vec::with_capacity((corner_segments as usize + 1) * 4);
+    for (center, start_angle) in corners {
+        for i in 0..=corner_segments {
+            let angle = start_angle + step * i as f32;
+            points.push(center + Vec2::new(angle.cos(), angle.sin()) * radius);
+        }
+    }
+    // When the radius is half the width or height, adjacent corners share an end point.
+    points.dedup_by(|a, b| a.distance_squared(*b) < 1e-10);
+    if points.len() > 1 && points[0].distance_squared(points[points.len() - 1]) < 1e-10 {
+        points.pop();
+    }
+    points
+}
+
+/// Evaluate a cubic Bezier curve at `t`.
+fn cubic_bezier<V: Copy + Add<Output = V> + Mul<f32, Output = V>>(
+    p0: V,
+    p1: V,
+    p2: V,
+    p3: V,
+    t: f32,
+) -> V {
+    let u = 1. - t;
+    p0 * (u * u * u) + p1 * (3. * u * u * t) + p2 * (3. * u * t * t) + p3 * (t * t * t)
+}
+
 impl MeshBuilder for ShapeBuilder {
     fn topology() -> PrimitiveTopology {
         PrimitiveTopology::TriangleList
     }
 
-    /// Copy the shape into a [`Mesh`]. This will consume the builder and return a mesh.
-    fn build(self, mesh: &mut Mesh) {
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.vertices);
-        mesh.insert_indices(Indices::U32(self.indices));
+    fn clear(&mut self) {
+        self.vertices.clear();
+        self.colors.clear();
+        self.indices.clear();
+    }
+
+    /// Copy the shape into a [`Mesh`]. The builder keeps its buffers, so it can be cleared
+    /// and reused for the next rebuild. Vertex colors are only written if any vertex was
+    /// given a color.
+    fn build(&self, mesh: &mut Mesh) {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.vertices.clone());
+        if self.colors.is_empty() {
+            mesh.remove_attribute(Mesh::ATTRIBUTE_COLOR);
+        } else {
+            mesh.insert_attribute(
+                Mesh::ATTRIBUTE_COLOR,
+                self.colors
+                    .iter()
+                    .map(|c| [c.red, c.green, c.blue, c.alpha])
+                    .collect::<Vec<_>>(),
+            );
+        }
+        mesh.insert_indices(Indices::U32(self.indices.clone()));
     }
 }
@@ -56,7 +56,7 @@ impl ViewTemplate for AddNodeButton {
                     graph
                         .0
                         .create_node(&mut commands, operator, position, &mut action);
-                    graph.0.add_undo_action(action);
+                    graph.0.add_undo_action(&mut commands, action);
                 }
             },
         );
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+
+use crate::{
+    commands::MarkModifiedCmd,
+    graph::{GraphNode, Terminal},
+};
+
+/// Component which animates the value of an operator parameter over time. This is attached to
+/// the entity for an input terminal of a [`GraphNode`]; the animated value is written into the
+/// operator field of the same name.
+///
+/// While an animation is present, the generated shader reads the parameter from a uniform
+/// rather than embedding it as a constant, so the shader does not need to be rebuilt every
+/// frame.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ParameterAnimation {
+    /// List of `(time, value)` pairs, with time in seconds. Should be sorted by time.
+    pub keyframes: Vec<(f64, f32)>,
+}
+
+impl ParameterAnimation {
+    /// Evaluate the animation at time `t`, interpolating linearly between keyframes. Times
+    /// before the first keyframe or after the last keyframe are clamped.
+    pub fn sample(&self, t: f64) -> Option<f32> {
+        let (first, last) = (self.keyframes.first()?, self.keyframes.last()?);
+        if t <= first.0 {
+            return Some(first.1);
+        }
+        if t >= last.0 {
+            return Some(last.1);
+        }
+        let next = self.keyframes.partition_point(|(kt, _)| *kt <= t);
+        let (t0, v0) = self.keyframes[next - 1];
+        let (t1, v1) = self.keyframes[next];
+        if t1 <= t0 {
+            return Some(v1);
+        }
+        let alpha = ((t - t0) / (t1 - t0)) as f32;
+        Some(v0 + (v1 - v0) * alpha)
+    }
+}
+
+/// Evaluate all parameter animations and write the results into the operator fields.
+pub(crate) fn animate_parameters(
+    time: Res<Time>,
+    q_animations: Query<(&Terminal, &ParameterAnimation)>,
+    mut q_nodes: Query<&mut GraphNode>,
+) {
+    let t = time.elapsed_seconds_f64();
+    for (terminal, animation) in q_animations.iter() {
+        let Some(value) = animation.sample(t) else {
+            continue;
+        };
+        let Ok(mut node) = q_nodes.get_mut(terminal.node_id) else {
+            continue;
+        };
+        let reflect = node.operator_reflect_mut();
+        if let Some(field) = reflect
+            .reflect_path_mut(terminal.name)
+            .ok()
+            .and_then(|f| f.downcast_mut::<f32>())
+        {
+            *field = value;
+        }
+    }
+}
+
+/// Adding or removing an animation changes whether a parameter is a constant or a uniform,
+/// so the shaders for the node and everything downstream of it need to be regenerated.
+pub(crate) fn rebuild_animated_nodes(
+    mut commands: Commands,
+    q_added: Query<&Terminal, Added<ParameterAnimation>>,
+    mut removed: RemovedComponents<ParameterAnimation>,
+    q_terminals: Query<&Terminal>,
+) {
+    for terminal in q_added.iter() {
+        commands.add(MarkModifiedCmd {
+            start: terminal.node_id,
+        });
+    }
+    for terminal_id in removed.read() {
+        if let Ok(terminal) = q_terminals.get(terminal_id) {
+            commands.add(MarkModifiedCmd {
+                start: terminal.node_id,
+            });
+        }
+    }
+}
@@ -15,7 +15,14 @@ pub(crate) struct AddConnectionCmd {
 }
 
 impl Command for AddConnectionCmd {
-    fn apply(self, world: &mut World) {
+    fn apply(mut self, world: &mut World) {
+        // Connections to a subgraph's proxy terminals are made to the member terminals.
+        if let Some(ProxyTerminal(inner)) = world.get::<ProxyTerminal>(self.input) {
+            self.input = *inner;
+        }
+        if let Some(ProxyTerminal(inner)) = world.get::<ProxyTerminal>(self.output) {
+            self.output = *inner;
+        }
         let mut st: SystemState<(ResMut<GraphResource>, Query<&mut Terminal>)> =
             SystemState::new(world);
         let (_, mut terminals) = st.get_mut(world);
@@ -47,17 +54,22 @@ impl Command for AddConnectionCmd {
             connections_to_remove.insert(replace);
         }
 
+        // Remove the old connections first, so that undo restores them after the new one is
+        // removed.
         let mut action = UndoAction::new("Add Connection");
+        world.resource_scope(|world, mut graph: Mut<GraphResource>| {
+            for conn_id in connections_to_remove.drain() {
+                graph.0.remove_connection(world, conn_id, &mut action);
+            }
+        });
+        let id = world.spawn(connection).id();
         action
             .mutations
-            .push(UndoMutation::AddConnection(connection));
-        let id = world.spawn(connection).id();
-        let (mut graph, _) = st.get_mut(world);
-        for conn_id in connections_to_remove.iter() {
-            graph.0.connections.remove(conn_id);
-        }
-        graph.0.connections.insert(id);
-        graph.0.add_undo_action(action);
+            .push(UndoMutation::AddConnection(id, connection));
+        world.resource_scope(|world, mut graph: Mut<GraphResource>| {
+            graph.0.connections.insert(id);
+            graph.0.add_undo_action(&mut world.commands(), action);
+        });
 
         // Insert the new connection.
         let (_, mut terminals) = st.get_mut(world);
@@ -70,10 +82,5 @@ impl Command for AddConnectionCmd {
 
         // Mark input node as modified.
         world.commands().add(MarkModifiedCmd { start: input_node });
-
-        // Despawn old connections
-        for conn_id in connections_to_remove.drain() {
-            world.entity_mut(conn_id).despawn();
-        }
     }
 }
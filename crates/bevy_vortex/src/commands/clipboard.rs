@@ -0,0 +1,161 @@
+use bevy::{
+    ecs::{system::SystemState, world::Command},
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{graph::*, operator::Operator};
+
+/// How far pasted nodes are offset from the nodes they were copied from.
+const PASTE_OFFSET: IVec2 = IVec2::new(20, 20);
+
+/// A node which has been copied to the clipboard.
+struct ClipboardNode {
+    operator: Box<dyn Operator>,
+    position: IVec2,
+}
+
+/// A connection between two copied nodes, which are identified by their index in the
+/// clipboard.
+struct ClipboardConnection {
+    output: (usize, &'static str),
+    input: (usize, &'static str),
+}
+
+/// Holds the nodes copied by [`CopySelectedCmd`], along with the connections between them.
+#[derive(Resource, Default)]
+pub(crate) struct GraphClipboard {
+    nodes: Vec<ClipboardNode>,
+    connections: Vec<ClipboardConnection>,
+}
+
+/// Copy the selected nodes, and the connections between them, to the clipboard.
+/// Subgraph nodes are not copied, since their members would need to be copied as well.
+pub(crate) struct CopySelectedCmd;
+
+impl Command for CopySelectedCmd {
+    fn apply(self, world: &mut World) {
+        let mut st: SystemState<(
+            Query<
+                (Entity, &GraphNode),
+                (
+                    With<NodeSelected>,
+                    Without<SubgraphOf>,
+                    Without<SubgraphMembers>,
+                ),
+            >,
+            Query<&Connection>,
+        )> = SystemState::new(world);
+        let (nodes, connections) = st.get(world);
+
+        let mut indices = HashMap::<Entity, usize>::default();
+        let mut clipboard = GraphClipboard::default();
+        for (entity, node) in nodes.iter() {
+            indices.insert(entity, clipboard.nodes.len());
+            clipboard.nodes.push(ClipboardNode {
+                operator: node.operator_clone(),
+                position: node.position,
+            });
+        }
+        if clipboard.nodes.is_empty() {
+            return;
+        }
+
+        // Only connections between two copied nodes are kept.
+        for conn in connections.iter() {
+            if let (Some(output), Some(input)) = (
+                indices.get(&conn.output.node_id),
+                indices.get(&conn.input.node_id),
+            ) {
+                clipboard.connections.push(ClipboardConnection {
+                    output: (*output, conn.output.terminal_name),
+                    input: (*input, conn.input.terminal_name),
+                });
+            }
+        }
+
+        world.insert_resource(clipboard);
+    }
+}
+
+/// Paste the nodes on the clipboard into the graph. The pasted nodes become the selection.
+pub(crate) struct PasteCmd;
+
+impl Command for PasteCmd {
+    fn apply(self, world: &mut World) {
+        let Some(mut clipboard) = world.get_resource_mut::<GraphClipboard>() else {
+            return;
+        };
+        if clipboard.nodes.is_empty() {
+            return;
+        }
+        // Offset the clipboard as well, so that pasting repeatedly doesn't stack the copies
+        // on top of each other.
+        let nodes: Vec<(Box<dyn Operator>, IVec2)> = clipboard
+            .nodes
+            .iter_mut()
+            .map(|node| {
+                node.position += PASTE_OFFSET;
+                (node.operator.to_boxed_clone(), node.position)
+            })
+            .collect();
+
+        // Clear the previous selection.
+        let mut selected = world.query_filtered::<Entity, With<NodeSelected>>();
+        let selected: Vec<Entity> = selected.iter(world).collect();
+        for entity in selected {
+            world.entity_mut(entity).remove::<NodeSelected>();
+        }
+
+        world.resource_scope(|world, mut graph: Mut<GraphResource>| {
+            let mut action = UndoAction::new("Paste");
+
+            // New nodes are selected when they are created.
+            let mut st: SystemState<Commands> = SystemState::new(world);
+            let mut commands = st.get_mut(world);
+            let node_ids: Vec<GraphNodeId> = nodes
+                .into_iter()
+                .map(|(operator, position)| {
+                    graph
+                        .0
+                        .create_node(&mut commands, operator, position, &mut action)
+                })
+                .collect();
+            st.apply(world);
+
+            let clipboard = world.resource::<GraphClipboard>();
+            let mut connections: Vec<Connection> = Vec::new();
+            for conn in clipboard.connections.iter() {
+                let output_node = graph.0.nodes[&node_ids[conn.output.0]];
+                let input_node = graph.0.nodes[&node_ids[conn.input.0]];
+                let output_terminal = world
+                    .get::<GraphNode>(output_node)
+                    .and_then(|node| node.get_output_terminal(conn.output.1));
+                let input_terminal = world
+                    .get::<GraphNode>(input_node)
+                    .and_then(|node| node.get_input_terminal(conn.input.1));
+                if let (Some(output_terminal), Some(input_terminal)) =
+                    (output_terminal, input_terminal)
+                {
+                    connections.push(Connection {
+                        output: OutputTerminalId {
+                            node_id: output_node,
+                            terminal_name: conn.output.1,
+                            terminal_id: output_terminal,
+                        },
+                        input: InputTerminalId {
+                            node_id: input_node,
+                            terminal_name: conn.input.1,
+                            terminal_id: input_terminal,
+                        },
+                    });
+                }
+            }
+            for connection in connections {
+                graph.0.add_connection(world, connection, &mut action);
+            }
+
+            graph.0.add_undo_action(&mut world.commands(), action);
+        });
+    }
+}
@@ -12,24 +12,38 @@ impl Command for DeleteSelectedCmd {
     fn apply(self, world: &mut World) {
         let mut st: SystemState<(
             Query<(Entity, &mut Connection)>,
-            Query<(Entity, &GraphNode, Option<&NodeSelected>)>,
+            Query<(
+                Entity,
+                &GraphNode,
+                Option<&NodeSelected>,
+                Option<&SubgraphOf>,
+            )>,
         )> = SystemState::new(world);
         let (mut connections, nodes) = st.get_mut(world);
 
+        // Nodes within a selected subgraph are deleted along with it.
+        let is_selected = |entity: Entity| -> bool {
+            match nodes.get(entity) {
+                Ok((_, _, Some(_), _)) => true,
+                Ok((_, _, None, Some(SubgraphOf(subgraph)))) => nodes
+                    .get(*subgraph)
+                    .map_or(false, |(_, _, sel, _)| sel.is_some()),
+                _ => false,
+            }
+        };
+
         let mut action = UndoAction::new("Delete");
         let mut connections_to_remove = HashSet::<Entity>::default();
         for (ent, conn) in connections.iter_mut() {
-            let (_, _, input_selected) = nodes.get(conn.input.node_id).unwrap();
-            let (_, _, output_selected) = nodes.get(conn.output.node_id).unwrap();
-            if input_selected.is_some() || output_selected.is_some() {
+            if is_selected(conn.input.node_id) || is_selected(conn.output.node_id) {
                 connections_to_remove.insert(ent);
             }
         }
 
         let selected_nodes = nodes
             .iter()
-            .filter_map(|(entity, node, selected)| {
-                if selected.is_some() {
+            .filter_map(|(entity, node, _, _)| {
+                if is_selected(entity) {
                     Some((entity, node.index))
                 } else {
                     None
@@ -37,16 +51,17 @@ impl Command for DeleteSelectedCmd {
             })
             .collect::<HashSet<_>>();
 
-        // Despawn old nodes
         world.resource_scope(|world, mut graph: Mut<GraphResource>| {
+            // Remove the connections before the nodes, so that undo restores them after.
+            for conn_id in connections_to_remove.drain() {
+                graph.0.remove_connection(world, conn_id, &mut action);
+            }
             for (_node_entity, node_index) in selected_nodes.iter() {
                 graph.0.delete_node(world, *node_index, &mut action);
             }
+            if !action.mutations.is_empty() {
+                graph.0.add_undo_action(&mut world.commands(), action);
+            }
         });
-
-        // Despawn old connections
-        for conn_id in connections_to_remove.drain() {
-            world.entity_mut(conn_id).despawn();
-        }
     }
 }
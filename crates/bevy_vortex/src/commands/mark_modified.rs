@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use bevy::{
     ecs::{system::SystemState, world::Command},
     prelude::*,
@@ -6,7 +8,8 @@ use bevy::{
 
 use crate::graph::*;
 
-/// Mark the given node, and all downstream nodes, has modified.
+/// Mark the given node, and all downstream nodes, has modified. Nodes which were already
+/// modified have their modification time reset, delaying the rebuild of their shaders.
 pub(crate) struct MarkModifiedCmd {
     /// Entity for the input terminal.
     pub(crate) start: Entity,
@@ -14,21 +17,18 @@ pub(crate) struct MarkModifiedCmd {
 
 impl Command for MarkModifiedCmd {
     fn apply(self, world: &mut World) {
-        let mut st: SystemState<(
-            Query<(&GraphNode, Option<&NodeModified>)>,
-            Query<&Terminal>,
-            Query<&Connection>,
-        )> = SystemState::new(world);
+        let mut st: SystemState<(Query<&GraphNode>, Query<&Terminal>, Query<&Connection>)> =
+            SystemState::new(world);
         let (nodes, terminals, connections) = st.get_mut(world);
         let mut to_visit = HashSet::<Entity>::with_capacity(64);
         let mut to_mark = HashSet::<Entity>::with_capacity(64);
         to_visit.insert(self.start);
         while let Some(node_id) = to_visit.iter().next().copied() {
             to_visit.remove(&node_id);
-            let Ok((node, modified)) = nodes.get(node_id) else {
+            let Ok(node) = nodes.get(node_id) else {
                 continue;
             };
-            if modified.is_some() || to_mark.contains(&node_id) {
+            if to_mark.contains(&node_id) {
                 continue;
             }
             to_mark.insert(node_id);
@@ -41,8 +41,11 @@ impl Command for MarkModifiedCmd {
             }
         }
 
+        let now = Instant::now();
         for node_id in to_mark.iter() {
-            world.entity_mut(*node_id).insert(NodeModified);
+            world
+                .entity_mut(*node_id)
+                .insert((NodeModified, NodeModifiedAt(now)));
         }
     }
 }
@@ -1,7 +1,13 @@
 mod add_connection;
+mod clipboard;
 mod delete_selected;
 mod mark_modified;
+mod subgraph;
+mod undo;
 
 pub(crate) use add_connection::AddConnectionCmd;
+pub(crate) use clipboard::{CopySelectedCmd, GraphClipboard, PasteCmd};
 pub(crate) use delete_selected::DeleteSelectedCmd;
 pub(crate) use mark_modified::MarkModifiedCmd;
+pub(crate) use subgraph::{CollapseToSubgraphCmd, ExpandSubgraphCmd};
+pub(crate) use undo::{RedoCmd, UndoCmd};
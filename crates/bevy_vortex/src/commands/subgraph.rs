@@ -0,0 +1,117 @@
+use bevy::{
+    ecs::{system::SystemState, world::Command},
+    prelude::*,
+    utils::HashSet,
+};
+
+use crate::graph::*;
+
+/// Collapse the selected nodes into a single subgraph node, placed at their centroid.
+/// Terminals which are connected to nodes outside of the selection are exposed on the
+/// subgraph node.
+pub(crate) struct CollapseToSubgraphCmd;
+
+impl Command for CollapseToSubgraphCmd {
+    fn apply(self, world: &mut World) {
+        let mut st: SystemState<(
+            Query<(Entity, &GraphNode), (With<NodeSelected>, Without<SubgraphMembers>)>,
+            Query<&Connection>,
+        )> = SystemState::new(world);
+        let (nodes, connections) = st.get(world);
+
+        let members: Vec<(Entity, IVec2)> = nodes
+            .iter()
+            .map(|(entity, node)| (entity, node.position))
+            .collect();
+        if members.is_empty() {
+            return;
+        }
+        let member_set: HashSet<Entity> = members.iter().map(|(entity, _)| *entity).collect();
+        let centroid =
+            members.iter().map(|(_, position)| *position).sum::<IVec2>() / members.len() as i32;
+
+        // Find the member terminals which are connected to nodes outside of the group.
+        let mut inputs: Vec<Entity> = Vec::new();
+        let mut outputs: Vec<Entity> = Vec::new();
+        for conn in connections.iter() {
+            let input_inside = member_set.contains(&conn.input.node_id);
+            let output_inside = member_set.contains(&conn.output.node_id);
+            if input_inside && !output_inside && !inputs.contains(&conn.input.terminal_id) {
+                inputs.push(conn.input.terminal_id);
+            } else if output_inside && !input_inside && !outputs.contains(&conn.output.terminal_id)
+            {
+                outputs.push(conn.output.terminal_id);
+            }
+        }
+
+        let mut action = UndoAction::new("Collapse to Subgraph");
+        let subgraph_node = world.resource_scope(|world, mut graph: Mut<GraphResource>| {
+            graph.0.create_subgraph_node(world, centroid, &mut action)
+        });
+
+        let mut add_proxy = |terminal: Entity| {
+            let proxy = world
+                .spawn(ProxyTerminal(terminal))
+                .set_parent(subgraph_node)
+                .id();
+            world.entity_mut(terminal).insert(ProxiedBy(proxy));
+            (proxy, terminal)
+        };
+        let inputs: Vec<(Entity, Entity)> = inputs.into_iter().map(&mut add_proxy).collect();
+        let outputs: Vec<(Entity, Entity)> = outputs.into_iter().map(&mut add_proxy).collect();
+
+        for (member, _) in members.iter() {
+            world
+                .entity_mut(*member)
+                .insert(SubgraphOf(subgraph_node))
+                .remove::<NodeSelected>();
+        }
+        world.entity_mut(subgraph_node).insert(SubgraphMembers {
+            members: members.iter().map(|(entity, _)| *entity).collect(),
+            origin: centroid,
+            inputs,
+            outputs,
+        });
+
+        world.resource_scope(|world, mut graph: Mut<GraphResource>| {
+            graph.0.add_undo_action(&mut world.commands(), action);
+        });
+    }
+}
+
+/// Expand a subgraph node, restoring its member nodes in place. The member nodes keep their
+/// positions relative to the subgraph node. Does nothing if the node is not a subgraph.
+pub(crate) struct ExpandSubgraphCmd(pub(crate) Entity);
+
+impl Command for ExpandSubgraphCmd {
+    fn apply(self, world: &mut World) {
+        let Some(subgraph) = world.get::<SubgraphMembers>(self.0).cloned() else {
+            return;
+        };
+        let node = world.get::<GraphNode>(self.0).unwrap();
+        let node_index = node.index;
+        let offset = node.position - subgraph.origin;
+
+        for member in subgraph.members.iter() {
+            let mut entt = world.entity_mut(*member);
+            entt.remove::<SubgraphOf>().insert(NodeSelected);
+            if let Some(mut node) = entt.get_mut::<GraphNode>() {
+                node.position += offset;
+            }
+        }
+        for (proxy, terminal) in subgraph.inputs.iter().chain(subgraph.outputs.iter()) {
+            if let Some(mut entt) = world.get_entity_mut(*terminal) {
+                entt.remove::<ProxiedBy>();
+            }
+            if let Some(entt) = world.get_entity_mut(*proxy) {
+                entt.despawn_recursive();
+            }
+        }
+
+        let mut action = UndoAction::new("Expand Subgraph");
+        world.resource_scope(|world, mut graph: Mut<GraphResource>| {
+            graph.0.delete_node(world, node_index, &mut action);
+            graph.0.add_undo_action(&mut world.commands(), action);
+        });
+    }
+}
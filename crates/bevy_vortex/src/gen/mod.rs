@@ -5,16 +5,19 @@ mod shader_assembly;
 mod shader_imports;
 mod terminal_reader;
 
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use crate::graph::NodeModified;
+use crate::graph::{NodeModified, NodeModifiedAt};
 use bevy::tasks::futures_lite::future;
 use bevy::{
     prelude::*,
     tasks::{block_on, AsyncComputeTaskPool, Task},
 };
 pub use expr::*;
-pub use shader_assembly::ShaderAssembly;
+pub use shader_assembly::{ShaderAssembly, MAX_UNIFORM_PARAMS};
 pub use terminal_reader::TerminalReader;
 
 /// Component used to indicate that a node is being observed. These nodes have higher priority
@@ -22,21 +25,28 @@ pub use terminal_reader::TerminalReader;
 // #[derive(Component, Debug, Clone, Copy)]
 // pub(crate) struct NodeObserved(Entity);
 
+/// How long a node must go without being modified, in milliseconds, before its shader is rebuilt.
+/// This avoids spawning a rebuild task every frame while a property is being dragged.
+pub const REBUILD_DEBOUNCE_MS: u64 = 250;
+
 /// Marker component that indicates that a graph node's shader is being rebuilt.
 #[derive(Component)]
 pub struct RebuildTask(Task<BuildShaderResult>);
 
-pub struct BuildShaderResult(Shader);
+pub struct BuildShaderResult(Shader, Vec<(Entity, &'static str)>);
 
 #[derive(Component)]
 pub struct NodeOutput {
     /// Shader handle
     pub shader: Handle<Shader>,
+
+    /// Operator parameters which are passed to the shader as uniforms, in slot order.
+    pub uniforms: Vec<(Entity, &'static str)>,
 }
 
 pub(crate) fn finish_build_shaders(
     mut commands: Commands,
-    q_output: Query<&NodeOutput>,
+    mut q_output: Query<&mut NodeOutput>,
     mut q_rebuilding: Query<(Entity, &mut RebuildTask)>,
     mut shaders: ResMut<Assets<Shader>>,
 ) {
@@ -46,15 +56,17 @@ pub(crate) fn finish_build_shaders(
         if let Some(result) = status {
             let mut entt = commands.entity(node_id);
             entt.remove::<RebuildTask>();
-            let shader = result.0;
+            let BuildShaderResult(shader, uniforms) = result;
             // println!("Shader built:\n{}", source);
-            if let Ok(output) = q_output.get(node_id) {
+            if let Ok(mut output) = q_output.get_mut(node_id) {
                 // Update shader asset in-place.
                 shaders.insert(output.shader.id(), shader);
+                output.uniforms = uniforms;
             } else {
                 // Create shader asset and attach to node.
                 commands.entity(node_id).insert(NodeOutput {
                     shader: shaders.add(shader),
+                    uniforms,
                 });
             }
         }
@@ -64,15 +76,20 @@ pub(crate) fn finish_build_shaders(
 pub(crate) fn begin_build_shaders(
     mut commands: Commands,
     reader: TerminalReader,
-    q_modified: Query<Entity, With<NodeModified>>,
+    q_modified: Query<(Entity, Option<&NodeModifiedAt>), With<NodeModified>>,
 ) {
-    // Spawn tasks for any nodes that are modified.
+    // Spawn tasks for any nodes that are modified, and which have not changed recently.
     // TODO: Limit
     let task_pool = AsyncComputeTaskPool::get();
-    for modified in q_modified.iter() {
+    let now = Instant::now();
+    let debounce = Duration::from_millis(REBUILD_DEBOUNCE_MS);
+    for (modified, modified_at) in q_modified.iter() {
+        if modified_at.is_some_and(|at| now.duration_since(at.0) < debounce) {
+            continue;
+        }
         if let Ok(node) = reader.nodes.get(modified) {
             let mut entt = commands.entity(modified);
-            entt.remove::<NodeModified>();
+            entt.remove::<(NodeModified, NodeModifiedAt)>();
             // Need to walk the graph and build expression tree here.
             // Not sure that we need an async task since a lot of the effort is just querying
             // the graph, which is not accessible in a thread.
@@ -86,13 +103,14 @@ pub(crate) fn begin_build_shaders(
             assembly.add_common_imports();
             let expr = Arc::new(node.gen(&mut assembly, &reader, modified, output.0));
             assembly.set_fragment_value(expr);
+            let uniforms = assembly.uniforms().to_vec();
 
             let task = task_pool.spawn(async move {
                 // println!("Task spawned");
                 // let assembly = ShaderAssembly::new(modified);
                 assembly.run_passes().unwrap();
                 let shader = Shader::from_wgsl(assembly.source().to_owned(), "".to_string());
-                BuildShaderResult(shader)
+                BuildShaderResult(shader, uniforms)
             });
             entt.insert(RebuildTask(task));
         }
@@ -2,6 +2,17 @@ use std::fmt::{Error, Write};
 
 const INDENT_SIZE: usize = 4;
 
+/// Returns the precedence of an infix operator. Higher values bind more tightly.
+pub fn operator_precedence(oper: &str) -> usize {
+    match oper {
+        "." => 10,
+        "*" | "/" | "%" => 5,
+        "+" | "-" => 3,
+        "==" | "!=" | "<" | "<=" | ">" | ">=" => 2,
+        _ => 1,
+    }
+}
+
 /// Struct which keeps track of the current line length and indentation level, and can break lines.
 pub struct LineWrapping {
     /// The current length of the current line.
@@ -94,6 +105,19 @@ impl From<&'static str> for OutputChunk {
 }
 
 impl OutputChunk {
+    /// Returns true if the `index`th argument of an infix operator with the given precedence
+    /// must be wrapped in parentheses to preserve evaluation order. Operators are
+    /// left-associative, so right-hand operands of equal precedence are also wrapped.
+    fn needs_parens(precedence: usize, index: usize, arg: &OutputChunk) -> bool {
+        match arg {
+            OutputChunk::Infix {
+                precedence: arg_precedence,
+                ..
+            } => *arg_precedence < precedence || (index > 0 && *arg_precedence == precedence),
+            _ => false,
+        }
+    }
+
     /// The total length, in characters, of this chunk and all its descendants.
     pub fn length(&self) -> usize {
         match self {
@@ -118,10 +142,20 @@ impl OutputChunk {
             OutputChunk::Infix {
                 oper,
                 args,
-                precedence: _,
+                precedence,
             } => {
                 let padding = if oper == "." { 0 } else { 1 };
-                args.iter().map(|c| c.length()).sum::<usize>()
+                args.iter()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        c.length()
+                            + if Self::needs_parens(*precedence, i, c) {
+                                2
+                            } else {
+                                0
+                            }
+                    })
+                    .sum::<usize>()
                     + (args.len() - 1) * (oper.len() + padding * 2)
             }
             OutputChunk::FCall { func, args } => {
@@ -143,8 +177,14 @@ impl OutputChunk {
             OutputChunk::Infix {
                 oper: _,
                 args,
-                precedence: _,
-            } => args[0].head_length(),
+                precedence,
+            } => {
+                if Self::needs_parens(*precedence, 0, &args[0]) {
+                    1
+                } else {
+                    args[0].head_length()
+                }
+            }
             OutputChunk::FCall { func, args: _ } => func.len() + 1,
         }
     }
@@ -202,7 +242,7 @@ impl OutputChunk {
             OutputChunk::Infix {
                 oper,
                 args,
-                precedence: _,
+                precedence,
             } => {
                 let padding = if oper == "." { 0 } else { 1 };
                 for (i, chunk) in args.iter().enumerate() {
@@ -215,7 +255,13 @@ impl OutputChunk {
                             out.write_char(' ')?;
                         }
                     }
-                    chunk.flatten(out)?;
+                    if Self::needs_parens(*precedence, i, chunk) {
+                        out.write_char('(')?;
+                        chunk.flatten(out)?;
+                        out.write_char(')')?;
+                    } else {
+                        chunk.flatten(out)?;
+                    }
                 }
             }
 
@@ -343,10 +389,9 @@ impl OutputChunk {
 
             OutputChunk::Infix {
                 oper,
-                precedence: _precedence,
+                precedence,
                 args,
             } => {
-                // TODO: Precedence
                 let padding = if oper == "." { 0 } else { 1 };
                 for (i, chunk) in args.iter().enumerate() {
                     if i > 0 {
@@ -364,7 +409,15 @@ impl OutputChunk {
                             wrap.line_length += 1;
                         }
                     }
-                    chunk.format(out, wrap)?;
+                    if Self::needs_parens(*precedence, i, chunk) {
+                        out.write_char('(')?;
+                        wrap.line_length += 1;
+                        chunk.format(out, wrap)?;
+                        out.write_char(')')?;
+                        wrap.line_length += 1;
+                    } else {
+                        chunk.format(out, wrap)?;
+                    }
                 }
                 Ok(())
             }
@@ -577,6 +630,86 @@ mod tests {
         assert_eq!(output.len(), chunk.length());
     }
 
+    fn infix(oper: &str, args: Vec<OutputChunk>) -> OutputChunk {
+        OutputChunk::Infix {
+            oper: String::from(oper),
+            precedence: operator_precedence(oper),
+            args,
+        }
+    }
+
+    #[test]
+    fn test_flatten_infix_precedence() {
+        // (a + b) * c
+        let chunk = infix(
+            "*",
+            vec![infix("+", vec!["a".into(), "b".into()]), "c".into()],
+        );
+        let mut output = String::new();
+        chunk.flatten(&mut output).unwrap();
+        assert_eq!(output, "(a + b) * c");
+        assert_eq!(output.len(), chunk.length());
+        assert_eq!(chunk.head_length(), 1);
+
+        // a * (b + c)
+        let chunk = infix(
+            "*",
+            vec!["a".into(), infix("+", vec!["b".into(), "c".into()])],
+        );
+        let mut output = String::new();
+        chunk.flatten(&mut output).unwrap();
+        assert_eq!(output, "a * (b + c)");
+        assert_eq!(output.len(), chunk.length());
+
+        // a * b + c
+        let chunk = infix(
+            "+",
+            vec![infix("*", vec!["a".into(), "b".into()]), "c".into()],
+        );
+        let mut output = String::new();
+        chunk.flatten(&mut output).unwrap();
+        assert_eq!(output, "a * b + c");
+
+        // a - (b - c)
+        let chunk = infix(
+            "-",
+            vec!["a".into(), infix("-", vec!["b".into(), "c".into()])],
+        );
+        let mut output = String::new();
+        chunk.flatten(&mut output).unwrap();
+        assert_eq!(output, "a - (b - c)");
+
+        // (a + b).x
+        let chunk = infix(
+            ".",
+            vec![infix("+", vec!["a".into(), "b".into()]), "x".into()],
+        );
+        let mut output = String::new();
+        chunk.flatten(&mut output).unwrap();
+        assert_eq!(output, "(a + b).x");
+    }
+
+    #[test]
+    fn test_format_infix_precedence() {
+        let chunk = infix(
+            "*",
+            vec![infix("+", vec!["a".into(), "b".into()]), "c".into()],
+        );
+        let mut wrap = LineWrapping::new(80);
+        let mut output = String::new();
+        chunk.format(&mut output, &mut wrap).unwrap();
+        assert_eq!(output, "(a + b) * c");
+
+        let chunk = infix(
+            "*",
+            vec!["a".into(), infix("+", vec!["b".into(), "c".into()])],
+        );
+        let mut wrap = LineWrapping::new(80);
+        let mut output = String::new();
+        chunk.format(&mut output, &mut wrap).unwrap();
+        assert_eq!(output, "a * (b + c)");
+    }
+
     #[test]
     fn test_flatten_fcall() {
         let chunk = OutputChunk::FCall {
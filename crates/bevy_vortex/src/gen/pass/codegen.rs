@@ -1,4 +1,7 @@
-use crate::gen::{output_chunk::OutputChunk, Expr};
+use crate::gen::{
+    output_chunk::{operator_precedence, OutputChunk},
+    Expr,
+};
 
 pub fn codegen(expr: &Expr) -> OutputChunk {
     match expr {
@@ -46,11 +49,11 @@ pub fn codegen(expr: &Expr) -> OutputChunk {
         Expr::Assign(_, _, _) => todo!(),
         Expr::RefLocal(_, name) => OutputChunk::Literal(name.clone()),
         Expr::RefInput(_, _) => todo!(),
-        Expr::RefUniform(_, _) => todo!(),
+        Expr::RefUniform(_, name) => OutputChunk::Literal(name.clone()),
         Expr::TypeCast(_, _) => unreachable!("TypeCast should have been lowered"),
         Expr::GetAttr(_, expr, fieldname) => OutputChunk::Infix {
             oper: ".".to_string(),
-            precedence: 1,
+            precedence: operator_precedence("."),
             args: vec![codegen(expr), OutputChunk::Str(fieldname)],
         },
         Expr::BinOp(_, _, _, _) => todo!(),
@@ -12,6 +12,10 @@ use super::{
     Expr,
 };
 
+/// Maximum number of scalar parameters which can be passed to a shader as uniforms. These
+/// are packed into an array of `vec4<f32>`.
+pub const MAX_UNIFORM_PARAMS: usize = 16;
+
 struct ShaderLocalVar {
     name: String,
     data_type: super::DataType,
@@ -39,6 +43,9 @@ pub struct ShaderAssembly {
     /// Local variable definitions. Note we use a vector here so we can preserve declaration order.
     locals: Vec<ShaderLocalVar>,
 
+    /// Operator parameters which are read from the uniform buffer, in slot order.
+    uniforms: Vec<(Entity, &'static str)>,
+
     /// Whether the fragment shader needs position information.
     pub(crate) needs_position: bool,
 
@@ -59,6 +66,7 @@ impl ShaderAssembly {
             imports: ShaderImports::default(),
             includes: Vec::new(),
             locals: Vec::new(),
+            uniforms: Vec::new(),
             needs_position: false,
             needs_normal: false,
             needs_uv: false,
@@ -111,6 +119,37 @@ impl ShaderAssembly {
         self.locals.iter().any(|var| var.name == *name)
     }
 
+    /// Declare a scalar operator parameter which is read from the uniform buffer rather
+    /// than being compiled into the shader as a constant. Returns `None` if there are no
+    /// uniform slots left.
+    pub fn declare_uniform(&mut self, node_id: Entity, field: &'static str) -> Option<Expr> {
+        let slot = match self
+            .uniforms
+            .iter()
+            .position(|u| u.0 == node_id && u.1 == field)
+        {
+            Some(slot) => slot,
+            None if self.uniforms.len() < MAX_UNIFORM_PARAMS => {
+                self.uniforms.push((node_id, field));
+                self.uniforms.len() - 1
+            }
+            None => return None,
+        };
+        Some(Expr::RefUniform(
+            super::DataType::F32,
+            format!(
+                "node_params.values[{}].{}",
+                slot / 4,
+                ["x", "y", "z", "w"][slot % 4]
+            ),
+        ))
+    }
+
+    /// List of operator parameters which are read from the uniform buffer, in slot order.
+    pub fn uniforms(&self) -> &[(Entity, &'static str)] {
+        &self.uniforms
+    }
+
     /// Return the source code for the shader.
     pub fn source(&self) -> &str {
         self.source.as_str()
@@ -125,6 +164,17 @@ impl ShaderAssembly {
         self.imports.write(&mut source)?;
         source.write_str("\n")?;
 
+        // Write uniforms
+        if !self.uniforms.is_empty() {
+            source.write_str("struct NodeParams {\n")?;
+            source.write_fmt(format_args!(
+                "    values: array<vec4<f32>, {}>,\n",
+                MAX_UNIFORM_PARAMS / 4
+            ))?;
+            source.write_str("};\n\n")?;
+            source.write_str("@group(2) @binding(0) var<uniform> node_params: NodeParams;\n\n")?;
+        }
+
         // Write vertex input format
         source.write_str("struct Vertex {\n")?;
         source.write_str("    @builtin(instance_index) instance_index: u32,\n")?;
@@ -226,20 +276,6 @@ impl ShaderAssembly {
         self.source = source;
         Ok(())
     }
-
-    // / Get the uniforms for the shader.
-    // fn uniforms(&self) -> String {
-    //     let mut result = String::new();
-    //     // let mut visited_nodes = HashSet::default();
-    //     // self.node.visit_upstream_nodes(|node| {
-    //     //     if !visited_nodes.contains(node) {
-    //     //         visited_nodes.insert(node);
-    //     //         result.push_str(&node.uniforms());
-    //     //     }
-    //     // });
-    //     // result.push_str(&self.node.uniforms());
-    //     result
-    // }
 }
 
 //   /** List of uniform declarations. */
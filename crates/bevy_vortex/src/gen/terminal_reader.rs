@@ -1,6 +1,9 @@
 use bevy::{ecs::system::SystemParam, prelude::*};
 
-use crate::graph::{Connection, GraphNode, GraphNodeId, Terminal};
+use crate::{
+    animation::ParameterAnimation,
+    graph::{Connection, GraphNode, GraphNodeId, Terminal},
+};
 
 use super::{Expr, ShaderAssembly};
 
@@ -14,10 +17,15 @@ pub struct TerminalReader<'w, 's> {
 
     /// Query for looking up connections by id
     connections: Query<'w, 's, &'static Connection>,
+
+    /// Query for determining whether a terminal is animated
+    animations: Query<'w, 's, (), With<ParameterAnimation>>,
 }
 
 impl<'w, 's> TerminalReader<'w, 's> {
-    /// Read the value of an input terminal.
+    /// Read the value of an input terminal. Returns `None` if the terminal is not connected,
+    /// in which case the operator should use its own field value, unless that field is
+    /// animated, in which case the value is read from a uniform.
     pub fn read_input_terminal(
         &self,
         assembly: &mut ShaderAssembly,
@@ -52,6 +60,8 @@ impl<'w, 's> TerminalReader<'w, 's> {
                 unimplemented!("Multiple connections not supported yet");
             }
             Some(src_node.gen(assembly, self, src_node_id, connection.output.terminal_name))
+        } else if self.animations.contains(dst_terminal_id) {
+            assembly.declare_uniform(dst_node, terminal_name)
         } else {
             None
         }
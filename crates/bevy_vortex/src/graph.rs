@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use bevy::{
     ecs::{system::SystemState, world::Command},
     hierarchy::BuildChildren,
@@ -9,8 +11,10 @@ use bevy::{
 use smallvec::SmallVec;
 
 use crate::{
+    commands::MarkModifiedCmd,
     gen::{Expr, ShaderAssembly, TerminalReader},
     operator::{Operator, OperatorInput, OperatorOutput},
+    ops,
 };
 
 #[derive(Resource, Default)]
@@ -19,6 +23,9 @@ pub struct GraphResource(pub(crate) Graph);
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct GraphNodeId(pub(crate) usize);
 
+/// Maximum number of actions kept on the undo stack.
+const MAX_UNDO_ACTIONS: usize = 64;
+
 /// A component that indicates that a particular view is displaying the output of a node,
 /// which means that it needs the material handle generated from the node.
 #[derive(Component, Default)]
@@ -68,16 +75,48 @@ impl Graph {
             outputs: default(),
         };
         node.create_terminals(commands, entity);
-        commands
-            .entity(entity)
-            .insert((node, NodeModified, NodeSelected));
+        commands.entity(entity).insert((
+            node,
+            NodeModified,
+            NodeModifiedAt(Instant::now()),
+            NodeSelected,
+        ));
         action.mutations.push(UndoMutation::AddNode(id, entity));
         self.nodes.insert(id, entity);
         id
     }
 
+    /// Create a node which stands in for a group of collapsed nodes. The caller is responsible
+    /// for adding the [`SubgraphMembers`] component.
+    pub(crate) fn create_subgraph_node(
+        &mut self,
+        world: &mut World,
+        position: IVec2,
+        action: &mut UndoAction,
+    ) -> Entity {
+        self.next_id += 1;
+        let id = GraphNodeId(self.next_id);
+        let entity = world
+            .spawn((
+                GraphNode {
+                    index: id,
+                    position,
+                    size: IVec2::ZERO,
+                    operator: Box::new(ops::Subgraph::default()),
+                    inputs: default(),
+                    outputs: default(),
+                },
+                NodeSelected,
+            ))
+            .id();
+        action.mutations.push(UndoMutation::AddNode(id, entity));
+        self.nodes.insert(id, entity);
+        entity
+    }
+
     /// Remove a node from the graph. The node's connections must be removed first, it will
-    /// panic if this has not been done.
+    /// panic if this has not been done. The node entity and its terminals are kept, so that
+    /// the node can be restored by undo.
     pub fn delete_node(
         &mut self,
         world: &mut World,
@@ -93,11 +132,10 @@ impl Graph {
             let mut node_entity = world.entity_mut(entity);
             // Remove the node from the world and put it on the undo stack.
             let node = node_entity.take::<GraphNode>().unwrap();
-            // world.commands().entity(entity).despawn_recursive();
-            node_entity.despawn_recursive();
+            node_entity.remove::<NodeSelected>();
             action
                 .mutations
-                .push(UndoMutation::RemoveNode(node_id, node));
+                .push(UndoMutation::RemoveNode(node_id, entity, node));
         }
     }
 
@@ -108,34 +146,136 @@ impl Graph {
         connection: Connection,
         action: &mut UndoAction,
     ) -> Entity {
+        let id = world.spawn(connection).id();
+        for terminal in [connection.input.terminal_id, connection.output.terminal_id] {
+            if let Some(mut terminal) = world.get_mut::<Terminal>(terminal) {
+                terminal.connections.insert(id);
+            }
+        }
         action
             .mutations
-            .push(UndoMutation::AddConnection(connection));
-        let id = world.spawn(connection).id();
+            .push(UndoMutation::AddConnection(id, connection));
         self.connections.insert(id);
         id
     }
 
-    /// Remove a connection from the graph.
+    /// Remove a connection from the graph. The connection entity is kept, so that the
+    /// connection can be restored by undo.
     pub fn remove_connection(
         &mut self,
         world: &mut World,
         connection: Entity,
         action: &mut UndoAction,
     ) {
-        if let Some(connection) = world.get_entity_mut(connection) {
-            self.connections.remove(&connection.id());
-            action.mutations.push(UndoMutation::RemoveConnection(
-                *connection.get::<Connection>().unwrap(),
-            ));
-            connection.despawn();
+        let removed = world
+            .get_entity_mut(connection)
+            .and_then(|mut entt| entt.take::<Connection>());
+        if let Some(removed) = removed {
+            self.connections.remove(&connection);
+            action
+                .mutations
+                .push(UndoMutation::RemoveConnection(connection, removed));
         }
     }
 
-    /// Add a new unfo action to the undo stack. Also clears the redo stack.
-    pub fn add_undo_action(&mut self, action: UndoAction) {
-        self.redo_stack.clear();
+    /// Add a new unfo action to the undo stack. Also clears the redo stack. Actions which are
+    /// discarded, either from the redo stack or because the undo stack is full, can no longer
+    /// be reverted, so the node and connection entities which they kept are despawned.
+    pub fn add_undo_action(&mut self, commands: &mut Commands, action: UndoAction) {
+        for discarded in self.redo_stack.drain(..) {
+            discarded.despawn_removed(commands);
+        }
         self.undo_stack.push(action);
+        if self.undo_stack.len() > MAX_UNDO_ACTIONS {
+            self.undo_stack.remove(0).despawn_removed(commands);
+        }
+    }
+
+    /// Revert the most recent action, and move it to the redo stack.
+    pub fn undo(&mut self, world: &mut World) {
+        if let Some(action) = self.undo_stack.pop() {
+            let inverse = self.revert(world, action);
+            self.redo_stack.push(inverse);
+        }
+    }
+
+    /// Re-apply the most recently undone action, and move it back to the undo stack.
+    pub fn redo(&mut self, world: &mut World) {
+        if let Some(action) = self.redo_stack.pop() {
+            let inverse = self.revert(world, action);
+            self.undo_stack.push(inverse);
+        }
+    }
+
+    /// Revert the mutations of an action in reverse order, returning an action which
+    /// reverts them in turn.
+    fn revert(&mut self, world: &mut World, action: UndoAction) -> UndoAction {
+        let mut inverse = UndoAction::new(action.label);
+        for mutation in action.mutations.into_iter().rev() {
+            let reverted = self.revert_mutation(world, mutation);
+            inverse.mutations.push(reverted);
+        }
+        inverse
+    }
+
+    fn revert_mutation(&mut self, world: &mut World, mutation: UndoMutation) -> UndoMutation {
+        match mutation {
+            UndoMutation::AddNode(node_id, entity) => {
+                self.nodes.remove(&node_id);
+                let mut node_entity = world.entity_mut(entity);
+                let node = node_entity.take::<GraphNode>().unwrap();
+                node_entity.remove::<NodeSelected>();
+                UndoMutation::RemoveNode(node_id, entity, node)
+            }
+
+            UndoMutation::RemoveNode(node_id, entity, node) => {
+                world.entity_mut(entity).insert((
+                    node,
+                    NodeModified,
+                    NodeModifiedAt(Instant::now()),
+                ));
+                self.nodes.insert(node_id, entity);
+                UndoMutation::AddNode(node_id, entity)
+            }
+
+            UndoMutation::AddConnection(entity, connection) => {
+                // Removing the component also removes it from the terminals.
+                world.entity_mut(entity).remove::<Connection>();
+                self.connections.remove(&entity);
+                world.commands().add(MarkModifiedCmd {
+                    start: connection.input.node_id,
+                });
+                UndoMutation::RemoveConnection(entity, connection)
+            }
+
+            UndoMutation::RemoveConnection(entity, connection) => {
+                world.entity_mut(entity).insert(connection);
+                for terminal in [connection.input.terminal_id, connection.output.terminal_id] {
+                    if let Some(mut terminal) = world.get_mut::<Terminal>(terminal) {
+                        terminal.connections.insert(entity);
+                    }
+                }
+                self.connections.insert(entity);
+                world.commands().add(MarkModifiedCmd {
+                    start: connection.input.node_id,
+                });
+                UndoMutation::AddConnection(entity, connection)
+            }
+
+            UndoMutation::MoveNodes(moves) => {
+                for (entity, before, _) in moves.iter() {
+                    if let Some(mut node) = world.get_mut::<GraphNode>(*entity) {
+                        node.position = *before;
+                    }
+                }
+                UndoMutation::MoveNodes(
+                    moves
+                        .into_iter()
+                        .map(|(entity, before, after)| (entity, after, before))
+                        .collect(),
+                )
+            }
+        }
     }
 }
 
@@ -169,6 +309,11 @@ impl GraphNode {
         self.operator.reflect_short_type_path()
     }
 
+    /// Return a copy of this node's operator.
+    pub fn operator_clone(&self) -> Box<dyn Operator> {
+        self.operator.to_boxed_clone()
+    }
+
     pub fn operator_reflect(&self) -> &dyn Reflect {
         self.operator.as_reflect()
     }
@@ -265,11 +410,50 @@ impl Clone for GraphNode {
     }
 }
 
+/// Component placed on a node which has been collapsed into a subgraph. Collapsed nodes are
+/// hidden from the graph view until the subgraph is expanded.
+#[derive(Component, Clone, Copy, PartialEq)]
+pub struct SubgraphOf(pub Entity);
+
+/// Component on a subgraph node, which lists the nodes it contains and the terminals it
+/// exposes. Each exposed terminal is a proxy for a terminal on one of the member nodes which
+/// is connected to a node outside the group. The connections themselves stay attached to the
+/// member terminals, so collapsing doesn't change the generated shader.
+#[derive(Component, Clone, Default)]
+pub struct SubgraphMembers {
+    /// The collapsed nodes.
+    pub(crate) members: Vec<Entity>,
+    /// Centroid of the member nodes when they were collapsed, used to restore their positions
+    /// relative to the subgraph node when it is expanded.
+    pub(crate) origin: IVec2,
+    /// Exposed input terminals, as pairs of (proxy terminal, member terminal).
+    pub(crate) inputs: Vec<(Entity, Entity)>,
+    /// Exposed output terminals, as pairs of (proxy terminal, member terminal).
+    pub(crate) outputs: Vec<(Entity, Entity)>,
+}
+
+/// Component on a terminal of a subgraph node, which holds the member terminal it stands in
+/// for. Connections made to the proxy are attached to the member terminal instead.
+#[derive(Component, Clone, Copy)]
+pub struct ProxyTerminal(pub Entity);
+
+/// Component on a member terminal which is exposed by a collapsed subgraph, which holds the
+/// proxy terminal. Connections to the member terminal are drawn to the proxy.
+#[derive(Component, Clone, Copy)]
+pub struct ProxiedBy(pub Entity);
+
 /// Marker component that indicates that a graph node has been modified, and it's built shader is
 /// out of date.
 #[derive(Component)]
 pub struct NodeModified;
 
+/// The time at which a graph node was most recently modified. Shader rebuilds are delayed until
+/// the node has stopped changing for a while, see [`REBUILD_DEBOUNCE_MS`].
+///
+/// [`REBUILD_DEBOUNCE_MS`]: crate::gen::REBUILD_DEBOUNCE_MS
+#[derive(Component)]
+pub struct NodeModifiedAt(pub Instant);
+
 /// Component used to store the position of a node while dragging.
 #[derive(Component)]
 pub struct NodeBasePosition(pub IVec2);
@@ -325,7 +509,6 @@ pub enum ConnectionDataType {
 }
 
 /// Represents a user-level action which can be undone or redone.
-#[allow(dead_code)]
 pub struct UndoAction {
     label: &'static str,
     pub(crate) mutations: Vec<UndoMutation>,
@@ -338,15 +521,32 @@ impl UndoAction {
             mutations: default(),
         }
     }
+
+    /// Despawn the entities of the nodes and connections which were removed by this action.
+    /// These are kept alive only so that the removal can be undone.
+    fn despawn_removed(self, commands: &mut Commands) {
+        for mutation in self.mutations {
+            match mutation {
+                UndoMutation::RemoveNode(_, entity, _)
+                | UndoMutation::RemoveConnection(entity, _) => {
+                    if let Some(entt) = commands.get_entity(entity) {
+                        entt.despawn_recursive();
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
 }
 
 /// Represents a single mutation within an [`UndoAction`].
-#[allow(dead_code)]
 pub enum UndoMutation {
     AddNode(GraphNodeId, Entity),
-    RemoveNode(GraphNodeId, GraphNode),
-    AddConnection(Connection),
-    RemoveConnection(Connection),
+    RemoveNode(GraphNodeId, Entity, GraphNode),
+    AddConnection(Entity, Connection),
+    RemoveConnection(Entity, Connection),
+    /// Nodes which were moved, as (node, position before, position after).
+    MoveNodes(Vec<(Entity, IVec2, IVec2)>),
 }
 
 #[allow(dead_code)]
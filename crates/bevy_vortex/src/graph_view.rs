@@ -1,15 +1,19 @@
 use crate::{
-    graph::{Connection, GraphNode, GraphResource, NodeSelected, Terminal},
+    graph::{
+        Connection, GraphNode, GraphResource, NodeSelected, ProxiedBy, SubgraphMembers, SubgraphOf,
+        Terminal,
+    },
     operator::{DisplayName, DisplayWidth, OperatorInput, OperatorInputOnly, OperatorOutput},
     propedit::GraphNodePropertyEdit,
+    quick_connect::{QuickConnect, QuickConnectPopup},
 };
 use bevy::{color::Color, prelude::*, reflect::TypeInfo, ui};
 use bevy_mod_stylebuilder::*;
 use bevy_quill::{prelude::*, Dynamic, IntoViewChild};
 use bevy_quill_obsidian::{colors, hooks::UseElementRect};
 use bevy_quill_obsidian_graph::{
-    ConnectionAnchor, ConnectionTarget, EdgeDisplay, GraphDisplay, InputTerminalDisplay,
-    NoTerminalDisplay, NodeDisplay, OutputTerminalDisplay,
+    ConnectionAnchor, ConnectionTarget, EdgeDisplay, GraphDisplay, GraphMinimap,
+    InputTerminalDisplay, NoTerminalDisplay, NodeDisplay, OutputTerminalDisplay,
 };
 
 fn style_node_graph(ss: &mut StyleBuilder) {
@@ -34,6 +38,8 @@ pub struct DragState {
     pub(crate) valid_connection: bool,
     /// The rectangle to display when selecting by dragging.
     pub(crate) selection_rect: Option<Rect>,
+    /// A connection which was dropped on a node body, waiting for the user to pick a terminal.
+    pub(crate) quick_connect: Option<QuickConnect>,
 }
 
 /// View template for graph. Entity is the id for the graph view.
@@ -44,18 +50,32 @@ impl ViewTemplate for GraphView {
     type View = impl View;
     fn create(&self, cx: &mut Cx) -> Self::View {
         let graph = cx.use_resource::<GraphResource>();
-        let node_ids: Vec<_> = graph.0.iter_nodes().map(|(_, v)| *v).collect();
+        // Nodes which have been collapsed into a subgraph are not displayed.
+        let node_ids: Vec<_> = graph
+            .0
+            .iter_nodes()
+            .map(|(_, v)| *v)
+            .filter(|node| cx.use_component::<SubgraphOf>(*node).is_none())
+            .collect();
         let connection_ids: Vec<_> = graph.0.iter_connections().cloned().collect();
         let graph_view_id = cx.use_inherited_component::<GraphViewId>().unwrap().0;
+        let node_rects: Vec<Rect> = node_ids
+            .iter()
+            .filter_map(|node| cx.use_component::<GraphNode>(*node))
+            .map(|node| Rect::from_center_size(node.position.as_vec2(), node.size.as_vec2()))
+            .collect();
 
         GraphDisplay::new()
             .entity(graph_view_id)
             .style(style_node_graph)
+            .minimap(GraphMinimap::new().nodes(node_rects))
             .children((
-                SelectionRectView,
                 For::each(connection_ids, |conn| ConnectionView(*conn)),
                 For::each(node_ids, |node| GraphNodeView(*node)),
                 ConnectionProxyView,
+                // Drawn after the nodes so that it appears on top of them.
+                SelectionRectView,
+                QuickConnectPopup,
             ))
     }
 }
@@ -72,9 +92,11 @@ impl ViewTemplate for GraphNodeView {
         let node = cx.use_component::<GraphNode>(node_id).unwrap();
         if node.size != size.as_ivec2() {
             // Save the node size
-            let mut entt = cx.world_mut().entity_mut(node_id);
-            let mut node = entt.get_mut::<GraphNode>().unwrap();
-            node.size = size.as_ivec2();
+            cx.with_world_mut(|world| {
+                let mut entt = world.entity_mut(node_id);
+                let mut node = entt.get_mut::<GraphNode>().unwrap();
+                node.size = size.as_ivec2();
+            });
         }
         let node = cx.use_component::<GraphNode>(node_id).unwrap();
 
@@ -110,10 +132,57 @@ impl ViewTemplate for GraphNodeView {
             .width(display_width)
             .title(node.title())
             .selected(is_selected)
-            .children(For::each(field_names, move |field| GraphNodePropertyView {
-                node: node_id,
-                field,
-            }))
+            .children((
+                For::each(field_names, move |field| GraphNodePropertyView {
+                    node: node_id,
+                    field,
+                }),
+                SubgraphTerminalsView(node_id),
+            ))
+    }
+}
+
+/// Displays the exposed terminals of a subgraph node. Each proxy terminal takes its color
+/// and label from the member terminal it stands in for.
+#[derive(Clone, PartialEq)]
+pub struct SubgraphTerminalsView(Entity);
+
+impl ViewTemplate for SubgraphTerminalsView {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let Some(subgraph) = cx.use_component::<SubgraphMembers>(self.0) else {
+            return ().into_view_child();
+        };
+        let terminal_name = |cx: &Cx, id: Entity| {
+            cx.use_component::<Terminal>(id)
+                .map_or_else(String::new, |terminal| terminal.name.to_string())
+        };
+        let inputs: Vec<_> = subgraph
+            .inputs
+            .iter()
+            .map(|(proxy, terminal)| {
+                InputTerminalDisplay::new(*proxy)
+                    .color(get_terminal_color(cx, *terminal))
+                    .incompatible(is_terminal_incompatible(cx, *terminal, false))
+                    .connected(true)
+                    .label(terminal_name(cx, *terminal))
+            })
+            .collect();
+        let outputs: Vec<_> = subgraph
+            .outputs
+            .iter()
+            .map(|(proxy, terminal)| OutputTerminalDisplay {
+                id: *proxy,
+                color: get_terminal_color(cx, *terminal),
+                incompatible: is_terminal_incompatible(cx, *terminal, true),
+                label: terminal_name(cx, *terminal),
+            })
+            .collect();
+        (
+            For::each(outputs, |output| output.clone()),
+            For::each(inputs, |input| input.clone()),
+        )
+            .into_view_child()
     }
 }
 
@@ -147,24 +216,24 @@ impl ViewTemplate for GraphNodePropertyView {
                 if field_attrs.contains::<OperatorInput>() {
                     let id = node.get_input_terminal(self.field).unwrap();
                     let terminal = cx.use_component::<Terminal>(id).unwrap();
-                    InputTerminalDisplay {
-                        id,
-                        color: get_terminal_color(cx, id),
-                        control: GraphNodePropertyEdit {
+                    InputTerminalDisplay::new(id)
+                        .color(get_terminal_color(cx, id))
+                        .incompatible(is_terminal_incompatible(cx, id, false))
+                        .connected(terminal.is_connected())
+                        .label(display_name)
+                        .control(GraphNodePropertyEdit {
                             node: self.node,
                             display_name,
                             field: self.field,
-                            editable: !(terminal.is_connected()
-                                || field_attrs.contains::<OperatorInputOnly>()),
-                        }
-                        .into_view_child(),
-                    }
-                    .into_view_child()
+                            editable: !field_attrs.contains::<OperatorInputOnly>(),
+                        })
+                        .into_view_child()
                 } else if field_attrs.contains::<OperatorOutput>() {
                     let id = node.get_output_terminal(self.field).unwrap();
                     OutputTerminalDisplay {
                         id,
                         color: get_terminal_color(cx, id),
+                        incompatible: is_terminal_incompatible(cx, id, true),
                         label: display_name.to_string(),
                     }
                     .into_view_child()
@@ -199,9 +268,15 @@ impl ViewTemplate for ConnectionView {
                 src_color: colors::U3,
                 dst_color: colors::U3,
                 hidden: true,
+                label: None,
             };
         };
         let Connection { output, input } = connection;
+        // Connections between nodes within the same subgraph are not displayed.
+        let output_subgraph = cx.use_component::<SubgraphOf>(output.node_id);
+        let input_subgraph = cx.use_component::<SubgraphOf>(input.node_id);
+        let internal = output_subgraph.is_some()
+            && output_subgraph.map(|s| s.0) == input_subgraph.map(|s| s.0);
         let src_pos = get_terminal_position(cx, output.terminal_id);
         let dst_pos = get_terminal_position(cx, input.terminal_id);
         let src_color = get_terminal_edge_color(cx, output.terminal_id);
@@ -213,7 +288,7 @@ impl ViewTemplate for ConnectionView {
                 edge == self.0
             }
             _ => false,
-        };
+        } || internal;
 
         EdgeDisplay {
             edge_id: Some(self.0),
@@ -222,6 +297,7 @@ impl ViewTemplate for ConnectionView {
             src_color,
             dst_color,
             hidden,
+            label: None,
         }
     }
 }
@@ -285,6 +361,7 @@ impl ViewTemplate for ConnectionProxyView {
                 src_color,
                 dst_color,
                 hidden: false,
+                label: None,
             },
             (),
         )
@@ -292,11 +369,15 @@ impl ViewTemplate for ConnectionProxyView {
 }
 
 fn get_terminal_position(cx: &Cx, terminal_id: Entity) -> IVec2 {
+    // Terminals of collapsed nodes are drawn at the subgraph node's proxy terminal.
+    let terminal_id = cx
+        .use_component::<ProxiedBy>(terminal_id)
+        .map_or(terminal_id, |proxy| proxy.0);
     let rect = get_relative_rect(cx, terminal_id, 4);
     rect.map_or(IVec2::default(), |f| f.center().as_ivec2())
 }
 
-fn get_terminal_color(cx: &Cx, terminal_id: Entity) -> Srgba {
+pub(crate) fn get_terminal_color(cx: &Cx, terminal_id: Entity) -> Srgba {
     if let Some(terminal) = cx.use_component::<Terminal>(terminal_id) {
         match terminal.data_type {
             crate::graph::ConnectionDataType::Scalar => colors::U4,
@@ -308,6 +389,47 @@ fn get_terminal_color(cx: &Cx, terminal_id: Entity) -> Srgba {
     }
 }
 
+/// Returns true if a connection is being dragged, and the given terminal is not a valid
+/// place to drop it. Connections must join an output to an input on a different node.
+fn is_terminal_incompatible(cx: &Cx, terminal_id: Entity, is_output: bool) -> bool {
+    let drag_state = cx.use_inherited_component::<DragState>().unwrap();
+    let (anchor_id, anchor_is_output) = match drag_state.connect_from {
+        Some(ConnectionAnchor::OutputTerminal(term)) => (term, true),
+        Some(ConnectionAnchor::InputTerminal(term)) => (term, false),
+        Some(ConnectionAnchor::EdgeSink(edge)) => {
+            // Dragging the sink end, so the output terminal is anchored.
+            match cx.use_component::<Connection>(edge) {
+                Some(conn) => (conn.output.terminal_id, true),
+                None => return false,
+            }
+        }
+        Some(ConnectionAnchor::EdgeSource(edge)) => {
+            // Dragging the source end, so the input terminal is anchored.
+            match cx.use_component::<Connection>(edge) {
+                Some(conn) => (conn.input.terminal_id, false),
+                None => return false,
+            }
+        }
+        None => return false,
+    };
+
+    if anchor_id == terminal_id {
+        return false;
+    }
+
+    if anchor_is_output == is_output {
+        return true;
+    }
+
+    match (
+        cx.use_component::<Terminal>(anchor_id),
+        cx.use_component::<Terminal>(terminal_id),
+    ) {
+        (Some(anchor), Some(terminal)) => anchor.node_id == terminal.node_id,
+        _ => false,
+    }
+}
+
 fn get_terminal_edge_color(cx: &Cx, terminal_id: Entity) -> Srgba {
     get_terminal_color(cx, terminal_id).mix(&Srgba::BLACK, 0.3)
 }
@@ -318,6 +440,11 @@ fn get_target_position(cx: &Cx, target: Option<ConnectionTarget>) -> IVec2 {
             get_terminal_position(cx, term)
         }
         Some(ConnectionTarget::Location(loc)) => loc.as_ivec2(),
+        Some(ConnectionTarget::NodeBody(node)) => cx
+            .use_component::<GraphNode>(node)
+            .map_or(IVec2::default(), |node| {
+                node.position + IVec2::new(node.size.x / 2, 0)
+            }),
         _ => IVec2::default(),
     }
 }
@@ -353,9 +480,12 @@ fn get_relative_rect(cx: &Cx, id: Entity, levels: usize) -> Option<Rect> {
 }
 
 fn style_selection_rect(ss: &mut StyleBuilder) {
-    ss.background_color(colors::TEXT_SELECT.with_alpha(0.02))
+    ss.position(ui::PositionType::Absolute)
+        .background_color(colors::TEXT_SELECT.with_alpha(0.02))
         .border_color(colors::TEXT_SELECT.with_alpha(0.1))
-        .border(2);
+        .border(2)
+        .z_index(5)
+        .pointer_events(false);
 }
 
 #[derive(Clone, PartialEq)]
@@ -371,6 +501,7 @@ impl ViewTemplate for SelectionRectView {
                 .selection_rect
                 .map(|rect| {
                     Element::<NodeBundle>::new()
+                        .named("SelectionRect")
                         .style(style_selection_rect)
                         .style_dyn(
                             |rect, sb| {
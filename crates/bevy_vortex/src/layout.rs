@@ -0,0 +1,199 @@
+use bevy::{
+    ecs::{system::SystemState, world::Command},
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+use crate::graph::{Connection, GraphNode, SubgraphOf};
+
+/// Horizontal gap between layers.
+const LAYER_SPACING: i32 = 60;
+
+/// Vertical gap between nodes in the same layer.
+const NODE_SPACING: i32 = 24;
+
+/// Size to assume for nodes which have not been displayed yet.
+const DEFAULT_NODE_SIZE: IVec2 = IVec2::new(150, 100);
+
+/// Number of passes of the crossing-reduction step.
+const ORDERING_PASSES: usize = 4;
+
+/// Arrange all of the nodes in the graph using a layered (Sugiyama-style) layout. Data flows
+/// from left to right: each node is placed in a column to the right of all of the nodes which
+/// it takes inputs from. Nodes which have been collapsed into a subgraph are laid out as part
+/// of the subgraph node.
+///
+/// The layout is centered on the previous center of the graph. Does nothing if the graph
+/// contains a cycle.
+pub(crate) struct LayoutGraphCmd;
+
+impl Command for LayoutGraphCmd {
+    fn apply(self, world: &mut World) {
+        let mut st: SystemState<(
+            Query<(Entity, &GraphNode), Without<SubgraphOf>>,
+            Query<&SubgraphOf>,
+            Query<&Connection>,
+        )> = SystemState::new(world);
+        let (nodes, subgraph_of, connections) = st.get(world);
+
+        let node_ids: Vec<Entity> = nodes.iter().map(|(entity, _)| entity).collect();
+        if node_ids.is_empty() {
+            return;
+        }
+        let sizes: HashMap<Entity, IVec2> = nodes
+            .iter()
+            .map(|(entity, node)| {
+                let size = if node.size.x > 0 && node.size.y > 0 {
+                    node.size
+                } else {
+                    DEFAULT_NODE_SIZE
+                };
+                (entity, size)
+            })
+            .collect();
+        let old_center =
+            nodes.iter().map(|(_, node)| node.position).sum::<IVec2>() / node_ids.len() as i32;
+
+        // Edges run from the node which produces a value to the node which consumes it.
+        let visible = |node: Entity| subgraph_of.get(node).map_or(node, |s| s.0);
+        let edges: HashSet<(Entity, Entity)> = connections
+            .iter()
+            .map(|conn| (visible(conn.output.node_id), visible(conn.input.node_id)))
+            .filter(|(src, dst)| src != dst)
+            .collect();
+
+        let Some(layers) = assign_layers(&node_ids, &edges) else {
+            warn!("Graph contains a cycle, unable to arrange nodes");
+            return;
+        };
+        let layers = order_layers(layers, &edges);
+
+        // Place each layer in a column, with the nodes stacked vertically and centered.
+        let mut positions: Vec<(Entity, IVec2)> = Vec::with_capacity(node_ids.len());
+        let mut left = 0;
+        for layer in layers.iter() {
+            let width = layer.iter().map(|node| sizes[node].x).max().unwrap_or(0);
+            let height = layer.iter().map(|node| sizes[node].y).sum::<i32>()
+                + NODE_SPACING * (layer.len() as i32 - 1);
+            let mut top = -height / 2;
+            for node in layer.iter() {
+                let size = sizes[node];
+                positions.push((*node, IVec2::new(left + width / 2, top + size.y / 2)));
+                top += size.y + NODE_SPACING;
+            }
+            left += width + LAYER_SPACING;
+        }
+
+        let new_center =
+            positions.iter().map(|(_, pos)| *pos).sum::<IVec2>() / positions.len() as i32;
+        let offset = old_center - new_center;
+        for (node, position) in positions {
+            if let Some(mut node) = world.get_mut::<GraphNode>(node) {
+                node.position = position + offset;
+            }
+        }
+    }
+}
+
+/// Assign each node to a layer, such that every edge goes from a lower layer to a higher one.
+/// Each node is placed in the layer after its furthest predecessor (longest-path layering).
+/// Returns `None` if the graph contains a cycle.
+fn assign_layers(nodes: &[Entity], edges: &HashSet<(Entity, Entity)>) -> Option<Vec<Vec<Entity>>> {
+    let mut in_degree: HashMap<Entity, usize> = nodes.iter().map(|node| (*node, 0)).collect();
+    let mut successors: HashMap<Entity, Vec<Entity>> = HashMap::default();
+    for (src, dst) in edges.iter() {
+        if !in_degree.contains_key(src) || !in_degree.contains_key(dst) {
+            continue;
+        }
+        *in_degree.get_mut(dst).unwrap() += 1;
+        successors.entry(*src).or_default().push(*dst);
+    }
+
+    // Kahn's algorithm; nodes are visited in topological order.
+    let mut ready: Vec<Entity> = nodes
+        .iter()
+        .filter(|node| in_degree[*node] == 0)
+        .copied()
+        .collect();
+    let mut layer_of: HashMap<Entity, usize> = HashMap::default();
+    let mut visited = 0;
+    while let Some(node) = ready.pop() {
+        visited += 1;
+        let layer = *layer_of.entry(node).or_insert(0);
+        for succ in successors.get(&node).into_iter().flatten() {
+            let succ_layer = layer_of.entry(*succ).or_insert(0);
+            *succ_layer = (*succ_layer).max(layer + 1);
+            let degree = in_degree.get_mut(succ).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                ready.push(*succ);
+            }
+        }
+    }
+    if visited < nodes.len() {
+        return None;
+    }
+
+    let num_layers = layer_of.values().max().map_or(0, |max| max + 1);
+    let mut layers: Vec<Vec<Entity>> = vec![Vec::new(); num_layers];
+    for node in nodes.iter() {
+        layers[layer_of[node]].push(*node);
+    }
+    Some(layers)
+}
+
+/// Reorder the nodes within each layer to reduce edge crossings, using the barycenter
+/// heuristic: each node is moved towards the average position of its neighbors in the
+/// adjacent layer. Alternates between sweeping forwards and backwards.
+fn order_layers(
+    mut layers: Vec<Vec<Entity>>,
+    edges: &HashSet<(Entity, Entity)>,
+) -> Vec<Vec<Entity>> {
+    for pass in 0..ORDERING_PASSES {
+        let forward = pass % 2 == 0;
+        let indices: Vec<usize> = if forward {
+            (1..layers.len()).collect()
+        } else {
+            (0..layers.len().saturating_sub(1)).rev().collect()
+        };
+        for index in indices {
+            let fixed = if forward {
+                &layers[index - 1]
+            } else {
+                &layers[index + 1]
+            };
+            let rank: HashMap<Entity, usize> = fixed
+                .iter()
+                .enumerate()
+                .map(|(i, node)| (*node, i))
+                .collect();
+            let mut keyed: Vec<(f32, usize, Entity)> = layers[index]
+                .iter()
+                .enumerate()
+                .map(|(i, node)| {
+                    let neighbors: Vec<usize> = edges
+                        .iter()
+                        .filter_map(|(src, dst)| {
+                            let other = if forward {
+                                (dst == node).then_some(src)
+                            } else {
+                                (src == node).then_some(dst)
+                            };
+                            other.and_then(|other| rank.get(other).copied())
+                        })
+                        .collect();
+                    // Nodes with no neighbors in the fixed layer keep their current position.
+                    let barycenter = if neighbors.is_empty() {
+                        i as f32
+                    } else {
+                        neighbors.iter().sum::<usize>() as f32 / neighbors.len() as f32
+                    };
+                    (barycenter, i, *node)
+                })
+                .collect();
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+            layers[index] = keyed.into_iter().map(|(_, _, node)| node).collect();
+        }
+    }
+    layers
+}
@@ -1,18 +1,22 @@
 #![feature(impl_trait_in_assoc_type)]
 
 mod add_node;
+mod animation;
 mod catalog;
 mod commands;
 mod gen;
 mod graph;
 mod graph_view;
+mod layout;
 mod operator;
 mod ops;
 mod pipeline;
 mod preview;
 mod propedit;
+mod quick_connect;
 
 use add_node::AddNodeButton;
+use animation::{animate_parameters, rebuild_animated_nodes};
 use bevy_mod_picking::{
     debug::DebugPickingMode,
     picking_core::Pickable,
@@ -30,16 +34,21 @@ use bevy_quill_obsidian_graph::{
     ConnectionAnchor, ConnectionTarget, DragAction, Gesture, GraphEvent, ObsidianGraphPlugin,
 };
 use catalog::{build_operator_catalog, CatalogView, OperatorCatalog, SelectedCatalogEntry};
-use commands::{AddConnectionCmd, DeleteSelectedCmd};
+use commands::{
+    AddConnectionCmd, CollapseToSubgraphCmd, CopySelectedCmd, DeleteSelectedCmd, ExpandSubgraphCmd,
+    GraphClipboard, PasteCmd, RedoCmd, UndoCmd,
+};
 use gen::{begin_build_shaders, finish_build_shaders};
 use graph::{
     sync_connection_refs, Connection, GraphNode, GraphResource, NodeBasePosition, NodeSelected,
-    ValidateConnectionCmd,
+    SubgraphOf, Terminal, UndoAction, UndoMutation, ValidateConnectionCmd,
 };
 use graph_view::{DragState, GraphView, GraphViewId};
+use layout::LayoutGraphCmd;
 use ops::OperatorsPlugin;
 use pipeline::NodeShaderMeshPlugin;
 use preview::{PreviewControls, PreviewPlugin};
+use quick_connect::QuickConnect;
 
 use bevy::{asset::embedded_asset, prelude::*, ui};
 use bevy_quill::*;
@@ -85,6 +94,7 @@ fn main() {
     App::new()
         .init_resource::<OperatorCatalog>()
         .init_resource::<GraphResource>()
+        .init_resource::<GraphClipboard>()
         .init_resource::<SelectedCatalogEntry>()
         .insert_resource(PanelWidth(300.))
         .init_resource::<viewport::ViewportInset>()
@@ -111,7 +121,8 @@ fn main() {
                 build_operator_catalog,
                 viewport::update_viewport_inset,
                 viewport::update_camera_viewport,
-                begin_build_shaders,
+                (animate_parameters, rebuild_animated_nodes),
+                begin_build_shaders.after(rebuild_animated_nodes),
                 finish_build_shaders,
             ),
         )
@@ -169,6 +180,20 @@ impl ViewTemplate for VortexUi {
                                     || event.key_code == KeyCode::Backspace
                                 {
                                     commands.add(DeleteSelectedCmd);
+                                } else if event.key_code == KeyCode::KeyG && event.ctrl {
+                                    commands.add(CollapseToSubgraphCmd);
+                                } else if event.key_code == KeyCode::KeyL && !event.ctrl {
+                                    commands.add(LayoutGraphCmd);
+                                } else if event.key_code == KeyCode::KeyC && event.ctrl {
+                                    commands.add(CopySelectedCmd);
+                                } else if event.key_code == KeyCode::KeyV && event.ctrl {
+                                    commands.add(PasteCmd);
+                                } else if event.key_code == KeyCode::KeyZ && event.ctrl {
+                                    if event.shift {
+                                        commands.add(RedoCmd);
+                                    } else {
+                                        commands.add(UndoCmd);
+                                    }
                                 }
                             },
                         ),
@@ -204,9 +229,11 @@ impl ViewTemplate for VortexUi {
                 Splitter::new()
                     .direction(SplitterDirection::Vertical)
                     .value(panel_width)
+                    .min_size(200.)
+                    .max_size(800.)
                     .on_change(cx.create_callback(|value: In<f32>, world: &mut World| {
                         let mut panel_width = world.get_resource_mut::<PanelWidth>().unwrap();
-                        panel_width.0 = value.clamp(200., 800.);
+                        panel_width.0 = *value;
                     })),
                 CenterPanel,
             ))
@@ -233,14 +260,19 @@ impl ViewTemplate for CenterPanel {
                         |event: Listener<GraphEvent>,
                          mut commands: Commands,
                          mut catalog_selection: ResMut<SelectedCatalogEntry>,
+                         mut graph: ResMut<GraphResource>,
                          mut query_drag_state: Query<&mut DragState>,
-                         mut query_graph_nodes: Query<(
-                            Entity,
-                            &mut GraphNode,
-                            Option<&NodeSelected>,
-                            Option<&NodeBasePosition>,
-                        )>,
-                         mut query_connections: Query<&mut Connection>| {
+                         mut query_graph_nodes: Query<
+                            (
+                                Entity,
+                                &mut GraphNode,
+                                Option<&NodeSelected>,
+                                Option<&NodeBasePosition>,
+                            ),
+                            Without<SubgraphOf>,
+                        >,
+                         mut query_connections: Query<&mut Connection>,
+                         query_terminals: Query<&Terminal>| {
                             let mut drag_state = query_drag_state.single_mut();
                             match event.gesture {
                                 // Move nodes by dragging.
@@ -270,8 +302,26 @@ impl ViewTemplate for CenterPanel {
                                         }
 
                                         DragAction::Finish => {
-                                            for (ent, _, _, _) in query_graph_nodes.iter_mut() {
-                                                commands.entity(ent).remove::<NodeBasePosition>();
+                                            // Record the moved nodes so that the move can be
+                                            // undone.
+                                            let mut moves = Vec::new();
+                                            for (ent, node, _, base) in query_graph_nodes.iter_mut()
+                                            {
+                                                if let Some(base) = base {
+                                                    if base.0 != node.position {
+                                                        moves.push((ent, base.0, node.position));
+                                                    }
+                                                    commands
+                                                        .entity(ent)
+                                                        .remove::<NodeBasePosition>();
+                                                }
+                                            }
+                                            if !moves.is_empty() {
+                                                let mut action = UndoAction::new("Move");
+                                                action
+                                                    .mutations
+                                                    .push(UndoMutation::MoveNodes(moves));
+                                                graph.0.add_undo_action(&mut commands, action);
                                             }
                                         }
                                     }
@@ -279,6 +329,7 @@ impl ViewTemplate for CenterPanel {
 
                                 Gesture::Connect(anchor, target, action) => match action {
                                     DragAction::Start | DragAction::Update => {
+                                        drag_state.quick_connect = None;
                                         drag_state.connect_from = Some(anchor);
                                         drag_state.connect_to = Some(target);
                                         match (anchor, target) {
@@ -374,6 +425,31 @@ impl ViewTemplate for CenterPanel {
                                                 });
                                             }
 
+                                            // Dropped on a node body, let the user pick an output.
+                                            (
+                                                ConnectionAnchor::InputTerminal(input),
+                                                ConnectionTarget::NodeBody(node),
+                                            ) => {
+                                                if query_terminals
+                                                    .get(input)
+                                                    .map_or(false, |t| t.node_id != node)
+                                                {
+                                                    drag_state.quick_connect =
+                                                        Some(QuickConnect { anchor, node });
+                                                }
+                                            }
+
+                                            (
+                                                ConnectionAnchor::EdgeSource(edge),
+                                                ConnectionTarget::NodeBody(node),
+                                            ) => {
+                                                let conn = query_connections.get_mut(edge).unwrap();
+                                                if conn.input.node_id != node {
+                                                    drag_state.quick_connect =
+                                                        Some(QuickConnect { anchor, node });
+                                                }
+                                            }
+
                                             _ => {}
                                         }
 
@@ -383,6 +459,17 @@ impl ViewTemplate for CenterPanel {
                                     }
                                 },
 
+                                // Resize a node by dragging its resize handle.
+                                Gesture::Resize(node, size, action) => {
+                                    if action != DragAction::Start {
+                                        if let Ok((_, mut node, _, _)) =
+                                            query_graph_nodes.get_mut(node)
+                                        {
+                                            node.size = size.as_ivec2();
+                                        }
+                                    }
+                                }
+
                                 // bevy_quill_obsidian_graph::Gesture::Scroll(_) => todo!(),
                                 Gesture::SelectRect(rect, action) => {
                                     if action == DragAction::Finish {
@@ -443,6 +530,7 @@ impl ViewTemplate for CenterPanel {
                                 }
 
                                 Gesture::SelectClear => {
+                                    drag_state.quick_connect = None;
                                     for (node, _, selected, _) in query_graph_nodes.iter_mut() {
                                         if selected.is_some() {
                                             commands.entity(node).remove::<NodeSelected>();
@@ -450,7 +538,12 @@ impl ViewTemplate for CenterPanel {
                                     }
                                 }
 
+                                Gesture::Open(node) => {
+                                    commands.add(ExpandSubgraphCmd(node));
+                                }
+
                                 Gesture::Cancel => {
+                                    drag_state.quick_connect = None;
                                     drag_state.connect_from = None;
                                     drag_state.connect_to = None;
                                     drag_state.selection_rect = None;
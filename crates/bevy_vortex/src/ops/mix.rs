@@ -10,9 +10,31 @@ use crate::{
     },
 };
 
+use super::wgsl::BLEND;
+
+/// How the two inputs of a [`Mix`] operator are combined.
+#[derive(Debug, Reflect, Clone, Copy, PartialEq, Default)]
+#[reflect(Default)]
+pub enum BlendMode {
+    /// Replace A with B.
+    #[default]
+    Normal,
+    /// Multiply A by B, which darkens.
+    Multiply,
+    /// Inverse of multiplying the inverses of A and B, which lightens.
+    Screen,
+    /// Multiply or screen, depending on A.
+    Overlay,
+    /// Absolute difference between A and B.
+    Difference,
+}
+
 #[derive(Debug, Reflect, Clone, Default)]
 #[reflect(Operator, Default, @OperatorClass(OperatorCategory::Filter), @OperatorDescription("
-Interpolate between two colors by a mix factor.
+Blend two colors together, and interpolate between the first color and the blended result
+by a mix factor.
+* **Blend Mode** selects how the colors are combined: normal, multiply, screen, overlay
+  or difference.
 "))]
 pub struct Mix {
     /// Output color
@@ -34,6 +56,10 @@ pub struct Mix {
         @OpValueRange::<f32>(0.0..=1.0),
         @OpValuePrecision(3))]
     pub factor: f32,
+
+    /// How the inputs are combined.
+    #[reflect(@DisplayName("Blend Mode"))]
+    pub blend_mode: BlendMode,
 }
 
 impl Operator for Mix {
@@ -63,10 +89,24 @@ impl Operator for Mix {
         // TODO: Constant folding. Maybe this should be done as a post-process? We'll need
         // to make a 'mix' object that can evaluate it's arguments.
 
+        if self.blend_mode == BlendMode::Normal {
+            return Expr::FnCall(
+                DataType::LinearRgba,
+                "mix",
+                vec![Arc::new(src_a), Arc::new(src_b), Arc::new(factor)],
+            );
+        }
+
+        assembly.add_include(BLEND);
         Expr::FnCall(
             DataType::LinearRgba,
-            "mix",
-            vec![Arc::new(src_a), Arc::new(src_b), Arc::new(factor)],
+            "blend",
+            vec![
+                Arc::new(Expr::ConstI32(self.blend_mode as i32)),
+                Arc::new(src_a),
+                Arc::new(src_b),
+                Arc::new(factor),
+            ],
         )
     }
 }
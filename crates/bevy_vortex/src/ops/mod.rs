@@ -7,28 +7,40 @@ mod grayscale;
 mod mix;
 mod noise;
 mod output;
+mod perlin;
+mod subgraph;
+mod voronoi;
 mod wgsl;
 
 use bricks::Bricks;
 use color::ConstColor;
 use geometry::Geometry;
 use grayscale::Grayscale;
-use mix::Mix;
+use mix::{BlendMode, Mix};
 use noise::Noise;
 use output::Output;
+use perlin::PerlinNoise;
+pub(crate) use subgraph::Subgraph;
+use voronoi::Voronoi;
 
 pub struct OperatorsPlugin;
 
 impl Plugin for OperatorsPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
+        embedded_asset!(app, "wgsl/blend.wgsl");
         embedded_asset!(app, "wgsl/bricks.wgsl");
+        embedded_asset!(app, "wgsl/perlin.wgsl");
         embedded_asset!(app, "wgsl/smootherstep.wgsl");
-        app.register_type::<Bricks>()
+        embedded_asset!(app, "wgsl/voronoi.wgsl");
+        app.register_type::<BlendMode>()
+            .register_type::<Bricks>()
             .register_type::<ConstColor>()
             .register_type::<Geometry>()
             .register_type::<Grayscale>()
             .register_type::<Mix>()
             .register_type::<Output>()
-            .register_type::<Noise>();
+            .register_type::<Noise>()
+            .register_type::<PerlinNoise>()
+            .register_type::<Voronoi>();
     }
 }
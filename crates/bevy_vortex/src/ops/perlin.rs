@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::{
+    gen::{DataType, Expr, ShaderAssembly, TerminalReader},
+    operator::{
+        DisplayName, OpValuePrecision, OpValueRange, Operator, OperatorCategory, OperatorClass,
+        OperatorDescription, OperatorInput, OperatorInputOnly, OperatorOutput, ReflectOperator,
+    },
+};
+
+use super::wgsl::PERLIN;
+
+#[derive(Debug, Reflect, Clone)]
+#[reflect(
+    Operator,
+    Default,
+    @OperatorClass(OperatorCategory::Generator),
+    @OperatorDescription("
+Classic gradient (Perlin) noise.
+* **Scale** is the frequency of the first octave.
+* **Octaves** is the number of layers of noise to combine.
+* **Roughness** controls how much each successive octave contributes.
+"))]
+pub struct PerlinNoise {
+    /// Output value
+    #[reflect(@OperatorOutput, @DisplayName("Out"))]
+    pub output: f32,
+
+    /// Input texture coordinates.
+    #[reflect(@OperatorInput, @OperatorInputOnly, @DisplayName("UV"))]
+    pub uv: Vec2,
+
+    /// Scale
+    #[reflect(
+        @OperatorInput,
+        @DisplayName("Scale"),
+        @OpValueRange::<f32>(0.0..=32.0),
+        @OpValuePrecision(3))]
+    pub scale: f32,
+
+    /// Octaves
+    #[reflect(
+        @DisplayName("Octaves"),
+        @OpValueRange::<i32>(1..=16))]
+    pub octaves: i32,
+
+    /// Persistence, a.k.a roughness, from 0 to 1.
+    #[reflect(
+        @OperatorInput,
+        @DisplayName("Roughness"),
+        @OpValueRange::<f32>(0.0..=1.0),
+        @OpValuePrecision(3))]
+    pub roughness: f32,
+}
+
+impl Operator for PerlinNoise {
+    fn to_boxed_clone(&self) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn gen(
+        &self,
+        assembly: &mut ShaderAssembly,
+        reader: &TerminalReader,
+        node_id: Entity,
+        _out_id: &str,
+    ) -> Expr {
+        assembly.add_include(PERLIN);
+
+        let uv = match reader.read_input_terminal(assembly, node_id, "uv") {
+            Some(expr) => expr.cast(DataType::Vec2),
+            None => {
+                assembly.needs_uv = true;
+                Expr::RefLocal(DataType::Vec2, "mesh.uv".to_string())
+            }
+        };
+
+        let scale = match reader.read_input_terminal(assembly, node_id, "scale") {
+            Some(expr) => expr.cast(DataType::F32),
+            None => Expr::ConstF32(self.scale),
+        };
+
+        let roughness = match reader.read_input_terminal(assembly, node_id, "roughness") {
+            Some(expr) => expr.cast(DataType::F32),
+            None => Expr::ConstF32(self.roughness),
+        };
+
+        Expr::FnCall(
+            DataType::F32,
+            "perlin_octaves",
+            vec![
+                Arc::new(uv),
+                Arc::new(scale),
+                Arc::new(Expr::ConstI32(self.octaves)),
+                Arc::new(roughness),
+            ],
+        )
+    }
+}
+
+impl Default for PerlinNoise {
+    fn default() -> Self {
+        PerlinNoise {
+            output: 0.0,
+            uv: Vec2::ZERO,
+            scale: 5.0,
+            octaves: 2,
+            roughness: 0.5,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use crate::{
+    gen::{Expr, ShaderAssembly, TerminalReader},
+    operator::{Operator, ReflectOperator},
+};
+
+/// Operator for a node which contains a group of collapsed nodes. This is not registered
+/// in the operator catalog; subgraph nodes are only created by collapsing a selection.
+///
+/// The connections to a collapsed node remain attached to the member nodes, so a subgraph
+/// node never takes part in code generation itself.
+#[derive(Debug, Reflect, Clone, Default)]
+#[reflect(Operator, Default)]
+pub struct Subgraph {}
+
+impl Operator for Subgraph {
+    fn to_boxed_clone(&self) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn gen(
+        &self,
+        _assembly: &mut ShaderAssembly,
+        _reader: &TerminalReader,
+        _node_id: Entity,
+        _out_id: &str,
+    ) -> Expr {
+        Expr::ConstF32(0.)
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::{
+    gen::{DataType, Expr, ShaderAssembly, TerminalReader},
+    operator::{
+        DisplayName, OpValuePrecision, OpValueRange, Operator, OperatorCategory, OperatorClass,
+        OperatorDescription, OperatorInput, OperatorInputOnly, OperatorOutput, ReflectOperator,
+    },
+};
+
+use super::wgsl::VORONOI;
+
+#[derive(Debug, Reflect, Clone)]
+#[reflect(
+    Operator,
+    Default,
+    @OperatorClass(OperatorCategory::Generator),
+    @OperatorDescription("
+Cellular (Voronoi) noise.
+* **Out** is the distance to the nearest feature point.
+* **Cell** is a random value between 0 and 1 which is unique to each cell.
+* **Scale** is the number of cells along each axis of the first octave.
+* **Octaves** is the number of layers of noise to combine.
+* **Roughness** controls how much each successive octave contributes.
+"))]
+pub struct Voronoi {
+    /// Distance to nearest point
+    #[reflect(@OperatorOutput, @DisplayName("Out"))]
+    pub output: f32,
+
+    /// Cell id
+    #[reflect(@OperatorOutput, @DisplayName("Cell"))]
+    pub cell: f32,
+
+    /// Input texture coordinates.
+    #[reflect(@OperatorInput, @OperatorInputOnly, @DisplayName("UV"))]
+    pub uv: Vec2,
+
+    /// Scale
+    #[reflect(
+        @OperatorInput,
+        @DisplayName("Scale"),
+        @OpValueRange::<f32>(0.0..=32.0),
+        @OpValuePrecision(3))]
+    pub scale: f32,
+
+    /// Octaves
+    #[reflect(
+        @DisplayName("Octaves"),
+        @OpValueRange::<i32>(1..=16))]
+    pub octaves: i32,
+
+    /// Persistence, a.k.a roughness, from 0 to 1.
+    #[reflect(
+        @OperatorInput,
+        @DisplayName("Roughness"),
+        @OpValueRange::<f32>(0.0..=1.0),
+        @OpValuePrecision(3))]
+    pub roughness: f32,
+}
+
+impl Operator for Voronoi {
+    fn to_boxed_clone(&self) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn gen(
+        &self,
+        assembly: &mut ShaderAssembly,
+        reader: &TerminalReader,
+        node_id: Entity,
+        out_id: &str,
+    ) -> Expr {
+        assembly.add_include(VORONOI);
+
+        let uv = match reader.read_input_terminal(assembly, node_id, "uv") {
+            Some(expr) => expr.cast(DataType::Vec2),
+            None => {
+                assembly.needs_uv = true;
+                Expr::RefLocal(DataType::Vec2, "mesh.uv".to_string())
+            }
+        };
+
+        let scale = match reader.read_input_terminal(assembly, node_id, "scale") {
+            Some(expr) => expr.cast(DataType::F32),
+            None => Expr::ConstF32(self.scale),
+        };
+
+        let roughness = match reader.read_input_terminal(assembly, node_id, "roughness") {
+            Some(expr) => expr.cast(DataType::F32),
+            None => Expr::ConstF32(self.roughness),
+        };
+
+        let id = reader.get_node_index(node_id);
+        let var_name = format!("voronoi_out_{}", id.0);
+        if !assembly.local_exists(&var_name) {
+            assembly.declare_local(
+                var_name.clone(),
+                DataType::Vec2,
+                false,
+                Arc::new(Expr::FnCall(
+                    DataType::Vec2,
+                    "voronoi_octaves",
+                    vec![
+                        Arc::new(uv),
+                        Arc::new(scale),
+                        Arc::new(Expr::ConstI32(self.octaves)),
+                        Arc::new(roughness),
+                    ],
+                )),
+            );
+        }
+
+        if out_id == "cell" {
+            Expr::RefLocal(DataType::F32, format!("{}.y", var_name))
+        } else {
+            Expr::RefLocal(DataType::F32, format!("{}.x", var_name))
+        }
+    }
+}
+
+impl Default for Voronoi {
+    fn default() -> Self {
+        Voronoi {
+            output: 0.0,
+            cell: 0.0,
+            uv: Vec2::ZERO,
+            scale: 5.0,
+            octaves: 1,
+            roughness: 0.5,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// Blend color `b` over color `a` using the given blend mode, then interpolate between `a`
+// and the blended result by `factor`.
+// Blend modes: 0 = Normal, 1 = Multiply, 2 = Screen, 3 = Overlay, 4 = Difference.
+fn blend(mode: i32, a: vec4<f32>, b: vec4<f32>, factor: f32) -> vec4<f32> {
+  var rgb: vec3<f32>;
+  switch mode {
+    case 1: {
+      rgb = a.rgb * b.rgb;
+    }
+    case 2: {
+      rgb = 1.0 - (1.0 - a.rgb) * (1.0 - b.rgb);
+    }
+    case 3: {
+      rgb = select(
+        1.0 - 2.0 * (1.0 - a.rgb) * (1.0 - b.rgb),
+        2.0 * a.rgb * b.rgb,
+        a.rgb < vec3<f32>(0.5));
+    }
+    case 4: {
+      rgb = abs(a.rgb - b.rgb);
+    }
+    default: {
+      rgb = b.rgb;
+    }
+  }
+  return mix(a, vec4<f32>(rgb, b.a), factor);
+}
@@ -1,3 +1,6 @@
+pub const BLEND: &str = include_str!("blend.wgsl");
 pub const BRICKS: &str = include_str!("bricks.wgsl");
 pub const NOISED: &str = include_str!("noised.wgsl");
+pub const PERLIN: &str = include_str!("perlin.wgsl");
 pub const SMOOTHERSTEP: &str = include_str!("smootherstep.wgsl");
+pub const VORONOI: &str = include_str!("voronoi.wgsl");
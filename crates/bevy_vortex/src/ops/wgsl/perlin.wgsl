@@ -0,0 +1,39 @@
+// Classic 2D gradient (Perlin) noise.
+// Returns a value in the range [-1, 1].
+fn perlin_gradient(p: vec2<f32>) -> vec2<f32> {
+  let q = vec2<f32>(dot(p, vec2<f32>(127.1, 311.7)), dot(p, vec2<f32>(269.5, 183.3)));
+  return -1.0 + 2.0 * fract(sin(q) * 43758.5453123);
+}
+
+fn perlin(x: vec2<f32>) -> f32 {
+  let i = floor(x);
+  let f = fract(x);
+
+  let u = f * f * f * (f * (f * 6.0 - 15.0) + 10.0);
+
+  let a = dot(perlin_gradient(i + vec2<f32>(0.0, 0.0)), f - vec2<f32>(0.0, 0.0));
+  let b = dot(perlin_gradient(i + vec2<f32>(1.0, 0.0)), f - vec2<f32>(1.0, 0.0));
+  let c = dot(perlin_gradient(i + vec2<f32>(0.0, 1.0)), f - vec2<f32>(0.0, 1.0));
+  let d = dot(perlin_gradient(i + vec2<f32>(1.0, 1.0)), f - vec2<f32>(1.0, 1.0));
+
+  return mix(mix(a, b, u.x), mix(c, d, u.x), u.y);
+}
+
+fn perlin_octaves(
+    uv: vec2<f32>,
+    scale: f32,
+    octaves: i32,
+    roughness: f32) -> f32 {
+
+    var pos = uv * scale;
+    var result = 0.0;
+    var coeff = 1.0;
+    var total = 0.0;
+    for (var i = 0u; i < u32(octaves); i++) {
+        result += perlin(pos) * coeff;
+        total += coeff;
+        coeff *= roughness;
+        pos *= 2.0;
+    }
+    return result / total * 0.5 + 0.5;
+}
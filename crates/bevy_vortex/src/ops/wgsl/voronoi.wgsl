@@ -0,0 +1,50 @@
+// Cellular (Voronoi) noise.
+fn voronoi_point(p: vec2<f32>) -> vec2<f32> {
+  let q = vec2<f32>(dot(p, vec2<f32>(127.1, 311.7)), dot(p, vec2<f32>(269.5, 183.3)));
+  return fract(sin(q) * 43758.5453123);
+}
+
+// Returns the distance to the nearest feature point (in .x) and a pseudo-random
+// id in the range [0, 1] for the cell containing that point (in .y).
+fn voronoi(x: vec2<f32>) -> vec2<f32> {
+  let n = floor(x);
+  let f = fract(x);
+
+  var min_dist = 8.0;
+  var cell = vec2<f32>(0.0);
+  for (var j = -1; j <= 1; j++) {
+    for (var i = -1; i <= 1; i++) {
+      let g = vec2<f32>(f32(i), f32(j));
+      let r = g + voronoi_point(n + g) - f;
+      let d = dot(r, r);
+      if d < min_dist {
+        min_dist = d;
+        cell = n + g;
+      }
+    }
+  }
+
+  let id = fract(sin(dot(cell, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+  return vec2<f32>(sqrt(min_dist), id);
+}
+
+fn voronoi_octaves(
+    uv: vec2<f32>,
+    scale: f32,
+    octaves: i32,
+    roughness: f32) -> vec2<f32> {
+
+    var pos = uv * scale;
+    let first = voronoi(pos);
+    var dist = first.x;
+    var coeff = 1.0;
+    var total = 1.0;
+    for (var i = 1u; i < u32(octaves); i++) {
+        coeff *= roughness;
+        pos *= 2.0;
+        dist += voronoi(pos).x * coeff;
+        total += coeff;
+    }
+    // Cell id always comes from the first octave.
+    return vec2<f32>(dist / total, first.y);
+}
@@ -1,35 +1,56 @@
 use bevy::{
     core_pipeline::core_3d::{Opaque3d, Opaque3dBinKey, CORE_3D_DEPTH_FORMAT},
+    ecs::{
+        query::ROQueryItem,
+        system::{
+            lifetimeless::{Read, SRes},
+            SystemParamItem,
+        },
+    },
     pbr::{
         DrawMesh, MeshPipeline, MeshPipelineKey, MeshPipelineViewLayoutKey, RenderMeshInstances,
         SetMeshBindGroup, SetMeshViewBindGroup,
     },
     prelude::*,
     render::{
-        extract_component::{ExtractComponent, ExtractComponentPlugin},
+        extract_component::{
+            ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+            UniformComponentPlugin,
+        },
         mesh::{GpuMesh, MeshVertexBufferLayoutRef},
         render_asset::RenderAssets,
         render_phase::{
-            AddRenderCommand, BinnedRenderPhaseType, DrawFunctions, SetItemPipeline,
-            ViewBinnedRenderPhases,
+            AddRenderCommand, BinnedRenderPhaseType, DrawFunctions, PhaseItem, RenderCommand,
+            RenderCommandResult, SetItemPipeline, TrackedRenderPass, ViewBinnedRenderPhases,
         },
         render_resource::{
-            BlendState, ColorTargetState, ColorWrites, CompareFunction, DepthBiasState,
-            DepthStencilState, Face, FragmentState, FrontFace, MultisampleState, PipelineCache,
-            PolygonMode, PrimitiveState, RenderPipelineDescriptor, SpecializedMeshPipeline,
-            SpecializedMeshPipelineError, SpecializedMeshPipelines, StencilState, TextureFormat,
-            VertexState,
+            binding_types::uniform_buffer, BindGroup, BindGroupEntries, BindGroupLayout,
+            BindGroupLayoutEntries, BlendState, ColorTargetState, ColorWrites, CompareFunction,
+            DepthBiasState, DepthStencilState, Face, FragmentState, FrontFace, MultisampleState,
+            PipelineCache, PolygonMode, PrimitiveState, RenderPipelineDescriptor, ShaderStages,
+            ShaderType, SpecializedMeshPipeline, SpecializedMeshPipelineError,
+            SpecializedMeshPipelines, StencilState, TextureFormat, VertexState,
         },
+        renderer::RenderDevice,
         texture::BevyDefault,
         view::{self, ExtractedView, ViewTarget, VisibilitySystems, VisibleEntities},
         Render, RenderApp, RenderSet,
     },
 };
 
+use crate::gen::MAX_UNIFORM_PARAMS;
+
 /// Component that associates a generated shader to a mesh.
 #[derive(Component, Default, Clone, ExtractComponent)]
 pub struct NodeShader3dHandle(pub Handle<Shader>);
 
+/// Values of animated operator parameters, passed to the generated shader as a uniform.
+/// See [`crate::gen::ShaderAssembly::declare_uniform`] for how these are laid out.
+#[derive(Component, Default, Clone, ExtractComponent, ShaderType)]
+pub struct NodeShaderParams {
+    pub values: [Vec4; MAX_UNIFORM_PARAMS / 4],
+}
+
 /// A query filter that tells [`view::check_visibility`] about our custom
 /// rendered entity.
 type WithNodeShader3dHandle = With<NodeShader3dHandle>;
@@ -39,8 +60,14 @@ type WithNodeShader3dHandle = With<NodeShader3dHandle>;
 pub struct NodeShaderMesh3dPipeline {
     /// this pipeline wraps the standard [`MeshPipeline`]
     mesh_pipeline: MeshPipeline,
+    /// Layout for the parameter uniforms
+    params_layout: BindGroupLayout,
 }
 
+/// Bind group for the parameter uniforms, rebuilt each frame.
+#[derive(Resource, Default)]
+struct NodeShaderParamsBindGroup(Option<BindGroup>);
+
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct NodeShaderMesh3dPipelineKey {
     /// Handle to the generated shader.
@@ -51,8 +78,17 @@ pub struct NodeShaderMesh3dPipelineKey {
 
 impl FromWorld for NodeShaderMesh3dPipeline {
     fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let params_layout = render_device.create_bind_group_layout(
+            "node_shader_params_layout",
+            &BindGroupLayoutEntries::single(
+                ShaderStages::FRAGMENT,
+                uniform_buffer::<NodeShaderParams>(true),
+            ),
+        );
         Self {
             mesh_pipeline: MeshPipeline::from_world(world),
+            params_layout,
         }
     }
 }
@@ -119,6 +155,8 @@ impl SpecializedMeshPipeline for NodeShaderMesh3dPipeline {
                     .clone(),
                 // Bind group 1 is the mesh uniform
                 self.mesh_pipeline.mesh_layouts.model_only.clone(),
+                // Bind group 2 is the parameter uniforms
+                self.params_layout.clone(),
             ],
             push_constant_ranges: vec![],
             primitive: PrimitiveState {
@@ -155,10 +193,53 @@ type DrawNodeShaderMeshCommands = (
     SetMeshViewBindGroup<0>,
     // Set the mesh uniform as bind group 1
     SetMeshBindGroup<1>,
+    // Set the parameter uniforms as bind group 2
+    SetNodeShaderParamsBindGroup<2>,
     // Draw the mesh
     DrawMesh,
 );
 
+struct SetNodeShaderParamsBindGroup<const I: usize>;
+
+impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetNodeShaderParamsBindGroup<I> {
+    type Param = SRes<NodeShaderParamsBindGroup>;
+    type ViewQuery = ();
+    type ItemQuery = Read<DynamicUniformIndex<NodeShaderParams>>;
+
+    fn render<'w>(
+        _item: &P,
+        _view: (),
+        uniform_index: Option<ROQueryItem<'w, Self::ItemQuery>>,
+        bind_group: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let (Some(uniform_index), Some(bind_group)) =
+            (uniform_index, bind_group.into_inner().0.as_ref())
+        else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_bind_group(I, bind_group, &[uniform_index.index()]);
+        RenderCommandResult::Success
+    }
+}
+
+/// A render-world system that creates the bind group for the parameter uniforms.
+fn prepare_node_shader_params(
+    mut commands: Commands,
+    pipeline: Res<NodeShaderMesh3dPipeline>,
+    render_device: Res<RenderDevice>,
+    params: Res<ComponentUniforms<NodeShaderParams>>,
+) {
+    let bind_group = params.uniforms().binding().map(|binding| {
+        render_device.create_bind_group(
+            "node_shader_params_bind_group",
+            &pipeline.params_layout,
+            &BindGroupEntries::single(binding),
+        )
+    });
+    commands.insert_resource(NodeShaderParamsBindGroup(bind_group));
+}
+
 /// A render-world system that enqueues the entity with custom rendering into
 /// the opaque render phases of each view.
 #[allow(clippy::too_many_arguments)]
@@ -244,14 +325,18 @@ pub struct NodeShaderMeshPlugin;
 
 impl Plugin for NodeShaderMeshPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ExtractComponentPlugin::<NodeShader3dHandle>::default())
-            .add_systems(
-                PostUpdate,
-                // Make sure to tell Bevy to check our entity for visibility. Bevy won't
-                // do this by default, for efficiency reasons.
-                view::check_visibility::<WithNodeShader3dHandle>
-                    .in_set(VisibilitySystems::CheckVisibility),
-            );
+        app.add_plugins((
+            ExtractComponentPlugin::<NodeShader3dHandle>::default(),
+            ExtractComponentPlugin::<NodeShaderParams>::default(),
+            UniformComponentPlugin::<NodeShaderParams>::default(),
+        ))
+        .add_systems(
+            PostUpdate,
+            // Make sure to tell Bevy to check our entity for visibility. Bevy won't
+            // do this by default, for efficiency reasons.
+            view::check_visibility::<WithNodeShader3dHandle>
+                .in_set(VisibilitySystems::CheckVisibility),
+        );
 
         // We make sure to add these to the render app, not the main app.
         let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
@@ -260,7 +345,14 @@ impl Plugin for NodeShaderMeshPlugin {
         render_app
             .init_resource::<SpecializedMeshPipelines<NodeShaderMesh3dPipeline>>()
             .add_render_command::<Opaque3d, DrawNodeShaderMeshCommands>()
-            .add_systems(Render, queue_node_shader_item.in_set(RenderSet::Queue));
+            .init_resource::<NodeShaderParamsBindGroup>()
+            .add_systems(
+                Render,
+                (
+                    queue_node_shader_item.in_set(RenderSet::Queue),
+                    prepare_node_shader_params.in_set(RenderSet::PrepareBindGroups),
+                ),
+            );
     }
 
     fn finish(&self, app: &mut App) {
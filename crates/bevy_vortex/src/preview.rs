@@ -8,7 +8,11 @@ use bevy_quill_obsidian::{
     viewport, RoundedCorners,
 };
 
-use crate::{gen::NodeOutput, graph::NodeSelected, pipeline::NodeShader3dHandle};
+use crate::{
+    gen::NodeOutput,
+    graph::{GraphNode, NodeSelected},
+    pipeline::{NodeShader3dHandle, NodeShaderParams},
+};
 
 #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 enum PreviewMode {
@@ -229,6 +233,7 @@ fn enter_mode_cuboid(
     commands.spawn((
         shape,
         NodeShader3dHandle(shader.0.clone()),
+        NodeShaderParams::default(),
         SpatialBundle {
             transform: Transform::from_rotation(Quat::from_rotation_x(-PI / 4.)),
             ..SpatialBundle::INHERITED_IDENTITY
@@ -246,6 +251,7 @@ fn enter_mode_sphere(
     commands.spawn((
         shape,
         NodeShader3dHandle(shader.0.clone()),
+        NodeShaderParams::default(),
         SpatialBundle {
             transform: Transform::from_rotation(Quat::from_rotation_x(-PI / 4.)),
             ..SpatialBundle::INHERITED_IDENTITY
@@ -263,6 +269,7 @@ fn enter_mode_tetra(
     commands.spawn((
         shape,
         NodeShader3dHandle(shader.0.clone()),
+        NodeShaderParams::default(),
         SpatialBundle {
             transform: Transform::from_rotation(Quat::from_rotation_x(-PI / 4.))
                 .with_scale(Vec3::splat(1.4)),
@@ -281,6 +288,7 @@ fn enter_mode_torus(
     commands.spawn((
         shape,
         NodeShader3dHandle(shader.0.clone()),
+        NodeShaderParams::default(),
         SpatialBundle {
             transform: Transform::from_rotation(Quat::from_rotation_x(-PI / 4.)),
             ..SpatialBundle::INHERITED_IDENTITY
@@ -336,6 +344,34 @@ fn update_preview_shader(
     }
 }
 
+/// Copy the current values of animated operator parameters into the preview shape uniforms.
+fn update_preview_params(
+    q_selected: Query<&NodeOutput, With<NodeSelected>>,
+    q_nodes: Query<&GraphNode>,
+    mut q_preview_shapes: Query<&mut NodeShaderParams, With<PreviewShape>>,
+) {
+    let Ok(output) = q_selected.get_single() else {
+        return;
+    };
+    if output.uniforms.is_empty() {
+        return;
+    }
+    let mut params = NodeShaderParams::default();
+    for (slot, (node_id, field)) in output.uniforms.iter().enumerate() {
+        let value = q_nodes
+            .get(*node_id)
+            .ok()
+            .and_then(|node| node.operator_reflect().reflect_path(*field).ok())
+            .and_then(|field| field.downcast_ref::<f32>())
+            .copied()
+            .unwrap_or_default();
+        params.values[slot / 4][slot % 4] = value;
+    }
+    for mut shape_params in q_preview_shapes.iter_mut() {
+        shape_params.values = params.values;
+    }
+}
+
 #[derive(Resource, Default)]
 struct PlaceholderShaderHandle(pub Handle<Shader>);
 
@@ -345,7 +381,14 @@ impl Plugin for PreviewPlugin {
     fn build(&self, app: &mut App) {
         app.insert_state(PreviewMode::Cuboid)
             .add_computed_state::<PreviewMode3d>()
-            .add_systems(Update, (update_preview_shader, rotate_preview_shapes))
+            .add_systems(
+                Update,
+                (
+                    update_preview_shader,
+                    update_preview_params.after(update_preview_shader),
+                    rotate_preview_shapes,
+                ),
+            )
             .add_systems(OnEnter(PreviewMode3d), enter_preview_3d)
             .add_systems(OnExit(PreviewMode3d), exit_preview_3d)
             .add_systems(OnEnter(PreviewMode::Cuboid), enter_mode_cuboid)
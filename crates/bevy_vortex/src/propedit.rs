@@ -3,12 +3,17 @@ use crate::{
     graph::GraphNode,
     operator::{OpValuePrecision, OpValueRange, OpValueStep},
 };
-use bevy::{prelude::*, reflect::TypeInfo, ui};
+use bevy::{
+    prelude::*,
+    reflect::{DynamicEnum, DynamicVariant, ReflectRef, TypeInfo},
+    ui,
+};
 use bevy_mod_stylebuilder::*;
 use bevy_quill::{prelude::*, Dynamic, IntoViewChild};
 use bevy_quill_obsidian::{
     controls::{
-        ColorEdit, ColorEditState, ColorMode, MenuButton, MenuPopup, Slider, SpinBox, Swatch,
+        ColorEdit, ColorEditState, ColorMode, MenuButton, MenuItem, MenuPopup, Slider, SpinBox,
+        Swatch,
     },
     floating::{FloatAlign, FloatSide},
     size::Size,
@@ -64,6 +69,19 @@ impl ViewTemplate for GraphNodePropertyEdit {
             }
             .into_view_child(),
 
+            _ if matches!(
+                reflect.reflect_path(self.field).map(|f| f.reflect_ref()),
+                Ok(ReflectRef::Enum(_))
+            ) =>
+            {
+                GraphNodePropertyEditEnum {
+                    node: self.node,
+                    display_name: self.display_name,
+                    field: self.field,
+                }
+                .into_view_child()
+            }
+
             _ => {
                 warn!("Unsupported type: {}", field.type_path());
                 self.display_name.into_view_child()
@@ -305,3 +323,93 @@ impl ViewTemplate for GraphNodePropertyEditLinearRgba {
             ))
     }
 }
+
+#[derive(Clone, PartialEq)]
+pub struct GraphNodePropertyEditEnum {
+    node: Entity,
+    display_name: &'static str,
+    field: &'static str,
+}
+
+impl ViewTemplate for GraphNodePropertyEditEnum {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let node_id = self.node;
+        let field_name = self.field;
+        let node = cx.use_component::<GraphNode>(node_id).unwrap();
+        let reflect = node.operator_reflect();
+        let field_reflect = reflect.reflect_path(self.field).unwrap();
+        let ReflectRef::Enum(en) = field_reflect.reflect_ref() else {
+            panic!("Expected enum");
+        };
+        let variant_name = en.variant_name();
+        let Some(TypeInfo::Enum(enum_info)) = field_reflect.get_represented_type_info() else {
+            panic!("Expected EnumInfo");
+        };
+        let variants: Vec<(&'static str, bool)> = enum_info
+            .variant_names()
+            .iter()
+            .map(|name| (*name, *name == variant_name))
+            .collect();
+
+        Element::<NodeBundle>::new()
+            .style(|sb: &mut StyleBuilder| {
+                sb.gap(4).justify_items(ui::JustifyItems::End);
+            })
+            .children((
+                Element::<NodeBundle>::new()
+                    .style(|sb: &mut StyleBuilder| {
+                        sb.flex_grow(1.0).flex_basis(0);
+                    })
+                    .children(self.display_name),
+                MenuButton::new()
+                    .size(Size::Xxs)
+                    .style(|sb: &mut StyleBuilder| {
+                        sb.min_width(64).height(NODE_PROP_HEIGHT);
+                    })
+                    .children(variant_name.to_string())
+                    .popup(
+                        MenuPopup::new()
+                            .side(FloatSide::Right)
+                            .align(FloatAlign::Start)
+                            .children(For::each(variants, move |(variant, selected)| {
+                                EnumVariantItem {
+                                    node: node_id,
+                                    field: field_name,
+                                    variant: *variant,
+                                    selected: *selected,
+                                }
+                            })),
+                    ),
+            ))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct EnumVariantItem {
+    node: Entity,
+    field: &'static str,
+    variant: &'static str,
+    selected: bool,
+}
+
+impl ViewTemplate for EnumVariantItem {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let node_id = self.node;
+        let field_name = self.field;
+        let variant = self.variant;
+        MenuItem::new()
+            .label(self.variant)
+            .checked(self.selected)
+            .on_click(cx.create_callback(
+                move |mut nodes: Query<&mut GraphNode>, mut commands: Commands| {
+                    let mut node = nodes.get_mut(node_id).unwrap();
+                    let reflect = node.operator_reflect_mut();
+                    let field_reflect = reflect.reflect_path_mut(field_name).unwrap();
+                    field_reflect.apply(&DynamicEnum::new(variant, DynamicVariant::Unit));
+                    commands.add(MarkModifiedCmd { start: node_id });
+                },
+            ))
+    }
+}
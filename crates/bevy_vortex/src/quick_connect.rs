@@ -0,0 +1,189 @@
+use bevy::{prelude::*, reflect::TypeInfo, ui};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill::{prelude::*, Dynamic, IntoViewChild};
+use bevy_quill_obsidian::{colors, controls::MenuItem};
+use bevy_quill_obsidian_graph::ConnectionAnchor;
+
+use crate::{
+    commands::AddConnectionCmd,
+    graph::{Connection, ConnectionDataType, GraphNode, Terminal},
+    graph_view::{get_terminal_color, DragState},
+    operator::DisplayName,
+};
+
+/// A pending choice of which output terminal to connect to. This is created when a connection
+/// which is being dragged from an input is dropped on the body of a node rather than on one of
+/// its terminals.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct QuickConnect {
+    /// The end of the connection which was being dragged.
+    pub(crate) anchor: ConnectionAnchor,
+    /// The node whose output terminals are being offered.
+    pub(crate) node: Entity,
+}
+
+fn style_quick_connect_popup(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .min_width(120)
+        .margin_left(8)
+        .padding((0, 2))
+        .background_color(colors::U1)
+        .border(1)
+        .border_color(Srgba::BLACK)
+        .border_radius(4.0)
+        .z_index(10);
+}
+
+fn style_quick_connect_title(ss: &mut StyleBuilder) {
+    ss.color(colors::DIM).font_size(12).padding((6, 2));
+}
+
+/// Popup which lists the output terminals of a node, allowing the user to pick one to
+/// complete a pending connection.
+#[derive(Clone, PartialEq)]
+pub(crate) struct QuickConnectPopup;
+
+impl ViewTemplate for QuickConnectPopup {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let drag_state = cx.use_inherited_component::<DragState>().unwrap();
+        let popup = drag_state.quick_connect.and_then(|quick_connect| {
+            let node = cx.use_component::<GraphNode>(quick_connect.node)?;
+            // Position the popup alongside the right edge of the node.
+            let position = node.position + IVec2::new(node.size.x / 2, -node.size.y / 2);
+            let outputs: Vec<_> = node
+                .outputs
+                .iter()
+                .map(|(name, id)| QuickConnectItem {
+                    anchor: quick_connect.anchor,
+                    terminal: *id,
+                    label: output_label(node, name),
+                })
+                .collect();
+            Some(
+                Element::<NodeBundle>::new()
+                    .named("QuickConnectPopup")
+                    .style(style_quick_connect_popup)
+                    .insert_dyn(
+                        |_| {
+                            // Don't let clicks on the popup clear the selection, which would
+                            // also dismiss the popup.
+                            On::<Pointer<Down>>::run(|mut event: ListenerMut<Pointer<Down>>| {
+                                event.stop_propagation();
+                            })
+                        },
+                        (),
+                    )
+                    .style_dyn(
+                        |position, sb| {
+                            sb.left(position.x).top(position.y);
+                        },
+                        position,
+                    )
+                    .children((
+                        Element::<NodeBundle>::new()
+                            .style(style_quick_connect_title)
+                            .children("Connect to:"),
+                        For::each(outputs, |item| item.clone()),
+                    )),
+            )
+        });
+
+        Dynamic::new(popup.into_view_child())
+    }
+}
+
+/// Returns the display name of an output field, falling back to the field name.
+fn output_label(node: &GraphNode, field: &'static str) -> &'static str {
+    let info = node.operator_reflect().get_represented_type_info();
+    if let Some(TypeInfo::Struct(st_info)) = info {
+        if let Some(dname) = st_info
+            .field(field)
+            .and_then(|f| f.custom_attributes().get::<DisplayName>())
+        {
+            return dname.0;
+        }
+    }
+    field
+}
+
+fn style_terminal_swatch(ss: &mut StyleBuilder) {
+    ss.width(8)
+        .height(8)
+        .border_radius(4)
+        .margin_right(6)
+        .flex_shrink(0.);
+}
+
+fn style_terminal_type(ss: &mut StyleBuilder) {
+    ss.color(colors::DIM).margin_left(6);
+}
+
+#[derive(Clone, PartialEq)]
+struct QuickConnectItem {
+    anchor: ConnectionAnchor,
+    terminal: Entity,
+    label: &'static str,
+}
+
+impl ViewTemplate for QuickConnectItem {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let anchor = self.anchor;
+        let output = self.terminal;
+        let color = get_terminal_color(cx, output);
+        let type_name = match cx.use_component::<Terminal>(output).map(|t| t.data_type) {
+            Some(ConnectionDataType::Scalar) => "scalar",
+            Some(ConnectionDataType::Vector) => "vector",
+            Some(ConnectionDataType::Color) => "color",
+            None => "",
+        };
+        let on_click = cx.create_callback(
+            move |mut commands: Commands,
+                  mut q_drag_state: Query<&mut DragState>,
+                  q_connections: Query<&Connection>| {
+                match anchor {
+                    ConnectionAnchor::InputTerminal(input) => {
+                        commands.add(AddConnectionCmd {
+                            output,
+                            input,
+                            replace: None,
+                        });
+                    }
+                    ConnectionAnchor::EdgeSource(edge) => {
+                        if let Ok(conn) = q_connections.get(edge) {
+                            commands.add(AddConnectionCmd {
+                                output,
+                                input: conn.input.terminal_id,
+                                replace: Some(edge),
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+                for mut drag_state in q_drag_state.iter_mut() {
+                    drag_state.quick_connect = None;
+                }
+            },
+        );
+
+        MenuItem::new()
+            .label((
+                Element::<NodeBundle>::new().style((
+                    style_terminal_swatch,
+                    move |sb: &mut StyleBuilder| {
+                        sb.background_color(color);
+                    },
+                )),
+                self.label,
+                Element::<NodeBundle>::new()
+                    .style(style_terminal_type)
+                    .children(type_name),
+            ))
+            .on_click(on_click)
+    }
+}
@@ -14,8 +14,8 @@ use bevy_mod_stylebuilder::*;
 use bevy_quill_obsidian::{
     colors,
     controls::{
-        Button, ButtonVariant, Checkbox, Dialog, DialogBody, DialogFooter, DialogHeader, ListView,
-        Slider, Splitter, SplitterDirection, ToolButton, ToolPalette,
+        Button, ButtonGroup, ButtonVariant, Checkbox, Dialog, DialogBody, DialogFooter,
+        DialogHeader, DialogSize, GroupButton, ListView, Slider, Splitter, SplitterDirection,
     },
     focus::TabGroup,
     prelude::ListRow,
@@ -205,7 +205,7 @@ impl ViewTemplate for DemoUi {
             .insert_dyn(move |_| (TabGroup::default(), TargetCamera(camera)), ())
             .children((
                 Dialog::new()
-                    .width(ui::Val::Px(400.))
+                    .size(DialogSize::Small)
                     .open(dialog_open.get(cx))
                     .on_close(cx.create_callback(move |world: &mut World| {
                         dialog_open.set(world, false);
@@ -238,56 +238,28 @@ impl ViewTemplate for DemoUi {
                         panel_width,
                     )
                     .children((
-                        ToolPalette::new().columns(3).children((
-                            ToolButton::new()
-                                .children("Preview")
-                                .corners(RoundedCorners::Left)
-                                .variant({
-                                    let st = cx.use_resource::<State<EditorState>>();
-                                    if *st.get() == EditorState::Preview {
-                                        ButtonVariant::Selected
-                                    } else {
-                                        ButtonVariant::Default
-                                    }
-                                })
-                                .on_click(cx.create_callback(
-                                    |mut mode: ResMut<NextState<EditorState>>| {
-                                        mode.set(EditorState::Preview);
-                                    },
-                                )),
-                            ToolButton::new()
-                                .children("Materials")
-                                .corners(RoundedCorners::None)
-                                .variant({
-                                    let st = cx.use_resource::<State<EditorState>>();
-                                    if *st.get() == EditorState::Graph {
-                                        ButtonVariant::Selected
-                                    } else {
-                                        ButtonVariant::Default
-                                    }
-                                })
-                                .on_click(cx.create_callback(
-                                    |mut mode: ResMut<NextState<EditorState>>| {
-                                        mode.set(EditorState::Graph);
-                                    },
-                                )),
-                            ToolButton::new()
-                                .children("Split")
-                                .corners(RoundedCorners::Right)
-                                .variant({
-                                    let st = cx.use_resource::<State<EditorState>>();
-                                    if *st.get() == EditorState::Split {
-                                        ButtonVariant::Selected
-                                    } else {
-                                        ButtonVariant::Default
-                                    }
-                                })
-                                .on_click(cx.create_callback(
-                                    |mut mode: ResMut<NextState<EditorState>>| {
-                                        mode.set(EditorState::Split);
-                                    },
-                                )),
-                        )),
+                        ButtonGroup::new()
+                            .value(cx.use_resource::<State<EditorState>>().get().clone())
+                            .on_change(cx.create_callback(
+                                |value: In<EditorState>,
+                                 mut mode: ResMut<NextState<EditorState>>| {
+                                    mode.set(value.0);
+                                },
+                            ))
+                            .children((
+                                GroupButton::new()
+                                    .value(EditorState::Preview)
+                                    .children("Preview")
+                                    .corners(RoundedCorners::Left),
+                                GroupButton::new()
+                                    .value(EditorState::Graph)
+                                    .children("Materials")
+                                    .corners(RoundedCorners::None),
+                                GroupButton::new()
+                                    .value(EditorState::Split)
+                                    .children("Split")
+                                    .corners(RoundedCorners::Right),
+                            )),
                         Element::<NodeBundle>::new()
                             .style(style_button_row)
                             .children((
@@ -312,9 +284,12 @@ impl ViewTemplate for DemoUi {
                             .style(style_column_group)
                             .children((
                                 Checkbox::new()
-                                    // .style(|ss: &mut StyleBuilder| {
-                                    //     ss.cursor_image("demo://unlock.png", Vec2::new(8., 8.));
-                                    // })
+                                    .style(|ss: &mut StyleBuilder| {
+                                        ss.cursor_image(
+                                            "embedded://bevy_quill_obsidian/assets/icons/lock.png",
+                                            Vec2::new(8., 8.),
+                                        );
+                                    })
                                     .label("Include Author Name")
                                     .checked(checked_1.get(cx))
                                     .on_change(cx.create_callback(
@@ -375,9 +350,12 @@ impl ViewTemplate for DemoUi {
                 Splitter::new()
                     .direction(SplitterDirection::Vertical)
                     .value(panel_width)
+                    .min_size(200.)
+                    .min_secondary(300.)
+                    .default_value(200.)
                     .on_change(cx.create_callback(|value: In<f32>, world: &mut World| {
                         let mut panel_width = world.get_resource_mut::<PanelWidth>().unwrap();
-                        panel_width.0 = value.max(200.);
+                        panel_width.0 = *value;
                     })),
                 CenterPanel,
             ))
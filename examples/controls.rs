@@ -8,8 +8,9 @@ use bevy_quill::*;
 use bevy_quill_obsidian::{
     colors,
     controls::{
-        Button, ButtonVariant, Checkbox, ColorGradient, Dialog, DialogFooter, DialogHeader,
-        GradientSlider, MenuButton, MenuDivider, MenuItem, MenuPopup, Slider, SpinBox, Swatch,
+        Button, ButtonVariant, Checkbox, CheckboxState, ColorGradient, Dialog, DialogFooter,
+        DialogHeader, GradientSlider, MenuButton, MenuDivider, MenuItem, MenuPopup, Slider,
+        SpinBox, Swatch,
     },
     ObsidianUiPlugin,
 };
@@ -97,6 +98,10 @@ impl ViewTemplate for ButtonsDemo {
                         .checked(checked.get(cx))
                         .on_change(on_checked)
                         .label("Checkbox (disabled)"),
+                    Checkbox::new()
+                        .state(CheckboxState::Indeterminate)
+                        .on_change(on_checked)
+                        .label("Checkbox (indeterminate)"),
                     Checkbox::new()
                         .checked(disabled.get(cx))
                         .on_change(
@@ -7,6 +7,7 @@ mod common;
 use std::f32::consts::PI;
 
 use bevy::{color::palettes, prelude::*};
+use bevy_mod_picking::DefaultPickingPlugins;
 use bevy_quill::prelude::*;
 use bevy_quill_overlays::{
     Overlay, PolygonOptions, QuillOverlaysPlugin, ShapeOrientation, StrokeMarker,
@@ -17,6 +18,7 @@ fn main() {
     App::new()
         .add_plugins((
             DefaultPlugins.set(ImagePlugin::default_nearest()),
+            DefaultPickingPlugins,
             QuillPlugin,
             QuillOverlaysPlugin,
         ))
@@ -41,11 +43,36 @@ struct OverlayExample;
 
 impl ViewTemplate for OverlayExample {
     type View = impl View;
-    fn create(&self, _cx: &mut Cx) -> Self::View {
+    fn create(&self, cx: &mut Cx) -> Self::View {
         let mut trans = Transform::from_translation(Vec3::new(0., 0.03, 0.));
         trans.rotate_local_y(-PI * 0.3);
 
-        Overlay::new()
+        let selected = cx.create_mutable(false);
+        let on_click = cx.create_callback(move |world: &mut World| {
+            let value = selected.get(world);
+            selected.set(world, !value);
+        });
+
+        let clickable = Overlay::new()
+            .named("Clickable")
+            .shape(|sb| {
+                sb.with_orientation(ShapeOrientation::YPositive)
+                    .fill_circle(Vec2::new(-3., -3.), 1.0, 32);
+            })
+            .color(match selected.get(cx) {
+                true => palettes::css::LIME,
+                false => palettes::css::RED,
+            })
+            .transform(trans)
+            .label(
+                "Click me",
+                Vec3::new(-3., 0.5, -3.),
+                14.,
+                palettes::css::WHITE,
+            )
+            .on_click(on_click);
+
+        let shapes = Overlay::new()
             .shape(|sb| {
                 sb.with_stroke_width(0.3)
                     .with_orientation(ShapeOrientation::YPositive)
@@ -107,10 +134,31 @@ impl ViewTemplate for OverlayExample {
                             ..default()
                         },
                     )
+                    .stroke_arc(
+                        Vec2::new(6., 3.),
+                        0.7,
+                        0.,
+                        PI * 1.5,
+                        24,
+                        PolygonOptions {
+                            end_marker: StrokeMarker::Arrowhead,
+                            ..default()
+                        },
+                    )
+                    .stroke_cubic_bezier(
+                        Vec2::new(5., 6.),
+                        Vec2::new(6., 8.),
+                        Vec2::new(7., 5.),
+                        Vec2::new(8., 7.),
+                        24,
+                        PolygonOptions::default(),
+                    )
                     .stroke_line_segment(Vec2::new(4., -6.), Vec2::new(5., -7.))
                     .stroke_line_segment_3d(Vec3::new(4., 1., -6.), Vec3::new(5., 1.5, -7.));
             })
             .color(palettes::css::YELLOW)
-            .transform(trans)
+            .transform(trans);
+
+        (shapes, clickable)
     }
 }